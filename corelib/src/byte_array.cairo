@@ -0,0 +1,172 @@
+use array::{ArrayTrait, SpanTrait};
+use bytes_31::{
+    BYTES_IN_BYTES31, Bytes31Trait, one_shift_left_bytes_felt252, split_bytes31, Bytes31IntoFelt252,
+    Felt252TryIntoBytes31
+};
+use clone::Clone;
+use option::OptionTrait;
+use traits::{Into, TryInto};
+
+/// A byte array of any length, built from words of 31 bytes each and a pending word.
+#[derive(Drop, Clone, Serde)]
+struct ByteArray {
+    // Full "words" of 31 bytes each. The first byte of each word in the byte array
+    // is the most significant byte in the word.
+    data: Array<bytes31>,
+    // This felt252 actually represents a bytes31, with < 31 bytes.
+    // It is represented as a felt252 to improve performance of building the byte array.
+    // The first byte is the most significant byte among the `pending_word_len` bytes in the word.
+    pending_word: felt252,
+    // Should be in range [0, 30].
+    pending_word_len: usize,
+}
+
+impl ByteArrayDefault of Default<ByteArray> {
+    fn default() -> ByteArray {
+        ByteArray { data: ArrayTrait::new(), pending_word: 0, pending_word_len: 0 }
+    }
+}
+
+trait ByteArrayTrait {
+    /// Appends a single word of `len` bytes to the end of the ByteArray.
+    /// Note: this function assumes that:
+    /// 1. `word` could be validly converted to a `bytes31` which has no more than `len` bytes
+    ///    of data.
+    /// 2. len <= BYTES_IN_BYTES31.
+    /// If these assumptions are not met, it can corrupt the ByteArray. Thus, this should be a
+    /// private function. We could add masking/assertions but it would be more expensive.
+    fn append_word(ref self: ByteArray, word: felt252, len: usize);
+    /// Appends a byte array to the end of `self`.
+    fn append(ref self: ByteArray, other: @ByteArray);
+    /// Concatenates two byte arrays and returns the result.
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray;
+    /// Appends a single byte to the end of `self`.
+    fn append_byte(ref self: ByteArray, byte: u8);
+    /// Returns the number of bytes in the byte array.
+    fn len(self: @ByteArray) -> usize;
+    /// Returns the byte at the given index, or `None` if the index is out of bounds.
+    fn at(self: @ByteArray, index: usize) -> Option<u8>;
+}
+
+impl ByteArrayImpl of ByteArrayTrait {
+    fn append_word(ref self: ByteArray, word: felt252, len: usize) {
+        if len == 0 {
+            return ();
+        }
+        let total_pending_bytes = self.pending_word_len + len;
+
+        if total_pending_bytes < BYTES_IN_BYTES31 {
+            self.pending_word = word + self.pending_word * one_shift_left_bytes_felt252(len);
+            self.pending_word_len = total_pending_bytes;
+            return ();
+        }
+
+        if total_pending_bytes == BYTES_IN_BYTES31 {
+            let to_append = word + self.pending_word * one_shift_left_bytes_felt252(len);
+            self.data.append(to_append.try_into().unwrap());
+            self.pending_word = 0;
+            self.pending_word_len = 0;
+            return ();
+        }
+
+        // The new word doesn't fit in the pending word - split it into the part completing the
+        // pending word to a full word, and the part that remains pending.
+        let split_index = total_pending_bytes - BYTES_IN_BYTES31;
+        let (first, second) = split_bytes31(word, len, split_index);
+        let to_append = first + self.pending_word * one_shift_left_bytes_felt252(len - split_index);
+        self.data.append(to_append.try_into().unwrap());
+        self.pending_word = second;
+        self.pending_word_len = split_index;
+    }
+
+    fn append(ref self: ByteArray, other: @ByteArray) {
+        let mut other_data = other.data.span();
+        loop {
+            match other_data.pop_front() {
+                Option::Some(word) => {
+                    self.append_word((*word).into(), BYTES_IN_BYTES31);
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        self.append_word(*other.pending_word, *other.pending_word_len);
+    }
+
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray {
+        let mut result = left.clone();
+        result.append(right);
+        result
+    }
+
+    fn append_byte(ref self: ByteArray, byte: u8) {
+        self.append_word(byte.into(), 1);
+    }
+
+    fn len(self: @ByteArray) -> usize {
+        self.data.len() * BYTES_IN_BYTES31 + *self.pending_word_len
+    }
+
+    fn at(self: @ByteArray, index: usize) -> Option<u8> {
+        let word_index = index / BYTES_IN_BYTES31;
+        let index_in_word = index % BYTES_IN_BYTES31;
+        let data_len = self.data.len();
+        if word_index == data_len {
+            // The index is in the pending word.
+            if index_in_word >= *self.pending_word_len {
+                return Option::None(());
+            }
+            // index_in_word is from MSB, we need index from LSB.
+            let pending_bytes31: bytes31 = (*self.pending_word).try_into().unwrap();
+            return Option::Some(pending_bytes31.at(*self.pending_word_len - 1 - index_in_word));
+        }
+        if word_index > data_len {
+            return Option::None(());
+        }
+        // index_in_word is from MSB, we need index from LSB.
+        Option::Some(self.data.at(word_index).at(BYTES_IN_BYTES31 - 1 - index_in_word))
+    }
+}
+
+impl ByteArrayPartialEq of PartialEq<ByteArray> {
+    fn eq(lhs: ByteArray, rhs: ByteArray) -> bool {
+        if lhs.len() != rhs.len() {
+            return false;
+        }
+        if lhs.pending_word != rhs.pending_word {
+            return false;
+        }
+        let mut lhs_data = lhs.data.span();
+        let mut rhs_data = rhs.data.span();
+        loop {
+            match lhs_data.pop_front() {
+                Option::Some(lhs_word) => {
+                    if *lhs_word != *rhs_data.pop_front().unwrap() {
+                        break false;
+                    }
+                },
+                Option::None(_) => {
+                    break true;
+                },
+            };
+        }
+    }
+    #[inline(always)]
+    fn ne(lhs: ByteArray, rhs: ByteArray) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl ByteArrayAdd of Add<ByteArray> {
+    #[inline(always)]
+    fn add(lhs: ByteArray, rhs: ByteArray) -> ByteArray {
+        ByteArrayTrait::concat(@lhs, @rhs)
+    }
+}
+impl ByteArrayAddEq of AddEq<ByteArray> {
+    #[inline(always)]
+    fn add_eq(ref self: ByteArray, other: ByteArray) {
+        self.append(@other);
+    }
+}
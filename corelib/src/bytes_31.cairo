@@ -0,0 +1,129 @@
+use traits::{Into, TryInto};
+use option::OptionTrait;
+use integer::{u128_safe_divmod, u128_as_non_zero, u128_to_felt252, u256_from_felt252};
+use serde::Serde;
+
+const BYTES_IN_BYTES31: usize = 31;
+const BYTES_IN_U128: usize = 16;
+const POW_2_128: felt252 = 0x100000000000000000000000000000000;
+
+#[derive(Copy, Drop)]
+extern type bytes31;
+
+extern fn bytes31_const<const value: felt252>() -> bytes31 nopanic;
+extern fn bytes31_try_from_felt252(value: felt252) -> Option<bytes31> implicits(RangeCheck) nopanic;
+extern fn bytes31_to_felt252(value: bytes31) -> felt252 nopanic;
+
+trait Bytes31Trait {
+    fn at(self: @bytes31, index: usize) -> u8;
+}
+
+impl Bytes31Impl of Bytes31Trait {
+    /// Gets the byte at the given index (LSB's index is 0), assuming that
+    /// `index < BYTES_IN_BYTES31`. If the assumption is not met, the behavior is undefined.
+    fn at(self: @bytes31, index: usize) -> u8 {
+        let u256{low, high } = u256_from_felt252(bytes31_to_felt252(*self));
+        let res_u128 = if index < BYTES_IN_U128 {
+            (low / one_shift_left_bytes_u128(index)) % 0x100
+        } else {
+            (high / one_shift_left_bytes_u128(index - BYTES_IN_U128)) % 0x100
+        };
+        res_u128.try_into().unwrap()
+    }
+}
+
+impl Bytes31IntoFelt252 of Into<bytes31, felt252> {
+    fn into(self: bytes31) -> felt252 {
+        bytes31_to_felt252(self)
+    }
+}
+
+impl Felt252TryIntoBytes31 of TryInto<felt252, bytes31> {
+    fn try_into(self: felt252) -> Option<bytes31> {
+        bytes31_try_from_felt252(self)
+    }
+}
+
+impl Bytes31PartialEq of PartialEq<bytes31> {
+    #[inline(always)]
+    fn eq(lhs: bytes31, rhs: bytes31) -> bool {
+        bytes31_to_felt252(lhs) == bytes31_to_felt252(rhs)
+    }
+    #[inline(always)]
+    fn ne(lhs: bytes31, rhs: bytes31) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+impl Bytes31Serde of Serde<bytes31> {
+    fn serialize(self: @bytes31, ref output: Array<felt252>) {
+        bytes31_to_felt252(*self).serialize(ref output);
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<bytes31> {
+        bytes31_try_from_felt252(Serde::<felt252>::deserialize(ref serialized)?)
+    }
+}
+
+/// Splits a word of `len` bytes (`len <= BYTES_IN_BYTES31`) into two words: the `index` least
+/// significant bytes, and the remaining most significant bytes.
+/// Returns the pair `(high, low)`. Assumes `index <= len`.
+fn split_bytes31(word: felt252, len: usize, index: usize) -> (felt252, felt252) {
+    if index == 0 {
+        return (word, 0);
+    }
+    if index == len {
+        return (0, word);
+    }
+
+    let u256{low, high } = u256_from_felt252(word);
+
+    if index == BYTES_IN_U128 {
+        return (u128_to_felt252(high), u128_to_felt252(low));
+    }
+
+    if len <= BYTES_IN_U128 {
+        let (quotient, remainder) = u128_safe_divmod(
+            low, u128_as_non_zero(one_shift_left_bytes_u128(index))
+        );
+        return (u128_to_felt252(quotient), u128_to_felt252(remainder));
+    }
+
+    // len > BYTES_IN_U128
+    if index < BYTES_IN_U128 {
+        let (low_quotient, low_remainder) = u128_safe_divmod(
+            low, u128_as_non_zero(one_shift_left_bytes_u128(index))
+        );
+        let high_part = u128_to_felt252(high) * one_shift_left_bytes_felt252(BYTES_IN_U128 - index)
+            + u128_to_felt252(low_quotient);
+        return (high_part, u128_to_felt252(low_remainder));
+    }
+
+    // len > BYTES_IN_U128 && index > BYTES_IN_U128
+    let (high_quotient, high_remainder) = u128_safe_divmod(
+        high, u128_as_non_zero(one_shift_left_bytes_u128(index - BYTES_IN_U128))
+    );
+    let low_part = u128_to_felt252(high_remainder) * POW_2_128 + u128_to_felt252(low);
+    (u128_to_felt252(high_quotient), low_part)
+}
+
+/// Returns `1 << (8 * n_bytes)` as felt252, assuming that `n_bytes < BYTES_IN_BYTES31`.
+fn one_shift_left_bytes_felt252(n_bytes: usize) -> felt252 {
+    if n_bytes < BYTES_IN_U128 {
+        u128_to_felt252(one_shift_left_bytes_u128(n_bytes))
+    } else {
+        u128_to_felt252(one_shift_left_bytes_u128(n_bytes - BYTES_IN_U128)) * POW_2_128
+    }
+}
+
+/// Returns `1 << (8 * n_bytes)` as u128, assuming that `n_bytes < BYTES_IN_U128`.
+fn one_shift_left_bytes_u128(n_bytes: usize) -> u128 {
+    let mut result = 1_u128;
+    let mut remaining = n_bytes;
+    loop {
+        if remaining == 0 {
+            break result;
+        }
+        result = result * 0x100;
+        remaining -= 1;
+    }
+}
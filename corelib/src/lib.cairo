@@ -183,6 +183,14 @@ type usize = u32;
 // Span.
 use array::Span;
 
+// Bytes31.
+mod bytes_31;
+use bytes_31::{bytes31, bytes31_const, Bytes31IntoFelt252, Bytes31Trait, Felt252TryIntoBytes31};
+
+// ByteArray.
+mod byte_array;
+use byte_array::{ByteArray, ByteArrayTrait};
+
 
 // Dictionary.
 mod dict;
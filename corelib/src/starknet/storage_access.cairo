@@ -1,3 +1,7 @@
+use array::{ArrayTrait, SpanTrait};
+use byte_array::ByteArray;
+use bytes_31::{BYTES_IN_BYTES31, Bytes31IntoFelt252, Felt252TryIntoBytes31};
+use poseidon::{hades_permutation, poseidon_hash_span};
 use traits::{Into, TryInto};
 use option::OptionTrait;
 use starknet::{
@@ -52,9 +56,35 @@ impl StorageAddressSerde of serde::Serde<StorageAddress> {
     }
 }
 
+/// Returns the address of the entry of `key` in a `Map` storage variable at address `base`.
+///
+/// The address is `poseidon_hash_span([base, ..serialized_key])`, where the key is serialized using
+/// `Serde`. For nested mappings (e.g. `Map<K0, Map<K1, V>>`), the address of the entry of `key0` is
+/// used as the base for `key1`. The final address is converted into a `StorageBaseAddress` using
+/// `storage_base_address_from_felt252`.
+fn storage_map_entry_address<K, impl KSerde: Serde<K>>(base: felt252, key: @K) -> felt252 {
+    let mut serialized = ArrayTrait::new();
+    serialized.append(base);
+    key.serialize(ref serialized);
+    poseidon_hash_span(serialized.span())
+}
+
+/// Reading and writing values of type `T` from and to the storage.
+///
+/// A value is stored in `size_internal()` consecutive storage slots. The `_at_offset_internal`
+/// functions access a value stored starting at `offset` slots from `base`, so that values can be
+/// laid out one after the other, as done for the members of structs deriving
+/// `starknet::StorageAccess`.
 trait StorageAccess<T> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<T>;
     fn write(address_domain: u32, base: StorageBaseAddress, value: T) -> SyscallResult<()>;
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<T>;
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: T
+    ) -> SyscallResult<()>;
+    fn size_internal() -> u8;
 }
 
 impl StorageAccessFelt252 of StorageAccess<felt252> {
@@ -66,6 +96,24 @@ impl StorageAccessFelt252 of StorageAccess<felt252> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: felt252) -> SyscallResult<()> {
         storage_write_syscall(address_domain, storage_address_from_base(base), value)
     }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<felt252> {
+        storage_read_syscall(address_domain, storage_address_from_base_and_offset(base, offset))
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: felt252
+    ) -> SyscallResult<()> {
+        storage_write_syscall(
+            address_domain, storage_address_from_base_and_offset(base, offset), value
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessBool of StorageAccess<bool> {
@@ -80,6 +128,29 @@ impl StorageAccessBool of StorageAccess<bool> {
             0
         })
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<bool> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)? != 0
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: bool
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, if value {
+                1
+            } else {
+                0
+            }
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU8 of StorageAccess<u8> {
@@ -94,6 +165,27 @@ impl StorageAccessU8 of StorageAccess<u8> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: u8) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u8> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessU8 - non u8')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u8
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU16 of StorageAccess<u16> {
@@ -108,6 +200,27 @@ impl StorageAccessU16 of StorageAccess<u16> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: u16) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u16> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessU16 - non u16')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u16
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU32 of StorageAccess<u32> {
@@ -122,6 +235,27 @@ impl StorageAccessU32 of StorageAccess<u32> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: u32) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u32> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessU32 - non u32')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u32
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU64 of StorageAccess<u64> {
@@ -136,6 +270,27 @@ impl StorageAccessU64 of StorageAccess<u64> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: u64) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u64> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessU64 - non u64')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u64
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU128 of StorageAccess<u128> {
@@ -150,6 +305,27 @@ impl StorageAccessU128 of StorageAccess<u128> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: u128) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u128> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('StorageAccessU128 - non u128')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u128
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU256 of StorageAccess<u256> {
@@ -171,6 +347,30 @@ impl StorageAccessU256 of StorageAccess<u256> {
             address_domain, storage_address_from_base_and_offset(base, 1_u8), value.high.into()
         )
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u256> {
+        Result::Ok(
+            u256 {
+                low: StorageAccess::<u128>::read_at_offset_internal(address_domain, base, offset)?,
+                high: StorageAccess::<u128>::read_at_offset_internal(
+                    address_domain, base, offset + 1_u8
+                )?
+            }
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u256
+    ) -> SyscallResult<()> {
+        StorageAccess::<u128>::write_at_offset_internal(address_domain, base, offset, value.low)?;
+        StorageAccess::<u128>::write_at_offset_internal(
+            address_domain, base, offset + 1_u8, value.high
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        2_u8
+    }
 }
 
 impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
@@ -187,6 +387,27 @@ impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     ) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<StorageAddress> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('Non StorageAddress')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: StorageAddress
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
@@ -203,6 +424,27 @@ impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
     ) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<ContractAddress> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('Non ContractAddress')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ContractAddress
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessClassHash of StorageAccess<ClassHash> {
@@ -215,4 +457,128 @@ impl StorageAccessClassHash of StorageAccess<ClassHash> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: ClassHash) -> SyscallResult<()> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<ClassHash> {
+        Result::Ok(
+            StorageAccess::<felt252>::read_at_offset_internal(address_domain, base, offset)?
+                .try_into()
+                .expect('Non ClassHash')
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ClassHash
+    ) -> SyscallResult<()> {
+        StorageAccess::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
+/// The number of storage slots in a single chunk of a stored `ByteArray`.
+const BYTE_ARRAY_CHUNK_SIZE: usize = 256;
+
+/// Stores a `ByteArray` as its length at `base` (and `offset`), followed by its words (the full
+/// words and then the pending word), in chunks of `BYTE_ARRAY_CHUNK_SIZE` storage slots. The base
+/// address of each chunk is derived from the address of the length and the chunk index, so it
+/// doesn't collide with other variables.
+impl StorageAccessByteArray of StorageAccess<ByteArray> {
+    #[inline(always)]
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<ByteArray> {
+        StorageAccess::<ByteArray>::read_at_offset_internal(address_domain, base, 0_u8)
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: ByteArray) -> SyscallResult<()> {
+        StorageAccess::<ByteArray>::write_at_offset_internal(address_domain, base, 0_u8, value)
+    }
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<ByteArray> {
+        let address = storage_address_from_base_and_offset(base, offset);
+        let len: usize = storage_read_syscall(address_domain, address)?
+            .try_into()
+            .expect('Invalid ByteArray length');
+        let n_full_words = len / BYTES_IN_BYTES31;
+        let mut data = ArrayTrait::new();
+        let mut word_index = 0;
+        let read_result = loop {
+            if word_index == n_full_words {
+                break Result::Ok(());
+            }
+            match storage_read_syscall(
+                address_domain, byte_array_word_address(address, word_index)
+            ) {
+                Result::Ok(word) => {
+                    data.append(word.try_into().expect('Non bytes31'));
+                },
+                Result::Err(err) => {
+                    break Result::Err(err);
+                },
+            };
+            word_index += 1;
+        };
+        read_result?;
+        let pending_word = storage_read_syscall(
+            address_domain, byte_array_word_address(address, n_full_words)
+        )?;
+        Result::Ok(
+            ByteArray {
+                data, pending_word, pending_word_len: len - n_full_words * BYTES_IN_BYTES31
+            }
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ByteArray
+    ) -> SyscallResult<()> {
+        let address = storage_address_from_base_and_offset(base, offset);
+        let ByteArray{data, pending_word, pending_word_len } = value;
+        let n_full_words = data.len();
+        storage_write_syscall(
+            address_domain, address, (n_full_words * BYTES_IN_BYTES31 + pending_word_len).into()
+        )?;
+        let mut data = data.span();
+        let mut word_index = 0;
+        let write_result = loop {
+            match data.pop_front() {
+                Option::Some(word) => {
+                    match storage_write_syscall(
+                        address_domain, byte_array_word_address(address, word_index), (*word).into()
+                    ) {
+                        Result::Ok(()) => {},
+                        Result::Err(err) => {
+                            break Result::Err(err);
+                        },
+                    };
+                },
+                Option::None(_) => {
+                    break Result::Ok(());
+                },
+            };
+            word_index += 1;
+        };
+        write_result?;
+        storage_write_syscall(
+            address_domain, byte_array_word_address(address, n_full_words), pending_word
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
+/// Returns the storage address of the word at `word_index` of a `ByteArray` whose length is stored
+/// at `address`.
+fn byte_array_word_address(address: StorageAddress, word_index: usize) -> StorageAddress {
+    let chunk = word_index / BYTE_ARRAY_CHUNK_SIZE;
+    let (chunk_base, _, _) = hades_permutation(address.into(), chunk.into(), 'ByteArray');
+    storage_address_from_base_and_offset(
+        storage_base_address_from_felt252(chunk_base),
+        (word_index % BYTE_ARRAY_CHUNK_SIZE).try_into().unwrap()
+    )
 }
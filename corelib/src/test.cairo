@@ -1,12 +1,17 @@
 mod array_test;
 mod bool_test;
 mod box_test;
+mod byte_array_test;
 mod dict_test;
 mod ec_test;
 mod felt_test;
 mod cmp_test;
+mod const_test;
 mod hash_test;
 mod integer_test;
 mod keccak_test;
+mod loop_test;
+mod match_test;
 mod plugins_test;
 mod testing_test;
+mod trait_items_test;
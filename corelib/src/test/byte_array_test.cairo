@@ -0,0 +1,140 @@
+use array::{ArrayTrait, SpanTrait};
+use byte_array::{ByteArray, ByteArrayTrait};
+use bytes_31::Bytes31IntoFelt252;
+use clone::Clone;
+use option::OptionTrait;
+use serde::Serde;
+use traits::{Into, TryInto};
+
+#[test]
+fn test_short_string_literal() {
+    let ba: ByteArray = "abc";
+    assert(ba.len() == 3, 'wrong len');
+    assert(ba.data.len() == 0, 'wrong data len');
+    assert(ba.pending_word == 'abc', 'wrong pending word');
+    assert(ba.pending_word_len == 3, 'wrong pending word len');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_empty_string_literal() {
+    let ba: ByteArray = "";
+    assert(ba.len() == 0, 'wrong len');
+    assert(ba == Default::default(), 'not empty');
+}
+
+#[test]
+fn test_long_string_literal() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!";
+    assert(ba.len() == 63, 'wrong len');
+    assert(ba.data.len() == 2, 'wrong data len');
+    assert((*ba.data[0]).into() == 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcde', 'wrong first word');
+    assert((*ba.data[1]).into() == 'fghijklmnopqrstuvwxyz0123456789', 'wrong second word');
+    assert(ba.pending_word == '!', 'wrong pending word');
+    assert(ba.pending_word_len == 1, 'wrong pending word len');
+}
+
+#[test]
+fn test_string_literal_of_full_words() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcde";
+    assert(ba.len() == 31, 'wrong len');
+    assert(ba.data.len() == 1, 'wrong data len');
+    assert(ba.pending_word == 0, 'wrong pending word');
+    assert(ba.pending_word_len == 0, 'wrong pending word len');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_append_byte() {
+    let mut ba: ByteArray = Default::default();
+    let mut c: felt252 = 'A';
+    loop {
+        if c == 'A' + 40 {
+            break ();
+        }
+        ba.append_byte(c.try_into().unwrap());
+        c += 1;
+    };
+    assert(ba.clone() == "ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefgh", 'wrong content');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_append_word() {
+    let mut ba: ByteArray = "ABCDEFGHIJKLMNOPQRST";
+    ba.append_word('UVWXYZabcdefghijklm', 19);
+    assert(ba.clone() == "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklm", 'wrong content');
+    assert(ba.data.len() == 1, 'wrong data len');
+    assert(ba.pending_word == 'fghijklm', 'wrong pending word');
+    assert(ba.pending_word_len == 8, 'wrong pending word len');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_append() {
+    let mut ba1: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrst";
+    let ba2: ByteArray = "uvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    ba1.append(@ba2);
+    let expected: ByteArray =
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    assert(ba1.clone() == expected, 'wrong content');
+    assert(ba1.len() == 88, 'wrong len');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_concat() {
+    let ba1: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrst";
+    let ba2: ByteArray = "uvwxyz";
+    let ba3 = ByteArrayTrait::concat(@ba1, @ba2);
+    assert(ba3.clone() == "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz", 'wrong concat');
+    assert(ba1 + ba2 == ba3, 'wrong add');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_add_eq() {
+    let mut ba: ByteArray = "ABC";
+    ba += "DEF";
+    assert(ba == "ABCDEF", 'wrong content');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_at() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    assert(ba.at(0).unwrap() == 'A', 'wrong byte at 0');
+    assert(ba.at(15).unwrap() == 'P', 'wrong byte at 15');
+    assert(ba.at(16).unwrap() == 'Q', 'wrong byte at 16');
+    assert(ba.at(30).unwrap() == 'e', 'wrong byte at 30');
+    assert(ba.at(31).unwrap() == 'f', 'wrong byte at 31');
+    assert(ba.at(51).unwrap() == 'z', 'wrong byte at 51');
+    assert(ba.at(52).is_none(), 'index 52 out of bounds');
+    assert(ba.at(100).is_none(), 'index 100 out of bounds');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_eq() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    assert(ba.clone() == "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz", 'should be equal');
+    assert(ba.clone() != "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxy", 'different length');
+    assert(ba.clone() != "aBCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz", 'different data');
+    assert(ba != "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyZ", 'different pending');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_serde() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let mut serialized = ArrayTrait::new();
+    ba.serialize(ref serialized);
+    assert(serialized.len() == 4, 'wrong serialized len');
+    assert(*serialized[0] == 1, 'wrong data len');
+    assert(*serialized[1] == 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcde', 'wrong first word');
+    assert(*serialized[2] == 'fghijklmnopqrstuvwxyz', 'wrong pending word');
+    assert(*serialized[3] == 21, 'wrong pending word len');
+    let mut span = serialized.span();
+    let deserialized: ByteArray = Serde::deserialize(ref span).unwrap();
+    assert(deserialized == ba, 'wrong deserialized');
+}
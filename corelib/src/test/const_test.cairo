@@ -0,0 +1,52 @@
+use option::OptionTrait;
+
+#[derive(Copy, Drop)]
+struct FeeConfig {
+    numerator: u128,
+    denominator: u128,
+}
+
+const DECIMALS: u128 = 6;
+const UNIT: u128 = 1000000;
+const MAX_SUPPLY: u256 = 21000000 * 1000000 * 1000000000000;
+const FEE: FeeConfig = FeeConfig { numerator: 3 * UNIT / 1000, denominator: UNIT };
+const FEE_AND_MASK: (FeeConfig, u128) = (FEE, 0xff & (UNIT - 1));
+const SELECTOR: felt252 = 'transfer' * 2 - 1;
+const MINUS_ONE: felt252 = -1;
+const MAYBE_DECIMALS: Option<u128> = Option::Some(DECIMALS + 2);
+const SCALED_FEE: u128 = scale(FEE, 1000000000);
+const IS_CHEAP: bool = SCALED_FEE < UNIT * 1000;
+
+const fn scale(fee: FeeConfig, amount: u128) -> u128 {
+    let FeeConfig { numerator, denominator } = fee;
+    if denominator == 0 {
+        0
+    } else {
+        amount * numerator / denominator
+    }
+}
+
+#[test]
+fn test_const_arithmetic() {
+    assert(UNIT == 1000000, 'wrong unit');
+    assert(MAX_SUPPLY == 21000000000000000000000000_u256, 'wrong max supply');
+    assert(SELECTOR == 'transfer' + 'transfer' - 1, 'wrong selector');
+    assert(MINUS_ONE + 1 == 0, 'wrong minus one');
+}
+
+#[test]
+fn test_const_composites() {
+    assert(FEE.numerator == 3000, 'wrong fee numerator');
+    assert(FEE.denominator == UNIT, 'wrong fee denominator');
+    let (fee, mask) = FEE_AND_MASK;
+    assert(fee.numerator == FEE.numerator, 'wrong tuple fee');
+    assert(mask == 0x3f, 'wrong mask');
+    assert(MAYBE_DECIMALS.unwrap() == 8, 'wrong option');
+}
+
+#[test]
+fn test_const_functions() {
+    assert(SCALED_FEE == 3000000, 'wrong scaled fee');
+    assert(IS_CHEAP, 'wrong comparison');
+    assert(scale(FEE, 2000) == SCALED_FEE / 500000, 'wrong runtime call');
+}
@@ -0,0 +1,69 @@
+#[derive(Copy, Drop)]
+struct Inner {
+    value: u32,
+    flag: bool,
+}
+
+#[derive(Copy, Drop)]
+struct Counter {
+    count: u32,
+    step: u32,
+    inner: Inner,
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_loop_member_mutation() {
+    let mut c = Counter { count: 0, step: 2, inner: Inner { value: 0, flag: false } };
+    loop {
+        if c.count >= 10 {
+            break ();
+        }
+        c.count += c.step;
+    };
+    assert(c.count == 10, 'wrong count');
+    assert(c.step == 2, 'wrong step');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_loop_member_mutation_in_branches() {
+    let mut c = Counter { count: 0, step: 1, inner: Inner { value: 0, flag: false } };
+    loop {
+        if c.count == 10 {
+            break ();
+        }
+        if c.inner.flag {
+            c.inner.value += 1;
+        } else {
+            c.inner.flag = true;
+        };
+        c.count += c.step;
+    };
+    assert(c.count == 10, 'wrong count');
+    assert(c.inner.value == 9, 'wrong inner value');
+    assert(c.inner.flag, 'wrong inner flag');
+}
+
+fn bump(ref self: Counter, n: u32) {
+    let mut i = 0;
+    loop {
+        if i == n {
+            break ();
+        }
+        match i % 2 {
+            0 => self.count += 1,
+            _ => self.inner.value += 1,
+        }
+        i += 1;
+    }
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_loop_ref_member_mutation() {
+    let mut c = Counter { count: 0, step: 1, inner: Inner { value: 0, flag: false } };
+    bump(ref c, 5);
+    assert(c.count == 3, 'wrong count');
+    assert(c.inner.value == 2, 'wrong inner value');
+}
@@ -0,0 +1,193 @@
+use array::ArrayTrait;
+
+#[derive(Copy, Drop)]
+enum State {
+    Idle: (),
+    Running: u32,
+    Paused: u32,
+    Done: (),
+}
+
+#[derive(Drop)]
+enum Event {
+    Start: (),
+    Pause: (),
+    Resume: (),
+    Stop: (),
+}
+
+fn step(state: State, event: Event) -> State {
+    match (state, event) {
+        (State::Idle(()), Event::Start(())) => State::Running(0),
+        (State::Running(t), Event::Pause(())) => State::Paused(t),
+        (State::Paused(t), Event::Resume(())) => State::Running(t + 1),
+        (State::Running(_), Event::Stop(()))
+            | (State::Paused(_), Event::Stop(())) => State::Done(()),
+        (s, _) => s,
+    }
+}
+
+fn code(state: @State) -> felt252 {
+    match state {
+        State::Done(_) => 4,
+        State::Idle(_) => 1,
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_match_wildcard_and_order() {
+    assert(code(@State::Idle(())) == 1, 'wrong idle code');
+    assert(code(@State::Running(3)) == 0, 'wrong running code');
+    assert(code(@State::Done(())) == 4, 'wrong done code');
+}
+
+#[test]
+fn test_match_tuple_patterns() {
+    let s = step(State::Idle(()), Event::Start(()));
+    let s = step(s, Event::Pause(()));
+    let s = step(s, Event::Resume(()));
+    match s {
+        State::Running(t) => assert(t == 1, 'wrong running time'),
+        _ => assert(false, 'expected running'),
+    }
+    let s = step(s, Event::Start(()));
+    let s = step(s, Event::Stop(()));
+    assert(code(@s) == 4, 'expected done');
+}
+
+fn unwrap_nested(x: Option<Option<u32>>) -> u32 {
+    match x {
+        Option::Some(Option::Some(v)) => v,
+        Option::Some(Option::None(())) | Option::None(()) => 0,
+    }
+}
+
+#[test]
+fn test_match_nested_patterns() {
+    assert(unwrap_nested(Option::Some(Option::Some(3))) == 3, 'wrong nested some');
+    assert(unwrap_nested(Option::Some(Option::None(()))) == 0, 'wrong nested none');
+    assert(unwrap_nested(Option::None(())) == 0, 'wrong none');
+}
+
+#[derive(Drop)]
+struct Pair {
+    a: Option<u32>,
+    b: u32,
+}
+
+fn pair_sum(p: Pair) -> u32 {
+    match p {
+        Pair{a: Option::Some(a), b } => a + b,
+        other => other.b,
+    }
+}
+
+fn array_len(x: Option<Array<felt252>>) -> usize {
+    match x {
+        Option::Some(arr) => arr.len(),
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_match_struct_patterns() {
+    assert(pair_sum(Pair { a: Option::Some(1), b: 2 }) == 3, 'wrong some sum');
+    assert(pair_sum(Pair { a: Option::None(()), b: 2 }) == 2, 'wrong none sum');
+    let mut arr = ArrayTrait::new();
+    arr.append(1);
+    arr.append(2);
+    assert(array_len(Option::Some(arr)) == 2, 'wrong array len');
+    assert(array_len(Option::None(())) == 0, 'wrong none len');
+}
+
+fn saturating_add(a: u128, b: u128) -> u128 {
+    match integer::u128_overflowing_add(a, b) {
+        Result::Err(_) => 0xffffffffffffffffffffffffffffffff,
+        Result::Ok(x) => x,
+    }
+}
+
+#[test]
+fn test_match_extern_out_of_order() {
+    assert(saturating_add(1, 2) == 3, 'wrong sum');
+    assert(
+        saturating_add(0xffffffffffffffffffffffffffffffff, 2) == 0xffffffffffffffffffffffffffffffff,
+        'wrong saturated sum'
+    );
+}
+
+fn opcode_name(op: felt252) -> felt252 {
+    match op {
+        0 => 'nop',
+        1 | 2 => 'push',
+        'add' => 'add',
+        _ => 'unknown',
+    }
+}
+
+#[test]
+fn test_match_felt252_literals() {
+    assert(opcode_name(0) == 'nop', 'wrong nop');
+    assert(opcode_name(2) == 'push', 'wrong push');
+    assert(opcode_name('add') == 'add', 'wrong add');
+    assert(opcode_name(3) == 'unknown', 'wrong unknown');
+}
+
+fn digit_count(x: u8) -> u8 {
+    match x {
+        0..=9 => 1,
+        10..=99 => 2,
+        _ => 3,
+    }
+}
+
+fn half(x: u8) -> felt252 {
+    match x {
+        0..=127 => 0,
+        128..=255 => 1,
+    }
+}
+
+fn bucket(x: (u32, Option<u64>)) -> felt252 {
+    match x {
+        (0, _) => 0,
+        (1..=1000, Option::Some(0..=9)) => 1,
+        (_, Option::Some(_)) => 2,
+        (_, Option::None(())) => 3,
+    }
+}
+
+#[test]
+fn test_match_integer_ranges() {
+    assert(digit_count(0) == 1, 'wrong 0 digits');
+    assert(digit_count(9) == 1, 'wrong 9 digits');
+    assert(digit_count(10) == 2, 'wrong 10 digits');
+    assert(digit_count(255) == 3, 'wrong 255 digits');
+    assert(half(127) == 0, 'wrong low half');
+    assert(half(128) == 1, 'wrong high half');
+    assert(bucket((0, Option::None(()))) == 0, 'wrong zero bucket');
+    assert(bucket((1000, Option::Some(9))) == 1, 'wrong range bucket');
+    assert(bucket((1001, Option::Some(9))) == 2, 'wrong some bucket');
+    assert(bucket((5, Option::None(()))) == 3, 'wrong none bucket');
+}
+
+fn felt252_class(x: felt252) -> felt252 {
+    match x {
+        0..=9 => 0,
+        10..=0xffffffffffffffffffffffffffffffff => 1,
+        0x100000000000000000000000000000000..=0x800000000000010ffffffffffffffffffffffffffffffffffffffffffffffff => 2,
+        0x800000000000011000000000000000000000000000000000000000000000000 => 3,
+    }
+}
+
+#[test]
+fn test_match_felt252_ranges() {
+    assert(felt252_class(0) == 0, 'wrong 0 class');
+    assert(felt252_class(9) == 0, 'wrong 9 class');
+    assert(felt252_class(10) == 1, 'wrong 10 class');
+    assert(felt252_class(0xffffffffffffffffffffffffffffffff) == 1, 'wrong u128 max class');
+    assert(felt252_class(0x100000000000000000000000000000000) == 2, 'wrong 2^128 class');
+    assert(felt252_class(-2) == 2, 'wrong -2 class');
+    assert(felt252_class(-1) == 3, 'wrong -1 class');
+}
@@ -0,0 +1,130 @@
+use option::OptionTrait;
+
+trait Token<T> {
+    type Balance;
+    const DECIMALS: u8;
+    fn unit(self: @T) -> Self::Balance;
+}
+
+#[derive(Copy, Drop)]
+struct Eth {}
+
+#[derive(Copy, Drop)]
+struct Usdc {}
+
+impl EthToken of Token<Eth> {
+    type Balance = u256;
+    const DECIMALS: u8 = 18;
+    fn unit(self: @Eth) -> u256 {
+        1000000000000000000
+    }
+}
+
+impl UsdcToken of Token<Usdc> {
+    type Balance = u128;
+    const DECIMALS: u8 = 6;
+    fn unit(self: @Usdc) -> Self::Balance {
+        1000000
+    }
+}
+
+fn decimals<T, impl TToken: Token<T>>(token: @T) -> u8 {
+    TToken::DECIMALS
+}
+
+fn unit_of<T, impl TToken: Token<T>>(token: @T) -> TToken::Balance {
+    token.unit()
+}
+
+#[test]
+fn test_impl_constants() {
+    assert(EthToken::DECIMALS == 18, 'wrong eth decimals');
+    assert(UsdcToken::DECIMALS + 1 == 7, 'wrong usdc decimals');
+    assert(decimals(@Eth {}) == 18, 'wrong generic eth decimals');
+    assert(decimals(@Usdc {}) == 6, 'wrong generic usdc decimals');
+}
+
+#[test]
+fn test_impl_types() {
+    let eth_unit: EthToken::Balance = Eth {}.unit();
+    assert(eth_unit == 1000000000000000000_u256, 'wrong eth unit');
+    let usdc_unit: u128 = Usdc {}.unit();
+    assert(usdc_unit == 1000000, 'wrong usdc unit');
+}
+
+#[test]
+fn test_generic_impl_types() {
+    assert(unit_of(@Usdc {}) == 1000000, 'wrong generic usdc unit');
+    assert(unit_of(@Eth {}) == 1000000000000000000, 'wrong generic eth unit');
+}
+
+#[test]
+fn test_trait_paths() {
+    assert(Token::<Usdc>::DECIMALS == 6, 'wrong trait path decimals');
+    let balance: Token::<Eth>::Balance = 5;
+    assert(balance == 5_u256, 'wrong trait path balance');
+}
+
+#[derive(Copy, Drop)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+trait Shape<T> {
+    const ORIGIN: Point;
+    const CLOSED: bool;
+    const AREA: u256;
+    const SIDES: Option<u8>;
+    const DEFAULT: T;
+}
+
+impl SquareShape of Shape<u16> {
+    const ORIGIN: Point = Point { x: 1, y: 2 };
+    const CLOSED: bool = true;
+    const AREA: u256 = 0x100000000000000000000000000000001;
+    const SIDES: Option<u8> = Option::Some(4);
+    const DEFAULT: u16 = 7;
+}
+
+impl LineShape of Shape<(felt252, bool)> {
+    const ORIGIN: Point = Point { x: 0, y: 0 };
+    const CLOSED: bool = false;
+    const AREA: u256 = 0;
+    const SIDES: Option<u8> = Option::None(());
+    const DEFAULT: (felt252, bool) = (5, true);
+}
+
+fn shape_info<T, impl TShape: Shape<T>>(value: @T) -> (Point, bool, u256, Option<u8>) {
+    (TShape::ORIGIN, TShape::CLOSED, TShape::AREA, TShape::SIDES)
+}
+
+fn shape_default<T, impl TShape: Shape<T>>(value: @T) -> T {
+    TShape::DEFAULT
+}
+
+#[test]
+fn test_impl_constants_of_any_type() {
+    assert(SquareShape::ORIGIN.y == 2, 'wrong square origin');
+    assert(SquareShape::CLOSED, 'wrong square closed');
+    assert(SquareShape::AREA.high == 1, 'wrong square area');
+    assert(SquareShape::SIDES.unwrap() == 4, 'wrong square sides');
+    assert(SquareShape::DEFAULT == 7, 'wrong square default');
+    let (default, flag) = LineShape::DEFAULT;
+    assert(default == 5 & flag, 'wrong line default');
+}
+
+#[test]
+fn test_generic_impl_constants_of_any_type() {
+    let (origin, closed, area, sides) = shape_info(@0_u16);
+    assert(origin.x == 1 & origin.y == 2, 'wrong generic square origin');
+    assert(closed, 'wrong generic square closed');
+    assert(area.low == 1 & area.high == 1, 'wrong generic square area');
+    assert(sides.unwrap() == 4, 'wrong generic square sides');
+    assert(shape_default(@0_u16) == 7, 'wrong generic square default');
+    let (origin, closed, area, sides) = shape_info(@(0, false));
+    assert(origin.x == 0 & !closed & area == 0, 'wrong generic line info');
+    assert(sides.is_none(), 'wrong generic line sides');
+    let (default, flag) = shape_default(@(0, false));
+    assert(default == 5 & flag, 'wrong generic line default');
+}
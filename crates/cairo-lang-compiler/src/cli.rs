@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_filesystem::flag::OptimizationLevel;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -18,6 +19,9 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// The level of optimizations to apply: 0, 1 or 2.
+    #[arg(short = 'O', long, default_value = "1")]
+    optimization_level: OptimizationLevel,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -28,7 +32,11 @@ fn main() -> anyhow::Result<()> {
 
     let sierra_program = compile_cairo_project_at_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            replace_ids: args.replace_ids,
+            optimization_level: args.optimization_level,
+            ..CompilerConfig::default()
+        },
     )?;
 
     match args.output {
@@ -1,3 +1,4 @@
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
@@ -34,7 +35,43 @@ use crate::project::update_crate_roots_from_project_config;
 pub struct RootDatabase {
     storage: salsa::Storage<RootDatabase>,
 }
-impl salsa::Database for RootDatabase {}
+impl salsa::Database for RootDatabase {
+    /// Cancels the computations on snapshots of the database once a change of it is pending, by
+    /// unwinding with [Cancelled] before executing any query.
+    fn salsa_event(&self, event: salsa::Event) {
+        if matches!(event.kind, salsa::EventKind::WillExecute { .. })
+            && self.salsa_runtime().is_current_revision_canceled()
+        {
+            Cancelled::throw();
+        }
+    }
+
+    /// A computation waiting for a query computed by another thread that panicked (usually by
+    /// being cancelled) is cancelled as well.
+    fn on_propagated_panic(&self) -> ! {
+        Cancelled::throw();
+    }
+}
+
+/// The payload of the unwinding of a computation on a snapshot of a [RootDatabase], cancelled
+/// since a change of the database is pending. See [Cancelled::catch].
+#[derive(Debug)]
+pub struct Cancelled;
+impl Cancelled {
+    fn throw() -> ! {
+        // Resuming the unwinding does not invoke the panic hook, so nothing is printed.
+        panic::resume_unwind(Box::new(Cancelled))
+    }
+
+    /// Runs a computation on a snapshot of the database, returning `Err(Cancelled)` if it was
+    /// cancelled by a change of the database. Other panics are propagated.
+    pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Cancelled> {
+        panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match payload.downcast() {
+            Ok(cancelled) => *cancelled,
+            Err(payload) => panic::resume_unwind(payload),
+        })
+    }
+}
 impl RootDatabase {
     fn new(plugins: Vec<Arc<dyn SemanticPlugin>>) -> Self {
         let mut res = Self { storage: Default::default() };
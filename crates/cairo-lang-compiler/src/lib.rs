@@ -7,7 +7,9 @@ use std::sync::Arc;
 
 use ::cairo_lang_diagnostics::ToOption;
 use anyhow::{Context, Result};
-use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::flag::{Flag, OptimizationLevel};
+use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
@@ -30,6 +32,9 @@ pub struct CompilerConfig<'c> {
     /// The name of the allowed libfuncs list to use in compilation.
     /// If None the default list of audited libfuncs will be used.
     pub allowed_libfuncs_list_name: Option<String>,
+
+    /// The level of optimizations applied by the compiler.
+    pub optimization_level: OptimizationLevel,
 }
 
 /// The default compiler configuration.
@@ -39,6 +44,7 @@ impl Default for CompilerConfig<'static> {
             diagnostics_reporter: DiagnosticsReporter::default(),
             replace_ids: false,
             allowed_libfuncs_list_name: None,
+            optimization_level: OptimizationLevel::default(),
         }
     }
 }
@@ -99,6 +105,10 @@ pub fn compile_prepared_db(
     main_crate_ids: Vec<CrateId>,
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<SierraProgram> {
+    db.set_flag(
+        FlagId::new(db, "optimization_level"),
+        Some(Arc::new(Flag::OptimizationLevel(compiler_config.optimization_level))),
+    );
     compiler_config.diagnostics_reporter.ensure(db)?;
 
     let mut sierra_program = db
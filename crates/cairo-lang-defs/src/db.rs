@@ -7,6 +7,7 @@ use cairo_lang_filesystem::ids::{CrateId, Directory, FileId, FileLongId, Virtual
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::GetDocComments;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -37,6 +38,10 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_impl_function(&self, id: ImplFunctionLongId) -> ImplFunctionId;
     #[salsa::interned]
+    fn intern_impl_type_def(&self, id: ImplTypeDefLongId) -> ImplTypeDefId;
+    #[salsa::interned]
+    fn intern_impl_constant_def(&self, id: ImplConstantDefLongId) -> ImplConstantDefId;
+    #[salsa::interned]
     fn intern_struct(&self, id: StructLongId) -> StructId;
     #[salsa::interned]
     fn intern_enum(&self, id: EnumLongId) -> EnumId;
@@ -53,6 +58,10 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_trait_function(&self, id: TraitFunctionLongId) -> TraitFunctionId;
     #[salsa::interned]
+    fn intern_trait_type(&self, id: TraitTypeLongId) -> TraitTypeId;
+    #[salsa::interned]
+    fn intern_trait_constant(&self, id: TraitConstantLongId) -> TraitConstantId;
+    #[salsa::interned]
     fn intern_impl(&self, id: ImplDefLongId) -> ImplDefId;
     #[salsa::interned]
     fn intern_extern_type(&self, id: ExternTypeLongId) -> ExternTypeId;
@@ -146,6 +155,9 @@ pub trait DefsGroup:
         &self,
         module_id: ModuleId,
     ) -> Maybe<Vec<(ModuleFileId, PluginDiagnostic)>>;
+
+    /// Returns the documentation of an item, written in its doc comments, if any.
+    fn documentation(&self, item_id: DocumentableItemId) -> Option<String>;
 }
 
 pub trait HasMacroPlugins {
@@ -652,3 +664,11 @@ fn module_item_name_stable_ptr(
         }
     })
 }
+
+fn documentation(db: &dyn DefsGroup, item_id: DocumentableItemId) -> Option<String> {
+    let file_id = db.module_file(item_id.module_file_id(db)).ok()?;
+    let syntax_file = db.file_syntax(file_id).ok()?;
+    let item_node =
+        syntax_file.as_syntax_node().lookup_ptr(db.upcast(), item_id.untyped_stable_ptr(db));
+    item_node.doc_comments(db.upcast())
+}
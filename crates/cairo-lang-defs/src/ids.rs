@@ -281,6 +281,7 @@ pub struct FileIndex(pub usize);
 pub struct ModuleFileId(pub ModuleId, pub FileIndex);
 
 define_language_element_id_as_enum! {
+    #[toplevel]
     /// Id for direct children of a module.
     pub enum ModuleItemId {
         Constant(ConstantId),
@@ -367,6 +368,72 @@ impl TopLevelLanguageElementId for ImplFunctionId {
     }
 }
 
+define_language_element_id_partial!(
+    ImplTypeDefId,
+    ImplTypeDefLongId,
+    ast::ItemTypeAlias,
+    lookup_intern_impl_type_def,
+    name
+);
+impl ImplTypeDefId {
+    pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
+        let ImplTypeDefLongId(module_file_id, ptr) = db.lookup_intern_impl_type_def(*self);
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let impl_ptr = ast::ItemImplPtr(parent);
+        db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
+    }
+}
+impl TopLevelLanguageElementId for ImplTypeDefId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_impl_type_def(*self).name(db)
+    }
+}
+
+define_language_element_id_partial!(
+    ImplConstantDefId,
+    ImplConstantDefLongId,
+    ast::ItemConstant,
+    lookup_intern_impl_constant_def,
+    name
+);
+impl ImplConstantDefId {
+    pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
+        let ImplConstantDefLongId(module_file_id, ptr) = db.lookup_intern_impl_constant_def(*self);
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let impl_ptr = ast::ItemImplPtr(parent);
+        db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
+    }
+}
+impl TopLevelLanguageElementId for ImplConstantDefId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_impl_constant_def(*self).name(db)
+    }
+}
+
 define_language_element_id_as_enum! {
     /// Represents a function that has a body.
     pub enum FunctionWithBodyId {
@@ -463,6 +530,74 @@ impl TopLevelLanguageElementId for TraitFunctionId {
     }
 }
 
+define_language_element_id_partial!(
+    TraitTypeId,
+    TraitTypeLongId,
+    ast::TraitItemType,
+    lookup_intern_trait_type,
+    name
+);
+impl TraitTypeId {
+    pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
+        let TraitTypeLongId(module_file_id, ptr) = db.lookup_intern_trait_type(*self);
+        // Trait type ast lies a few levels bellow the trait ast.
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let trait_ptr = ast::ItemTraitPtr(parent);
+        db.intern_trait(TraitLongId(module_file_id, trait_ptr))
+    }
+}
+impl TopLevelLanguageElementId for TraitTypeId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.trait_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_trait_type(*self).name(db)
+    }
+}
+
+define_language_element_id_partial!(
+    TraitConstantId,
+    TraitConstantLongId,
+    ast::TraitItemConstant,
+    lookup_intern_trait_constant,
+    name
+);
+impl TraitConstantId {
+    pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
+        let TraitConstantLongId(module_file_id, ptr) = db.lookup_intern_trait_constant(*self);
+        // Trait constant ast lies a few levels bellow the trait ast.
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(ptr.untyped()) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let SyntaxStablePtr::Child{parent, ..} = db.lookup_intern_stable_ptr(parent) else {
+            panic!()
+        };
+        let trait_ptr = ast::ItemTraitPtr(parent);
+        db.intern_trait(TraitLongId(module_file_id, trait_ptr))
+    }
+}
+impl TopLevelLanguageElementId for TraitConstantId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.trait_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_trait_constant(*self).name(db)
+    }
+}
+
 // Struct items.
 // TODO(spapini): Override full_path for to include parents, for better debug.
 define_language_element_id!(MemberId, MemberLongId, ast::Member, lookup_intern_member, name);
@@ -735,5 +870,17 @@ define_language_element_id_as_enum! {
         ModuleItem(ModuleItemId),
         // TODO(spapini): Replace with ImplItemId.
         ImplFunction(ImplFunctionId),
+        TraitFunction(TraitFunctionId),
+    }
+}
+
+define_language_element_id_as_enum! {
+    /// Items that can be documented with doc comments.
+    pub enum DocumentableItemId {
+        ModuleItem(ModuleItemId),
+        TraitFunction(TraitFunctionId),
+        ImplFunction(ImplFunctionId),
+        Member(MemberId),
+        Variant(VariantId),
     }
 }
@@ -17,7 +17,8 @@ use indoc::indoc;
 
 use crate::db::{DefsDatabase, DefsGroup, HasMacroPlugins};
 use crate::ids::{
-    FileIndex, GenericParamLongId, ModuleFileId, ModuleId, ModuleItemId, SubmoduleLongId,
+    DocumentableItemId, FileIndex, GenericParamLongId, ModuleFileId, ModuleId, ModuleItemId,
+    SubmoduleLongId, TopLevelLanguageElementId,
 };
 use crate::plugin::{
     DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginDiagnostic,
@@ -207,6 +208,46 @@ fn test_submodules() {
     );
 }
 
+#[test]
+fn test_documentation() {
+    let mut db_val = DatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            /// Does nothing.
+            ///
+            ///   Really.
+            fn foo() {}
+
+            //// Not a doc comment.
+            // Neither this.
+            #[derive(Drop)]
+            /// A struct.
+            struct S {}
+
+            /// A module.
+            mod inner {}
+
+            fn undocumented() {}
+        "},
+    );
+    let db = &db_val;
+    let documentation = |name: &str| {
+        let item_id = *db
+            .module_items(module_id)
+            .unwrap()
+            .iter()
+            .find(|item_id| item_id.name(db) == name)
+            .unwrap();
+        db.documentation(DocumentableItemId::ModuleItem(item_id))
+    };
+
+    assert_eq!(documentation("foo").as_deref(), Some("Does nothing.\n\n  Really."));
+    assert_eq!(documentation("S").as_deref(), Some("A struct."));
+    assert_eq!(documentation("inner").as_deref(), Some("A module."));
+    assert_eq!(documentation("undocumented"), None);
+}
+
 #[derive(Debug)]
 struct DummyAuxData;
 impl GeneratedFileAuxData for DummyAuxData {
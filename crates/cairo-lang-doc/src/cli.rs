@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_doc::{generate_docs, DocFormat};
+use cairo_lang_semantic::corelib::core_crate;
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+use clap::Parser;
+
+/// Command line args parser.
+/// Generates the API documentation of the crates of a Cairo project.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The project to document: a directory with a cairo project file, or a .cairo file.
+    path: PathBuf,
+    /// The directory to write the documentation to.
+    #[arg(short, long, default_value = "doc")]
+    output: PathBuf,
+    /// The format of the documentation: markdown or html.
+    #[arg(short, long, default_value = "markdown")]
+    format: DocFormat,
+    /// Documents the corelib as well.
+    #[arg(long, default_value_t = false)]
+    include_corelib: bool,
+    /// Enables the StarkNet plugin, for projects containing contracts.
+    #[arg(long, default_value_t = false)]
+    starknet: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let mut db_builder = RootDatabase::builder();
+    db_builder.detect_corelib();
+    if args.starknet {
+        db_builder.with_semantic_plugin(Arc::new(StarkNetPlugin::default()));
+    }
+    let db = &mut db_builder.build()?;
+
+    let mut crate_ids = setup_project(db, &args.path)?;
+    let core = core_crate(db);
+    if args.include_corelib && !crate_ids.contains(&core) {
+        crate_ids.push(core);
+    }
+
+    fs::create_dir_all(&args.output).with_context(|| {
+        format!("Failed to create the output directory {}.", args.output.display())
+    })?;
+    for file in generate_docs(db, &crate_ids, args.format) {
+        let path = args.output.join(&file.name);
+        fs::write(&path, file.content)
+            .with_context(|| format!("Failed to write {}.", path.display()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,645 @@
+//! Collection of the pages of the documentation, from the semantic model of the crates.
+
+use cairo_lang_defs::ids::{
+    DocumentableItemId, EnumId, GenericTypeId, ImplDefId, LanguageElementId, ModuleId,
+    ModuleItemId, StructId, TopLevelLanguageElementId, TraitId,
+};
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::{ConstValue, Constant};
+use cairo_lang_semantic::items::free_function::is_const_free_function;
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_semantic::{
+    ConcreteTraitId, GenericArgumentId, GenericParam, Mutability, Signature, TypeId, TypeLongId,
+};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::OptionFrom;
+use itertools::Itertools;
+
+use crate::page::{Block, Code, Page, Span, Target};
+
+/// The name of the index page of the documentation.
+pub const INDEX_PAGE: &str = "index";
+
+/// Collects the pages of the documentation of the given crates: an index page listing the crates,
+/// and a page for each of their modules.
+pub fn collect_pages(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<Page> {
+    let collector = DocCollector::new(db, crate_ids);
+    let mut pages = vec![collector.index_page()];
+    pages.extend(collector.module_ids.iter().map(|module_id| collector.module_page(*module_id)));
+    pages
+}
+
+/// Returns the name of the page of a module.
+pub fn module_page_name(db: &dyn SemanticGroup, module_id: ModuleId) -> String {
+    module_id.full_path(db.upcast()).replace("::", ".")
+}
+
+/// Collects the pages of the documentation of a set of crates.
+struct DocCollector<'a> {
+    db: &'a dyn SemanticGroup,
+    crate_ids: &'a [CrateId],
+    /// The documented modules. Links are only made to items of these modules.
+    module_ids: OrderedHashSet<ModuleId>,
+    /// The impls of the documented modules, by the types their trait is implemented for.
+    type_impls: OrderedHashMap<GenericTypeId, Vec<ImplDefId>>,
+}
+impl<'a> DocCollector<'a> {
+    fn new(db: &'a dyn SemanticGroup, crate_ids: &'a [CrateId]) -> Self {
+        let module_ids: OrderedHashSet<_> = crate_ids
+            .iter()
+            .flat_map(|crate_id| db.crate_modules(*crate_id).iter().copied().collect_vec())
+            .collect();
+        let mut type_impls: OrderedHashMap<_, Vec<_>> = OrderedHashMap::default();
+        for module_id in module_ids.iter() {
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default() {
+                let Ok(concrete_trait) = db.impl_def_concrete_trait(impl_def_id) else {
+                    continue;
+                };
+                let generic_types: OrderedHashSet<_> = concrete_trait
+                    .generic_args(db)
+                    .into_iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgumentId::Type(ty) => generic_type(db, ty),
+                        GenericArgumentId::Literal(_) | GenericArgumentId::Impl(_) => None,
+                    })
+                    .collect();
+                for generic_type in generic_types {
+                    type_impls.entry(generic_type).or_default().push(impl_def_id);
+                }
+            }
+        }
+        Self { db, crate_ids, module_ids, type_impls }
+    }
+
+    /// Returns the index page, listing the documented crates.
+    fn index_page(&self) -> Page {
+        let entries = self
+            .crate_ids
+            .iter()
+            .map(|crate_id| {
+                let mut code = Code::default();
+                code.item(
+                    self.db.lookup_intern_crate(*crate_id).0,
+                    Some(Target {
+                        page: module_page_name(self.db, ModuleId::CrateRoot(*crate_id)),
+                        anchor: None,
+                    }),
+                );
+                (code, None)
+            })
+            .collect();
+        Page { name: INDEX_PAGE.into(), title: "Crates".into(), blocks: vec![Block::List(entries)] }
+    }
+
+    /// Returns the page of a module, documenting all of its items.
+    fn module_page(&self, module_id: ModuleId) -> Page {
+        let db = self.db;
+        let mut blocks = vec![];
+        if let ModuleId::Submodule(submodule_id) = module_id {
+            if let Some(docs) = self.item_documentation(ModuleItemId::Submodule(submodule_id)) {
+                blocks.push(Block::Docs(docs));
+            }
+        }
+
+        let items = db.module_items(module_id).unwrap_or_default();
+        let sections = items
+            .iter()
+            .filter_map(|item| Some((section_title(item)?, *item)))
+            .sorted_by_key(|((order, _), _)| *order)
+            .group_by(|((_, title), _)| *title);
+        let mut submodules = vec![];
+        for (title, section_items) in &sections {
+            blocks.push(Block::Heading { level: 2, text: title.into(), anchor: None });
+            for (_, item) in section_items {
+                if let ModuleItemId::Submodule(_) = item {
+                    // Submodules have their own pages, so only a summary of them is listed.
+                    let mut code = Code::default();
+                    code.text("mod ");
+                    code.item(item.name(db.upcast()), self.item_target(item));
+                    let summary = self
+                        .item_documentation(item)
+                        .map(|docs| docs.split("\n\n").next().unwrap_or_default().to_string());
+                    submodules.push((code, summary));
+                } else {
+                    blocks.extend(self.item_blocks(item));
+                }
+            }
+            if !submodules.is_empty() {
+                blocks.push(Block::List(std::mem::take(&mut submodules)));
+            }
+        }
+
+        Page {
+            name: module_page_name(db, module_id),
+            title: format!("Module {}", module_id.full_path(db.upcast())),
+            blocks,
+        }
+    }
+
+    /// Returns the blocks documenting a module item: its heading, its signature, its doc comments
+    /// and the lists of its sub-items.
+    fn item_blocks(&self, item: ModuleItemId) -> Vec<Block> {
+        let db = self.db;
+        let name = item.name(db.upcast());
+        let mut blocks = vec![
+            Block::Heading {
+                level: 3,
+                text: format!("{} {name}", item_keyword(item)),
+                anchor: Some(name.into()),
+            },
+            Block::Code(self.item_signature(item)),
+        ];
+        if let Some(docs) = self.item_documentation(item) {
+            blocks.push(Block::Docs(docs));
+        }
+        match item {
+            ModuleItemId::Struct(struct_id) => {
+                push_sub_list(&mut blocks, "Members", self.members_list(struct_id))
+            }
+            ModuleItemId::Enum(enum_id) => {
+                push_sub_list(&mut blocks, "Variants", self.variants_list(enum_id))
+            }
+            ModuleItemId::Trait(trait_id) => self.push_trait_items(&mut blocks, trait_id),
+            ModuleItemId::Impl(impl_def_id) => self.push_impl_items(&mut blocks, impl_def_id),
+            _ => {}
+        }
+        if let Some(impl_def_ids) = GenericTypeId::option_from(item)
+            .and_then(|generic_type| self.type_impls.get(&generic_type))
+        {
+            let entries = impl_def_ids
+                .iter()
+                .map(|impl_def_id| {
+                    let mut code = Code::default();
+                    let impl_item = ModuleItemId::Impl(*impl_def_id);
+                    self.impl_def_code(&mut code, *impl_def_id, self.item_target(impl_item));
+                    (code, None)
+                })
+                .collect();
+            push_sub_list(&mut blocks, "Implementations", entries);
+        }
+        blocks
+    }
+
+    /// Returns the signature of a module item.
+    fn item_signature(&self, item: ModuleItemId) -> Code {
+        let db = self.db;
+        let name = item.name(db.upcast());
+        let mut code = Code::default();
+        match item {
+            ModuleItemId::Constant(id) => {
+                self.constant_code(&mut code, &name, db.constant_semantic_data(id))
+            }
+            ModuleItemId::FreeFunction(id) => self.function_code(
+                &mut code,
+                if is_const_free_function(db, id).unwrap_or_default() {
+                    "const fn "
+                } else {
+                    "fn "
+                },
+                &name,
+                db.free_function_generic_params(id),
+                db.free_function_signature(id),
+            ),
+            ModuleItemId::ExternFunction(id) => self.function_code(
+                &mut code,
+                "extern fn ",
+                &name,
+                db.extern_function_declaration_generic_params(id),
+                db.extern_function_signature(id),
+            ),
+            ModuleItemId::Struct(id) => {
+                code.text(format!("struct {name}"));
+                self.generic_params_code(&mut code, db.struct_generic_params(id));
+            }
+            ModuleItemId::Enum(id) => {
+                code.text(format!("enum {name}"));
+                self.generic_params_code(&mut code, db.enum_generic_params(id));
+            }
+            ModuleItemId::TypeAlias(id) => {
+                code.text(format!("type {name}"));
+                self.generic_params_code(&mut code, db.type_alias_generic_params(id));
+                if let Ok(ty) = db.type_alias_resolved_type(id) {
+                    code.text(" = ");
+                    self.type_code(&mut code, ty);
+                }
+            }
+            ModuleItemId::ImplAlias(id) => {
+                code.text(format!("impl {name}"));
+                self.generic_params_code(&mut code, db.impl_alias_generic_params(id));
+                if let Ok(impl_id) = db.impl_alias_resolved_impl(id) {
+                    code.text(" = ");
+                    self.impl_code(&mut code, impl_id);
+                }
+            }
+            ModuleItemId::Trait(id) => {
+                code.text(format!("trait {name}"));
+                self.generic_params_code(&mut code, db.trait_generic_params(id));
+            }
+            ModuleItemId::Impl(id) => self.impl_def_code(&mut code, id, None),
+            ModuleItemId::ExternType(id) => {
+                code.text(format!("extern type {name}"));
+                self.generic_params_code(&mut code, db.extern_type_declaration_generic_params(id));
+            }
+            ModuleItemId::Submodule(_) | ModuleItemId::Use(_) => {
+                code.text(format!("{} {name}", item_keyword(item)))
+            }
+        }
+        code
+    }
+
+    /// Returns the list of the members of a struct.
+    fn members_list(&self, struct_id: StructId) -> Vec<(Code, Option<String>)> {
+        let db = self.db;
+        db.struct_members(struct_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, member)| {
+                let mut code = Code::default();
+                code.text(format!("{name}: "));
+                self.type_code(&mut code, member.ty);
+                (code, db.documentation(DocumentableItemId::Member(member.id)))
+            })
+            .collect()
+    }
+
+    /// Returns the list of the variants of an enum. Variants of the unit type are shown without
+    /// their type.
+    fn variants_list(&self, enum_id: EnumId) -> Vec<(Code, Option<String>)> {
+        let db = self.db;
+        db.enum_variants(enum_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, variant_id)| {
+                let mut code = Code::default();
+                code.text(name);
+                if let Ok(variant) = db.variant_semantic(enum_id, *variant_id) {
+                    if !variant.ty.is_unit(db) {
+                        code.text(": ");
+                        self.type_code(&mut code, variant.ty);
+                    }
+                }
+                (code, db.documentation(DocumentableItemId::Variant(*variant_id)))
+            })
+            .collect()
+    }
+
+    /// Pushes the lists of the associated types, constants and functions of a trait.
+    fn push_trait_items(&self, blocks: &mut Vec<Block>, trait_id: TraitId) {
+        let db = self.db;
+        let types = db
+            .trait_types(trait_id)
+            .unwrap_or_default()
+            .keys()
+            .map(|name| (Code(vec![Span::Text(format!("type {name}"))]), None))
+            .collect();
+        push_sub_list(blocks, "Types", types);
+
+        let constants = db
+            .trait_constants(trait_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, constant_id)| {
+                let mut code = Code::default();
+                code.text(format!("const {name}"));
+                if let Ok(ty) = db.trait_constant_type(*constant_id) {
+                    code.text(": ");
+                    self.type_code(&mut code, ty);
+                }
+                (code, None)
+            })
+            .collect();
+        push_sub_list(blocks, "Constants", constants);
+
+        let functions = db
+            .trait_functions(trait_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, function_id)| {
+                let mut code = Code::default();
+                self.function_code(
+                    &mut code,
+                    "fn ",
+                    name,
+                    db.trait_function_generic_params(*function_id),
+                    db.trait_function_signature(*function_id),
+                );
+                (code, db.documentation(DocumentableItemId::TraitFunction(*function_id)))
+            })
+            .collect();
+        push_sub_list(blocks, "Functions", functions);
+    }
+
+    /// Pushes the lists of the associated types, constants and functions of an impl.
+    fn push_impl_items(&self, blocks: &mut Vec<Block>, impl_def_id: ImplDefId) {
+        let db = self.db;
+        let trait_id =
+            db.impl_def_concrete_trait(impl_def_id).map(|concrete| concrete.trait_id(db));
+
+        let mut types = vec![];
+        let mut constants = vec![];
+        if let Ok(trait_id) = trait_id {
+            for (name, trait_type_id) in db.trait_types(trait_id).unwrap_or_default().iter() {
+                let Ok(Some(type_def_id)) = db.impl_type_by_trait_type(impl_def_id, *trait_type_id)
+                else {
+                    continue;
+                };
+                let mut code = Code::default();
+                code.text(format!("type {name}"));
+                if let Ok(ty) = db.impl_type_def_resolved_type(type_def_id) {
+                    code.text(" = ");
+                    self.type_code(&mut code, ty);
+                }
+                types.push((code, None));
+            }
+            for (name, trait_constant_id) in db.trait_constants(trait_id).unwrap_or_default().iter()
+            {
+                let Ok(Some(constant_def_id)) =
+                    db.impl_constant_by_trait_constant(impl_def_id, *trait_constant_id)
+                else {
+                    continue;
+                };
+                let mut code = Code::default();
+                self.constant_code(&mut code, name, db.impl_constant_def_value(constant_def_id));
+                constants.push((code, None));
+            }
+        }
+        push_sub_list(blocks, "Types", types);
+        push_sub_list(blocks, "Constants", constants);
+
+        let functions = db
+            .impl_functions(impl_def_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, function_id)| {
+                let mut code = Code::default();
+                self.function_code(
+                    &mut code,
+                    "fn ",
+                    name,
+                    db.impl_function_generic_params(*function_id),
+                    db.impl_function_signature(*function_id),
+                );
+                (code, db.documentation(DocumentableItemId::ImplFunction(*function_id)))
+            })
+            .collect();
+        push_sub_list(blocks, "Functions", functions);
+    }
+
+    /// Appends the signature of a constant to the code. The value is only shown for integers.
+    fn constant_code(&self, code: &mut Code, name: &str, constant: Maybe<Constant>) {
+        code.text(format!("const {name}"));
+        let Ok(constant) = constant else {
+            return;
+        };
+        code.text(": ");
+        self.type_code(code, constant.ty);
+        if let ConstValue::Int(value, _) = constant.value {
+            code.text(format!(" = {value}"));
+        }
+    }
+
+    /// Appends the signature of a function to the code.
+    fn function_code(
+        &self,
+        code: &mut Code,
+        keyword: &str,
+        name: &str,
+        generic_params: Maybe<Vec<GenericParam>>,
+        signature: Maybe<Signature>,
+    ) {
+        code.text(format!("{keyword}{name}"));
+        self.generic_params_code(code, generic_params);
+        let Ok(signature) = signature else {
+            return;
+        };
+        code.text("(");
+        for (i, param) in signature.params.iter().enumerate() {
+            if i > 0 {
+                code.text(", ");
+            }
+            let modifier = match param.mutability {
+                Mutability::Immutable => "",
+                Mutability::Mutable => "mut ",
+                Mutability::Reference => "ref ",
+            };
+            code.text(format!("{modifier}{}: ", param.name));
+            self.type_code(code, param.ty);
+        }
+        code.text(")");
+        if !signature.return_type.is_unit(self.db) {
+            code.text(" -> ");
+            self.type_code(code, signature.return_type);
+        }
+        if !signature.implicits.is_empty() {
+            code.text(" implicits(");
+            self.list_code(code, &signature.implicits, |code, ty| self.type_code(code, *ty));
+            code.text(")");
+        }
+        if !signature.panicable {
+            code.text(" nopanic");
+        }
+    }
+
+    /// Appends the signature of an impl to the code, linking its name to the given target.
+    fn impl_def_code(&self, code: &mut Code, impl_def_id: ImplDefId, target: Option<Target>) {
+        let db = self.db;
+        code.text("impl ");
+        code.item(impl_def_id.name(db.upcast()), target);
+        self.generic_params_code(code, db.impl_def_generic_params(impl_def_id));
+        if let Ok(concrete_trait) = db.impl_def_concrete_trait(impl_def_id) {
+            code.text(" of ");
+            self.concrete_trait_code(code, concrete_trait);
+        }
+    }
+
+    /// Appends generic parameters to the code, if there are any.
+    fn generic_params_code(&self, code: &mut Code, generic_params: Maybe<Vec<GenericParam>>) {
+        let db = self.db;
+        let generic_params = generic_params.unwrap_or_default();
+        if generic_params.is_empty() {
+            return;
+        }
+        code.text("<");
+        self.list_code(code, &generic_params, |code, param| match param {
+            GenericParam::Type(param) => code.text(param.id.name(db.upcast())),
+            GenericParam::Const(param) => {
+                code.text(format!("const {}: ", param.id.name(db.upcast())));
+                self.type_code(code, param.ty);
+            }
+            GenericParam::Impl(param) => {
+                code.text(format!("impl {}", param.id.name(db.upcast())));
+                if let Ok(concrete_trait) = param.concrete_trait {
+                    code.text(": ");
+                    self.concrete_trait_code(code, concrete_trait);
+                }
+            }
+        });
+        code.text(">");
+    }
+
+    /// Appends generic arguments to the code, if there are any.
+    fn generic_args_code(&self, code: &mut Code, generic_args: &[GenericArgumentId]) {
+        if generic_args.is_empty() {
+            return;
+        }
+        code.text("<");
+        self.list_code(code, generic_args, |code, arg| match arg {
+            GenericArgumentId::Type(ty) => self.type_code(code, *ty),
+            GenericArgumentId::Literal(literal) => code.text(literal.format(self.db)),
+            GenericArgumentId::Impl(impl_id) => self.impl_code(code, *impl_id),
+        });
+        code.text(">");
+    }
+
+    /// Appends a type to the code, with links to the documentation of the types in it.
+    fn type_code(&self, code: &mut Code, ty: TypeId) {
+        let db = self.db;
+        match db.lookup_intern_type(ty) {
+            TypeLongId::Concrete(concrete) => {
+                let generic_type = concrete.generic_type(db);
+                code.item(
+                    generic_type.name(db.upcast()),
+                    self.item_target(generic_type_item(generic_type)),
+                );
+                self.generic_args_code(code, &concrete.generic_args(db));
+            }
+            TypeLongId::Tuple(types) => {
+                code.text("(");
+                self.list_code(code, &types, |code, ty| self.type_code(code, *ty));
+                code.text(if types.len() == 1 { ",)" } else { ")" });
+            }
+            TypeLongId::Snapshot(ty) => {
+                code.text("@");
+                self.type_code(code, ty);
+            }
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Missing(_) => code.text(ty.format(db)),
+        }
+    }
+
+    /// Appends an impl to the code, with links to the documentation of the items in it.
+    fn impl_code(&self, code: &mut Code, impl_id: ImplId) {
+        let db = self.db;
+        match impl_id {
+            ImplId::Concrete(concrete) => {
+                let concrete = db.lookup_intern_concrete_impl(concrete);
+                code.item(
+                    concrete.impl_def_id.name(db.upcast()),
+                    self.item_target(ModuleItemId::Impl(concrete.impl_def_id)),
+                );
+                self.generic_args_code(code, &concrete.generic_args);
+            }
+            ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::SelfImpl(_) => {
+                code.text(impl_id.name(db))
+            }
+        }
+    }
+
+    /// Appends a concrete trait to the code, with links to the documentation of the items in it.
+    fn concrete_trait_code(&self, code: &mut Code, concrete_trait: ConcreteTraitId) {
+        let db = self.db;
+        let trait_id = concrete_trait.trait_id(db);
+        code.item(trait_id.name(db.upcast()), self.item_target(ModuleItemId::Trait(trait_id)));
+        self.generic_args_code(code, &concrete_trait.generic_args(db));
+    }
+
+    /// Appends a comma separated list of elements to the code.
+    fn list_code<T>(&self, code: &mut Code, elements: &[T], mut f: impl FnMut(&mut Code, &T)) {
+        for (i, element) in elements.iter().enumerate() {
+            if i > 0 {
+                code.text(", ");
+            }
+            f(code, element);
+        }
+    }
+
+    /// Returns the target of links to a module item, if it is documented.
+    fn item_target(&self, item: ModuleItemId) -> Option<Target> {
+        let db = self.db;
+        let module_id = item.parent_module(db.upcast());
+        if !self.module_ids.contains(&module_id) {
+            return None;
+        }
+        Some(match item {
+            ModuleItemId::Submodule(submodule_id) => Target {
+                page: module_page_name(db, ModuleId::Submodule(submodule_id)),
+                anchor: None,
+            },
+            _ => Target {
+                page: module_page_name(db, module_id),
+                anchor: Some(item.name(db.upcast()).into()),
+            },
+        })
+    }
+
+    /// Returns the documentation of a module item.
+    fn item_documentation(&self, item: ModuleItemId) -> Option<String> {
+        self.db.documentation(DocumentableItemId::ModuleItem(item))
+    }
+}
+
+/// Returns the order and the title of the section of a module page documenting an item, or None
+/// for items that are not documented.
+fn section_title(item: &ModuleItemId) -> Option<(usize, &'static str)> {
+    Some(match item {
+        ModuleItemId::Submodule(_) => (0, "Modules"),
+        ModuleItemId::Constant(_) => (1, "Constants"),
+        ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_) => (2, "Functions"),
+        ModuleItemId::Struct(_) => (3, "Structs"),
+        ModuleItemId::Enum(_) => (4, "Enums"),
+        ModuleItemId::TypeAlias(_) => (5, "Type aliases"),
+        ModuleItemId::Trait(_) => (6, "Traits"),
+        ModuleItemId::Impl(_) => (7, "Impls"),
+        ModuleItemId::ImplAlias(_) => (8, "Impl aliases"),
+        ModuleItemId::ExternType(_) => (9, "Extern types"),
+        ModuleItemId::Use(_) => return None,
+    })
+}
+
+/// Returns the keyword declaring a module item.
+fn item_keyword(item: ModuleItemId) -> &'static str {
+    match item {
+        ModuleItemId::Constant(_) => "const",
+        ModuleItemId::Submodule(_) => "mod",
+        ModuleItemId::Use(_) => "use",
+        ModuleItemId::FreeFunction(_) => "fn",
+        ModuleItemId::ExternFunction(_) => "extern fn",
+        ModuleItemId::Struct(_) => "struct",
+        ModuleItemId::Enum(_) => "enum",
+        ModuleItemId::TypeAlias(_) => "type",
+        ModuleItemId::ImplAlias(_) | ModuleItemId::Impl(_) => "impl",
+        ModuleItemId::Trait(_) => "trait",
+        ModuleItemId::ExternType(_) => "extern type",
+    }
+}
+
+/// Returns the module item defining a generic type.
+fn generic_type_item(generic_type: GenericTypeId) -> ModuleItemId {
+    match generic_type {
+        GenericTypeId::Struct(id) => ModuleItemId::Struct(id),
+        GenericTypeId::Enum(id) => ModuleItemId::Enum(id),
+        GenericTypeId::Extern(id) => ModuleItemId::ExternType(id),
+    }
+}
+
+/// Returns the generic type of a type, looking through snapshots, if it has one.
+fn generic_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<GenericTypeId> {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(concrete) => Some(concrete.generic_type(db)),
+        TypeLongId::Snapshot(ty) => generic_type(db, ty),
+        _ => None,
+    }
+}
+
+/// Pushes a titled list of sub-items to the blocks, if it is not empty.
+fn push_sub_list(blocks: &mut Vec<Block>, title: &str, entries: Vec<(Code, Option<String>)>) {
+    if !entries.is_empty() {
+        blocks.push(Block::Heading { level: 4, text: title.into(), anchor: None });
+        blocks.push(Block::List(entries));
+    }
+}
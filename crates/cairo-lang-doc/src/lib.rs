@@ -0,0 +1,70 @@
+//! Cairo API documentation generator.
+//!
+//! This crate walks the modules of Cairo crates, and renders the signatures and the doc comments
+//! of their items as static markdown or HTML pages, with links between them.
+use std::str::FromStr;
+
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::db::SemanticGroup;
+
+use crate::collect::collect_pages;
+use crate::render::render_page;
+
+pub mod collect;
+pub mod page;
+pub mod render;
+
+#[cfg(test)]
+mod test;
+
+/// The format of the generated documentation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DocFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+impl DocFormat {
+    /// Returns the extension of the files of the documentation.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+impl FromStr for DocFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" | "md" => Ok(DocFormat::Markdown),
+            "html" => Ok(DocFormat::Html),
+            _ => Err(format!("Invalid documentation format `{s}`, expected markdown or html.")),
+        }
+    }
+}
+
+/// A file of the generated documentation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocFile {
+    /// The name of the file, relative to the output directory.
+    pub name: String,
+    pub content: String,
+}
+
+/// Generates the documentation of the given crates: an index page, and a page for each of their
+/// modules.
+pub fn generate_docs(
+    db: &dyn SemanticGroup,
+    crate_ids: &[CrateId],
+    format: DocFormat,
+) -> Vec<DocFile> {
+    collect_pages(db, crate_ids)
+        .iter()
+        .map(|page| DocFile {
+            name: format!("{}.{}", page.name, format.extension()),
+            content: render_page(page, format),
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+//! The pages of the documentation, independently of the format they are rendered in.
+
+/// A page of the documentation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page {
+    /// The name of the file of the page, without its extension.
+    pub name: String,
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+/// A block of a page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Block {
+    /// A heading, with an optional anchor links can refer to.
+    Heading { level: usize, text: String, anchor: Option<String> },
+    /// The signature of an item.
+    Code(Code),
+    /// Documentation written in doc comments, in markdown.
+    Docs(String),
+    /// A list of signatures, each with its optional documentation.
+    List(Vec<(Code, Option<String>)>),
+}
+
+/// A piece of code, made of text and of links to the documentation of items.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Code(pub Vec<Span>);
+impl Code {
+    /// Appends text to the code.
+    pub fn text(&mut self, text: impl AsRef<str>) {
+        if let Some(Span::Text(last)) = self.0.last_mut() {
+            last.push_str(text.as_ref());
+        } else {
+            self.0.push(Span::Text(text.as_ref().into()));
+        }
+    }
+
+    /// Appends the name of an item to the code, linking to its documentation if it has any.
+    pub fn item(&mut self, text: impl AsRef<str>, target: Option<Target>) {
+        match target {
+            Some(target) => self.0.push(Span::Link { text: text.as_ref().into(), target }),
+            None => self.text(text),
+        }
+    }
+}
+
+/// A span of code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Span {
+    Text(String),
+    Link { text: String, target: Target },
+}
+
+/// The target of a link: a page, and optionally an anchor in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Target {
+    pub page: String,
+    pub anchor: Option<String>,
+}
@@ -0,0 +1,167 @@
+//! Rendering of the pages of the documentation, as markdown or as HTML.
+
+use std::fmt::Write;
+
+use crate::page::{Block, Code, Page, Span, Target};
+use crate::DocFormat;
+
+/// The style sheet of the HTML pages.
+const HTML_STYLE: &str = "body { max-width: 60em; margin: auto; padding: 1em; font-family: \
+                          sans-serif; } pre { background: #f4f4f4; padding: 0.5em; overflow-x: \
+                          auto; } a { text-decoration: none; } .docs { margin-left: 1em; }";
+
+/// Renders a page of the documentation in the given format.
+pub fn render_page(page: &Page, format: DocFormat) -> String {
+    match format {
+        DocFormat::Markdown => render_markdown(page),
+        DocFormat::Html => render_html(page),
+    }
+}
+
+/// Renders a page as markdown. Code is rendered as HTML, which markdown allows, so that it can
+/// contain links.
+fn render_markdown(page: &Page) -> String {
+    let format = DocFormat::Markdown;
+    let mut out = format!("# {}\n", escape(&page.title));
+    for block in &page.blocks {
+        out.push('\n');
+        match block {
+            Block::Heading { level, text, anchor } => {
+                if let Some(anchor) = anchor {
+                    writeln!(out, "<a name=\"{anchor}\"></a>\n").unwrap();
+                }
+                writeln!(out, "{} {}", "#".repeat(*level), escape(text)).unwrap();
+            }
+            Block::Code(code) => {
+                writeln!(out, "<pre><code>{}</code></pre>", render_code(code, format)).unwrap();
+            }
+            Block::Docs(docs) => writeln!(out, "{docs}").unwrap(),
+            Block::List(entries) => {
+                for (code, docs) in entries {
+                    writeln!(out, "- <code>{}</code>", render_code(code, format)).unwrap();
+                    if let Some(docs) = docs {
+                        out.push('\n');
+                        for line in docs.lines() {
+                            if line.is_empty() {
+                                out.push('\n');
+                            } else {
+                                writeln!(out, "  {line}").unwrap();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Renders a page as a standalone HTML document.
+fn render_html(page: &Page) -> String {
+    let format = DocFormat::Html;
+    let title = escape(&page.title);
+    let mut out = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{title}</title>\n<style>{HTML_STYLE}</style>\n</head>\n"));
+    out.push_str(&format!("<body>\n<h1>{title}</h1>\n"));
+    for block in &page.blocks {
+        match block {
+            Block::Heading { level, text, anchor } => {
+                let id =
+                    anchor.as_ref().map(|anchor| format!(" id=\"{anchor}\"")).unwrap_or_default();
+                writeln!(out, "<h{level}{id}>{}</h{level}>", escape(text)).unwrap();
+            }
+            Block::Code(code) => {
+                writeln!(out, "<pre><code>{}</code></pre>", render_code(code, format)).unwrap();
+            }
+            Block::Docs(docs) => {
+                writeln!(out, "<div class=\"docs\">\n{}</div>", docs_to_html(docs)).unwrap();
+            }
+            Block::List(entries) => {
+                out.push_str("<ul>\n");
+                for (code, docs) in entries {
+                    write!(out, "<li><code>{}</code>", render_code(code, format)).unwrap();
+                    if let Some(docs) = docs {
+                        write!(out, "\n<div class=\"docs\">\n{}</div>\n", docs_to_html(docs))
+                            .unwrap();
+                    }
+                    out.push_str("</li>\n");
+                }
+                out.push_str("</ul>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Renders code as HTML, with links to the pages of the given format.
+fn render_code(code: &Code, format: DocFormat) -> String {
+    code.0
+        .iter()
+        .map(|span| match span {
+            Span::Text(text) => escape(text),
+            Span::Link { text, target } => {
+                format!("<a href=\"{}\">{}</a>", href(target, format), escape(text))
+            }
+        })
+        .collect()
+}
+
+/// Returns the relative URL of a link target.
+fn href(target: &Target, format: DocFormat) -> String {
+    let page = format!("{}.{}", target.page, format.extension());
+    match &target.anchor {
+        Some(anchor) => format!("{page}#{anchor}"),
+        None => page,
+    }
+}
+
+/// Converts documentation written in markdown to HTML. Only paragraphs, code blocks and inline
+/// code are supported; the rest is kept as text.
+fn docs_to_html(docs: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = vec![];
+    let mut in_code_block = false;
+    let flush = |html: &mut String, paragraph: &mut Vec<&str>| {
+        if !paragraph.is_empty() {
+            writeln!(html, "<p>{}</p>", inline_code_to_html(&paragraph.join("\n"))).unwrap();
+            paragraph.clear();
+        }
+    };
+    for line in docs.lines() {
+        if line.trim_start().starts_with("```") {
+            flush(&mut html, &mut paragraph);
+            html.push_str(if in_code_block { "</code></pre>\n" } else { "<pre><code>" });
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            writeln!(html, "{}", escape(line)).unwrap();
+        } else if line.trim().is_empty() {
+            flush(&mut html, &mut paragraph);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    if in_code_block {
+        html.push_str("</code></pre>\n");
+    }
+    flush(&mut html, &mut paragraph);
+    html
+}
+
+/// Escapes text, and converts the inline code spans in it (between backticks) to HTML.
+fn inline_code_to_html(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(
+            |(i, part)| {
+                if i % 2 == 1 { format!("<code>{}</code>", escape(part)) } else { escape(part) }
+            },
+        )
+        .collect()
+}
+
+/// Escapes the characters of text that are special in HTML (and thus in markdown).
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
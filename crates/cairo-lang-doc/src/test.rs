@@ -0,0 +1,29 @@
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+
+use crate::{generate_docs, DocFormat};
+
+cairo_lang_test_utils::test_file_test!(
+    docs,
+    "src/test_data",
+    {
+        docs: "docs",
+        html: "html",
+    },
+    test_generate_docs
+);
+
+/// Generates the documentation of a crate, and returns all of its files, each preceded by its name.
+fn test_generate_docs(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(db, inputs["cairo_code"].as_str());
+    let format = inputs["format"].parse::<DocFormat>().unwrap();
+
+    let docs = generate_docs(db, &[crate_id], format)
+        .into_iter()
+        .map(|file| format!("==> {} <==\n{}", file.name, file.content))
+        .join("\n");
+    OrderedHashMap::from([("docs".into(), docs)])
+}
@@ -1,7 +1,37 @@
+use std::str::FromStr;
+
 /// A compilation flag.
 #[derive(PartialEq, Eq, Debug)]
 pub enum Flag {
     /// Whether automatically add `withdraw_gas` calls in code cycles.
     /// Default is true - automatically add.
     AddWithdrawGas(bool),
+    /// The level of optimizations applied by the compiler.
+    /// Default is [OptimizationLevel::O1].
+    OptimizationLevel(OptimizationLevel),
+}
+
+/// The level of optimizations applied by the compiler.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptimizationLevel {
+    /// Only functions marked with `#[inline(always)]` are inlined.
+    O0,
+    /// Functions whose bodies are estimated to be cheaper than calling them are inlined as well.
+    #[default]
+    O1,
+    /// Larger functions are inlined as well, including functions with a single call site in their
+    /// crate.
+    O2,
+}
+impl FromStr for OptimizationLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(OptimizationLevel::O0),
+            "1" => Ok(OptimizationLevel::O1),
+            "2" => Ok(OptimizationLevel::O2),
+            _ => Err(format!("Invalid optimization level `{s}`, expected 0, 1 or 2.")),
+        }
+    }
 }
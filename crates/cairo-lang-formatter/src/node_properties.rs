@@ -11,6 +11,7 @@ impl SyntaxNodeFormat for SyntaxNode {
     fn force_no_space_before(&self, db: &dyn SyntaxGroup) -> bool {
         match self.kind(db) {
             SyntaxKind::TokenDot
+            | SyntaxKind::TokenDotDotEq
             | SyntaxKind::TokenColonColon
             | SyntaxKind::TokenComma
             | SyntaxKind::TokenSemicolon
@@ -53,6 +54,7 @@ impl SyntaxNodeFormat for SyntaxNode {
     fn force_no_space_after(&self, db: &dyn SyntaxGroup) -> bool {
         match self.kind(db) {
             SyntaxKind::TokenDot
+            | SyntaxKind::TokenDotDotEq
             | SyntaxKind::TokenNot
             | SyntaxKind::TokenBitNot
             | SyntaxKind::TokenAt
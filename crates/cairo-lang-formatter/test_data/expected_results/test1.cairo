@@ -52,3 +52,11 @@ fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252
         },
     }
 }
+
+fn opcode_class(op: u8) -> felt252 {
+    match op {
+        0 => 0,
+        1..=9 | 42 => 1,
+        _ => 2
+    }
+}
@@ -0,0 +1,589 @@
+//! Code actions - quick fixes for the diagnostics of a file, and for unused variables.
+
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleItemId, VarId};
+use cairo_lang_diagnostics::{DiagnosticEntry, ToOption};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::diagnostic::LoweringDiagnosticKind;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnosticKind};
+use cairo_lang_semantic::expr::inference::InferenceError;
+use cairo_lang_semantic::items::function_with_body::FunctionBody;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::{
+    ConcreteTypeId, Expr, ExprFunctionCallArg, ExprId, GenericArgumentId, Pattern, Statement,
+    TypeLongId,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::{PathSegmentEx, QueryAttrs};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use lsp::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Range, TextEdit, Url,
+    WorkspaceEdit,
+};
+
+use crate::references::{lookup_item_exists, user_location};
+use crate::{get_node_and_lookup_items, get_range};
+
+#[cfg(test)]
+#[path = "code_actions_test.rs"]
+mod test;
+
+/// A fix, given by the text edits it makes in the files of the database.
+struct QuickFix {
+    title: String,
+    edits: Vec<(FileId, TextSpan, String)>,
+}
+impl QuickFix {
+    /// Returns a fix that inserts text at an offset of a file.
+    fn insert(title: String, file: FileId, offset: TextOffset, text: String) -> Self {
+        Self { title, edits: vec![(file, TextSpan { start: offset, end: offset }, text)] }
+    }
+}
+
+/// Returns the quick fixes for the diagnostics of a file that intersect the given range, and for
+/// the unused variables defined there. The fixes of a diagnostic refer to the diagnostics reported
+/// to the client at its location.
+pub fn code_actions(
+    db: &(dyn LoweringGroup + 'static),
+    file: FileId,
+    range: Range,
+    reported_diagnostics: &[Diagnostic],
+    get_uri: impl Fn(FileId) -> Url,
+) -> Vec<CodeActionOrCommand> {
+    let semantic_db: &(dyn SemanticGroup + 'static) = db.upcast();
+    let mut actions = vec![];
+    let mut add_actions = |span: TextSpan, message: Option<String>, fixes: Vec<QuickFix>| {
+        let Some(span_range) = get_range(semantic_db, file, span) else { return; };
+        if span_range.end < range.start || range.end < span_range.start {
+            return;
+        }
+        let diagnostics = message.map(|message| {
+            reported_diagnostics
+                .iter()
+                .filter(|diagnostic| {
+                    diagnostic.range.start == span_range.start && diagnostic.message == message
+                })
+                .cloned()
+                .collect()
+        });
+        for fix in fixes {
+            let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+            for (file, span, new_text) in fix.edits {
+                let Some(range) = get_range(semantic_db, file, span) else { continue; };
+                changes.entry(get_uri(file)).or_default().push(TextEdit { range, new_text });
+            }
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: diagnostics.clone(),
+                edit: Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }),
+                ..CodeAction::default()
+            }));
+        }
+    };
+
+    for diagnostic in db.file_semantic_diagnostics(file).unwrap_or_default().get_all() {
+        // Diagnostics of code generated by plugins are wrapped, with the location of the code they
+        // were generated from.
+        let (location, kind) = match &diagnostic.kind {
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. } => {
+                let location = diagnostic.location(semantic_db);
+                ((location.file_id, location.span), &original_diag.kind)
+            }
+            kind => {
+                let stable_location = diagnostic.stable_location;
+                let Some(location) = user_location(
+                    semantic_db,
+                    stable_location.module_file_id,
+                    stable_location.stable_ptr,
+                ) else { continue; };
+                (location, kind)
+            }
+        };
+        if location.0 != file {
+            continue;
+        }
+        let fixes = semantic_diagnostic_fixes(semantic_db, file, location.1, kind);
+        add_actions(location.1, Some(diagnostic.format(semantic_db)), fixes);
+    }
+    for diagnostic in db.file_lowering_diagnostics(file).unwrap_or_default().get_all() {
+        let stable_location = diagnostic.stable_location;
+        let Some((location_file, span)) = user_location(
+            semantic_db,
+            stable_location.module_file_id,
+            stable_location.stable_ptr,
+        ) else { continue; };
+        if location_file != file {
+            continue;
+        }
+        let fixes = lowering_diagnostic_fixes(semantic_db, file, span, &diagnostic.kind);
+        add_actions(span, Some(diagnostic.format(semantic_db)), fixes);
+    }
+    for (span, fix) in unused_variable_fixes(semantic_db, file, range) {
+        add_actions(span, None, vec![fix]);
+    }
+    actions
+}
+
+/// Returns the fixes of a semantic diagnostic at a span of a file.
+fn semantic_diagnostic_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    span: TextSpan,
+    kind: &SemanticDiagnosticKind,
+) -> Vec<QuickFix> {
+    let syntax_db = db.upcast();
+    let Some(root) = db.file_syntax(file).to_option() else { return vec![] };
+    let node = root.as_syntax_node().lookup_offset(syntax_db, span.start);
+    match kind {
+        SemanticDiagnosticKind::PathNotFound(item_type) => {
+            import_fixes(db, file, node, Some(*item_type))
+        }
+        SemanticDiagnosticKind::VariableNotFound { .. } => import_fixes(db, file, node, None),
+        SemanticDiagnosticKind::RefArgNotExplicit => {
+            vec![QuickFix::insert("Pass as `ref`".into(), file, span.start, "ref ".into())]
+        }
+        SemanticDiagnosticKind::WrongArgumentType { expected_ty, actual_ty }
+            if db.lookup_intern_type(*expected_ty) == TypeLongId::Snapshot(*actual_ty) =>
+        {
+            vec![QuickFix::insert("Pass a snapshot".into(), file, span.start, "@".into())]
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the fixes of a lowering diagnostic at a span of a file.
+fn lowering_diagnostic_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    span: TextSpan,
+    kind: &LoweringDiagnosticKind,
+) -> Vec<QuickFix> {
+    match kind {
+        LoweringDiagnosticKind::VariableNotDropped { drop_err, .. } => {
+            derive_fix(db, drop_err, "Drop").into_iter().collect()
+        }
+        LoweringDiagnosticKind::VariableMoved { inference_error } => {
+            derive_fix(db, inference_error, "Copy").into_iter().collect()
+        }
+        LoweringDiagnosticKind::MissingMatchArm(witness) => {
+            missing_match_arm_fix(db, file, span, witness).into_iter().collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the fixes adding a `use` of the items named like an identifier that could not be
+/// resolved, if it is the first segment of its path. With `item_type` of [None], the identifier is
+/// a variable, which may only be a constant.
+fn import_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    item_type: Option<NotFoundItemType>,
+) -> Vec<QuickFix> {
+    let syntax_db = db.upcast();
+    let Some(path_node) = find_ancestor(syntax_db, node.clone(), SyntaxKind::ExprPath) else {
+        return vec![];
+    };
+    let segments = ast::ExprPath::from_syntax_node(syntax_db, path_node).elements(syntax_db);
+    let identifier = segments[0].identifier_ast(syntax_db);
+    if identifier.as_syntax_node().span_without_trivia(syntax_db)
+        != node.span_without_trivia(syntax_db)
+    {
+        return vec![];
+    }
+    let name = identifier.text(syntax_db);
+    let is_last_segment = segments.len() == 1;
+    let Some((offset, text_prefix, text_suffix)) = use_insertion_point(db, file, node) else {
+        return vec![];
+    };
+
+    let mut paths = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let Ok(Some(module_item)) = db.module_item_by_name(*module_id, name.clone()) else {
+                continue;
+            };
+            if !is_importable(module_item, item_type, is_last_segment) {
+                continue;
+            }
+            // Skip items generated by plugins, which are not a part of the user's code.
+            let Ok(name_ptr) = db.module_item_name_stable_ptr(*module_id, module_item) else {
+                continue;
+            };
+            if user_location(db, module_item.module_file_id(db.upcast()), name_ptr).is_none() {
+                continue;
+            }
+            paths.push(format!("{}::{name}", module_id.full_path(db.upcast())));
+        }
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+        .into_iter()
+        .map(|path| {
+            QuickFix::insert(
+                format!("Import `{path}`"),
+                file,
+                offset,
+                format!("{text_prefix}use {path};{text_suffix}"),
+            )
+        })
+        .collect()
+}
+
+/// Returns whether a module item may be imported to resolve a path. The item type of the path
+/// only applies to its last segment, and a variable may only be a constant.
+fn is_importable(
+    module_item: ModuleItemId,
+    item_type: Option<NotFoundItemType>,
+    is_last_segment: bool,
+) -> bool {
+    let Some(item_type) = item_type else {
+        return matches!(module_item, ModuleItemId::Constant(_));
+    };
+    if !is_last_segment {
+        return !matches!(
+            module_item,
+            ModuleItemId::Constant(_)
+                | ModuleItemId::Use(_)
+                | ModuleItemId::FreeFunction(_)
+                | ModuleItemId::ExternFunction(_)
+        );
+    }
+    match item_type {
+        NotFoundItemType::Identifier => !matches!(module_item, ModuleItemId::Use(_)),
+        NotFoundItemType::Function => {
+            matches!(module_item, ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_))
+        }
+        NotFoundItemType::Type => matches!(
+            module_item,
+            ModuleItemId::Struct(_)
+                | ModuleItemId::Enum(_)
+                | ModuleItemId::ExternType(_)
+                | ModuleItemId::TypeAlias(_)
+        ),
+        NotFoundItemType::Trait => matches!(module_item, ModuleItemId::Trait(_)),
+        NotFoundItemType::Impl => {
+            matches!(module_item, ModuleItemId::Impl(_) | ModuleItemId::ImplAlias(_))
+        }
+    }
+}
+
+/// Returns where to insert a `use` item in the module of a syntax node - after its last `use`
+/// item, or before its first item - along with the text to insert before and after it.
+pub(crate) fn use_insertion_point(
+    db: &dyn SemanticGroup,
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<(TextOffset, String, String)> {
+    let syntax_db = db.upcast();
+    let mut module_node = node;
+    let items = loop {
+        module_node = module_node.parent()?;
+        match module_node.kind(syntax_db) {
+            SyntaxKind::SyntaxFile => {
+                break ast::SyntaxFile::from_syntax_node(syntax_db, module_node).items(syntax_db);
+            }
+            SyntaxKind::ItemModule => {
+                let item_module = ast::ItemModule::from_syntax_node(syntax_db, module_node.clone());
+                if let ast::MaybeModuleBody::Some(body) = item_module.body(syntax_db) {
+                    break body.items(syntax_db);
+                }
+            }
+            _ => {}
+        }
+    };
+    let items = items.elements(syntax_db);
+    let first_item = items.first()?.as_syntax_node();
+    let indentation = line_indentation(db, file, first_item.span_start_without_trivia(syntax_db))?;
+    Some(match items.iter().rev().find(|item| matches!(item, ast::Item::Use(_))) {
+        Some(last_use) => (
+            last_use.as_syntax_node().span_end_without_trivia(syntax_db),
+            format!("\n{indentation}"),
+            "".into(),
+        ),
+        // Before the leading trivia of the first item, so that its comments remain attached.
+        None => (first_item.span(syntax_db).start, indentation, "\n".into()),
+    })
+}
+
+/// Returns the fix adding a derive of a trait to the type an inference error failed to find an
+/// implementation of the trait for, if it is a struct or an enum that is not in the corelib.
+fn derive_fix(
+    db: &(dyn SemanticGroup + 'static),
+    inference_error: &InferenceError,
+    trait_name: &str,
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let InferenceError::NoImplsFound { concrete_trait_id } = inference_error else {
+        return None;
+    };
+    if concrete_trait_id.trait_id(db).name(db.upcast()) != trait_name {
+        return None;
+    }
+    let [GenericArgumentId::Type(ty)] = concrete_trait_id.generic_args(db)[..] else {
+        return None;
+    };
+    let (module_item, name) = match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            let struct_id = concrete_struct_id.struct_id(db);
+            (ModuleItemId::Struct(struct_id), struct_id.name(db.upcast()))
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            let enum_id = concrete_enum_id.enum_id(db);
+            (ModuleItemId::Enum(enum_id), enum_id.name(db.upcast()))
+        }
+        _ => return None,
+    };
+    let module_file_id = module_item.module_file_id(db.upcast());
+    if module_file_id.0.owning_crate(db.upcast()) == db.core_crate() {
+        return None;
+    }
+    let (file, span) =
+        user_location(db, module_file_id, module_item.untyped_stable_ptr(db.upcast()))?;
+    let root = db.file_syntax(file).to_option()?.as_syntax_node();
+    let item_node = ancestors(root.lookup_offset(syntax_db, span.start)).find(|node| {
+        matches!(node.kind(syntax_db), SyntaxKind::ItemStruct | SyntaxKind::ItemEnum)
+            && node.span_without_trivia(syntax_db) == span
+    })?;
+    let attributes = match ast::Item::from_syntax_node(syntax_db, item_node.clone()) {
+        ast::Item::Struct(item) => item.attributes(syntax_db),
+        ast::Item::Enum(item) => item.attributes(syntax_db),
+        _ => return None,
+    };
+    let title = format!("Add `#[derive({trait_name})]` to `{name}`");
+    let derive_args = attributes.query_attr(syntax_db, "derive").into_iter().find_map(|attr| {
+        match attr.arguments(syntax_db) {
+            ast::OptionArgListParenthesized::ArgListParenthesized(args) => Some(args),
+            ast::OptionArgListParenthesized::Empty(_) => None,
+        }
+    });
+    let Some(derive_args) = derive_args else {
+        let offset = item_node.span_start_without_trivia(syntax_db);
+        let indentation = line_indentation(db, file, offset)?;
+        return Some(QuickFix::insert(
+            title,
+            file,
+            offset,
+            format!("#[derive({trait_name})]\n{indentation}"),
+        ));
+    };
+    let args = derive_args.args(syntax_db);
+    if args.elements(syntax_db).is_empty() {
+        let offset =
+            derive_args.rparen(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db);
+        return Some(QuickFix::insert(title, file, offset, trait_name.into()));
+    }
+    if args
+        .elements(syntax_db)
+        .iter()
+        .any(|arg| arg.as_syntax_node().get_text_without_trivia(syntax_db) == trait_name)
+    {
+        return None;
+    }
+    let offset = args.as_syntax_node().span_end_without_trivia(syntax_db);
+    Some(QuickFix::insert(title, file, offset, format!(", {trait_name}")))
+}
+
+/// Returns the fix adding an arm for a pattern not covered by the match at a span of a file.
+fn missing_match_arm_fix(
+    db: &dyn SemanticGroup,
+    file: FileId,
+    span: TextSpan,
+    witness: &str,
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let root = db.file_syntax(file).to_option()?.as_syntax_node();
+    let match_node =
+        find_ancestor(syntax_db, root.lookup_offset(syntax_db, span.start), SyntaxKind::ExprMatch)?;
+    let expr_match = ast::ExprMatch::from_syntax_node(syntax_db, match_node.clone());
+    let arms = expr_match.arms(syntax_db);
+    let title = format!("Add a match arm for `{witness}`");
+    let Some(first_arm) = arms.elements(syntax_db).first().cloned() else {
+        let indentation =
+            line_indentation(db, file, match_node.span_start_without_trivia(syntax_db))?;
+        let offset = expr_match.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db);
+        return Some(QuickFix::insert(
+            title,
+            file,
+            offset,
+            format!("\n{indentation}    {witness} => {{}},\n{indentation}"),
+        ));
+    };
+    let indentation = line_indentation(
+        db,
+        file,
+        first_arm.as_syntax_node().span_start_without_trivia(syntax_db),
+    )?;
+    let separator = if arms.has_tail(syntax_db) { "," } else { "" };
+    Some(QuickFix::insert(
+        title,
+        file,
+        arms.as_syntax_node().span_end_without_trivia(syntax_db),
+        format!("{separator}\n{indentation}{witness} => {{}},"),
+    ))
+}
+
+/// Returns the fixes of the variables defined by `let` statements and never used, in the
+/// function at the start of the given range, along with the spans of the statements. A variable
+/// may be prefixed with an underscore, or removed along with its statement if its initializer has
+/// no side effects.
+fn unused_variable_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+) -> Vec<(TextSpan, QuickFix)> {
+    let syntax_db = db.upcast();
+    let Some((_, lookup_items)) = get_node_and_lookup_items(db, file, range.start) else {
+        return vec![];
+    };
+    let Some(function_id) = lookup_items
+        .into_iter()
+        .filter(|lookup_item_id| lookup_item_exists(db, *lookup_item_id))
+        .find_map(|lookup_item_id| lookup_item_id.function_with_body())
+    else {
+        return vec![];
+    };
+    let Ok(body) = db.function_body(function_id) else { return vec![] };
+    let Some(root) = db.file_syntax(file).to_option() else { return vec![] };
+    let used_variables = used_variables(db, function_id);
+    let mut fixes = vec![];
+    for (_, statement) in body.statements.iter() {
+        let Statement::Let(statement_let) = statement else { continue; };
+        let Pattern::Variable(variable) = &statement_let.pattern else { continue; };
+        if variable.name.starts_with('_') || used_variables.contains(&VarId::Local(variable.var.id))
+        {
+            continue;
+        }
+        let node = root.as_syntax_node().lookup_ptr(syntax_db, statement_let.stable_ptr.untyped());
+        let span = node.span_without_trivia(syntax_db);
+        let pattern_node =
+            root.as_syntax_node().lookup_ptr(syntax_db, variable.stable_ptr.untyped());
+        // A variable without modifiers is parsed as a path.
+        let name_offset = match ast::Pattern::from_syntax_node(syntax_db, pattern_node) {
+            ast::Pattern::Identifier(pattern) => {
+                pattern.name(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db)
+            }
+            ast::Pattern::Path(path) => path.as_syntax_node().span_start_without_trivia(syntax_db),
+            _ => continue,
+        };
+        fixes.push((
+            span,
+            QuickFix::insert(
+                format!("Prefix unused variable `{}` with an underscore", variable.name),
+                file,
+                name_offset,
+                "_".into(),
+            ),
+        ));
+        if !is_side_effect_free(&body, statement_let.expr) {
+            continue;
+        }
+        let Some(removed_span) = whole_lines_span(db, file, span) else { continue; };
+        fixes.push((
+            span,
+            QuickFix {
+                title: format!("Remove unused variable `{}`", variable.name),
+                edits: vec![(file, removed_span, "".into())],
+            },
+        ));
+    }
+    fixes
+}
+
+/// Returns whether evaluating an expression has no side effects, so that removing it keeps the
+/// behavior of the function. Function calls, including operators, may have side effects or panic.
+fn is_side_effect_free(body: &FunctionBody, expr_id: ExprId) -> bool {
+    match &body.exprs[expr_id] {
+        Expr::Var(_)
+        | Expr::Literal(_)
+        | Expr::StringLiteral(_)
+        | Expr::Constant(_)
+        | Expr::ImplConstant(_) => true,
+        Expr::Snapshot(expr) => is_side_effect_free(body, expr.inner),
+        Expr::Desnap(expr) => is_side_effect_free(body, expr.inner),
+        Expr::MemberAccess(expr) => is_side_effect_free(body, expr.expr),
+        Expr::EnumVariantCtor(expr) => is_side_effect_free(body, expr.value_expr),
+        Expr::Tuple(expr) => expr.items.iter().all(|item| is_side_effect_free(body, *item)),
+        Expr::StructCtor(expr) => {
+            expr.members.iter().all(|(_, member)| is_side_effect_free(body, *member))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the variables a function reads or assigns to.
+fn used_variables(db: &dyn SemanticGroup, function_id: FunctionWithBodyId) -> Vec<VarId> {
+    let Ok(body) = db.function_body(function_id) else { return vec![] };
+    let mut used_variables = vec![];
+    for (_, expr) in body.exprs.iter() {
+        match expr {
+            Expr::Var(expr_var) => used_variables.push(expr_var.var),
+            Expr::Assignment(expr_assignment) => {
+                used_variables.push(expr_assignment.ref_arg.base_var())
+            }
+            Expr::FunctionCall(expr_function_call) => {
+                used_variables.extend(expr_function_call.args.iter().filter_map(|arg| match arg {
+                    ExprFunctionCallArg::Reference(ref_arg) => Some(ref_arg.base_var()),
+                    ExprFunctionCallArg::Value(_) => None,
+                }))
+            }
+            _ => {}
+        }
+    }
+    used_variables
+}
+
+/// Returns the span of the lines of a span of a file, if nothing else is written on them.
+/// Otherwise, returns the span itself.
+fn whole_lines_span(db: &dyn SemanticGroup, file: FileId, span: TextSpan) -> Option<TextSpan> {
+    let content = db.file_content(file)?;
+    let file_summary = db.file_summary(file)?;
+    let start_line = span.start.position_in_file(db.upcast(), file)?.line;
+    let end_line = span.end.position_in_file(db.upcast(), file)?.line;
+    let line_start = file_summary.line_offsets[start_line];
+    let next_line_start =
+        file_summary.line_offsets.get(end_line + 1).copied().unwrap_or(file_summary.last_offset);
+    let before = TextSpan { start: line_start, end: span.start }.take(&content);
+    let after = TextSpan { start: span.end, end: next_line_start }.take(&content);
+    if before.trim().is_empty() && after.trim().is_empty() {
+        Some(TextSpan { start: line_start, end: next_line_start })
+    } else {
+        Some(span)
+    }
+}
+
+/// Returns the whitespace at the start of the line of an offset in a file.
+fn line_indentation(db: &dyn SemanticGroup, file: FileId, offset: TextOffset) -> Option<String> {
+    let content = db.file_content(file)?;
+    let line = offset.position_in_file(db.upcast(), file)?.line;
+    let line_start = db.file_summary(file)?.line_offsets[line];
+    Some(
+        TextSpan { start: line_start, end: offset }
+            .take(&content)
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect(),
+    )
+}
+
+/// Returns the innermost ancestor of a syntax node of the given kind.
+pub(crate) fn find_ancestor(
+    db: &dyn SyntaxGroup,
+    node: SyntaxNode,
+    kind: SyntaxKind,
+) -> Option<SyntaxNode> {
+    ancestors(node).find(|node| node.kind(db) == kind)
+}
+
+/// Returns a syntax node and its ancestors, from the innermost.
+pub(crate) fn ancestors(node: SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
+    std::iter::successors(Some(node), |node| node.parent())
+}
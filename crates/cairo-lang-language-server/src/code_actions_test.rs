@@ -0,0 +1,58 @@
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp::{CodeActionOrCommand, Position, Range, Url};
+
+use super::code_actions;
+
+cairo_lang_test_utils::test_file_test!(
+    code_actions,
+    "src/test_data",
+    {
+        code_actions: "code_actions",
+    },
+    test_code_actions
+);
+
+/// Returns the quick fixes at a position of a crate, given as `line:character` (both 0-based),
+/// each followed by its edits.
+fn test_code_actions(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let db = &mut RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(db, inputs["cairo_code"].as_str());
+    let file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let (line, character) = inputs["position"].split_once(':').unwrap();
+    let position = Position { line: line.parse().unwrap(), character: character.parse().unwrap() };
+    let range = Range { start: position, end: position };
+
+    let db = &*db;
+    let actions = code_actions(db, file, range, &[], |file| {
+        Url::parse(&format!("file:///{}", file.file_name(db))).unwrap()
+    });
+    let fixes = actions
+        .into_iter()
+        .map(|action| {
+            let CodeActionOrCommand::CodeAction(action) = action else {
+                panic!("Expected a code action.");
+            };
+            let edits = action
+                .edit
+                .and_then(|edit| edit.changes)
+                .into_iter()
+                .flat_map(|changes| changes.into_values().flatten())
+                .map(|edit| {
+                    let Range { start, end } = edit.range;
+                    format!(
+                        "  {}:{}-{}:{} {:?}",
+                        start.line, start.character, end.line, end.character, edit.new_text
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}\n{edits}", action.title)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    OrderedHashMap::from([("fixes".into(), fixes)])
+}
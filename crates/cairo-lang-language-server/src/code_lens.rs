@@ -0,0 +1,94 @@
+//! Code lenses running the tests of a file.
+
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use lsp::{CodeLens, Command};
+use serde_json::Value;
+
+use crate::{find_node_module, get_range};
+
+/// The command running the tests whose path is, or is under, the path given as its argument.
+pub const RUN_TEST_COMMAND: &str = "cairo1.runTest";
+
+/// Returns the code lenses running the tests of a file: one above each test function, and one
+/// above each module containing tests.
+pub fn test_code_lenses(db: &(dyn SemanticGroup + 'static), file: FileId) -> Option<Vec<CodeLens>> {
+    let syntax_db = db.upcast();
+    let items = db.file_syntax(file).to_option()?.items(syntax_db);
+    let mut lenses = vec![];
+    add_items_lenses(db, file, items, &mut lenses);
+    Some(lenses)
+}
+
+/// Adds the code lenses of the tests in a list of items, and returns the number of tests there.
+fn add_items_lenses(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    items: ast::ItemList,
+    lenses: &mut Vec<CodeLens>,
+) -> usize {
+    let syntax_db = db.upcast();
+    let mut n_tests = 0;
+    for item in items.elements(syntax_db) {
+        match item {
+            ast::Item::FreeFunction(function) => {
+                if !function.has_attr(syntax_db, "test") {
+                    continue;
+                }
+                n_tests += 1;
+                let name = function.declaration(syntax_db).name(syntax_db);
+                if let Some(lens) = run_test_lens(db, file, name, "Run test") {
+                    lenses.push(lens);
+                }
+            }
+            ast::Item::Module(module) => {
+                let ast::MaybeModuleBody::Some(body) = module.body(syntax_db) else { continue; };
+                // The lens of the module comes before the lenses of its tests.
+                let module_lens_index = lenses.len();
+                let n_module_tests = add_items_lenses(db, file, body.items(syntax_db), lenses);
+                if n_module_tests == 0 {
+                    continue;
+                }
+                n_tests += n_module_tests;
+                if let Some(lens) = run_test_lens(db, file, module.name(syntax_db), "Run tests") {
+                    lenses.insert(module_lens_index, lens);
+                }
+            }
+            _ => {}
+        }
+    }
+    n_tests
+}
+
+/// Returns a code lens above the name of a test function or of a module, running the tests at its
+/// path.
+fn run_test_lens(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    name: ast::TerminalIdentifier,
+    title: &str,
+) -> Option<CodeLens> {
+    let syntax_db: &dyn SyntaxGroup = db.upcast();
+    let node = name.as_syntax_node();
+    // The module found for the name of a module is the module itself.
+    let module_id = find_node_module(db, file, node.clone())?;
+    let module_path = module_id.full_path(db.upcast());
+    let path = match node.parent()?.kind(syntax_db) {
+        SyntaxKind::ItemModule => module_path,
+        _ => format!("{module_path}::{}", name.text(syntax_db)),
+    };
+    Some(CodeLens {
+        range: get_range(db, file, node.span_without_trivia(syntax_db))?,
+        command: Some(Command {
+            title: title.into(),
+            command: RUN_TEST_COMMAND.into(),
+            arguments: Some(vec![Value::String(path)]),
+        }),
+        data: None,
+    })
+}
@@ -1,8 +1,14 @@
+use std::collections::{HashMap, HashSet};
+
 use cairo_lang_defs::ids::{
-    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId,
+    DocumentableItemId, FileIndex, FunctionWithBodyId, GenericTypeId, LanguageElementId,
+    LookupItemId, ModuleFileId, ModuleId, ModuleItemId, TopLevelLanguageElementId, TraitFunctionId,
 };
 use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_semantic::corelib::core_module;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
 use cairo_lang_semantic::items::imp::ImplId;
 use cairo_lang_semantic::items::structure::SemanticStructEx;
@@ -10,12 +16,22 @@ use cairo_lang_semantic::items::us::SemanticUseEx;
 use cairo_lang_semantic::lookup_item::{HasResolverData, LookupItemEx};
 use cairo_lang_semantic::lsp_helpers::TypeFilter;
 use cairo_lang_semantic::resolve::{ResolvedGenericItem, Resolver};
-use cairo_lang_semantic::{ConcreteTypeId, TypeLongId};
+use cairo_lang_semantic::{
+    ConcreteTypeId, Expr, GenericParam, Pattern, Statement, TypeId, TypeLongId,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
-use lsp::{CompletionItem, CompletionItemKind, Position, Range, TextEdit};
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use lsp::{
+    CompletionItem, CompletionItemKind, Documentation, MarkupContent, MarkupKind, Position, Range,
+    TextEdit,
+};
 
-use crate::get_node_and_lookup_items;
+use crate::code_actions::{ancestors, find_ancestor, use_insertion_point};
+use crate::inlay_hints::is_fully_inferred;
+use crate::references::{lookup_item_exists, user_location};
+use crate::{find_node_module, get_node_and_lookup_items, get_offset, get_range};
 
 pub fn dot_completions(
     db: &(dyn SemanticGroup + 'static),
@@ -64,6 +80,7 @@ pub fn dot_completions(
                 let completion = CompletionItem {
                     label: name.to_string(),
                     detail: Some(member.ty.format(db.upcast())),
+                    documentation: documentation(db, DocumentableItemId::Member(member.id)),
                     kind: Some(CompletionItemKind::FIELD),
                     ..CompletionItem::default()
                 };
@@ -74,6 +91,696 @@ pub fn dot_completions(
     Some(completions)
 }
 
+/// The kinds of items a path is expected to resolve to at a completion position.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PathContext {
+    /// An expression or a pattern, where items of any kind may appear.
+    Any,
+    /// A type, e.g. in a type clause or in generic arguments.
+    Type,
+    /// A trait, e.g. in a generic impl parameter or in the declaration of an impl.
+    Trait,
+}
+
+/// The ranks of the completions, by the kind of their item. Completions of a lower rank are
+/// sorted first.
+const RANK_VARIABLE: u8 = 0;
+const RANK_GENERIC_PARAM: u8 = 1;
+const RANK_MODULE_ITEM: u8 = 2;
+const RANK_CORE_ITEM: u8 = 3;
+const RANK_IMPORTABLE_ITEM: u8 = 4;
+
+/// The attributes handled by the compiler and its plugins, along with their descriptions.
+const ATTRIBUTES: &[(&str, &str)] = &[
+    ("derive", "Derives implementations of traits for a type."),
+    ("inline", "Controls the inlining of a function."),
+    (
+        "panic_with",
+        "Generates a wrapper of a function returning an `Option` or a `Result`, which panics with \
+         the given error instead of returning `None` or `Err`.",
+    ),
+    ("cfg", "Compiles an item only under the given configuration."),
+    ("test", "Marks a function as a test."),
+    ("ignore", "Ignores a test unless ignored tests are included."),
+    ("should_panic", "Expects a test to panic."),
+    ("available_gas", "Sets the gas available to a test."),
+    ("contract", "Declares a Starknet contract."),
+    ("account_contract", "Declares a Starknet account contract."),
+    ("component", "Declares a component, which contracts can embed."),
+    ("embed", "Embeds the components of a `use` in a contract."),
+    ("abi", "Declares the interface of a contract, and generates its dispatchers."),
+    ("external", "Marks a contract function as an external entry point."),
+    ("view", "Marks a contract function as a view entry point."),
+    ("constructor", "Marks a contract function as its constructor."),
+    ("l1_handler", "Marks a contract function as an L1 handler."),
+    ("raw_output", "Returns the output of an entry point as is, without serializing it."),
+    ("event", "Declares a contract event."),
+    ("key", "Marks an event member as a key."),
+];
+
+/// The traits that can be derived with `#[derive(...)]`.
+const DERIVABLE_TRAITS: &[&str] = &[
+    "Copy",
+    "Drop",
+    "Clone",
+    "Destruct",
+    "PartialEq",
+    "Serde",
+    "starknet::Event",
+    "starknet::StorageAccess",
+];
+
+/// Returns the completions at a position in a file that does not follow a dot: attributes, struct
+/// members in constructors and patterns, path segments after `::`, and the identifiers in scope.
+pub fn generic_completions(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<Vec<CompletionItem>> {
+    let syntax_db = db.upcast();
+    let offset = get_offset(db, file, position)?;
+    // The token ending at the cursor, if any.
+    let token_position =
+        Position { character: position.character.saturating_sub(1), line: position.line };
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, token_position)?;
+    let lookup_items: Vec<_> = lookup_items
+        .into_iter()
+        .filter(|lookup_item_id| lookup_item_exists(db, *lookup_item_id))
+        .collect();
+    let module_file_id = ModuleFileId(find_node_module(db, file, node.clone())?, FileIndex(0));
+
+    if let Some(attribute) = find_ancestor(syntax_db, node.clone(), SyntaxKind::Attribute) {
+        let attribute = ast::Attribute::from_syntax_node(syntax_db, attribute);
+        return Some(attribute_completions(db, attribute, offset));
+    }
+    if let Some(completions) = struct_member_completions(db, &node, module_file_id) {
+        return Some(completions);
+    }
+
+    let (prefix, context) = path_prefix(db, &node);
+    if !prefix.is_empty() {
+        return path_completions(db, module_file_id, prefix, context);
+    }
+    let typed_prefix = match node.kind(syntax_db) {
+        SyntaxKind::TokenIdentifier => node.clone().get_text_without_trivia(syntax_db),
+        _ => "".into(),
+    };
+    Some(scope_completions(
+        db,
+        file,
+        &node,
+        offset,
+        module_file_id,
+        &lookup_items,
+        context,
+        &typed_prefix,
+    ))
+}
+
+/// Returns the completions of the arguments of an attribute: its name, or the derived traits.
+fn attribute_completions(
+    db: &(dyn SemanticGroup + 'static),
+    attribute: ast::Attribute,
+    offset: TextOffset,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    let ast::OptionArgListParenthesized::ArgListParenthesized(arguments) =
+        attribute.arguments(syntax_db)
+    else {
+        return attribute_name_completions();
+    };
+    if offset <= arguments.lparen(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db) {
+        return attribute_name_completions();
+    }
+    if attribute.attr(syntax_db).text(syntax_db) != "derive" {
+        return vec![];
+    }
+    let derived = arguments.args(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db);
+    let derived: Vec<_> = derived.split(',').map(|name| name.trim()).collect();
+    DERIVABLE_TRAITS
+        .iter()
+        .filter(|name| !derived.contains(name))
+        .map(|name| CompletionItem {
+            label: name.to_string(),
+            kind: Some(CompletionItemKind::INTERFACE),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+/// Returns the completions of the names of the attributes.
+fn attribute_name_completions() -> Vec<CompletionItem> {
+    ATTRIBUTES
+        .iter()
+        .map(|(name, description)| CompletionItem {
+            label: name.to_string(),
+            detail: Some(description.to_string()),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+/// Returns the completions of the members of a struct, at the place of a member in a struct
+/// constructor or pattern, excluding the members already there.
+fn struct_member_completions(
+    db: &(dyn SemanticGroup + 'static),
+    node: &SyntaxNode,
+    module_file_id: ModuleFileId,
+) -> Option<Vec<CompletionItem>> {
+    let syntax_db = db.upcast();
+    let (path, given_members) = ancestors(node.clone()).find_map(|ancestor| {
+        match ancestor.kind(syntax_db) {
+            // The value of a member, which is not a member name.
+            SyntaxKind::ExprPath
+            | SyntaxKind::StructArgExpr
+            | SyntaxKind::StructArgTail
+            | SyntaxKind::PatternStructParamWithExpr => Some(None),
+            SyntaxKind::ExprStructCtorCall => {
+                let ctor = ast::ExprStructCtorCall::from_syntax_node(syntax_db, ancestor);
+                let arguments = ctor.arguments(syntax_db).arguments(syntax_db).elements(syntax_db);
+                let given_members = arguments
+                    .into_iter()
+                    .filter_map(|argument| match argument {
+                        ast::StructArg::StructArgSingle(argument) => {
+                            Some(argument.identifier(syntax_db))
+                        }
+                        ast::StructArg::StructArgTail(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+                Some(Some((ctor.path(syntax_db), given_members)))
+            }
+            SyntaxKind::PatternStruct => {
+                let pattern = ast::PatternStruct::from_syntax_node(syntax_db, ancestor);
+                let params = pattern.params(syntax_db).elements(syntax_db);
+                let given_members = params
+                    .into_iter()
+                    .filter_map(|param| match param {
+                        ast::PatternStructParam::Single(param) => Some(param.name(syntax_db)),
+                        ast::PatternStructParam::WithExpr(param) => Some(param.name(syntax_db)),
+                        ast::PatternStructParam::Tail(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+                Some(Some((pattern.path(syntax_db), given_members)))
+            }
+            _ => None,
+        }
+    })??;
+
+    // Note: The path of a struct pattern is not resolved by the semantic model, so it is resolved
+    // here.
+    let mut resolver = Resolver::new(db, module_file_id);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let item =
+        resolver.resolve_generic_path(&mut diagnostics, &path, NotFoundItemType::Type).ok()?;
+    let ResolvedGenericItem::GenericType(GenericTypeId::Struct(struct_id)) = item else {
+        return None;
+    };
+    // The member at the cursor is not given yet.
+    let given_members: Vec<_> = given_members
+        .into_iter()
+        .filter(|member| !member.as_syntax_node().span(syntax_db).contains(node.span(syntax_db)))
+        .map(|member| member.text(syntax_db))
+        .collect();
+    Some(
+        db.struct_members(struct_id)
+            .ok()?
+            .iter()
+            .filter(|(name, _)| !given_members.contains(name))
+            .map(|(name, member)| CompletionItem {
+                label: name.to_string(),
+                detail: Some(member.ty.format(db)),
+                documentation: documentation(db, DocumentableItemId::Member(member.id)),
+                kind: Some(CompletionItemKind::FIELD),
+                ..CompletionItem::default()
+            })
+            .collect(),
+    )
+}
+
+/// Returns the segments of the path at a syntax node that precede it, and the kind of items the
+/// path is expected to resolve to.
+fn path_prefix(
+    db: &(dyn SemanticGroup + 'static),
+    node: &SyntaxNode,
+) -> (Vec<ast::PathSegment>, PathContext) {
+    let syntax_db = db.upcast();
+    let node_start = node.span_start_without_trivia(syntax_db);
+    let mut use_prefix = vec![];
+    for ancestor in ancestors(node.clone()) {
+        match ancestor.kind(syntax_db) {
+            SyntaxKind::ExprPath => {
+                let path = ast::ExprPath::from_syntax_node(syntax_db, ancestor.clone());
+                let prefix = path
+                    .elements(syntax_db)
+                    .into_iter()
+                    .take_while(|segment| {
+                        segment.as_syntax_node().span_end_without_trivia(syntax_db) <= node_start
+                    })
+                    .collect();
+                return (prefix, path_context(syntax_db, ancestor));
+            }
+            // Note: The segments of a `use` path are nested, so the prefix is collected from the
+            // innermost segment outwards.
+            SyntaxKind::UsePathSingle => {
+                let segment =
+                    ast::UsePathSingle::from_syntax_node(syntax_db, ancestor).ident(syntax_db);
+                if segment.as_syntax_node().span_end_without_trivia(syntax_db) <= node_start {
+                    use_prefix.push(segment);
+                }
+            }
+            SyntaxKind::ItemUse => {
+                use_prefix.reverse();
+                return (use_prefix, PathContext::Any);
+            }
+            _ => {}
+        }
+    }
+    // The token before the cursor, or its trivia, is in a terminal, which is in the syntax node a
+    // path is expected to follow.
+    let context = ancestors(node.clone())
+        .find(|ancestor| ancestor.kind(syntax_db).is_terminal())
+        .map_or(PathContext::Any, |terminal| path_context(syntax_db, terminal));
+    (vec![], context)
+}
+
+/// Returns the kind of items expected at a path, or after a terminal a path is expected to follow,
+/// by its parent.
+fn path_context(db: &dyn SyntaxGroup, node: SyntaxNode) -> PathContext {
+    let Some(parent) = node.parent() else { return PathContext::Any };
+    match parent.kind(db) {
+        SyntaxKind::GenericParamImpl | SyntaxKind::ItemImpl => PathContext::Trait,
+        SyntaxKind::TypeClause | SyntaxKind::ReturnTypeClause | SyntaxKind::GenericArgExpr => {
+            PathContext::Type
+        }
+        _ => PathContext::Any,
+    }
+}
+
+/// Returns the completions of the segment following a path prefix: the items of a module, or the
+/// variants of an enum.
+fn path_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    prefix: Vec<ast::PathSegment>,
+    context: PathContext,
+) -> Option<Vec<CompletionItem>> {
+    let mut resolver = Resolver::new(db, module_file_id);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let item = resolver
+        .resolve_generic_path(&mut diagnostics, prefix, NotFoundItemType::Identifier)
+        .ok()?;
+    match item {
+        ResolvedGenericItem::Module(module_id) => Some(
+            db.module_items(module_id)
+                .ok()?
+                .iter()
+                .filter_map(|item| module_item_completion(db, *item, context, RANK_MODULE_ITEM))
+                .collect(),
+        ),
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id))
+            if context == PathContext::Any =>
+        {
+            Some(
+                db.enum_variants(enum_id)
+                    .ok()?
+                    .iter()
+                    .map(|(name, variant_id)| CompletionItem {
+                        label: name.to_string(),
+                        detail: db
+                            .variant_semantic(enum_id, *variant_id)
+                            .ok()
+                            .map(|variant| variant.ty.format(db)),
+                        documentation: documentation(db, DocumentableItemId::Variant(*variant_id)),
+                        kind: Some(CompletionItemKind::ENUM_MEMBER),
+                        ..CompletionItem::default()
+                    })
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Returns the completions of the identifiers in scope at a syntax node: the variables and generic
+/// parameters, the items of the module and of the corelib, and the crates. Items of other modules
+/// named like the typed prefix are completed along with a `use` importing them.
+#[allow(clippy::too_many_arguments)]
+fn scope_completions(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: &SyntaxNode,
+    offset: TextOffset,
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    context: PathContext,
+    typed_prefix: &str,
+) -> Vec<CompletionItem> {
+    let mut completions = vec![];
+    // The names in scope, completed by the first completion of each.
+    let mut names = HashSet::new();
+    let mut add_completion = |completion: CompletionItem| {
+        if names.insert(completion.label.clone()) {
+            completions.push(completion);
+        }
+    };
+
+    if let Some(lookup_item_id) = lookup_items.first() {
+        if context == PathContext::Any {
+            if let Some(function_id) = lookup_item_id.function_with_body() {
+                variable_completions(db, function_id, node, offset)
+                    .into_iter()
+                    .for_each(&mut add_completion);
+            }
+        }
+        for param in generic_params(db, *lookup_item_id) {
+            let kind = match param {
+                GenericParam::Type(_) => CompletionItemKind::TYPE_PARAMETER,
+                GenericParam::Const(_) => CompletionItemKind::CONSTANT,
+                GenericParam::Impl(_) => CompletionItemKind::CLASS,
+            };
+            let is_in_context = match context {
+                PathContext::Any => true,
+                PathContext::Type => matches!(param, GenericParam::Type(_)),
+                PathContext::Trait => false,
+            };
+            if is_in_context {
+                add_completion(ranked(
+                    CompletionItem {
+                        label: param.id().name(db.upcast()).into(),
+                        kind: Some(kind),
+                        ..CompletionItem::default()
+                    },
+                    RANK_GENERIC_PARAM,
+                ));
+            }
+        }
+    }
+
+    let module_id = module_file_id.0;
+    let core_module_id = core_module(db);
+    for (module_id, rank) in [(module_id, RANK_MODULE_ITEM), (core_module_id, RANK_CORE_ITEM)] {
+        for item in db.module_items(module_id).map(|items| items.to_vec()).unwrap_or_default() {
+            if let Some(completion) = module_item_completion(db, item, context, rank) {
+                add_completion(completion);
+            }
+        }
+    }
+    for crate_id in db.crates() {
+        add_completion(ranked(
+            CompletionItem {
+                label: db.lookup_intern_crate(crate_id).0.into(),
+                kind: Some(CompletionItemKind::MODULE),
+                ..CompletionItem::default()
+            },
+            RANK_CORE_ITEM,
+        ));
+    }
+
+    if !typed_prefix.is_empty() {
+        importable_completions(db, file, node, module_id, core_module_id, context, typed_prefix)
+            .into_iter()
+            .for_each(&mut add_completion);
+    }
+    completions
+}
+
+/// Returns the completions of the local variables and the parameters of a function, which are in
+/// scope at a syntax node, from the innermost.
+fn variable_completions(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    node: &SyntaxNode,
+    offset: TextOffset,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    let variable_types = variable_types(db, function_id);
+    let mut variables = vec![];
+    for ancestor in ancestors(node.clone()) {
+        match ancestor.kind(syntax_db) {
+            SyntaxKind::StatementList => {
+                let statements =
+                    ast::StatementList::from_syntax_node(syntax_db, ancestor).elements(syntax_db);
+                for statement in statements.into_iter().rev() {
+                    let ast::Statement::Let(statement) = statement else { continue };
+                    if statement.as_syntax_node().span_end_without_trivia(syntax_db) <= offset {
+                        pattern_variables(syntax_db, statement.pattern(syntax_db), &mut variables);
+                    }
+                }
+            }
+            SyntaxKind::MatchArm => {
+                let arm = ast::MatchArm::from_syntax_node(syntax_db, ancestor);
+                // All the alternatives of an arm bind the same variables.
+                let patterns = arm.patterns(syntax_db).elements(syntax_db);
+                let expression_start =
+                    arm.expression(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db);
+                if let (Some(pattern), true) =
+                    (patterns.into_iter().next(), expression_start <= offset)
+                {
+                    pattern_variables(syntax_db, pattern, &mut variables);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut completions: Vec<_> = variables
+        .into_iter()
+        .map(|name| CompletionItem {
+            detail: variable_types.get(&name.stable_ptr()).map(|ty| ty.format(db)),
+            label: name.text(syntax_db).into(),
+            kind: Some(CompletionItemKind::VARIABLE),
+            ..CompletionItem::default()
+        })
+        .collect();
+    if let Ok(signature) = db.function_with_body_signature(function_id) {
+        completions.extend(signature.params.into_iter().map(|param| CompletionItem {
+            label: param.name.into(),
+            detail: Some(param.ty.format(db)),
+            kind: Some(CompletionItemKind::VARIABLE),
+            ..CompletionItem::default()
+        }));
+    }
+    completions.into_iter().map(|completion| ranked(completion, RANK_VARIABLE)).collect()
+}
+
+/// Adds the names of the variables bound by a pattern, from the last.
+fn pattern_variables(
+    db: &dyn SyntaxGroup,
+    pattern: ast::Pattern,
+    variables: &mut Vec<ast::TerminalIdentifier>,
+) {
+    let mut patterns = vec![pattern];
+    let mut names = vec![];
+    while let Some(pattern) = patterns.pop() {
+        match pattern {
+            ast::Pattern::Identifier(pattern) => names.push(pattern.name(db)),
+            // A path of a single segment is a variable.
+            ast::Pattern::Path(path) => {
+                if let [segment] = &path.elements(db)[..] {
+                    names.push(segment.identifier_ast(db));
+                }
+            }
+            ast::Pattern::Struct(pattern) => {
+                for param in pattern.params(db).elements(db) {
+                    match param {
+                        ast::PatternStructParam::Single(param) => names.push(param.name(db)),
+                        ast::PatternStructParam::WithExpr(param) => {
+                            patterns.push(param.pattern(db))
+                        }
+                        ast::PatternStructParam::Tail(_) => {}
+                    }
+                }
+            }
+            ast::Pattern::Tuple(pattern) => patterns.extend(pattern.patterns(db).elements(db)),
+            ast::Pattern::Enum(pattern) => patterns.push(pattern.pattern(db)),
+            _ => {}
+        }
+    }
+    names.sort_by_key(|name| name.as_syntax_node().offset());
+    variables.extend(names.into_iter().rev());
+}
+
+/// Returns the types of the variables defined in the body of a function, by their names.
+fn variable_types(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> HashMap<ast::TerminalIdentifierPtr, TypeId> {
+    let Ok(body) = db.function_body(function_id) else { return HashMap::new() };
+    let mut patterns: Vec<&Pattern> = vec![];
+    for (_, statement) in body.statements.iter() {
+        if let Statement::Let(statement) = statement {
+            patterns.push(&statement.pattern);
+        }
+    }
+    for (_, expr) in body.exprs.iter() {
+        if let Expr::Match(expr) = expr {
+            patterns.extend(expr.arms.iter().flat_map(|arm| arm.patterns.iter()));
+        }
+    }
+    let mut types = HashMap::new();
+    while let Some(pattern) = patterns.pop() {
+        match pattern {
+            Pattern::Variable(pattern) => {
+                if is_fully_inferred(db, pattern.var.ty) {
+                    types.insert(pattern.var.stable_ptr(db.upcast()), pattern.var.ty);
+                }
+            }
+            Pattern::Struct(pattern) => {
+                patterns.extend(pattern.field_patterns.iter().map(|(_, pattern)| pattern.as_ref()))
+            }
+            Pattern::Tuple(pattern) => {
+                patterns.extend(pattern.field_patterns.iter().map(|pattern| pattern.as_ref()))
+            }
+            Pattern::EnumVariant(pattern) => patterns.push(&pattern.inner_pattern),
+            Pattern::Literal(_) | Pattern::Range(_) | Pattern::Otherwise(_) => {}
+        }
+    }
+    types
+}
+
+/// Returns the generic parameters in scope in a lookup item.
+fn generic_params(db: &dyn SemanticGroup, lookup_item_id: LookupItemId) -> Vec<GenericParam> {
+    if let Some(function_id) = lookup_item_id.function_with_body() {
+        return db.function_with_body_generic_params(function_id).unwrap_or_default();
+    }
+    let LookupItemId::ModuleItem(item) = lookup_item_id else { return vec![] };
+    match item {
+        ModuleItemId::Struct(id) => db.struct_generic_params(id),
+        ModuleItemId::Enum(id) => db.enum_generic_params(id),
+        ModuleItemId::TypeAlias(id) => db.type_alias_generic_params(id),
+        ModuleItemId::ImplAlias(id) => db.impl_alias_generic_params(id),
+        ModuleItemId::Trait(id) => db.trait_generic_params(id),
+        ModuleItemId::Impl(id) => db.impl_def_generic_params(id),
+        ModuleItemId::ExternType(id) => db.extern_type_declaration_generic_params(id),
+        ModuleItemId::ExternFunction(id) => db.extern_function_declaration_generic_params(id),
+        _ => Ok(vec![]),
+    }
+    .unwrap_or_default()
+}
+
+/// Returns the completions of the items of other modules named like the typed prefix, which are
+/// not in scope, along with a `use` importing them.
+fn importable_completions(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: &SyntaxNode,
+    module_id: ModuleId,
+    core_module_id: ModuleId,
+    context: PathContext,
+    typed_prefix: &str,
+) -> Vec<CompletionItem> {
+    let Some((offset, text_prefix, text_suffix)) = use_insertion_point(db, file, node.clone())
+    else {
+        return vec![];
+    };
+    let Some(range) = get_range(db, file, TextSpan { start: offset, end: offset }) else {
+        return vec![];
+    };
+    let typed_prefix = typed_prefix.to_lowercase();
+    let mut completions = vec![];
+    for crate_id in db.crates() {
+        for other_module_id in db.crate_modules(crate_id).iter().copied() {
+            if other_module_id == module_id || other_module_id == core_module_id {
+                continue;
+            }
+            let Ok(items) = db.module_items(other_module_id) else { continue };
+            for item in items.iter().copied() {
+                // Uses are imported from the modules they refer to.
+                if matches!(item, ModuleItemId::Use(_)) {
+                    continue;
+                }
+                let name = item.name(db.upcast());
+                if !name.to_lowercase().starts_with(&typed_prefix) {
+                    continue;
+                }
+                // Skip items generated by plugins, which are not a part of the user's code.
+                let Ok(name_ptr) = db.module_item_name_stable_ptr(other_module_id, item) else {
+                    continue;
+                };
+                if user_location(db, item.module_file_id(db.upcast()), name_ptr).is_none() {
+                    continue;
+                }
+                let Some(completion) =
+                    module_item_completion(db, item, context, RANK_IMPORTABLE_ITEM)
+                else {
+                    continue;
+                };
+                let path = item.full_path(db.upcast());
+                completions.push(CompletionItem {
+                    detail: Some(path.clone()),
+                    additional_text_edits: Some(vec![TextEdit {
+                        range,
+                        new_text: format!("{text_prefix}use {path};{text_suffix}"),
+                    }]),
+                    ..completion
+                });
+            }
+        }
+    }
+    completions
+}
+
+/// Returns the completion of a module item, if it is of the kind expected in the context.
+fn module_item_completion(
+    db: &dyn SemanticGroup,
+    item: ModuleItemId,
+    context: PathContext,
+    rank: u8,
+) -> Option<CompletionItem> {
+    let resolved_item = ResolvedGenericItem::from_module_item(db, item).ok()?;
+    let (kind, is_type, is_trait) = match &resolved_item {
+        ResolvedGenericItem::Constant(_) => (CompletionItemKind::CONSTANT, false, false),
+        ResolvedGenericItem::Module(_) => (CompletionItemKind::MODULE, true, true),
+        ResolvedGenericItem::GenericFunction(_) | ResolvedGenericItem::TraitFunction(_) => {
+            (CompletionItemKind::FUNCTION, false, false)
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Struct(_)) => {
+            (CompletionItemKind::STRUCT, true, false)
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(_)) => {
+            (CompletionItemKind::ENUM, true, false)
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Extern(_))
+        | ResolvedGenericItem::GenericTypeAlias(_) => (CompletionItemKind::CLASS, true, false),
+        ResolvedGenericItem::Variant(_) => (CompletionItemKind::ENUM_MEMBER, false, false),
+        ResolvedGenericItem::Trait(_) => (CompletionItemKind::INTERFACE, false, true),
+        ResolvedGenericItem::Impl(_) | ResolvedGenericItem::GenericImplAlias(_) => {
+            (CompletionItemKind::VALUE, false, false)
+        }
+    };
+    let is_in_context = match context {
+        PathContext::Any => true,
+        PathContext::Type => is_type,
+        PathContext::Trait => is_trait,
+    };
+    if !is_in_context {
+        return None;
+    }
+    Some(ranked(
+        CompletionItem {
+            label: item.name(db.upcast()).into(),
+            detail: Some(resolved_item.full_path(db)),
+            documentation: documentation(db, DocumentableItemId::ModuleItem(item)),
+            kind: Some(kind),
+            ..CompletionItem::default()
+        },
+        rank,
+    ))
+}
+
+/// Sets the rank of a completion, by which it is sorted before its label.
+fn ranked(completion: CompletionItem, rank: u8) -> CompletionItem {
+    CompletionItem { sort_text: Some(format!("{rank}{}", completion.label)), ..completion }
+}
+
+/// Returns the documentation of an item, as the markdown documentation of a completion.
+fn documentation(db: &dyn SemanticGroup, item_id: DocumentableItemId) -> Option<Documentation> {
+    db.documentation(item_id).map(|value| {
+        Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value })
+    })
+}
+
 /// Returns a completion item for a method.
 fn completion_for_method(
     db: &dyn SemanticGroup,
@@ -104,6 +811,7 @@ fn completion_for_method(
         label: format!("{}()", name),
         insert_text: Some(format!("{}(", name)),
         detail: Some(detail),
+        documentation: documentation(db, DocumentableItemId::TraitFunction(trait_function)),
         kind: Some(CompletionItemKind::METHOD),
         additional_text_edits: Some(additional_text_edits),
         ..CompletionItem::default()
@@ -0,0 +1,262 @@
+//! Finding the definitions of items and of the types of expressions, and the implementations of
+//! traits.
+
+use cairo_lang_defs::ids::{
+    FileIndex, FunctionWithBodyId, GenericTypeId, LanguageElementId, ModuleFileId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use cairo_lang_semantic::items::us::get_use_segments;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::{ResolvedGenericItem, Resolver};
+use cairo_lang_semantic::{Expr, Pattern, Statement, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::Position;
+
+use crate::references::{
+    definition_name, find_item_at, item_definition_name, user_location, UserLocation,
+};
+use crate::{find_node_module, get_node_and_lookup_items, nearest_semantic_expr};
+
+/// Returns the location of the definition of the item referred to by the identifier at the given
+/// position. Path segments the semantic model keeps no resolution for (e.g. the prefixes of `use`
+/// paths, or paths in code replaced by plugins) are resolved in the module they appear in.
+pub fn find_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<UserLocation> {
+    let item = match find_item_at(db, file, position) {
+        Some((item, _)) => item,
+        None => resolve_path_at(db, file, position)?,
+    };
+    item_location(db, &item)
+}
+
+/// Returns the location of the definition of the type of the item, expression or variable at the
+/// given position.
+pub fn find_type_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<UserLocation> {
+    let generic_type = match find_item_at(db, file, position) {
+        Some((ResolvedGenericItem::GenericType(generic_type), _)) => generic_type,
+        Some((ResolvedGenericItem::GenericTypeAlias(type_alias), _)) => {
+            generic_type(db, db.type_alias_resolved_type(type_alias).to_option()?)?
+        }
+        Some((ResolvedGenericItem::Constant(constant), _)) => {
+            generic_type(db, db.constant_semantic_data(constant).to_option()?.ty)?
+        }
+        Some((ResolvedGenericItem::Variant(variant), _)) => generic_type(db, variant.ty)?,
+        _ => generic_type(db, type_at(db, file, position)?)?,
+    };
+    item_location(db, &ResolvedGenericItem::GenericType(generic_type))
+}
+
+/// Returns the locations of the implementations of the trait, or of the trait function, referred
+/// to by the identifier at the given position: the names of the impls of the trait, or of the
+/// functions implementing the trait function in them.
+pub fn find_implementations(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Vec<UserLocation> {
+    let (trait_id, trait_function_id) = match find_item_at(db, file, position) {
+        Some((ResolvedGenericItem::Trait(trait_id), _)) => (trait_id, None),
+        Some((ResolvedGenericItem::TraitFunction(trait_function_id), _)) => {
+            (trait_function_id.trait_id(db.upcast()), Some(trait_function_id))
+        }
+        _ => return vec![],
+    };
+    let mut locations = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default() {
+                let Ok(concrete_trait) = db.impl_def_concrete_trait(impl_def_id) else {
+                    continue;
+                };
+                if concrete_trait.trait_id(db) != trait_id {
+                    continue;
+                }
+                let location = match trait_function_id {
+                    None => item_location(db, &ResolvedGenericItem::Impl(impl_def_id)),
+                    Some(trait_function_id) => {
+                        let Ok(Some(impl_function_id)) =
+                            db.impl_function_by_trait_function(impl_def_id, trait_function_id)
+                        else {
+                            continue;
+                        };
+                        definition_name(
+                            db,
+                            impl_function_id.module_file_id(db.upcast()),
+                            impl_function_id.untyped_stable_ptr(db.upcast()),
+                        )
+                        .and_then(|(module_file_id, name)| {
+                            name_location(db, module_file_id, name.untyped())
+                        })
+                    }
+                };
+                locations.extend(location);
+            }
+        }
+    }
+    locations
+}
+
+/// Returns the location of the definition of an item: its name in its definition, or the start of
+/// the file of a module that is not defined inline.
+pub(crate) fn item_location(
+    db: &(dyn SemanticGroup + 'static),
+    item: &ResolvedGenericItem,
+) -> Option<UserLocation> {
+    let declaration = item_definition_name(db, item)
+        .and_then(|(module_file_id, name)| name_location(db, module_file_id, name.untyped()));
+    let ResolvedGenericItem::Module(module_id) = item else {
+        return declaration;
+    };
+    let file = db.module_main_file(*module_id).to_option()?;
+    Some(match declaration {
+        Some(location) if location.0 == file => location,
+        _ => (file, TextSpan { start: TextOffset::default(), end: TextOffset::default() }),
+    })
+}
+
+/// Returns the location of a name in a module file. Names in code generated by plugins that was
+/// not copied from the user's code are located in the generated file.
+fn name_location(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<UserLocation> {
+    user_location(db, module_file_id, stable_ptr).or_else(|| {
+        let syntax_db = db.upcast();
+        let file = db.module_file(module_file_id).to_option()?;
+        let node =
+            db.file_syntax(file).to_option()?.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
+        Some((file, node.span_without_trivia(syntax_db)))
+    })
+}
+
+/// Resolves the path up to (and including) the segment at the given position, in the module the
+/// path is in.
+fn resolve_path_at(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<ResolvedGenericItem> {
+    let syntax_db = db.upcast();
+    let (node, _) = get_node_and_lookup_items(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let segment_node = node.parent()?.parent()?;
+    let path_node = segment_node.parent()?;
+    let segments = match path_node.kind(syntax_db) {
+        SyntaxKind::ExprPath => {
+            let mut segments =
+                ast::ExprPath::from_syntax_node(syntax_db, path_node).elements(syntax_db);
+            let index = segments.iter().position(|segment| {
+                segment.as_syntax_node().stable_ptr() == segment_node.stable_ptr()
+            })?;
+            segments.truncate(index + 1);
+            segments
+        }
+        SyntaxKind::UsePathLeaf | SyntaxKind::UsePathSingle => {
+            let mut segments = vec![];
+            get_use_segments(
+                syntax_db,
+                &ast::UsePath::from_syntax_node(syntax_db, path_node),
+                &mut segments,
+            )
+            .ok()?;
+            segments
+        }
+        _ => return None,
+    };
+    let module_file_id = ModuleFileId(find_node_module(db, file, node)?, FileIndex(0));
+    let mut resolver = Resolver::new(db, module_file_id);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    if let Ok(item) = resolver.resolve_generic_path(
+        &mut diagnostics,
+        segments.clone(),
+        NotFoundItemType::Identifier,
+    ) {
+        return Some(item);
+    }
+    resolver
+        .resolve_concrete_path(&mut diagnostics, segments, NotFoundItemType::Identifier)
+        .ok()?
+        .generic(db)
+}
+
+/// Returns the type of the expression, or of the variable bound by a pattern, at the given
+/// position.
+fn type_at(db: &(dyn SemanticGroup + 'static), file: FileId, position: Position) -> Option<TypeId> {
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    let function_id = lookup_items.into_iter().find_map(|item| item.function_with_body())?;
+    if let Some(ty) = variable_type(db, function_id, node.clone()) {
+        return Some(ty);
+    }
+    Some(nearest_semantic_expr(db, node, function_id)?.ty())
+}
+
+/// Returns the type of the variable bound by the pattern containing the given node, if any.
+fn variable_type(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    mut node: SyntaxNode,
+) -> Option<TypeId> {
+    let syntax_db = db.upcast();
+    while !ast::Pattern::is_variant(node.kind(syntax_db)) {
+        node = node.parent()?;
+    }
+    let stable_ptr = ast::Pattern::from_syntax_node(syntax_db, node).stable_ptr();
+    let body = db.function_body(function_id).to_option()?;
+    let let_patterns = body.statements.iter().filter_map(|(_, statement)| match statement {
+        Statement::Let(statement_let) => Some(&statement_let.pattern),
+        _ => None,
+    });
+    let arm_patterns = body.exprs.iter().flat_map(|(_, expr)| match expr {
+        Expr::Match(expr_match) => {
+            expr_match.arms.iter().flat_map(|arm| arm.patterns.iter()).collect()
+        }
+        _ => vec![],
+    });
+    let_patterns.chain(arm_patterns).find_map(|pattern| pattern_variable_type(pattern, stable_ptr))
+}
+
+/// Returns the type of the variable at the given pointer, bound by a pattern or by its
+/// sub-patterns.
+fn pattern_variable_type(pattern: &Pattern, stable_ptr: ast::PatternPtr) -> Option<TypeId> {
+    match pattern {
+        Pattern::Variable(variable) if variable.stable_ptr == stable_ptr => Some(variable.var.ty),
+        Pattern::Struct(pattern_struct) => pattern_struct
+            .field_patterns
+            .iter()
+            .find_map(|(_, field_pattern)| pattern_variable_type(field_pattern, stable_ptr)),
+        Pattern::Tuple(pattern_tuple) => pattern_tuple
+            .field_patterns
+            .iter()
+            .find_map(|field_pattern| pattern_variable_type(field_pattern, stable_ptr)),
+        Pattern::EnumVariant(pattern_enum_variant) => {
+            pattern_variable_type(&pattern_enum_variant.inner_pattern, stable_ptr)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the generic type of a type, looking through snapshots. Returns None for types that are
+/// not defined by an item, e.g. tuples.
+fn generic_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<GenericTypeId> {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(concrete_type) => Some(concrete_type.generic_type(db)),
+        TypeLongId::Snapshot(inner) => generic_type(db, inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,205 @@
+//! Diagnostics of the files of the database, computed and published on a background task.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use cairo_lang_compiler::db::{Cancelled, RootDatabase};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::Upcast;
+use salsa::Database;
+use tokio::sync::{mpsc, Mutex};
+use tower_lsp::lsp_types::{Diagnostic, Range, Url};
+use tower_lsp::Client;
+
+use crate::{file_uri, from_pos, FileDiagnostics, State};
+
+/// The time to wait after the last change before computing diagnostics.
+const DIAGNOSTICS_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Schedules the computation of the diagnostics of the files of the database, and publishes them
+/// to the client.
+///
+/// Every scheduling, or change of the database announced by [DiagnosticsScheduler::cancel],
+/// supersedes the computations scheduled before it. A computation waits for the changes to settle
+/// down before starting, and stops between two files once superseded.
+#[derive(Clone)]
+pub struct DiagnosticsScheduler {
+    client: Client,
+    db_mutex: Arc<Mutex<RootDatabase>>,
+    state_mutex: Arc<Mutex<State>>,
+    /// The generation of the latest scheduled computation. A computation is superseded once it
+    /// differs from its own generation.
+    generation: Arc<AtomicU64>,
+}
+
+/// An update of the diagnostics of a file, sent from the computation to the publishing task.
+enum DiagnosticsUpdate {
+    /// New diagnostics of a file.
+    Publish { file_id: FileId, uri: Url, diagnostics: FileDiagnostics, lsp: Vec<Diagnostic> },
+    /// The file is no longer a part of the database.
+    Clear { file_id: FileId, uri: Url },
+}
+
+impl DiagnosticsScheduler {
+    pub fn new(
+        client: Client,
+        db_mutex: Arc<Mutex<RootDatabase>>,
+        state_mutex: Arc<Mutex<State>>,
+    ) -> Self {
+        Self { client, db_mutex, state_mutex, generation: Default::default() }
+    }
+
+    /// Supersedes the scheduled computations. Should be called before changing the database, so
+    /// that the computations running on snapshots of it stop as soon as possible, and release the
+    /// snapshots the change waits for.
+    pub fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Schedules a computation of the diagnostics of all the files of the database, starting with
+    /// the given files, then the open ones.
+    pub fn schedule(&self, priority_files: Vec<FileId>) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let scheduler = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(DIAGNOSTICS_DEBOUNCE).await;
+            if !scheduler.is_superseded(generation) {
+                scheduler.refresh(generation, priority_files).await;
+            }
+        });
+    }
+
+    /// Returns true if a computation of the given generation is superseded.
+    fn is_superseded(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) != generation
+    }
+
+    /// Computes the diagnostics on a blocking thread, and publishes the ones that changed, file by
+    /// file.
+    async fn refresh(&self, generation: u64, priority_files: Vec<FileId>) {
+        let (open_files, known_files) = {
+            let state = self.state_mutex.lock().await;
+            let open_files: Vec<_> = state.open_files.iter().copied().collect();
+            let known_files: HashSet<_> = state.file_diagnostics.keys().copied().collect();
+            (open_files, known_files)
+        };
+        let db = self.db_mutex.lock().await.snapshot();
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let scheduler = self.clone();
+        let computation = tokio::task::spawn_blocking(move || {
+            // A computation cancelled by a change of the database just stops.
+            let _ = Cancelled::catch(|| {
+                let is_cancelled = || {
+                    scheduler.is_superseded(generation)
+                        || db.salsa_runtime().is_current_revision_canceled()
+                };
+
+                // Get all files, starting with the priority and open ones.
+                let mut files_set: OrderedHashSet<_> =
+                    priority_files.into_iter().chain(open_files).collect();
+                for crate_id in db.crates() {
+                    for module_id in db.crate_modules(crate_id).iter() {
+                        for file_id in db.module_files(*module_id).unwrap_or_default() {
+                            files_set.insert(file_id);
+                        }
+                    }
+                    if is_cancelled() {
+                        return;
+                    }
+                }
+
+                // Get all diagnostics.
+                for file_id in files_set.iter().copied() {
+                    let parser = db.file_syntax_diagnostics(file_id);
+                    let semantic = db.file_semantic_diagnostics(file_id).unwrap_or_default();
+                    if is_cancelled() {
+                        return;
+                    }
+                    let lowering = db.file_lowering_diagnostics(file_id).unwrap_or_default();
+                    if is_cancelled() {
+                        return;
+                    }
+                    let diagnostics = FileDiagnostics { parser, semantic, lowering };
+                    let mut lsp = Vec::new();
+                    get_diagnostics(db.upcast(), &mut lsp, &diagnostics.parser);
+                    get_diagnostics(db.upcast(), &mut lsp, &diagnostics.semantic);
+                    get_diagnostics(db.upcast(), &mut lsp, &diagnostics.lowering);
+                    let uri = file_uri(&db, file_id);
+                    let update = DiagnosticsUpdate::Publish { file_id, uri, diagnostics, lsp };
+                    if sender.send(update).is_err() {
+                        return;
+                    }
+                }
+
+                // Clear old diagnostics.
+                for file_id in known_files {
+                    if !files_set.contains(&file_id) {
+                        let uri = file_uri(&db, file_id);
+                        if sender.send(DiagnosticsUpdate::Clear { file_id, uri }).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        });
+
+        while let Some(update) = receiver.recv().await {
+            // Checking under the lock makes sure superseded diagnostics are never published after
+            // the ones superseding them.
+            let mut state = self.state_mutex.lock().await;
+            if self.is_superseded(generation) {
+                break;
+            }
+            match update {
+                DiagnosticsUpdate::Publish { file_id, uri, diagnostics, lsp } => {
+                    // Since we are using Arcs, this comparison should be efficient.
+                    if state.file_diagnostics.get(&file_id) == Some(&diagnostics) {
+                        continue;
+                    }
+                    state.file_diagnostics.insert(file_id, diagnostics);
+                    self.client.publish_diagnostics(uri, lsp, None).await;
+                }
+                DiagnosticsUpdate::Clear { file_id, uri } => {
+                    state.file_diagnostics.remove(&file_id);
+                    self.client.publish_diagnostics(uri, Vec::new(), None).await;
+                }
+            }
+        }
+        // Dropping the receiver stops the computation if it is still running.
+        drop(receiver);
+        if let Err(err) = computation.await {
+            self.client
+                .log_message(
+                    tower_lsp::lsp_types::MessageType::ERROR,
+                    format!("Diagnostics computation failed: {err}"),
+                )
+                .await;
+        }
+    }
+}
+
+/// Converts internal format diagnostics to LSP format.
+fn get_diagnostics<T: DiagnosticEntry>(
+    db: &T::DbType,
+    diags: &mut Vec<Diagnostic>,
+    diagnostics: &Diagnostics<T>,
+) {
+    for diagnostic in diagnostics.get_all() {
+        let location = diagnostic.location(db);
+        let message = diagnostic.format(db);
+        let start =
+            from_pos(location.span.start.position_in_file(db.upcast(), location.file_id).unwrap());
+        let end =
+            from_pos(location.span.start.position_in_file(db.upcast(), location.file_id).unwrap());
+        diags.push(Diagnostic { range: Range { start, end }, message, ..Diagnostic::default() });
+    }
+}
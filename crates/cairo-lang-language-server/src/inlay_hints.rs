@@ -0,0 +1,198 @@
+//! Inlay hints - the inferred types of `let` bindings and the parameter names of call arguments.
+
+use cairo_lang_defs::ids::{FileIndex, FunctionWithBodyId, ModuleFileId};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::{
+    Expr, ExprFunctionCall, GenericArgumentId, Pattern, Statement, StatementLet, TypeId, TypeLongId,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range};
+
+use crate::references::lookup_item_exists;
+use crate::{find_node_module, from_pos, lookup_item_from_ast};
+
+/// Returns the inlay hints of the functions in a file that intersect the given range.
+pub fn inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+) -> Option<Vec<InlayHint>> {
+    let syntax_db = db.upcast();
+    let root = db.file_syntax(file).to_option()?.as_syntax_node();
+    let mut hints = vec![];
+    for function_node in function_nodes(syntax_db, root.clone()) {
+        let span = function_node.span_without_trivia(syntax_db);
+        let (Some(start), Some(end)) = (
+            span.start.position_in_file(db.upcast(), file),
+            span.end.position_in_file(db.upcast(), file),
+        ) else { continue; };
+        if from_pos(end) < range.start || range.end < from_pos(start) {
+            continue;
+        }
+        let Some(module_id) = find_node_module(db, file, function_node.clone()) else { continue; };
+        let Some(lookup_item_id) = lookup_item_from_ast(
+            db,
+            ModuleFileId(module_id, FileIndex(0)),
+            function_node,
+        ) else { continue; };
+        if !lookup_item_exists(db, lookup_item_id) {
+            continue;
+        }
+        let Some(function_id) = lookup_item_id.function_with_body() else { continue; };
+        let mut builder = HintsBuilder { db, file, root: &root, hints: vec![] };
+        builder.add_function_hints(function_id);
+        hints.extend(builder.hints);
+    }
+    hints.retain(|hint| range.start <= hint.position && hint.position <= range.end);
+    Some(hints)
+}
+
+/// Returns all the functions with a body defined under a syntax node.
+fn function_nodes(db: &dyn SyntaxGroup, node: SyntaxNode) -> Vec<SyntaxNode> {
+    if node.kind(db) == SyntaxKind::FunctionWithBody {
+        return vec![node];
+    }
+    node.children(db).flat_map(|child| function_nodes(db, child)).collect()
+}
+
+/// Returns whether a type is known, i.e. neither missing nor containing inference variables, which
+/// remain when the inference of the function failed.
+pub(crate) fn is_fully_inferred(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(concrete) => {
+            concrete.generic_args(db).into_iter().all(|generic_arg| match generic_arg {
+                GenericArgumentId::Type(ty) => is_fully_inferred(db, ty),
+                GenericArgumentId::Literal(_) | GenericArgumentId::Impl(_) => true,
+            })
+        }
+        TypeLongId::Tuple(types) => types.into_iter().all(|ty| is_fully_inferred(db, ty)),
+        TypeLongId::Snapshot(ty) => is_fully_inferred(db, ty),
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => true,
+        TypeLongId::Var(_) | TypeLongId::Missing(_) => false,
+    }
+}
+
+/// Collects the inlay hints of the expressions in a function, located by their stable pointers in
+/// the syntax tree of the file.
+struct HintsBuilder<'a> {
+    db: &'a (dyn SemanticGroup + 'static),
+    file: FileId,
+    root: &'a SyntaxNode,
+    hints: Vec<InlayHint>,
+}
+impl<'a> HintsBuilder<'a> {
+    /// Adds the hints of the `let` statements and the function calls of a function.
+    fn add_function_hints(&mut self, function_id: FunctionWithBodyId) {
+        let Ok(body) = self.db.function_body(function_id) else { return; };
+        for (_, statement) in body.statements.iter() {
+            if let Statement::Let(statement_let) = statement {
+                self.add_let_hints(statement_let);
+            }
+        }
+        for (_, expr) in body.exprs.iter() {
+            if let Expr::FunctionCall(call) = expr {
+                self.add_call_hints(call);
+            }
+        }
+    }
+
+    /// Adds the types of the variables bound by a `let` statement without a type clause.
+    fn add_let_hints(&mut self, statement_let: &StatementLet) {
+        let syntax_db = self.db.upcast();
+        let node = self.root.lookup_ptr(syntax_db, statement_let.stable_ptr.untyped());
+        let ast::Statement::Let(let_ast) = ast::Statement::from_syntax_node(syntax_db, node) else {
+            return;
+        };
+        if matches!(let_ast.type_clause(syntax_db), ast::OptionTypeClause::TypeClause(_)) {
+            return;
+        }
+        self.add_pattern_hints(&statement_let.pattern);
+    }
+
+    /// Adds the types of the variables bound by a pattern, directly or in a tuple.
+    fn add_pattern_hints(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Variable(variable) => {
+                if !is_fully_inferred(self.db, variable.var.ty) {
+                    return;
+                }
+                let syntax_db = self.db.upcast();
+                let node = self.root.lookup_ptr(syntax_db, variable.stable_ptr.untyped());
+                let Some(position) = self.position(node.span_without_trivia(syntax_db).end) else {
+                    return;
+                };
+                self.hints.push(InlayHint {
+                    position,
+                    label: InlayHintLabel::String(format!(": {}", variable.var.ty.format(self.db))),
+                    kind: Some(InlayHintKind::TYPE),
+                    text_edits: None,
+                    tooltip: None,
+                    padding_left: None,
+                    padding_right: None,
+                    data: None,
+                });
+            }
+            Pattern::Tuple(tuple) => {
+                for field_pattern in &tuple.field_patterns {
+                    self.add_pattern_hints(field_pattern);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the names of the parameters of a function call before its unnamed arguments, unless
+    /// the argument is already named like the parameter.
+    fn add_call_hints(&mut self, call: &ExprFunctionCall) {
+        let syntax_db = self.db.upcast();
+        let node = self.root.lookup_ptr(syntax_db, call.stable_ptr.untyped());
+        // Operators are function calls as well, and are skipped.
+        let (call_ast, is_method_call) = match ast::Expr::from_syntax_node(syntax_db, node) {
+            ast::Expr::FunctionCall(call_ast) => (call_ast, false),
+            ast::Expr::Binary(binary) => match (binary.op(syntax_db), binary.rhs(syntax_db)) {
+                (ast::BinaryOperator::Dot(_), ast::Expr::FunctionCall(call_ast)) => {
+                    (call_ast, true)
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        let Ok(signature) = self.db.concrete_function_signature(call.function) else { return; };
+        let args = call_ast.arguments(syntax_db).args(syntax_db).elements(syntax_db);
+        // The `self` argument of a method call is its left-hand side.
+        let params = signature.params.iter().skip(usize::from(is_method_call));
+        for (arg, param) in args.iter().zip(params) {
+            let ast::ArgClause::Unnamed(arg_clause) = arg.arg_clause(syntax_db) else { continue; };
+            let value = arg_clause.value(syntax_db).as_syntax_node();
+            if value.get_text_without_trivia(syntax_db) == param.name {
+                continue;
+            }
+            let Some(position) =
+                self.position(arg.as_syntax_node().span_start_without_trivia(syntax_db))
+            else {
+                continue;
+            };
+            self.hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(format!("{}:", param.name)),
+                kind: Some(InlayHintKind::PARAMETER),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            });
+        }
+    }
+
+    /// Returns the position of an offset in the file.
+    fn position(&self, offset: TextOffset) -> Option<Position> {
+        Some(from_pos(offset.position_in_file(self.db.upcast(), self.file)?))
+    }
+}
@@ -7,43 +7,42 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::bail;
-use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::db::{Cancelled, RootDatabase};
 use cairo_lang_compiler::project::{setup_project, update_crate_roots_from_project_config};
-use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{
-    ConstantLongId, EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex,
-    FreeFunctionLongId, FunctionTitleId, FunctionWithBodyId, ImplDefLongId, ImplFunctionLongId,
-    LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId,
-    TraitLongId, UseLongId,
+    ConstantLongId, DocumentableItemId, EnumLongId, ExternFunctionLongId, ExternTypeLongId,
+    FileIndex, FreeFunctionLongId, FunctionWithBodyId, GenericTypeId, ImplAliasLongId,
+    ImplDefLongId, ImplFunctionLongId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId,
+    StructLongId, TraitFunctionLongId, TraitLongId, TypeAliasLongId, UseLongId,
 };
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
+use cairo_lang_diagnostics::{Diagnostics, ToOption};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::db::{
     init_dev_corelib, AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
 };
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileId, FileLongId};
-use cairo_lang_filesystem::span::{TextPosition, TextWidth};
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
 use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
-use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::diagnostic::LoweringDiagnostic;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_parser::ParserDiagnostic;
 use cairo_lang_project::ProjectConfig;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_runner::RunResultValue;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
 use cairo_lang_semantic::resolve::ResolvedGenericItem;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_starknet::plugin::StarkNetPlugin;
-use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::GetIdentifier;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_syntax::node::utils::is_grandparent_of_kind;
 use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_test_runner::{TestRunner, TestStatus};
 use cairo_lang_utils::logging::init_logging;
-use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper, Upcast};
 use log::warn;
 use lsp::notification::Notification;
@@ -52,20 +51,40 @@ use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tower_lsp::jsonrpc::Result;
+use tower_lsp::jsonrpc::{ErrorCode, Result};
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 use vfs::{ProvideVirtualFileRequest, ProvideVirtualFileResponse};
 
-use crate::completions::dot_completions;
+use crate::code_actions::code_actions;
+use crate::code_lens::{test_code_lenses, RUN_TEST_COMMAND};
+use crate::completions::{dot_completions, generic_completions};
+use crate::definition::{find_definition, find_implementations, find_type_definition};
+use crate::diagnostics::DiagnosticsScheduler;
+use crate::inlay_hints::inlay_hints;
+use crate::references::{find_item_at, find_references, is_renamable};
 use crate::scarb_service::{is_scarb_manifest_path, ScarbService};
+use crate::signature_help::signature_help;
+use crate::symbols::{document_symbols, workspace_symbols};
 
 mod scarb_service;
 mod semantic_highlighting;
 
+pub mod code_actions;
+pub mod code_lens;
 pub mod completions;
+pub mod definition;
+pub mod diagnostics;
+pub mod inlay_hints;
+pub mod references;
+pub mod signature_help;
+pub mod symbols;
 pub mod vfs;
 
+#[cfg(test)]
+#[path = "lib_test.rs"]
+mod test;
+
 const MAX_CRATE_DETECTION_DEPTH: usize = 20;
 
 pub async fn serve_language_service() {
@@ -86,6 +105,7 @@ pub async fn serve_language_service() {
 
     let (service, socket) = LspService::build(|client| Backend::new(client, db.into()))
         .custom_method("vfs/provide", Backend::vfs_provide)
+        .custom_method("textDocument/inlayHint", Backend::inlay_hint)
         .finish();
     Server::new(stdin, stdout, socket).serve(service).await;
 }
@@ -103,9 +123,10 @@ pub struct State {
 }
 pub struct Backend {
     pub client: Client,
-    // TODO(spapini): Remove this once we support ParallelDatabase.
-    pub db_mutex: tokio::sync::Mutex<RootDatabase>,
-    pub state_mutex: tokio::sync::Mutex<State>,
+    /// The database, locked for changes. Read-only requests are handled on snapshots of it.
+    pub db_mutex: Arc<tokio::sync::Mutex<RootDatabase>>,
+    pub state_mutex: Arc<tokio::sync::Mutex<State>>,
+    pub diagnostics: DiagnosticsScheduler,
     pub scarb: ScarbService,
 }
 fn from_pos(pos: TextPosition) -> Position {
@@ -113,130 +134,72 @@ fn from_pos(pos: TextPosition) -> Position {
 }
 impl Backend {
     pub fn new(client: Client, db_mutex: tokio::sync::Mutex<RootDatabase>) -> Self {
-        Self {
-            client,
-            db_mutex,
-            state_mutex: State::default().into(),
-            scarb: ScarbService::default(),
-        }
-    }
-
-    /// Locks and gets a database instance.
-    async fn db(&self) -> tokio::sync::MutexGuard<'_, RootDatabase> {
-        self.db_mutex.lock().await
-    }
-    /// Gets a FileId from a URI.
-    fn file(&self, db: &RootDatabase, uri: Url) -> FileId {
-        match uri.scheme() {
-            "file" => {
-                let path = uri.to_file_path().unwrap();
-                FileId::new(db, path)
-            }
-            "vfs" => {
-                let id = uri.host_str().unwrap().parse::<usize>().unwrap();
-                FileId::from_intern_id(id.into())
-            }
-            _ => panic!(),
-        }
+        let db_mutex = Arc::new(db_mutex);
+        let state_mutex: Arc<tokio::sync::Mutex<State>> = Default::default();
+        let diagnostics =
+            DiagnosticsScheduler::new(client.clone(), db_mutex.clone(), state_mutex.clone());
+        Self { client, db_mutex, state_mutex, diagnostics, scarb: ScarbService::default() }
     }
 
-    fn get_uri(&self, db: &RootDatabase, file_id: FileId) -> Url {
-        let virtual_file = match db.lookup_intern_file(file_id) {
-            FileLongId::OnDisk(path) => return Url::from_file_path(path).unwrap(),
-            FileLongId::Virtual(virtual_file) => virtual_file,
-        };
-        let uri = Url::parse(
-            format!("vfs://{}/{}.cairo", file_id.as_intern_id().as_usize(), virtual_file.name)
-                .as_str(),
-        )
-        .unwrap();
-        uri
+    /// Gets a read-only snapshot of the database.
+    ///
+    /// Changes of the database wait for all its snapshots to be dropped, so a snapshot must not be
+    /// held across an `.await`.
+    async fn db(&self) -> RootDatabase {
+        self.db_mutex.lock().await.snapshot()
     }
 
-    // TODO(spapini): Consider managing vfs in a different way, using the
-    // client.send_notification::<UpdateVirtualFile> call.
-
-    // Refresh diagnostics and send diffs to client.
-    async fn refresh_diagnostics(&self) {
-        let mut state = self.state_mutex.lock().await;
-
-        // Get all files. Try to go over open files first.
-        let mut files_set: OrderedHashSet<_> = state.open_files.iter().copied().collect();
+    /// Handles a read-only request on a snapshot of the database, on a blocking thread, so that
+    /// long computations do not stall the other requests.
+    ///
+    /// The computation is cancelled once a change of the database is pending, so that the change
+    /// does not wait for it, and the request fails with [ErrorCode::ContentModified].
+    async fn with_db<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&RootDatabase) -> T + Send + 'static,
+    ) -> Result<T> {
         let db = self.db().await;
-        for crate_id in db.crates() {
-            for module_id in db.crate_modules(crate_id).iter() {
-                for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    files_set.insert(file_id);
-                }
-            }
-        }
-
-        // Get all diagnostics.
-        for file_id in files_set.iter().copied() {
-            let uri = self.get_uri(&db, file_id);
-            let new_file_diagnostics = FileDiagnostics {
-                parser: db.file_syntax_diagnostics(file_id),
-                semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
-                lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
-            };
-            // Since we are using Arcs, this comparison should be efficient.
-            if let Some(old_file_diagnostics) = state.file_diagnostics.get(&file_id) {
-                if old_file_diagnostics == &new_file_diagnostics {
-                    continue;
-                }
-            }
-            let mut diags = Vec::new();
-            self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.parser);
-            self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.semantic);
-            self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lowering);
-            state.file_diagnostics.insert(file_id, new_file_diagnostics);
-
-            self.client.publish_diagnostics(uri, diags, None).await
-        }
-
-        // Clear old diagnostics.
-        let old_files: Vec<_> = state.file_diagnostics.keys().copied().collect();
-        for file_id in old_files {
-            if files_set.contains(&file_id) {
-                continue;
-            }
-            state.file_diagnostics.remove(&file_id);
-            let uri = self.get_uri(&db, file_id);
-            self.client.publish_diagnostics(uri, Vec::new(), None).await;
+        match tokio::task::spawn_blocking(move || Cancelled::catch(|| f(&db))).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(Cancelled)) => Err(tower_lsp::jsonrpc::Error {
+                code: ErrorCode::ContentModified,
+                message: "The request was cancelled by a change of the content.".to_string(),
+                data: None,
+            }),
+            Err(err) => Err(tower_lsp::jsonrpc::Error {
+                code: ErrorCode::InternalError,
+                message: format!("The request failed: {err}"),
+                data: None,
+            }),
         }
     }
 
-    /// Converts internal format diagnostics to LSP format.
-    fn get_diagnostics<T: DiagnosticEntry>(
-        &self,
-        db: &T::DbType,
-        diags: &mut Vec<Diagnostic>,
-        diagnostics: &Diagnostics<T>,
-    ) {
-        for diagnostic in diagnostics.get_all() {
-            let location = diagnostic.location(db);
-            let message = diagnostic.format(db);
-            let start = from_pos(
-                location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
-            );
-            let end = from_pos(
-                location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
-            );
-            diags.push(Diagnostic {
-                range: Range { start, end },
-                message,
-                ..Diagnostic::default()
-            });
-        }
+    /// Locks and gets the database instance for changes, superseding the scheduled diagnostics
+    /// computations.
+    async fn db_mut(&self) -> tokio::sync::MutexGuard<'_, RootDatabase> {
+        let db = self.db_mutex.lock().await;
+        self.diagnostics.cancel();
+        db
     }
+    // TODO(spapini): Consider managing vfs in a different way, using the
+    // client.send_notification::<UpdateVirtualFile> call.
 
     pub async fn vfs_provide(
         &self,
         params: ProvideVirtualFileRequest,
     ) -> Result<ProvideVirtualFileResponse> {
-        let db = self.db().await;
-        let file_id = self.file(&db, params.uri);
-        Ok(ProvideVirtualFileResponse { content: db.file_content(file_id).map(|s| (*s).clone()) })
+        self.with_db(move |db| {
+            let file_id = file_id(db, params.uri);
+            ProvideVirtualFileResponse { content: db.file_content(file_id).map(|s| (*s).clone()) }
+        })
+        .await
+    }
+
+    /// Handles `textDocument/inlayHint`, which is not a part of the [LanguageServer] trait of this
+    /// version of tower-lsp.
+    pub async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        self.with_db(move |db| inlay_hints(db, file_id(db, params.text_document.uri), params.range))
+            .await
     }
 
     pub async fn notify_scarb_missing(&self) {
@@ -303,9 +266,100 @@ impl Backend {
         }
     }
 
+    /// Runs the tests whose path is, or is under, the given path, in the crates of the database.
+    /// The result of each test is logged, and a summary is shown to the user.
+    pub async fn run_tests(&self, path: String) {
+        let db = self.db().await;
+        let filter = path.clone();
+        // The snapshot is dropped once the tests are compiled, so that changes of the database do
+        // not wait for the tests to run. A change during the compilation cancels it.
+        let compiled = tokio::task::spawn_blocking(move || {
+            Cancelled::catch(move || {
+                let core_crate = db.core_crate();
+                let main_crate_ids =
+                    db.crates().into_iter().filter(|id| *id != core_crate).collect();
+                TestRunner::with_db(db, main_crate_ids, &filter, true, true).compile()
+            })
+            .unwrap_or_else(|Cancelled| bail!("The tests were cancelled by a change of the code."))
+        });
+        let results = match compiled.await {
+            Ok(Ok(compiled)) => tokio::task::spawn_blocking(move || compiled.run_silently()).await,
+            Ok(Err(err)) => Ok(Err(err)),
+            Err(err) => Err(err),
+        };
+        let results = match results {
+            Ok(Ok(results)) => results,
+            Ok(Err(err)) => {
+                self.client.show_message(MessageType::ERROR, format!("{err:#}")).await;
+                return;
+            }
+            Err(err) => {
+                self.client.show_message(MessageType::ERROR, err).await;
+                return;
+            }
+        };
+        let prefix = format!("{path}::");
+        let (mut passed, mut failed, mut ignored) = (0, 0, 0);
+        for (name, result) in results {
+            if name != path && !name.starts_with(&prefix) {
+                continue;
+            }
+            let status = match result.status {
+                TestStatus::Success => {
+                    passed += 1;
+                    "ok".to_string()
+                }
+                TestStatus::Fail(RunResultValue::Success(_)) => {
+                    failed += 1;
+                    "fail - expected panic but finished successfully".to_string()
+                }
+                TestStatus::Fail(RunResultValue::Panic(values)) => {
+                    failed += 1;
+                    let values = values
+                        .iter()
+                        .map(|value| match as_cairo_short_string(value) {
+                            Some(as_string) => format!("{value} ('{as_string}')"),
+                            None => value.to_string(),
+                        })
+                        .collect::<Vec<_>>();
+                    format!("fail - panicked with [{}]", values.join(", "))
+                }
+                TestStatus::Ignore => {
+                    ignored += 1;
+                    "ignored".to_string()
+                }
+            };
+            let gas_used = match result.gas_used {
+                Some(gas_used) => format!(" (gas used: {gas_used})"),
+                None => "".to_string(),
+            };
+            self.client
+                .log_message(MessageType::INFO, format!("test {name} ... {status}{gas_used}"))
+                .await;
+        }
+        let (message_type, result) = match (passed + failed + ignored, failed) {
+            (0, _) => {
+                self.client
+                    .show_message(MessageType::WARNING, format!("No tests found at `{path}`."))
+                    .await;
+                return;
+            }
+            (_, 0) => (MessageType::INFO, "ok"),
+            _ => (MessageType::ERROR, "FAILED"),
+        };
+        self.client
+            .show_message(
+                message_type,
+                format!(
+                    "test result: {result}. {passed} passed; {failed} failed; {ignored} ignored"
+                ),
+            )
+            .await;
+    }
+
     /// Reload crate detection for all open files.
     pub async fn reload(&self) {
-        let mut db = self.db().await;
+        let mut db = self.db_mut().await;
         for file in self.state_mutex.lock().await.open_files.iter() {
             let file = db.lookup_intern_file(*file);
             if let FileLongId::OnDisk(file_path) = file {
@@ -315,7 +369,7 @@ impl Backend {
             }
         }
         drop(db);
-        self.refresh_diagnostics().await;
+        self.diagnostics.schedule(vec![]);
     }
 }
 
@@ -332,6 +386,7 @@ impl Notification for ScarbPathMissing {
 
 pub enum ServerCommands {
     Reload,
+    RunTest,
 }
 
 impl TryFrom<String> for ServerCommands {
@@ -340,6 +395,7 @@ impl TryFrom<String> for ServerCommands {
     fn try_from(value: String) -> anyhow::Result<Self> {
         match value.as_str() {
             "cairo1.reload" => Ok(ServerCommands::Reload),
+            RUN_TEST_COMMAND => Ok(ServerCommands::RunTest),
             _ => bail!("Unrecognized command: {value}"),
         }
     }
@@ -352,16 +408,17 @@ impl LanguageServer for Backend {
             server_info: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
-                    trigger_characters: Some(vec![".".to_string()]),
+                    trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                     work_done_progress_options: Default::default(),
                     all_commit_characters: None,
+                    completion_item: None,
                 }),
                 execute_command_provider: Some(ExecuteCommandOptions {
-                    commands: vec!["cairo1.reload".to_string()],
+                    commands: vec!["cairo1.reload".to_string(), RUN_TEST_COMMAND.to_string()],
                     work_done_progress_options: Default::default(),
                 }),
                 workspace: Some(WorkspaceServerCapabilities {
@@ -385,8 +442,26 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
+                implementation_provider: Some(ImplementationProviderCapability::Simple(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: Default::default(),
+                }),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                code_lens_provider: Some(CodeLensOptions { resolve_provider: Some(false) }),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
+            offset_encoding: None,
         })
     }
 
@@ -423,10 +498,10 @@ impl LanguageServer for Backend {
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
         // Invalidate changed cairo files.
-        let mut db = self.db().await;
+        let mut db = self.db_mut().await;
         for change in &params.changes {
             if is_cairo_file_path(&change.uri) {
-                let file = self.file(&db, change.uri.clone());
+                let file = file_id(&db, change.uri.clone());
                 PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file);
             }
         }
@@ -437,6 +512,7 @@ impl LanguageServer for Backend {
                 self.reload().await;
             }
         }
+        self.diagnostics.schedule(vec![]);
     }
 
     async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
@@ -446,6 +522,14 @@ impl LanguageServer for Backend {
                 ServerCommands::Reload => {
                     self.reload().await;
                 }
+                ServerCommands::RunTest => {
+                    let Some(Value::String(path)) = params.arguments.into_iter().next() else {
+                        return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                            "Expected the path of the tests to run.",
+                        ));
+                    };
+                    self.run_tests(path).await;
+                }
             }
         }
 
@@ -459,269 +543,362 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
-        let mut db = self.db().await;
+        let mut db = self.db_mut().await;
         let uri = params.text_document.uri;
         let path = uri.path();
         self.detect_crate_for(&mut db, path).await;
 
-        let file = self.file(&db, uri.clone());
+        let file = file_id(&db, uri.clone());
+        db.override_file_content(file, Some(Arc::new(params.text_document.text)));
         self.state_mutex.lock().await.open_files.insert(file);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.diagnostics.schedule(vec![file]);
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let text =
-            if let [TextDocumentContentChangeEvent { text, .. }] = &params.content_changes[..] {
-                text
-            } else {
-                eprintln!("Unexpected format of document change.");
-                return;
-            };
-        let mut db = self.db().await;
+        // Note: The database is locked before any other `.await`, so that the changes, which may
+        // be handled concurrently, are applied in the order they were sent.
+        let mut db = self.db_mut().await;
         let uri = params.text_document.uri;
-        let file = self.file(&db, uri.clone());
-        db.override_file_content(file, Some(Arc::new(text.into())));
+        let file = file_id(&db, uri.clone());
+        let Some(content) = db.file_content(file) else {
+            eprintln!("Change of a missing file '{uri}'.");
+            return;
+        };
+        let mut text = (*content).clone();
+        for change in params.content_changes {
+            if let Err(err) = apply_content_change(&mut text, change) {
+                eprintln!("Unexpected document change of '{uri}': {err}");
+                return;
+            }
+        }
+        db.override_file_content(file, Some(Arc::new(text)));
         drop(db);
-        self.refresh_diagnostics().await;
+        self.diagnostics.schedule(vec![file]);
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
-        let mut db = self.db().await;
-        let file = self.file(&db, params.text_document.uri);
+        let mut db = self.db_mut().await;
+        let file = file_id(&db, params.text_document.uri);
         PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file);
         db.override_file_content(file, None);
+        drop(db);
+        self.diagnostics.schedule(vec![file]);
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
-        let mut db = self.db().await;
-        let file = self.file(&db, params.text_document.uri);
+        let mut db = self.db_mut().await;
+        let file = file_id(&db, params.text_document.uri);
         self.state_mutex.lock().await.open_files.remove(&file);
         db.override_file_content(file, None);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.diagnostics.schedule(vec![]);
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
-        let db = self.db().await;
-        let db = &*db;
-        let text_document_position = params.text_document_position;
-        let file_uri = text_document_position.text_document.uri;
-        eprintln!("Complete {file_uri}");
-        let file = self.file(db, file_uri);
-        let position = text_document_position.position;
-
-        let completions =
-            if params.context.and_then(|x| x.trigger_character).map(|x| x == *".") == Some(true) {
+        self.with_db(move |db| {
+            let text_document_position = params.text_document_position;
+            let file_uri = text_document_position.text_document.uri;
+            eprintln!("Complete {file_uri}");
+            let file = file_id(db, file_uri);
+            let position = text_document_position.position;
+
+            let completions = if params.context.and_then(|x| x.trigger_character).map(|x| x == *".")
+                == Some(true)
+            {
                 dot_completions(db, file, position)
             } else {
-                Some(vec![])
+                generic_completions(db, file, position)
             };
-        Ok(completions.map(CompletionResponse::Array))
+            completions.map(CompletionResponse::Array)
+        })
+        .await
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        self.with_db(move |db| {
+            let text_document_position = params.text_document_position_params;
+            let file = file_id(db, text_document_position.text_document.uri);
+            signature_help(db, file, text_document_position.position)
+        })
+        .await
     }
 
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
     ) -> Result<Option<SemanticTokensResult>> {
-        let db = self.db().await;
-        let file_uri = params.text_document.uri;
-        let file = self.file(&db, file_uri.clone());
-        let syntax = if let Ok(syntax) = db.file_syntax(file) {
-            syntax
-        } else {
-            eprintln!("Semantic analysis failed. File '{file_uri}' does not exist.");
-            return Ok(None);
-        };
+        self.with_db(move |db| {
+            let file_uri = params.text_document.uri;
+            let file = file_id(db, file_uri.clone());
+            let syntax = if let Ok(syntax) = db.file_syntax(file) {
+                syntax
+            } else {
+                eprintln!("Semantic analysis failed. File '{file_uri}' does not exist.");
+                return None;
+            };
 
-        let node = syntax.as_syntax_node();
-        let mut data: Vec<SemanticToken> = Vec::new();
-        SemanticTokensTraverser::default().find_semantic_tokens((*db).upcast(), &mut data, node);
-        Ok(Some(SemanticTokensResult::Tokens(SemanticTokens { result_id: None, data })))
+            let node = syntax.as_syntax_node();
+            let mut data: Vec<SemanticToken> = Vec::new();
+            SemanticTokensTraverser::default().find_semantic_tokens(db.upcast(), &mut data, node);
+            Some(SemanticTokensResult::Tokens(SemanticTokens { result_id: None, data }))
+        })
+        .await
     }
 
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
-        let db = self.db().await;
-        let file_uri = params.text_document.uri;
-        let file = self.file(&db, file_uri.clone());
-        let syntax = if let Ok(syntax) = db.file_syntax(file) {
-            syntax
-        } else {
-            eprintln!("Formatting failed. File '{file_uri}' does not exist.");
-            return Ok(None);
-        };
-        let new_text = get_formatted_file(
-            (*db).upcast(),
-            &syntax.as_syntax_node(),
-            FormatterConfig::default(),
-        );
-
-        let file_summary = if let Some(summary) = db.file_summary(file) {
-            summary
-        } else {
-            eprintln!("Formatting failed. Cannot get summary for file '{file_uri}'.");
-            return Ok(None);
-        };
-        let old_line_count = if let Ok(count) = file_summary.line_count().try_into() {
-            count
-        } else {
-            eprintln!("Formatting failed. Line count out of bound in file '{file_uri}'.");
-            return Ok(None);
-        };
+        self.with_db(move |db| {
+            let file_uri = params.text_document.uri;
+            let file = file_id(db, file_uri.clone());
+            let syntax = if let Ok(syntax) = db.file_syntax(file) {
+                syntax
+            } else {
+                eprintln!("Formatting failed. File '{file_uri}' does not exist.");
+                return None;
+            };
+            let new_text = get_formatted_file(
+                db.upcast(),
+                &syntax.as_syntax_node(),
+                FormatterConfig::default(),
+            );
 
-        Ok(Some(vec![TextEdit {
-            range: Range {
-                start: Position { line: 0, character: 0 },
-                end: Position { line: old_line_count, character: 0 },
-            },
-            new_text,
-        }]))
+            let file_summary = if let Some(summary) = db.file_summary(file) {
+                summary
+            } else {
+                eprintln!("Formatting failed. Cannot get summary for file '{file_uri}'.");
+                return None;
+            };
+            let old_line_count = if let Ok(count) = file_summary.line_count().try_into() {
+                count
+            } else {
+                eprintln!("Formatting failed. Line count out of bound in file '{file_uri}'.");
+                return None;
+            };
+
+            Some(vec![TextEdit {
+                range: Range {
+                    start: Position { line: 0, character: 0 },
+                    end: Position { line: old_line_count, character: 0 },
+                },
+                new_text,
+            }])
+        })
+        .await
     }
 
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
-        let db = self.db().await;
-        let file_uri = params.text_document_position_params.text_document.uri;
-        eprintln!("Hover {file_uri}");
-        let file = self.file(&db, file_uri);
-        let position = params.text_document_position_params.position;
-        let Some((node, lookup_items)) =
-            get_node_and_lookup_items(&*db, file, position) else { return Ok(None); };
-        let Some(lookup_item_id) = lookup_items.into_iter().next() else {
-                return Ok(None);
-            };
-        let function_id = match lookup_item_id {
-            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
-                FunctionWithBodyId::Free(free_function_id)
-            }
-            LookupItemId::ImplFunction(impl_function_id) => {
-                FunctionWithBodyId::Impl(impl_function_id)
+        self.with_db(move |db| {
+            let file_uri = params.text_document_position_params.text_document.uri;
+            eprintln!("Hover {file_uri}");
+            let file = file_id(db, file_uri);
+            let position = params.text_document_position_params.position;
+            let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+            let lookup_item_id = lookup_items.into_iter().next()?;
+
+            // Build texts.
+            let mut hints = Vec::new();
+            if let Some(function_id) = lookup_item_id.function_with_body() {
+                if let Some(hint) = get_expr_hint(db, function_id, node.clone()) {
+                    hints.push(MarkedString::String(hint));
+                };
             }
-            _ => {
-                return Ok(None);
+            if let Some(hint) = get_identifier_hint(db, lookup_item_id, node) {
+                hints.push(MarkedString::String(hint));
+            };
+            if let Some(documentation) =
+                find_item_at(db, file, position).and_then(|(item, _)| item_documentation(db, &item))
+            {
+                hints.push(MarkedString::String(documentation));
             }
-        };
-
-        // Build texts.
-        let mut hints = Vec::new();
-        if let Some(hint) = get_expr_hint(&*db, function_id, node.clone()) {
-            hints.push(MarkedString::String(hint));
-        };
-        if let Some(hint) = get_identifier_hint(&*db, lookup_item_id, node) {
-            hints.push(MarkedString::String(hint));
-        };
 
-        Ok(Some(Hover { contents: HoverContents::Array(hints), range: None }))
+            Some(Hover { contents: HoverContents::Array(hints), range: None })
+        })
+        .await
     }
     async fn goto_definition(
         &self,
         params: GotoDefinitionParams,
     ) -> Result<Option<GotoDefinitionResponse>> {
-        let db = self.db().await;
-        let syntax_db = (*db).upcast();
-        let file_uri = params.text_document_position_params.text_document.uri;
-        let file = self.file(&db, file_uri.clone());
-        let position = params.text_document_position_params.position;
-        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
-        for lookup_item_id in lookup_items {
-            if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
-                continue;
-            }
-            let identifier =
-                ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
-            let Some(item) = db.lookup_resolved_generic_item_by_ptr(
-                lookup_item_id, identifier.stable_ptr())
-            else { continue; };
-
-            let defs_db = (*db).upcast();
-            let (module_id, file_index, stable_ptr) = match item {
-                ResolvedGenericItem::Constant(item) => (
-                    item.parent_module(defs_db),
-                    item.file_index(defs_db),
-                    item.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::Module(item) => {
-                    (item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
-                }
-                ResolvedGenericItem::GenericFunction(item) => {
-                    let title = match item {
-                        GenericFunctionId::Free(id) => FunctionTitleId::Free(id),
-                        GenericFunctionId::Extern(id) => FunctionTitleId::Extern(id),
-                        GenericFunctionId::Impl(id) => {
-                            // Note: Only the trait title is returned.
-                            FunctionTitleId::Trait(id.function)
-                        }
-                    };
-                    (
-                        title.parent_module(defs_db),
-                        title.file_index(defs_db),
-                        title.untyped_stable_ptr(defs_db),
-                    )
-                }
-                ResolvedGenericItem::GenericType(generic_type) => (
-                    generic_type.parent_module(defs_db),
-                    generic_type.file_index(defs_db),
-                    generic_type.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::GenericTypeAlias(type_alias) => (
-                    type_alias.parent_module(defs_db),
-                    type_alias.file_index(defs_db),
-                    type_alias.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::GenericImplAlias(impl_alias) => (
-                    impl_alias.parent_module(defs_db),
-                    impl_alias.file_index(defs_db),
-                    impl_alias.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::Variant(variant) => (
-                    variant.id.parent_module(defs_db),
-                    variant.id.file_index(defs_db),
-                    variant.id.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::Trait(trt) => (
-                    trt.parent_module(defs_db),
-                    trt.file_index(defs_db),
-                    trt.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::Impl(imp) => (
-                    imp.parent_module(defs_db),
-                    imp.file_index(defs_db),
-                    imp.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::TraitFunction(trait_function) => (
-                    trait_function.parent_module(defs_db),
-                    trait_function.file_index(defs_db),
-                    trait_function.stable_ptr(defs_db).untyped(),
-                ),
-            };
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document_position_params.text_document.uri);
+            let position = params.text_document_position_params.position;
+            let (file, span) = find_definition(db, file, position)?;
+            let range = get_range(db, file, span)?;
+            Some(GotoDefinitionResponse::Scalar(Location { uri: file_uri(db, file), range }))
+        })
+        .await
+    }
 
-            let file = if let Ok(files) = db.module_files(module_id) {
-                files[file_index.0]
-            } else {
-                return Ok(None);
-            };
+    async fn goto_type_definition(
+        &self,
+        params: request::GotoTypeDefinitionParams,
+    ) -> Result<Option<request::GotoTypeDefinitionResponse>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document_position_params.text_document.uri);
+            let position = params.text_document_position_params.position;
+            let (file, span) = find_type_definition(db, file, position)?;
+            let range = get_range(db, file, span)?;
+            Some(GotoDefinitionResponse::Scalar(Location { uri: file_uri(db, file), range }))
+        })
+        .await
+    }
 
-            let uri = self.get_uri(&db, file);
-            let syntax = if let Ok(syntax) = db.file_syntax(file) {
-                syntax
-            } else {
-                eprintln!("Formatting failed. File '{file_uri}' does not exist.");
-                return Ok(None);
-            };
-            let node = syntax.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
-            let span = node.span_without_trivia(syntax_db);
+    async fn goto_implementation(
+        &self,
+        params: request::GotoImplementationParams,
+    ) -> Result<Option<request::GotoImplementationResponse>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document_position_params.text_document.uri);
+            let position = params.text_document_position_params.position;
+            let locations: Vec<_> = find_implementations(db, file, position)
+                .into_iter()
+                .filter_map(|(file, span)| {
+                    Some(Location { uri: file_uri(db, file), range: get_range(db, file, span)? })
+                })
+                .collect();
+            if locations.is_empty() { None } else { Some(GotoDefinitionResponse::Array(locations)) }
+        })
+        .await
+    }
 
-            let start = from_pos(span.start.position_in_file((*db).upcast(), file).unwrap());
-            let end = from_pos(span.end.position_in_file((*db).upcast(), file).unwrap());
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document_position.text_document.uri);
+            let position = params.text_document_position.position;
+            let (item, _) = find_item_at(db, file, position)?;
+            let locations = find_references(db, &item, params.context.include_declaration)
+                .into_iter()
+                .filter_map(|(file, span)| {
+                    Some(Location { uri: file_uri(db, file), range: get_range(db, file, span)? })
+                })
+                .collect();
+            Some(locations)
+        })
+        .await
+    }
 
-            return Ok(Some(GotoDefinitionResponse::Scalar(Location {
-                uri,
-                range: Range { start, end },
-            })));
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document.uri);
+            Some(code_actions(db, file, params.range, &params.context.diagnostics, |file| {
+                file_uri(db, file)
+            }))
+        })
+        .await
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        self.with_db(move |db| test_code_lenses(db, file_id(db, params.text_document.uri))).await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document.uri);
+            document_symbols(db, file).map(DocumentSymbolResponse::Nested)
+        })
+        .await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        self.with_db(move |db| {
+            Some(workspace_symbols(db, &params.query, |file| file_uri(db, file)))
+        })
+        .await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document.uri);
+            let (item, identifier) = find_item_at(db, file, params.position)?;
+            if !is_renamable(db, &item) {
+                return None;
+            }
+            let span = identifier.as_syntax_node().span_without_trivia(db.upcast());
+            get_range(db, file, span).map(PrepareRenameResponse::Range)
+        })
+        .await
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let new_name = params.new_name;
+        if !is_identifier(&new_name) {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "`{new_name}` is not a valid identifier."
+            )));
         }
-        return Ok(None);
+        self.with_db(move |db| {
+            let file = file_id(db, params.text_document_position.text_document.uri);
+            let position = params.text_document_position.position;
+            let (item, _) = find_item_at(db, file, position)?;
+            if !is_renamable(db, &item) {
+                return None;
+            }
+            let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+            for (file, span) in find_references(db, &item, true) {
+                let Some(range) = get_range(db, file, span) else { continue };
+                changes
+                    .entry(file_uri(db, file))
+                    .or_default()
+                    .push(TextEdit { range, new_text: new_name.clone() });
+            }
+            Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() })
+        })
+        .await
     }
 }
 
+/// Gets a FileId from a URI.
+fn file_id(db: &RootDatabase, uri: Url) -> FileId {
+    match uri.scheme() {
+        "file" => {
+            let path = uri.to_file_path().unwrap();
+            FileId::new(db, path)
+        }
+        "vfs" => {
+            let id = uri.host_str().unwrap().parse::<usize>().unwrap();
+            FileId::from_intern_id(id.into())
+        }
+        _ => panic!(),
+    }
+}
+
+/// Returns the URI of a file.
+fn file_uri(db: &RootDatabase, file_id: FileId) -> Url {
+    let virtual_file = match db.lookup_intern_file(file_id) {
+        FileLongId::OnDisk(path) => return Url::from_file_path(path).unwrap(),
+        FileLongId::Virtual(virtual_file) => virtual_file,
+    };
+    let uri = Url::parse(
+        format!("vfs://{}/{}.cairo", file_id.as_intern_id().as_usize(), virtual_file.name).as_str(),
+    )
+    .unwrap();
+    uri
+}
+
+/// Converts a span in a file to an LSP range.
+fn get_range(db: &dyn SemanticGroup, file: FileId, span: TextSpan) -> Option<Range> {
+    Some(Range {
+        start: from_pos(span.start.position_in_file(db.upcast(), file)?),
+        end: from_pos(span.end.position_in_file(db.upcast(), file)?),
+    })
+}
+
+/// Returns true if the given text is a valid identifier.
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next().map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// If the ast node is a lookup item, return the corresponding id. Otherwise, return None.
 /// See [LookupItemId].
 fn lookup_item_from_ast(
@@ -730,7 +907,7 @@ fn lookup_item_from_ast(
     node: SyntaxNode,
 ) -> Option<LookupItemId> {
     let syntax_db = db.upcast();
-    // TODO(spapini): Handle trait items.
+    // TODO(spapini): Handle trait constants and types.
     match node.kind(syntax_db) {
         SyntaxKind::ItemConstant => Some(LookupItemId::ModuleItem(ModuleItemId::Constant(
             db.intern_constant(ConstantLongId(
@@ -777,6 +954,24 @@ fn lookup_item_from_ast(
                 ast::ItemImpl::from_syntax_node(syntax_db, node).stable_ptr(),
             )))))
         }
+        SyntaxKind::TraitItemFunction => {
+            Some(LookupItemId::TraitFunction(db.intern_trait_function(TraitFunctionLongId(
+                module_file_id,
+                ast::TraitItemFunction::from_syntax_node(syntax_db, node).stable_ptr(),
+            ))))
+        }
+        SyntaxKind::ItemTypeAlias => Some(LookupItemId::ModuleItem(ModuleItemId::TypeAlias(
+            db.intern_type_alias(TypeAliasLongId(
+                module_file_id,
+                ast::ItemTypeAlias::from_syntax_node(syntax_db, node).stable_ptr(),
+            )),
+        ))),
+        SyntaxKind::ItemImplAlias => Some(LookupItemId::ModuleItem(ModuleItemId::ImplAlias(
+            db.intern_impl_alias(ImplAliasLongId(
+                module_file_id,
+                ast::ItemImplAlias::from_syntax_node(syntax_db, node).stable_ptr(),
+            )),
+        ))),
         SyntaxKind::ItemStruct => {
             Some(LookupItemId::ModuleItem(ModuleItemId::Struct(db.intern_struct(StructLongId(
                 module_file_id,
@@ -812,33 +1007,15 @@ fn get_node_and_lookup_items(
 
     // Get syntax for file.
     let syntax = db.file_syntax(file).to_option().on_none(|| {
-        eprintln!("Formatting failed. File '{filename}' does not exist.");
-    })?;
-
-    // Get file summary and content.
-    let file_summary = db.file_summary(file).on_none(|| {
-        eprintln!("Hover failed. File '{filename}' does not exist.");
-    })?;
-    let content = db.file_content(file).on_none(|| {
-        eprintln!("Hover failed. File '{filename}' does not exist.");
+        eprintln!("Failed to find a node. File '{filename}' does not exist.");
     })?;
 
-    // Find offset for position.
-    let mut offset = *file_summary.line_offsets.get(position.line as usize).on_none(|| {
-        eprintln!("Hover failed. Position out of bounds.");
-    })?;
-    let mut chars_it = offset.take_from(&content).chars();
-    for _ in 0..position.character {
-        let c = chars_it.next().on_none(|| {
-            eprintln!("Position does not exist.");
-        })?;
-        offset = offset.add_width(TextWidth::from_char(c));
-    }
+    let offset = get_offset(db, file, position)?;
     let node = syntax.as_syntax_node().lookup_offset(syntax_db, offset);
 
     // Find module.
     let module_id = find_node_module(db, file, node.clone()).on_none(|| {
-        eprintln!("Hover failed. Failed to find module.");
+        eprintln!("Failed to find the module of a node in file '{filename}'.");
     })?;
     let file_index = FileIndex(0);
     let module_file_id = ModuleFileId(module_id, file_index);
@@ -858,6 +1035,66 @@ fn get_node_and_lookup_items(
     }
 }
 
+/// Applies a change of an open document to its text.
+fn apply_content_change(
+    text: &mut String,
+    change: TextDocumentContentChangeEvent,
+) -> anyhow::Result<()> {
+    let Some(range) = change.range else {
+        *text = change.text;
+        return Ok(());
+    };
+    let start = get_text_offset(text, range.start)?;
+    let end = get_text_offset(text, range.end)?;
+    if start > end {
+        bail!("Invalid range {range:?}.");
+    }
+    text.replace_range(start..end, &change.text);
+    Ok(())
+}
+
+/// Returns the byte offset of a position in a text. Like in [get_offset], the character of the
+/// position is counted in chars, and is clamped to the length of its line.
+fn get_text_offset(text: &str, position: Position) -> anyhow::Result<usize> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        let Some(line_length) = text[line_start..].find('\n') else {
+            bail!("Position {position:?} out of bounds.");
+        };
+        line_start += line_length + 1;
+    }
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let column = line.char_indices().nth(position.character as usize).map(|(i, _)| i);
+    Ok(line_start + column.unwrap_or(line.len()))
+}
+
+/// Returns the offset of a position in a file.
+fn get_offset(db: &dyn SemanticGroup, file: FileId, position: Position) -> Option<TextOffset> {
+    let filename = file.file_name(db.upcast());
+
+    // Get file summary and content.
+    let file_summary = db.file_summary(file).on_none(|| {
+        eprintln!("Failed to find an offset. File '{filename}' does not exist.");
+    })?;
+    let content = db.file_content(file).on_none(|| {
+        eprintln!("Failed to find an offset. File '{filename}' does not exist.");
+    })?;
+
+    // Find offset for position.
+    let mut offset = *file_summary.line_offsets.get(position.line as usize).on_none(|| {
+        eprintln!("Failed to find an offset. Position out of bounds in file '{filename}'.");
+    })?;
+    let mut chars_it = offset.take_from(&content).chars();
+    for _ in 0..position.character {
+        let c = chars_it.next().on_none(|| {
+            eprintln!("Failed to find an offset. Position does not exist in file '{filename}'.");
+        })?;
+        offset = offset.add_width(TextWidth::from_char(c));
+    }
+    Some(offset)
+}
+
 fn find_node_module(
     db: &(dyn SemanticGroup + 'static),
     main_file: FileId,
@@ -907,6 +1144,44 @@ fn get_identifier_hint(
     Some(format!("`{}`", item.full_path(db)))
 }
 
+/// Returns the documentation of an item, written in its doc comments, if any. The documentation of
+/// an impl function is the one of the trait function it implements.
+pub(crate) fn item_documentation(
+    db: &dyn SemanticGroup,
+    item: &ResolvedGenericItem,
+) -> Option<String> {
+    let module_item_id = match item {
+        ResolvedGenericItem::Constant(id) => ModuleItemId::Constant(*id),
+        ResolvedGenericItem::Module(ModuleId::Submodule(id)) => ModuleItemId::Submodule(*id),
+        ResolvedGenericItem::Module(ModuleId::CrateRoot(_)) => return None,
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id)) => {
+            ModuleItemId::FreeFunction(*id)
+        }
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Extern(id)) => {
+            ModuleItemId::ExternFunction(*id)
+        }
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Impl(id)) => {
+            return db.documentation(DocumentableItemId::TraitFunction(id.function));
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Struct(id)) => ModuleItemId::Struct(*id),
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(id)) => ModuleItemId::Enum(*id),
+        ResolvedGenericItem::GenericType(GenericTypeId::Extern(id)) => {
+            ModuleItemId::ExternType(*id)
+        }
+        ResolvedGenericItem::GenericTypeAlias(id) => ModuleItemId::TypeAlias(*id),
+        ResolvedGenericItem::GenericImplAlias(id) => ModuleItemId::ImplAlias(*id),
+        ResolvedGenericItem::Variant(variant) => {
+            return db.documentation(DocumentableItemId::Variant(variant.id));
+        }
+        ResolvedGenericItem::Trait(id) => ModuleItemId::Trait(*id),
+        ResolvedGenericItem::Impl(id) => ModuleItemId::Impl(*id),
+        ResolvedGenericItem::TraitFunction(id) => {
+            return db.documentation(DocumentableItemId::TraitFunction(*id));
+        }
+    };
+    db.documentation(DocumentableItemId::ModuleItem(module_item_id))
+}
+
 /// If the node is an expression, retrieves a hover hint for it.
 fn get_expr_hint(
     db: &(dyn SemanticGroup + 'static),
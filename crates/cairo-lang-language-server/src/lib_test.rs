@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_parser::db::ParserGroup;
+use salsa::Database;
+use tower_lsp::jsonrpc::ErrorCode;
+use tower_lsp::lsp_types::{
+    DidChangeTextDocumentParams, TextDocumentContentChangeEvent, Url,
+    VersionedTextDocumentIdentifier,
+};
+use tower_lsp::{LanguageServer, LspService};
+
+use super::Backend;
+
+/// Returns a backend with a database containing a single file with the given content.
+fn setup(path: &str, content: &str) -> (Backend, FileId) {
+    let mut db = RootDatabase::builder().build().unwrap();
+    let file = FileId::new(&db, PathBuf::from(path));
+    db.override_file_content(file, Some(Arc::new(content.into())));
+    let mut client = None;
+    let (_service, _socket) = LspService::new(|service_client| {
+        client = Some(service_client.clone());
+        Backend::new(service_client, RootDatabase::builder().build().unwrap().into())
+    });
+    (Backend::new(client.unwrap(), db.into()), file)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_change_during_read() {
+    let path = "/test.cairo";
+    let (backend, file) = setup(path, "fn foo() {}");
+
+    let read = backend.with_db(move |db| {
+        // A long computation, which is still running once the change arrives.
+        while !db.salsa_runtime().is_current_revision_canceled() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        db.file_syntax(file).is_ok()
+    });
+    let change = backend.did_change(DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier {
+            uri: Url::from_file_path(path).unwrap(),
+            version: 1,
+        },
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "fn bar() {}".into(),
+        }],
+    });
+    let (read, ()) = tokio::join!(read, change);
+
+    // The read is cancelled, rather than delaying the change until it is done.
+    assert_eq!(read.unwrap_err().code, ErrorCode::ContentModified);
+    let content = backend.with_db(move |db| db.file_content(file)).await.unwrap();
+    assert_eq!(content.unwrap().as_str(), "fn bar() {}");
+}
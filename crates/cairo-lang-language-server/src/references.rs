@@ -0,0 +1,371 @@
+//! Finding the references to items, and renaming them.
+
+use cairo_lang_defs::ids::{
+    GenericTypeId, LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::{CrateId, FileId};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::corelib::core_crate;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::plugin::DynPluginAuxData;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use lsp::Position;
+
+use crate::get_node_and_lookup_items;
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
+/// A location in the user's code - a file and a span in it.
+pub type UserLocation = (FileId, TextSpan);
+
+/// Returns the item referred to (or defined) by the identifier at the given position, along with
+/// the identifier.
+pub fn find_item_at(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<(ResolvedGenericItem, ast::TerminalIdentifier)> {
+    let syntax_db = db.upcast();
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent()?);
+    let mut found_lookup_item = false;
+    for lookup_item_id in lookup_items {
+        if !lookup_item_exists(db, lookup_item_id) {
+            continue;
+        }
+        found_lookup_item = true;
+        if let Some(item) =
+            db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+        {
+            return Some((usage_item(item), identifier));
+        }
+        if let Some((item, ..)) = defined_names(db, lookup_item_id)
+            .into_iter()
+            .find(|(_, _, name)| *name == identifier.stable_ptr())
+        {
+            return Some((item, identifier));
+        }
+    }
+    if found_lookup_item {
+        return None;
+    }
+    // The code was replaced by code generated by a plugin (e.g. the storage variables of a
+    // contract), so the item is found by the location its name was copied from, among the items
+    // of the crate.
+    let crate_id = db.file_modules(file).ok()?.first()?.owning_crate(db.upcast());
+    let location = Some((file, identifier.as_syntax_node().span_without_trivia(syntax_db)));
+    let item = crate_lookup_items(db, crate_id)
+        .flat_map(|lookup_item_id| defined_names(db, lookup_item_id))
+        .find_map(|(item, module_file_id, name)| {
+            (user_location(db, module_file_id, name.untyped()) == location).then_some(item)
+        })?;
+    Some((item, identifier))
+}
+
+/// Returns the locations of the references to an item in the user's code, optionally including
+/// its definition. Usages in code generated by plugins are mapped to the code they were copied
+/// from, and are omitted if there is no such code. The name of an impl function is considered a
+/// usage of the trait function it implements.
+pub fn find_references(
+    db: &(dyn SemanticGroup + 'static),
+    item: &ResolvedGenericItem,
+    include_declaration: bool,
+) -> Vec<UserLocation> {
+    let mut locations = OrderedHashSet::default();
+    if include_declaration {
+        if let Some(location) = item_definition_name(db, item)
+            .and_then(|(module_file_id, name)| user_location(db, module_file_id, name.untyped()))
+        {
+            locations.insert(location);
+        }
+    }
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for (module_file_id, identifier, usage) in
+                db.module_usages(*module_id).unwrap_or_default().iter()
+            {
+                if usage_item(usage.clone()) != *item {
+                    continue;
+                }
+                if let Some(location) = user_location(db, *module_file_id, identifier.untyped()) {
+                    locations.insert(location);
+                }
+            }
+            let ResolvedGenericItem::TraitFunction(trait_function_id) = item else { continue; };
+            let trait_id = trait_function_id.trait_id(db.upcast());
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default() {
+                if db.impl_def_concrete_trait(impl_def_id).map(|c| c.trait_id(db)) != Ok(trait_id) {
+                    continue;
+                }
+                let Ok(Some(impl_function_id)) =
+                    db.impl_function_by_trait_function(impl_def_id, *trait_function_id)
+                else {
+                    continue;
+                };
+                if let Some(location) = definition_name(
+                    db,
+                    impl_function_id.module_file_id(db.upcast()),
+                    impl_function_id.untyped_stable_ptr(db.upcast()),
+                )
+                .and_then(|(module_file_id, name)| {
+                    user_location(db, module_file_id, name.untyped())
+                }) {
+                    locations.insert(location);
+                }
+            }
+        }
+    }
+    locations.into_iter().collect()
+}
+
+/// Returns true if the item can be renamed: it is defined in the user's code, outside of the
+/// corelib.
+pub fn is_renamable(db: &(dyn SemanticGroup + 'static), item: &ResolvedGenericItem) -> bool {
+    let Some((module_file_id, name)) = item_definition_name(db, item) else { return false; };
+    module_file_id.0.owning_crate(db.upcast()) != core_crate(db)
+        && user_location(db, module_file_id, name.untyped()).is_some()
+}
+
+/// Returns the item a usage refers to, for the purpose of finding references - a trait function
+/// called through an impl is considered a usage of the trait function.
+fn usage_item(item: ResolvedGenericItem) -> ResolvedGenericItem {
+    match item {
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Impl(impl_function)) => {
+            ResolvedGenericItem::TraitFunction(impl_function.function)
+        }
+        item => item,
+    }
+}
+
+/// Returns the lookup items of all the modules of a crate, including the functions of their
+/// traits and impls.
+fn crate_lookup_items(
+    db: &dyn SemanticGroup,
+    crate_id: CrateId,
+) -> impl Iterator<Item = LookupItemId> + '_ {
+    db.crate_modules(crate_id).iter().copied().collect::<Vec<_>>().into_iter().flat_map(
+        move |module_id| {
+            let mut lookup_items = vec![];
+            for module_item in db.module_items(module_id).unwrap_or_default().iter() {
+                lookup_items.push(LookupItemId::ModuleItem(*module_item));
+                match module_item {
+                    ModuleItemId::Trait(trait_id) => lookup_items.extend(
+                        db.trait_functions(*trait_id)
+                            .unwrap_or_default()
+                            .values()
+                            .map(|id| LookupItemId::TraitFunction(*id)),
+                    ),
+                    ModuleItemId::Impl(impl_def_id) => lookup_items.extend(
+                        db.impl_functions(*impl_def_id)
+                            .unwrap_or_default()
+                            .values()
+                            .map(|id| LookupItemId::ImplFunction(*id)),
+                    ),
+                    _ => {}
+                }
+            }
+            lookup_items
+        },
+    )
+}
+
+/// Returns true if the lookup item exists in the module it refers to. Lookup items built from
+/// the user's code may not exist in the module if it was replaced by code generated by a plugin.
+pub(crate) fn lookup_item_exists(db: &dyn SemanticGroup, lookup_item_id: LookupItemId) -> bool {
+    match lookup_item_id {
+        LookupItemId::ModuleItem(module_item) => db
+            .module_items(module_item.parent_module(db.upcast()))
+            .map_or(false, |module_items| module_items.contains(&module_item)),
+        LookupItemId::ImplFunction(impl_function_id) => {
+            let impl_def_id = impl_function_id.impl_def_id(db.upcast());
+            lookup_item_exists(db, LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)))
+                && db.impl_functions(impl_def_id).map_or(false, |impl_functions| {
+                    impl_functions.values().any(|id| *id == impl_function_id)
+                })
+        }
+        LookupItemId::TraitFunction(trait_function_id) => {
+            let trait_id = trait_function_id.trait_id(db.upcast());
+            lookup_item_exists(db, LookupItemId::ModuleItem(ModuleItemId::Trait(trait_id)))
+                && db.trait_functions(trait_id).map_or(false, |trait_functions| {
+                    trait_functions.values().any(|id| *id == trait_function_id)
+                })
+        }
+    }
+}
+
+/// Returns the items defined by a lookup item, along with their names and the module files they
+/// are in: the item itself, along with the functions of a trait and the variants of an enum. The
+/// name of an impl function is considered a definition of the trait function it implements.
+fn defined_names(
+    db: &dyn SemanticGroup,
+    lookup_item_id: LookupItemId,
+) -> Vec<(ResolvedGenericItem, ModuleFileId, ast::TerminalIdentifierPtr)> {
+    let items = match lookup_item_id {
+        LookupItemId::ModuleItem(ModuleItemId::Use(_)) => vec![],
+        // The functions of a trait are defined by the trait itself.
+        LookupItemId::TraitFunction(_) => vec![],
+        LookupItemId::ModuleItem(ModuleItemId::Trait(trait_id)) => {
+            let mut items = vec![ResolvedGenericItem::Trait(trait_id)];
+            items.extend(
+                db.trait_functions(trait_id).unwrap_or_default().values().map(
+                    |trait_function_id| ResolvedGenericItem::TraitFunction(*trait_function_id),
+                ),
+            );
+            items
+        }
+        LookupItemId::ModuleItem(ModuleItemId::Enum(enum_id)) => {
+            let mut items = vec![ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id))];
+            for variant_id in db.enum_variants(enum_id).unwrap_or_default().values() {
+                if let Ok(variant) = db.variant_semantic(enum_id, *variant_id) {
+                    items.push(ResolvedGenericItem::Variant(variant));
+                }
+            }
+            items
+        }
+        LookupItemId::ModuleItem(module_item) => {
+            ResolvedGenericItem::from_module_item(db, module_item).into_iter().collect()
+        }
+        LookupItemId::ImplFunction(impl_function_id) => {
+            let Ok(trait_function_id) = db.impl_function_trait_function(impl_function_id) else {
+                return vec![];
+            };
+            return definition_name(
+                db,
+                impl_function_id.module_file_id(db.upcast()),
+                impl_function_id.untyped_stable_ptr(db.upcast()),
+            )
+            .map(|(module_file_id, name)| {
+                (ResolvedGenericItem::TraitFunction(trait_function_id), module_file_id, name)
+            })
+            .into_iter()
+            .collect();
+        }
+    };
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let (module_file_id, name) = item_definition_name(db, &item)?;
+            Some((item, module_file_id, name))
+        })
+        .collect()
+}
+
+/// Returns the name identifier in the definition of an item, along with the module file it is in.
+pub(crate) fn item_definition_name(
+    db: &dyn SemanticGroup,
+    item: &ResolvedGenericItem,
+) -> Option<(ModuleFileId, ast::TerminalIdentifierPtr)> {
+    fn definition<T: LanguageElementId>(
+        db: &dyn SemanticGroup,
+        id: T,
+    ) -> (ModuleFileId, SyntaxStablePtrId) {
+        (id.module_file_id(db.upcast()), id.untyped_stable_ptr(db.upcast()))
+    }
+    let (module_file_id, stable_ptr) = match item {
+        ResolvedGenericItem::Constant(id) => definition(db, *id),
+        ResolvedGenericItem::Module(ModuleId::Submodule(id)) => definition(db, *id),
+        ResolvedGenericItem::Module(ModuleId::CrateRoot(_)) => return None,
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id)) => definition(db, *id),
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Extern(id)) => definition(db, *id),
+        ResolvedGenericItem::GenericFunction(GenericFunctionId::Impl(id)) => {
+            definition(db, id.function)
+        }
+        ResolvedGenericItem::TraitFunction(id) => definition(db, *id),
+        ResolvedGenericItem::GenericType(id) => definition(db, *id),
+        ResolvedGenericItem::GenericTypeAlias(id) => definition(db, *id),
+        ResolvedGenericItem::GenericImplAlias(id) => definition(db, *id),
+        ResolvedGenericItem::Variant(variant) => definition(db, variant.id),
+        ResolvedGenericItem::Trait(id) => definition(db, *id),
+        ResolvedGenericItem::Impl(id) => definition(db, *id),
+    };
+    definition_name(db, module_file_id, stable_ptr)
+}
+
+/// Returns the name identifier of the item defined by the syntax node at the given stable pointer
+/// in a module file, along with the module file.
+pub(crate) fn definition_name(
+    db: &dyn SemanticGroup,
+    module_file_id: ModuleFileId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<(ModuleFileId, ast::TerminalIdentifierPtr)> {
+    let syntax_db = db.upcast();
+    let file = db.module_file(module_file_id).to_option()?;
+    let node = db.file_syntax(file).to_option()?.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
+    let name = match node.kind(syntax_db) {
+        SyntaxKind::FunctionWithBody => ast::FunctionWithBody::from_syntax_node(syntax_db, node)
+            .declaration(syntax_db)
+            .name(syntax_db),
+        SyntaxKind::ItemExternFunction => {
+            ast::ItemExternFunction::from_syntax_node(syntax_db, node)
+                .declaration(syntax_db)
+                .name(syntax_db)
+        }
+        SyntaxKind::TraitItemFunction => ast::TraitItemFunction::from_syntax_node(syntax_db, node)
+            .declaration(syntax_db)
+            .name(syntax_db),
+        SyntaxKind::ItemConstant => {
+            ast::ItemConstant::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::ItemModule => {
+            ast::ItemModule::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::ItemStruct => {
+            ast::ItemStruct::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::ItemEnum => ast::ItemEnum::from_syntax_node(syntax_db, node).name(syntax_db),
+        SyntaxKind::ItemTypeAlias => {
+            ast::ItemTypeAlias::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::ItemImplAlias => {
+            ast::ItemImplAlias::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::ItemTrait => ast::ItemTrait::from_syntax_node(syntax_db, node).name(syntax_db),
+        SyntaxKind::ItemImpl => ast::ItemImpl::from_syntax_node(syntax_db, node).name(syntax_db),
+        SyntaxKind::ItemExternType => {
+            ast::ItemExternType::from_syntax_node(syntax_db, node).name(syntax_db)
+        }
+        SyntaxKind::Member => ast::Member::from_syntax_node(syntax_db, node).name(syntax_db),
+        _ => return None,
+    };
+    Some((module_file_id, name.stable_ptr()))
+}
+
+/// Returns the location in the user's code of the syntax node at the given stable pointer in a
+/// module file. Code generated by plugins is mapped to the code it was copied from, if any.
+pub(crate) fn user_location(
+    db: &(dyn SemanticGroup + 'static),
+    mut module_file_id: ModuleFileId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<UserLocation> {
+    let syntax_db = db.upcast();
+    let file = db.module_file(module_file_id).to_option()?;
+    let mut span = db
+        .file_syntax(file)
+        .to_option()?
+        .as_syntax_node()
+        .lookup_ptr(syntax_db, stable_ptr)
+        .span_without_trivia(syntax_db);
+    loop {
+        let ModuleFileId(module_id, file_index) = module_file_id;
+        let generated_file_infos = db.module_generated_file_infos(module_id).to_option()?;
+        let Some(generated_file_info) = generated_file_infos.get(file_index.0)?.clone() else {
+            break;
+        };
+        let mapper =
+            generated_file_info.aux_data.0.as_any().downcast_ref::<DynPluginAuxData>()?.clone();
+        span = mapper.map_span(db, span)?;
+        module_file_id = generated_file_info.origin;
+    }
+    Some((db.module_file(module_file_id).to_option()?, span))
+}
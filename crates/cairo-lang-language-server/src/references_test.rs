@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use lsp::{Position, Range};
+
+use super::{find_item_at, find_references, is_renamable};
+use crate::get_range;
+
+cairo_lang_test_utils::test_file_test!(
+    references,
+    "src/test_data",
+    {
+        references: "references",
+    },
+    test_references
+);
+
+cairo_lang_test_utils::test_file_test!(
+    rename,
+    "src/test_data",
+    {
+        rename: "rename",
+    },
+    test_rename
+);
+
+/// Sets up a crate with the given code, and returns the item at the given position of it, given
+/// as `line:character` (both 0-based).
+fn setup(inputs: &OrderedHashMap<String, String>) -> (RootDatabase, Option<ResolvedGenericItem>) {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate(&mut db, inputs["cairo_code"].as_str());
+    let file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let (line, character) = inputs["position"].split_once(':').unwrap();
+    let position = Position { line: line.parse().unwrap(), character: character.parse().unwrap() };
+    let item = find_item_at(&db, file, position).map(|(item, _)| item);
+    (db, item)
+}
+
+/// Returns the references to the item at a position of a crate, including its definition, as
+/// `line:character-line:character` ranges.
+fn test_references(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let (db, item) = setup(inputs);
+    let references = match item {
+        Some(item) => find_references(&db, &item, true)
+            .into_iter()
+            .map(|(file, span)| {
+                let Range { start, end } = get_range(&db, file, span).unwrap();
+                format!("{}:{}-{}:{}", start.line, start.character, end.line, end.character)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => "None".into(),
+    };
+    OrderedHashMap::from([("references".into(), references)])
+}
+
+/// Returns the code of a crate after renaming the item at a position of it to `renamed`.
+fn test_rename(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
+    let (db, item) = setup(inputs);
+    let renamed_code = match item.filter(|item| is_renamable(&db, item)) {
+        Some(item) => {
+            let mut code = inputs["cairo_code"].clone();
+            let mut spans = find_references(&db, &item, true)
+                .into_iter()
+                .map(|(_, span)| span)
+                .collect::<Vec<_>>();
+            // Replace from the end, so that the offsets of the remaining spans stay valid.
+            spans.sort_by_key(|span| std::cmp::Reverse(span.start));
+            for span in spans {
+                let offset = |offset: TextOffset| code.len() - offset.take_from(&code).len();
+                let range = offset(span.start)..offset(span.end);
+                code.replace_range(range, "renamed");
+            }
+            code
+        }
+        None => "None".into(),
+    };
+    OrderedHashMap::from([("renamed_code".into(), renamed_code)])
+}
@@ -0,0 +1,208 @@
+//! Signature help for function calls.
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LookupItemId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::{Expr, GenericParam, Mutability, Signature};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::{ParameterInformation, ParameterLabel, Position, SignatureHelp, SignatureInformation};
+use smol_str::SmolStr;
+
+use crate::{get_node_and_lookup_items, get_offset};
+
+/// Returns the signature of the function called by the innermost call whose arguments contain the
+/// given position, with the argument at the position as the active parameter.
+pub fn signature_help(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let syntax_db = db.upcast();
+    let offset = get_offset(db, file, position)?;
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    let lookup_item_id = lookup_items.into_iter().next()?;
+    let function_id = lookup_item_id.function_with_body()?;
+    let (call_expr, call) = find_call(syntax_db, node, offset)?;
+    let is_method_call = call_expr.as_syntax_node().kind(syntax_db) == SyntaxKind::ExprBinary;
+    let (name, generic_params, signature) =
+        called_function_signature(db, function_id, lookup_item_id, &call_expr, &call)?;
+
+    let (label, parameters) = format_signature(db, &name, &generic_params, &signature);
+    let args = call.arguments(syntax_db).args(syntax_db).elements(syntax_db);
+    let active_arg = args
+        .iter()
+        .take_while(|arg| arg.as_syntax_node().span_without_trivia(syntax_db).end < offset)
+        .count();
+    // The `self` argument of a method call is its left-hand side.
+    let active_parameter = active_arg + usize::from(is_method_call);
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter as u32),
+    })
+}
+
+/// Returns the innermost function call whose argument list contains the offset, along with the
+/// expression of the call - which is a binary expression for method calls.
+fn find_call(
+    db: &dyn SyntaxGroup,
+    mut node: SyntaxNode,
+    offset: TextOffset,
+) -> Option<(ast::Expr, ast::ExprFunctionCall)> {
+    loop {
+        if node.kind(db) == SyntaxKind::ExprFunctionCall {
+            let call = ast::ExprFunctionCall::from_syntax_node(db, node.clone());
+            let arguments = call.arguments(db);
+            if arguments.lparen(db).as_syntax_node().span_without_trivia(db).end <= offset
+                && offset <= arguments.rparen(db).as_syntax_node().span_without_trivia(db).start
+            {
+                let parent = node.parent()?;
+                let call_expr = if parent.kind(db) == SyntaxKind::ExprBinary
+                    && matches!(
+                        ast::ExprBinary::from_syntax_node(db, parent.clone()).op(db),
+                        ast::BinaryOperator::Dot(_)
+                    ) {
+                    ast::Expr::from_syntax_node(db, parent)
+                } else {
+                    ast::Expr::FunctionCall(call.clone())
+                };
+                return Some((call_expr, call));
+            }
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Returns the name, the generic parameters and the signature of the function called by a call
+/// expression.
+fn called_function_signature(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    lookup_item_id: LookupItemId,
+    call_expr: &ast::Expr,
+    call: &ast::ExprFunctionCall,
+) -> Option<(SmolStr, Vec<GenericParam>, Signature)> {
+    let generic_function = if let Some(Expr::FunctionCall(expr)) = db
+        .lookup_expr_by_ptr(function_id, call_expr.stable_ptr())
+        .ok()
+        .map(|expr_id| db.expr_semantic(function_id, expr_id))
+    {
+        db.lookup_intern_function(expr.function).function.generic_function
+    } else {
+        // The call may be missing from the semantic model, e.g. while its arguments are being
+        // typed. Fall back to the item its path was resolved to.
+        let segment = call.path(db.upcast()).elements(db.upcast()).pop()?;
+        match db.lookup_resolved_generic_item_by_ptr(
+            lookup_item_id,
+            segment.identifier_ast(db.upcast()).stable_ptr(),
+        )? {
+            ResolvedGenericItem::GenericFunction(generic_function) => generic_function,
+            ResolvedGenericItem::TraitFunction(trait_function_id) => {
+                return Some((
+                    trait_function_id.name(db.upcast()),
+                    db.trait_function_generic_params(trait_function_id).ok()?,
+                    db.trait_function_signature(trait_function_id).ok()?,
+                ));
+            }
+            _ => return None,
+        }
+    };
+    let name = match generic_function {
+        GenericFunctionId::Free(id) => id.name(db.upcast()),
+        GenericFunctionId::Extern(id) => id.name(db.upcast()),
+        GenericFunctionId::Impl(id) => id.function.name(db.upcast()),
+    };
+    Some((
+        name,
+        generic_function.generic_params(db).ok()?,
+        generic_function.generic_signature(db).ok()?,
+    ))
+}
+
+/// Formats the signature of a function, returning the label and the information of its
+/// parameters, given by their offsets in the label.
+fn format_signature(
+    db: &dyn SemanticGroup,
+    name: &str,
+    generic_params: &[GenericParam],
+    signature: &Signature,
+) -> (String, Vec<ParameterInformation>) {
+    let mut label = format!("fn {name}");
+    if !generic_params.is_empty() {
+        let generic_params = generic_params
+            .iter()
+            .map(|generic_param| format_generic_param(db, generic_param))
+            .collect::<Vec<_>>();
+        label.push_str(&format!("<{}>", generic_params.join(", ")));
+    }
+    label.push('(');
+    let mut parameters = vec![];
+    for (i, param) in signature.params.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let modifier = match param.mutability {
+            Mutability::Immutable => "",
+            Mutability::Mutable => "mut ",
+            Mutability::Reference => "ref ",
+        };
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&format!("{modifier}{}: {}", param.name, param.ty.format(db)));
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    if !signature.return_type.is_unit(db) {
+        label.push_str(&format!(" -> {}", signature.return_type.format(db)));
+    }
+    if !signature.implicits.is_empty() {
+        let implicits =
+            signature.implicits.iter().map(|implicit| implicit.format(db)).collect::<Vec<_>>();
+        label.push_str(&format!(" implicits({})", implicits.join(", ")));
+    }
+    if !signature.panicable {
+        label.push_str(" nopanic");
+    }
+    (label, parameters)
+}
+
+/// Formats a generic parameter as it is declared.
+fn format_generic_param(db: &dyn SemanticGroup, generic_param: &GenericParam) -> String {
+    let name = generic_param.id().name(db.upcast());
+    match generic_param {
+        GenericParam::Type(_) => name.to_string(),
+        GenericParam::Const(param) => format!("const {name}: {}", param.ty.format(db)),
+        GenericParam::Impl(param) => {
+            let Ok(concrete_trait) = param.concrete_trait else {
+                return format!("impl {name}");
+            };
+            let generic_args = concrete_trait
+                .generic_args(db)
+                .iter()
+                .map(|generic_arg| generic_arg.format(db))
+                .collect::<Vec<_>>();
+            let trait_name = concrete_trait.trait_id(db).name(db.upcast());
+            if generic_args.is_empty() {
+                format!("impl {name}: {trait_name}")
+            } else {
+                format!("impl {name}: {trait_name}<{}>", generic_args.join(", "))
+            }
+        }
+    }
+}
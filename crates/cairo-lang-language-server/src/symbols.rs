@@ -0,0 +1,316 @@
+//! Document and workspace symbols.
+
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_starknet::plugin::consts::{
+    ACCOUNT_CONTRACT_ATTR, COMPONENT_ATTR, CONSTRUCTOR_ATTR, CONTRACT_ATTR, EXTERNAL_ATTR,
+    L1_HANDLER_ATTR, VIEW_ATTR,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use lsp::{DocumentSymbol, Location, SymbolInformation, SymbolKind, Url};
+
+use crate::get_range;
+use crate::references::user_location;
+
+/// The attributes marking the entry points of a contract.
+const ENTRY_POINT_ATTRS: [&str; 4] = [EXTERNAL_ATTR, VIEW_ATTR, CONSTRUCTOR_ATTR, L1_HANDLER_ATTR];
+/// The attributes marking the modules compiled by the Starknet plugin.
+const CONTRACT_MODULE_ATTRS: [&str; 3] = [CONTRACT_ATTR, ACCOUNT_CONTRACT_ATTR, COMPONENT_ATTR];
+
+/// Returns the outline of a file - the symbols of the items of its module, nested by module.
+pub fn document_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+) -> Option<Vec<DocumentSymbol>> {
+    let module_id = *db.file_modules(file).to_option()?.first()?;
+    Some(module_symbols(db, file, module_id))
+}
+
+/// Returns the symbols of all the items in the database whose names fuzzy-match the query, best
+/// matches first.
+pub fn workspace_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    query: &str,
+    get_uri: impl Fn(FileId) -> Url,
+) -> Vec<SymbolInformation> {
+    let mut symbols = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let container_name = module_id.full_path(db.upcast());
+            for module_item in db.module_items(*module_id).unwrap_or_default().iter() {
+                let Some((file, symbol)) = module_item_symbol(db, *module_item) else { continue; };
+                flatten_symbol(symbol, &container_name, &mut |symbol, container_name| {
+                    let Some(rank) = match_rank(query, &symbol.name) else { return; };
+                    #[allow(deprecated)]
+                    symbols.push((
+                        rank,
+                        SymbolInformation {
+                            name: symbol.name.clone(),
+                            kind: symbol.kind,
+                            tags: None,
+                            deprecated: None,
+                            location: Location {
+                                uri: get_uri(file),
+                                range: symbol.selection_range,
+                            },
+                            container_name: Some(container_name.to_string()),
+                        },
+                    ));
+                });
+            }
+        }
+    }
+    symbols.sort_by_key(|(rank, _)| *rank);
+    symbols.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+/// Returns the symbols of the items of a module that are defined in the given file, sorted by
+/// their position. Inline submodules are nested with their items as children.
+fn module_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    module_id: ModuleId,
+) -> Vec<DocumentSymbol> {
+    let mut symbols = vec![];
+    for module_item in db.module_items(module_id).unwrap_or_default().iter() {
+        let Some((item_file, mut symbol)) = module_item_symbol(db, *module_item) else {
+            continue;
+        };
+        if item_file != file {
+            continue;
+        }
+        if let (ModuleItemId::Submodule(submodule_id), Some(children)) =
+            (module_item, &mut symbol.children)
+        {
+            *children = module_symbols(db, file, ModuleId::Submodule(*submodule_id));
+        }
+        symbols.push(symbol);
+    }
+    symbols.sort_by_key(|symbol| symbol.range.start);
+    symbols
+}
+
+/// Returns the symbol of a module item, along with the user file it is defined in. The symbol is
+/// built from the code the item was defined by, which, for items generated by plugins, is the
+/// code their names were copied from (e.g. the storage members of a contract). Items that were
+/// not copied from the user's code are omitted.
+///
+/// The children of an inline submodule are not included, and are left as an empty list.
+fn module_item_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    module_item: ModuleItemId,
+) -> Option<(FileId, DocumentSymbol)> {
+    if matches!(module_item, ModuleItemId::Use(_)) {
+        return None;
+    }
+    let syntax_db = db.upcast();
+    let name = db
+        .module_item_name_stable_ptr(module_item.parent_module(db.upcast()), module_item)
+        .to_option()?;
+    let (file, span) = user_location(db, module_item.module_file_id(db.upcast()), name)?;
+    let node =
+        db.file_syntax(file).to_option()?.as_syntax_node().lookup_offset(syntax_db, span.start);
+    let mut item_node = node.parent()?;
+    while symbol_kind(item_node.kind(syntax_db)).is_none() {
+        item_node = item_node.parent()?;
+    }
+    Some((file, syntax_symbol(db, file, item_node)?))
+}
+
+/// Returns the symbol kind of an item defined by a syntax node of the given kind, if it is one.
+fn symbol_kind(kind: SyntaxKind) -> Option<SymbolKind> {
+    Some(match kind {
+        SyntaxKind::ItemModule => SymbolKind::MODULE,
+        SyntaxKind::FunctionWithBody | SyntaxKind::ItemExternFunction => SymbolKind::FUNCTION,
+        SyntaxKind::TraitItemFunction => SymbolKind::METHOD,
+        SyntaxKind::ItemConstant | SyntaxKind::TraitItemConstant => SymbolKind::CONSTANT,
+        SyntaxKind::ItemStruct | SyntaxKind::ItemExternType => SymbolKind::STRUCT,
+        SyntaxKind::ItemEnum => SymbolKind::ENUM,
+        SyntaxKind::ItemTypeAlias | SyntaxKind::TraitItemType => SymbolKind::TYPE_PARAMETER,
+        SyntaxKind::ItemTrait => SymbolKind::INTERFACE,
+        SyntaxKind::ItemImpl | SyntaxKind::ItemImplAlias => SymbolKind::OBJECT,
+        SyntaxKind::Member => SymbolKind::FIELD,
+        _ => return None,
+    })
+}
+
+/// Returns the symbol of the item defined by a syntax node, with its members, variants or
+/// functions as children.
+fn syntax_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    let mut kind = symbol_kind(node.kind(syntax_db))?;
+    let mut detail = None;
+    let mut children = None;
+    let name = match node.kind(syntax_db) {
+        SyntaxKind::ItemModule => {
+            let item = ast::ItemModule::from_syntax_node(syntax_db, node.clone());
+            detail = find_attr(syntax_db, &item, &CONTRACT_MODULE_ATTRS);
+            if let ast::MaybeModuleBody::Some(_) = item.body(syntax_db) {
+                children = Some(vec![]);
+            }
+            item.name(syntax_db)
+        }
+        SyntaxKind::FunctionWithBody => {
+            let item = ast::FunctionWithBody::from_syntax_node(syntax_db, node.clone());
+            if node.parent().and_then(|parent| parent.parent()).map(|node| node.kind(syntax_db))
+                == Some(SyntaxKind::ImplBody)
+            {
+                kind = SymbolKind::METHOD;
+            }
+            detail = find_attr(syntax_db, &item, &ENTRY_POINT_ATTRS);
+            item.declaration(syntax_db).name(syntax_db)
+        }
+        SyntaxKind::ItemExternFunction => {
+            ast::ItemExternFunction::from_syntax_node(syntax_db, node.clone())
+                .declaration(syntax_db)
+                .name(syntax_db)
+        }
+        SyntaxKind::TraitItemFunction => {
+            ast::TraitItemFunction::from_syntax_node(syntax_db, node.clone())
+                .declaration(syntax_db)
+                .name(syntax_db)
+        }
+        SyntaxKind::ItemConstant => {
+            ast::ItemConstant::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::TraitItemConstant => {
+            ast::TraitItemConstant::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemStruct => {
+            let item = ast::ItemStruct::from_syntax_node(syntax_db, node.clone());
+            children = Some(members_symbols(db, file, item.members(syntax_db)));
+            item.name(syntax_db)
+        }
+        SyntaxKind::ItemEnum => {
+            let item = ast::ItemEnum::from_syntax_node(syntax_db, node.clone());
+            let mut variants = members_symbols(db, file, item.variants(syntax_db));
+            for variant in variants.iter_mut() {
+                variant.kind = SymbolKind::ENUM_MEMBER;
+            }
+            children = Some(variants);
+            item.name(syntax_db)
+        }
+        SyntaxKind::ItemExternType => {
+            ast::ItemExternType::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemTypeAlias => {
+            ast::ItemTypeAlias::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::TraitItemType => {
+            ast::TraitItemType::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::ItemTrait => {
+            let item = ast::ItemTrait::from_syntax_node(syntax_db, node.clone());
+            if let ast::MaybeTraitBody::Some(body) = item.body(syntax_db) {
+                children = Some(items_symbols(
+                    db,
+                    file,
+                    body.items(syntax_db)
+                        .elements(syntax_db)
+                        .into_iter()
+                        .map(|item| item.as_syntax_node()),
+                ));
+            }
+            item.name(syntax_db)
+        }
+        SyntaxKind::ItemImpl => {
+            let item = ast::ItemImpl::from_syntax_node(syntax_db, node.clone());
+            if let ast::MaybeImplBody::Some(body) = item.body(syntax_db) {
+                children = Some(items_symbols(
+                    db,
+                    file,
+                    body.items(syntax_db)
+                        .elements(syntax_db)
+                        .into_iter()
+                        .map(|item| item.as_syntax_node()),
+                ));
+            }
+            item.name(syntax_db)
+        }
+        SyntaxKind::ItemImplAlias => {
+            ast::ItemImplAlias::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        SyntaxKind::Member => {
+            ast::Member::from_syntax_node(syntax_db, node.clone()).name(syntax_db)
+        }
+        _ => return None,
+    };
+    #[allow(deprecated)]
+    Some(DocumentSymbol {
+        name: name.text(syntax_db).to_string(),
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: get_range(db, file, node.span_without_trivia(syntax_db))?,
+        selection_range: get_range(db, file, name.as_syntax_node().span_without_trivia(syntax_db))?,
+        children,
+    })
+}
+
+/// Returns the symbols of the members of a struct, or the variants of an enum.
+fn members_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    members: ast::MemberList,
+) -> Vec<DocumentSymbol> {
+    items_symbols(
+        db,
+        file,
+        members.elements(db.upcast()).into_iter().map(|member| member.as_syntax_node()),
+    )
+}
+
+/// Returns the symbols of the items defined by syntax nodes, skipping the nodes that don't define
+/// items.
+fn items_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    nodes: impl Iterator<Item = SyntaxNode>,
+) -> Vec<DocumentSymbol> {
+    nodes.filter_map(|node| syntax_symbol(db, file, node)).collect()
+}
+
+/// Returns the first of the given attributes the item has, if any.
+fn find_attr(db: &dyn SyntaxGroup, item: &impl QueryAttrs, attrs: &[&str]) -> Option<String> {
+    attrs.iter().find(|attr| item.has_attr(db, attr)).map(|attr| attr.to_string())
+}
+
+/// Calls `f` on a symbol and all of its descendants, along with the name of their container.
+fn flatten_symbol(
+    symbol: DocumentSymbol,
+    container_name: &str,
+    f: &mut impl FnMut(&DocumentSymbol, &str),
+) {
+    f(&symbol, container_name);
+    let container_name = format!("{container_name}::{}", symbol.name);
+    for child in symbol.children.into_iter().flatten() {
+        flatten_symbol(child, &container_name, f);
+    }
+}
+
+/// Returns how well a name matches a query, ignoring case - lower is better: an exact match, a
+/// prefix, a substring, or the characters of the query appearing in the name in order. Returns
+/// None if the name doesn't match.
+fn match_rank(query: &str, name: &str) -> Option<usize> {
+    let (query, name) = (query.to_lowercase(), name.to_lowercase());
+    if name == query {
+        Some(0)
+    } else if name.starts_with(&query) {
+        Some(1)
+    } else if name.contains(&query) {
+        Some(2)
+    } else {
+        let mut name_chars = name.chars();
+        query.chars().all(|c| name_chars.any(|name_char| name_char == c)).then_some(3)
+    }
+}
@@ -1,11 +1,19 @@
+use cairo_lang_defs as defs;
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic::corelib::get_core_ty_by_name;
+use cairo_lang_semantic::items::constant::ConstValue;
 use cairo_lang_semantic::substitution::{
     GenericSubstitution, SemanticRewriter, SubstitutionRewriter,
 };
+use num_bigint::BigInt;
 
 use crate::db::LoweringGroup;
 use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction};
-use crate::{FlatBlockEnd, FlatLowered, MatchArm, Statement};
+use crate::lower::context::{VarRequest, VariableAllocator};
+use crate::{
+    FlatBlockEnd, FlatLowered, MatchArm, Statement, StatementEnumConstruct, StatementImplConstant,
+    StatementLiteral, StatementStructConstruct, VariableId,
+};
 
 /// Rewrites a [FunctionId] with a [SubstitutionRewriter].
 fn concretize_function(
@@ -26,9 +34,11 @@ fn concretize_function(
 }
 
 /// Concretizes a lowered generic function by applying a generic parameter substitution on its
-/// variable types, variants and called functions.
+/// variable types, variants and called functions, and by replacing the associated constants of
+/// impls that become concrete with their values.
 pub fn concretize_lowered(
     db: &dyn LoweringGroup,
+    function_id: defs::ids::FunctionWithBodyId,
     lowered: &mut FlatLowered,
     substitution: &GenericSubstitution,
 ) -> Maybe<()> {
@@ -40,23 +50,40 @@ pub fn concretize_lowered(
             *impl_id = rewriter.rewrite(*impl_id)?;
         }
     }
+    let mut variables =
+        VariableAllocator::new(db, function_id, std::mem::take(&mut lowered.variables))?;
     // Substitute all statements.
     for block in lowered.blocks.iter_mut() {
-        for stmt in block.statements.iter_mut() {
-            match stmt {
+        let mut statements = Vec::with_capacity(block.statements.len());
+        for mut stmt in std::mem::take(&mut block.statements) {
+            match &mut stmt {
                 Statement::Call(stmt) => {
                     stmt.function = concretize_function(db, &mut rewriter, stmt.function)?;
                 }
                 Statement::EnumConstruct(stmt) => {
                     stmt.variant = rewriter.rewrite(stmt.variant.clone())?;
                 }
+                Statement::ImplConstant(StatementImplConstant { impl_constant_id, output }) => {
+                    *impl_constant_id = rewriter.rewrite(*impl_constant_id)?;
+                    if let Some(constant) = impl_constant_id.concrete_value(db.upcast()) {
+                        const_value_statements(
+                            &mut variables,
+                            constant?.value,
+                            *output,
+                            &mut statements,
+                        );
+                        continue;
+                    }
+                }
                 Statement::Snapshot(_)
                 | Statement::Desnap(_)
                 | Statement::Literal(_)
                 | Statement::StructConstruct(_)
                 | Statement::StructDestructure(_) => {}
             }
+            statements.push(stmt);
         }
+        block.statements = statements;
         if let FlatBlockEnd::Match { info } = &mut block.end {
             match info {
                 crate::MatchInfo::Enum(s) => {
@@ -73,7 +100,58 @@ pub fn concretize_lowered(
             }
         }
     }
+    lowered.variables = variables.variables;
     lowered.signature = rewriter.rewrite(lowered.signature.clone())?;
 
     Ok(())
 }
+
+/// Adds the statements that bind a value computed at compile time to `output`, allocating the
+/// variables of the members of structs and of the inner values of enum variants.
+fn const_value_statements(
+    variables: &mut VariableAllocator<'_>,
+    value: ConstValue,
+    output: VariableId,
+    statements: &mut Vec<Statement>,
+) {
+    let semantic_db = variables.db.upcast();
+    let location = variables[output].location;
+    let new_input = |variables: &mut VariableAllocator<'_>,
+                     value: ConstValue,
+                     statements: &mut Vec<Statement>| {
+        let input = variables.new_var(VarRequest { ty: value.ty(semantic_db), location });
+        const_value_statements(variables, value, input, statements);
+        input
+    };
+    match value {
+        ConstValue::Int(value, ty)
+            if ty == get_core_ty_by_name(semantic_db, "u256".into(), vec![]) =>
+        {
+            // A u256 is a struct of its low and high 128 bits.
+            let u128_ty = get_core_ty_by_name(semantic_db, "u128".into(), vec![]);
+            let inputs = [&value & BigInt::from(u128::MAX), value >> 128]
+                .into_iter()
+                .map(|value| new_input(variables, ConstValue::Int(value, u128_ty), statements))
+                .collect();
+            statements
+                .push(Statement::StructConstruct(StatementStructConstruct { inputs, output }));
+        }
+        ConstValue::Int(value, _) => {
+            statements.push(Statement::Literal(StatementLiteral { value, output }));
+        }
+        ConstValue::Struct(values, _) => {
+            let inputs =
+                values.into_iter().map(|value| new_input(variables, value, statements)).collect();
+            statements
+                .push(Statement::StructConstruct(StatementStructConstruct { inputs, output }));
+        }
+        ConstValue::Enum(variant, value) => {
+            let input = new_input(variables, *value, statements);
+            statements.push(Statement::EnumConstruct(StatementEnumConstruct {
+                variant,
+                input,
+                output,
+            }));
+        }
+    }
+}
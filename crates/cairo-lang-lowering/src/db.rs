@@ -4,9 +4,10 @@ use std::sync::Arc;
 use cairo_lang_defs as defs;
 use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
-use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::ids::{CrateId, FileId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::TypeId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
@@ -19,6 +20,8 @@ use crate::diagnostic::LoweringDiagnostic;
 use crate::implicits::lower_implicits;
 use crate::inline::{apply_inlining, PrivInlineData};
 use crate::lower::{lower_semantic_function, MultiLowering};
+use crate::optimizations::const_folding::const_folding;
+use crate::optimizations::dead_code::eliminate_dead_code;
 use crate::optimizations::delay_var_def::delay_var_def;
 use crate::optimizations::match_optimizer::optimize_matches;
 use crate::optimizations::remappings::optimize_remappings;
@@ -46,6 +49,14 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     #[salsa::invoke(crate::inline::priv_inline_data)]
     fn priv_inline_data(&self, function_id: ids::FunctionWithBodyId) -> Maybe<Arc<PrivInlineData>>;
 
+    /// Returns the number of call sites of each function with a body in the given crate, before
+    /// inlining.
+    #[salsa::invoke(crate::inline::priv_crate_call_site_counts)]
+    fn priv_crate_call_site_counts(
+        &self,
+        crate_id: CrateId,
+    ) -> Arc<OrderedHashMap<ids::FunctionWithBodyId, usize>>;
+
     /// Computes the lowered representation of a function with a body, along with all it generated
     /// functions (e.g. closures, lambdas, loops, ...).
     fn priv_function_with_body_multi_lowering(
@@ -311,7 +322,12 @@ fn priv_concrete_function_with_body_lowered_flat(
     let semantic_db = db.upcast();
     let mut lowered =
         (*db.function_with_body_lowering(function.function_with_body_id(db))?).clone();
-    concretize_lowered(db, &mut lowered, &function.substitution(semantic_db)?)?;
+    concretize_lowered(
+        db,
+        function.function_with_body_id(db).base_semantic_function(db),
+        &mut lowered,
+        &function.substitution(semantic_db)?,
+    )?;
     Ok(Arc::new(lowered))
 }
 
@@ -333,6 +349,8 @@ fn concrete_function_with_body_postpanic_lowered(
 }
 
 // * Optimizes remappings.
+// * Folds constants.
+// * Eliminates dead code.
 // * Delays var definitions.
 // * Lowers implicits.
 // * Optimizes matches.
@@ -345,6 +363,8 @@ fn concrete_function_with_body_lowered(
 ) -> Maybe<Arc<FlatLowered>> {
     let mut lowered = (*db.concrete_function_with_body_postpanic_lowered(function)?).clone();
     optimize_remappings(&mut lowered);
+    const_folding(db, &mut lowered);
+    eliminate_dead_code(&mut lowered);
     delay_var_def(&mut lowered);
     optimize_matches(&mut lowered);
     lower_implicits(db, function, &mut lowered);
@@ -45,12 +45,6 @@ impl DiagnosticEntry for LoweringDiagnostic {
     fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
-            LoweringDiagnosticKind::NonZeroValueInMatch => {
-                "Match with a non-zero value is not supported.".into()
-            }
-            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => {
-                "Only match zero (match ... { 0 => ..., _ => ... }) is currently supported.".into()
-            }
             LoweringDiagnosticKind::VariableMoved { inference_error } => {
                 format!("Variable was previously moved. {}", inference_error.format(db))
             }
@@ -64,22 +58,24 @@ impl DiagnosticEntry for LoweringDiagnostic {
             LoweringDiagnosticKind::DesnappingANonCopyableType { inference_error } => {
                 format!("Cannot desnap a non copyable type. {}", inference_error.format(db))
             }
-            LoweringDiagnosticKind::UnsupportedMatch => "Unsupported match. Currently, matches \
-                                                         require one arm per variant, in the \
-                                                         order of variant definition."
-                .into(),
-            LoweringDiagnosticKind::UnsupportedMatchArmNotAVariant => {
-                "Unsupported match arm - not a variant.".into()
+            LoweringDiagnosticKind::UnavailableRefOrMemberValue => {
+                "Unsupported expression. The value of a `ref` parameter or of a struct member is \
+                 not available at this point."
+                    .into()
             }
-            LoweringDiagnosticKind::UnsupportedMatchArmOutOfOrder => {
-                "Unsupported match arm - variants must be the same order as enum declaration."
+            LoweringDiagnosticKind::UnsupportedLiteralPattern => {
+                "Literal patterns are only supported for felt252 and unsigned integers up to u128."
                     .into()
             }
+            LoweringDiagnosticKind::MissingMatchArm(witness) => {
+                format!("Missing match arm: `{witness}` not covered.")
+            }
+            LoweringDiagnosticKind::UnreachableMatchArm => "Unreachable match arm.".into(),
             LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself => {
                 "Cannot inline a function that might call itself.".into()
             }
-            LoweringDiagnosticKind::MemberPathLoop => {
-                "Currently, loops must change the entire variable.".into()
+            LoweringDiagnosticKind::UnboundLoopMemberPath => {
+                "The value of a variable used by the loop is not available at this point.".into()
             }
         }
     }
@@ -101,16 +97,13 @@ impl DiagnosticEntry for LoweringDiagnostic {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum LoweringDiagnosticKind {
     Unreachable { last_statement_ptr: SyntaxStablePtrId },
-    // TODO(lior): Remove once supported.
-    NonZeroValueInMatch,
-    // TODO(lior): Remove once supported.
-    OnlyMatchZeroIsSupported,
     VariableMoved { inference_error: InferenceError },
     VariableNotDropped { drop_err: InferenceError, destruct_err: InferenceError },
     DesnappingANonCopyableType { inference_error: InferenceError },
-    UnsupportedMatch,
-    UnsupportedMatchArmNotAVariant,
-    UnsupportedMatchArmOutOfOrder,
+    UnavailableRefOrMemberValue,
+    UnsupportedLiteralPattern,
+    MissingMatchArm(String),
+    UnreachableMatchArm,
     CannotInlineFunctionThatMightCallItself,
-    MemberPathLoop,
+    UnboundLoopMemberPath,
 }
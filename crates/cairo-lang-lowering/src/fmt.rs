@@ -4,7 +4,7 @@ use id_arena::Arena;
 
 use crate::db::LoweringGroup;
 use crate::objects::{
-    BlockId, MatchExternInfo, Statement, StatementCall, StatementLiteral,
+    BlockId, MatchExternInfo, Statement, StatementCall, StatementImplConstant, StatementLiteral,
     StatementStructDestructure, VariableId,
 };
 use crate::{
@@ -152,6 +152,7 @@ impl DebugWithDb<LoweredFormatter<'_>> for Statement {
         write!(f, ") <- ")?;
         match self {
             Statement::Literal(stmt) => stmt.fmt(f, ctx),
+            Statement::ImplConstant(stmt) => stmt.fmt(f, ctx),
             Statement::Call(stmt) => stmt.fmt(f, ctx),
             Statement::StructConstruct(stmt) => stmt.fmt(f, ctx),
             Statement::StructDestructure(stmt) => stmt.fmt(f, ctx),
@@ -181,6 +182,12 @@ impl DebugWithDb<LoweredFormatter<'_>> for StatementLiteral {
     }
 }
 
+impl DebugWithDb<LoweredFormatter<'_>> for StatementImplConstant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, ctx: &LoweredFormatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.impl_constant_id.format(ctx.db.upcast()))
+    }
+}
+
 impl DebugWithDb<LoweredFormatter<'_>> for StatementCall {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, ctx: &LoweredFormatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}(", self.function.lookup(ctx.db).debug(ctx.db))?;
@@ -1,19 +1,27 @@
 #[cfg(test)]
 mod test;
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::LanguageElementId;
+use cairo_lang_defs as defs;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe};
-use cairo_lang_semantic::items::functions::InlineConfiguration;
+use cairo_lang_filesystem::flag::{Flag, OptimizationLevel};
+use cairo_lang_filesystem::ids::{CrateId, FlagId};
+use cairo_lang_semantic::items::functions::{GenericFunctionId, InlineConfiguration};
+use cairo_lang_sierra::ids::GenericLibfuncId;
+use cairo_lang_sierra_gas::core_libfunc_cost::FUNCTION_CALL_COST;
+use cairo_lang_sierra_gas::libfunc_cost_estimate::estimate_libfunc_cost;
+use cairo_lang_sierra_gas::objects::ConstCost;
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use itertools::{izip, Itertools};
+use itertools::{chain, izip, Itertools};
 
 use crate::blocks::{FlatBlocks, FlatBlocksBuilder};
 use crate::db::LoweringGroup;
 use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiagnostics};
-use crate::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyId};
+use crate::ids::{ConcreteFunctionWithBodyId, FunctionId, FunctionLongId, FunctionWithBodyId};
 use crate::lower::context::{VarRequest, VariableAllocator};
 use crate::utils::{Rebuilder, RebuilderEx};
 use crate::{BlockId, FlatBlock, FlatBlockEnd, FlatLowered, Statement, VarRemapping, VariableId};
@@ -68,9 +76,20 @@ fn gather_inlining_info(
 ) -> Maybe<InlineInfo> {
     let semantic_function_id = function_id.base_semantic_function(db);
     let stable_ptr = semantic_function_id.untyped_stable_ptr(db.upcast());
-    // TODO(ilya): Relax requirement, if one of the functions does not have `#[inline(always)]` then
-    // we can inline it.
-    if db.in_cycle(function_id)? {
+    // Functions that are only inlined by the heuristic are not inlined when they might call
+    // themselves, so they break the cycles they are in. An `#[inline(always)]` function can
+    // therefore be inlined, unless it is in a cycle of functions that are all inlined regardless of
+    // the heuristic.
+    let is_inline_always = matches!(
+        db.function_declaration_inline_config(semantic_function_id)?,
+        InlineConfiguration::Always(_)
+    );
+    let in_cycle = if is_inline_always {
+        in_inline_cycle(db, function_id)?
+    } else {
+        db.in_cycle(function_id)?
+    };
+    if in_cycle {
         if report_diagnostics {
             diagnostics.report(
                 stable_ptr,
@@ -82,23 +101,229 @@ fn gather_inlining_info(
 
     let lowered = db.function_with_body_lowering(function_id)?;
 
-    Ok(InlineInfo { is_inlinable: true, should_inline: should_inline(db, &lowered)? })
+    Ok(InlineInfo { is_inlinable: true, should_inline: should_inline(db, function_id, &lowered)? })
+}
+
+/// Returns whether the given function is in a cycle of functions that are all inlined regardless of
+/// the inlining heuristic (i.e. `#[inline(always)]` and `#[inline]` functions), so that inlining it
+/// might never end.
+fn in_inline_cycle(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<bool> {
+    if !db.in_cycle(function_id)? {
+        return Ok(false);
+    }
+    let scc = db.function_with_body_scc(function_id);
+    let mut visited = HashSet::new();
+    let mut stack = vec![function_id];
+    while let Some(caller) = stack.pop() {
+        for callee in db.function_with_body_direct_function_with_body_callees(caller)? {
+            if callee == function_id {
+                return Ok(true);
+            }
+            if !scc.contains(&callee) || visited.contains(&callee) {
+                continue;
+            }
+            let config =
+                db.function_declaration_inline_config(callee.base_semantic_function(db))?;
+            if matches!(config, InlineConfiguration::Always(_) | InlineConfiguration::Should(_)) {
+                visited.insert(callee);
+                stack.push(callee);
+            }
+        }
+    }
+    Ok(false)
 }
 
-// A heuristic to decide if a function should be inlined.
-fn should_inline(_db: &dyn LoweringGroup, lowered: &FlatLowered) -> Maybe<bool> {
-    let root_block = lowered.blocks.root_block()?;
+/// The weight of a call to a function with a body: the steps of the `call` instruction and of the
+/// `ret` instruction of the callee.
+const CALL_WEIGHT: usize = FUNCTION_CALL_COST.steps as usize;
+
+/// The factor by which the weight of a function may exceed the overhead of calling it, for the
+/// function to be inlined in [OptimizationLevel::O2].
+const O2_CALL_OVERHEAD_FACTOR: usize = 4;
+
+/// The maximal weight of a function with a single call site in its crate, for the function to be
+/// inlined in [OptimizationLevel::O2].
+const O2_SINGLE_CALL_SITE_MAX_WEIGHT: usize = 64;
+
+/// A heuristic to decide if a function should be inlined, based on its weight: the estimated
+/// number of steps it adds to its callers (see [inline_weight]). Calls to libfuncs weigh their
+/// estimated gas cost, in steps, according to the cost model of `cairo-lang-sierra-gas`. This
+/// includes the range checks they use and the holes their branches leave for aligning the ap
+/// change. Calls to functions with a body weigh [CALL_WEIGHT], and other statements and the arms of
+/// matches weigh the few instructions they compile to.
+///
+/// - In [OptimizationLevel::O0], no function is inlined.
+/// - In [OptimizationLevel::O1], a function is inlined if its weight is at most the overhead of
+///   calling it (see [call_overhead_weight]).
+/// - In [OptimizationLevel::O2], a function is also inlined if its weight is at most
+///   [O2_CALL_OVERHEAD_FACTOR] times the overhead of calling it, or at most
+///   [O2_SINGLE_CALL_SITE_MAX_WEIGHT] if it has a single call site in its crate.
+///
+/// Functions with explicit implicits are never inlined by this heuristic.
+fn should_inline(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    lowered: &FlatLowered,
+) -> Maybe<bool> {
+    let optimization_level = optimization_level(db);
+    // Inlining a function with explicit implicits would drop them from the caller.
+    if optimization_level == OptimizationLevel::O0 || !lowered.signature.implicits.is_empty() {
+        return Ok(false);
+    }
 
-    Ok(match &root_block.end {
-        FlatBlockEnd::Return(_) => {
-            // Inline a function that only calls another function or returns a literal.
-            matches!(root_block.statements.as_slice(), [Statement::Call(_) | Statement::Literal(_)])
+    let weight = inline_weight(db, lowered)?;
+    let call_overhead = call_overhead_weight(lowered);
+    if weight <= call_overhead {
+        return Ok(true);
+    }
+    if optimization_level < OptimizationLevel::O2 {
+        return Ok(false);
+    }
+    if weight <= call_overhead * O2_CALL_OVERHEAD_FACTOR {
+        return Ok(true);
+    }
+    Ok(weight <= O2_SINGLE_CALL_SITE_MAX_WEIGHT && call_site_count(db, function_id) == 1)
+}
+
+/// Returns the optimization level of the compilation, according to the `optimization_level` flag.
+fn optimization_level(db: &dyn LoweringGroup) -> OptimizationLevel {
+    db.get_flag(FlagId::new(db.upcast(), "optimization_level"))
+        .map(|flag| extract_matches!(*flag, Flag::OptimizationLevel))
+        .unwrap_or_default()
+}
+
+/// Returns the estimated number of steps added to a caller by inlining the given function into it.
+fn inline_weight(db: &dyn LoweringGroup, lowered: &FlatLowered) -> Maybe<usize> {
+    let mut weight = 0;
+    for (_, block) in lowered.blocks.iter() {
+        for stmt in &block.statements {
+            weight += match stmt {
+                Statement::Call(stmt) => {
+                    if stmt.function.body(db)?.is_some() {
+                        CALL_WEIGHT
+                    } else {
+                        libfunc_call_weight(db, stmt.function)
+                    }
+                }
+                Statement::Literal(_)
+                | Statement::ImplConstant(_)
+                | Statement::EnumConstruct(_) => 1,
+                Statement::StructConstruct(_)
+                | Statement::StructDestructure(_)
+                | Statement::Snapshot(_)
+                | Statement::Desnap(_) => 0,
+            };
         }
-        FlatBlockEnd::Goto(..) | FlatBlockEnd::Match { .. } | FlatBlockEnd::Panic(_) => false,
-        FlatBlockEnd::NotSet => {
-            panic!("Unexpected block end.");
+        weight += match &block.end {
+            // Each arm starts with a `branch_align`, and all arms but the last end with a jump.
+            FlatBlockEnd::Match { info } => (2 * info.arms().len()).saturating_sub(1),
+            FlatBlockEnd::Return(_) | FlatBlockEnd::Panic(_) | FlatBlockEnd::Goto(..) => 0,
+            FlatBlockEnd::NotSet => {
+                panic!("Unexpected block end.");
+            }
+        };
+    }
+    Ok(weight)
+}
+
+/// Returns the estimated number of steps of a call to the given extern function: the gas cost of
+/// its most expensive branch, in steps, rounded up (see [estimate_libfunc_cost]). The cost of
+/// generic libfuncs depends on their generic arguments, which are only known as Sierra types after
+/// lowering, so calls to generic libfuncs, and to libfuncs whose cost is unknown, weigh a single
+/// step.
+fn libfunc_call_weight(db: &dyn LoweringGroup, function: FunctionId) -> usize {
+    let FunctionLongId::Semantic(function) = function.lookup(db) else {
+        return 1;
+    };
+    let concrete_function = function.get_concrete(db.upcast());
+    let GenericFunctionId::Extern(extern_function_id) = concrete_function.generic_function else {
+        return 1;
+    };
+    if !concrete_function.generic_args.is_empty() {
+        return 1;
+    }
+    let libfunc_id = GenericLibfuncId::from_string(extern_function_id.name(db.upcast()));
+    let Some(cost) = estimate_libfunc_cost(&libfunc_id) else {
+        return 1;
+    };
+    let step_cost = ConstCost { steps: 1, ..Default::default() }.cost();
+    let steps = (cost.cost() + step_cost - 1) / step_cost;
+    steps.max(1) as usize
+}
+
+/// Returns the estimated number of steps of calling the given function, not including the
+/// function's body: the call itself and storing each of the arguments.
+fn call_overhead_weight(lowered: &FlatLowered) -> usize {
+    CALL_WEIGHT + lowered.parameters.len()
+}
+
+/// Returns the number of call sites of the given function in its crate.
+fn call_site_count(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> usize {
+    let semantic_function_id = function_id.base_semantic_function(db);
+    let crate_id = semantic_function_id.module_file_id(db.upcast()).0.owning_crate(db.upcast());
+    db.priv_crate_call_site_counts(crate_id).get(&function_id).copied().unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::LoweringGroup::priv_crate_call_site_counts].
+pub fn priv_crate_call_site_counts(
+    db: &dyn LoweringGroup,
+    crate_id: CrateId,
+) -> Arc<OrderedHashMap<FunctionWithBodyId, usize>> {
+    let mut call_site_counts = OrderedHashMap::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        let Ok(module_items) = db.module_items(*module_id) else {
+            continue;
+        };
+        for item in module_items.iter() {
+            match item {
+                ModuleItemId::FreeFunction(free_function) => count_call_sites(
+                    db,
+                    defs::ids::FunctionWithBodyId::Free(*free_function),
+                    &mut call_site_counts,
+                ),
+                ModuleItemId::Impl(impl_def_id) => {
+                    let Ok(impl_functions) = db.impl_functions(*impl_def_id) else {
+                        continue;
+                    };
+                    for impl_function in impl_functions.values() {
+                        count_call_sites(
+                            db,
+                            defs::ids::FunctionWithBodyId::Impl(*impl_function),
+                            &mut call_site_counts,
+                        );
+                    }
+                }
+                _ => {}
+            }
         }
-    })
+    }
+    Arc::new(call_site_counts)
+}
+
+/// Adds the call sites in the given function, including the functions it generated, to
+/// `call_site_counts`.
+fn count_call_sites(
+    db: &dyn LoweringGroup,
+    semantic_function_id: defs::ids::FunctionWithBodyId,
+    call_site_counts: &mut OrderedHashMap<FunctionWithBodyId, usize>,
+) {
+    let Ok(multi_lowering) = db.priv_function_with_body_multi_lowering(semantic_function_id) else {
+        return;
+    };
+    let lowerings =
+        chain!([&multi_lowering.main_lowering], multi_lowering.generated_lowerings.values());
+    for lowered in lowerings {
+        for (_, block) in lowered.blocks.iter() {
+            for stmt in &block.statements {
+                let Statement::Call(stmt) = stmt else {
+                    continue;
+                };
+                if let Ok(Some(callee)) = stmt.function.body(db) {
+                    *call_site_counts.entry(callee.function_with_body_id(db)).or_default() += 1;
+                }
+            }
+        }
+    }
 }
 
 // TODO(ilya): Add Rewriter trait.
@@ -21,8 +21,8 @@ use crate::{BlockId, FlatBlock, FlatBlockEnd, MatchInfo, Statement, VarRemapping
 pub struct BlockBuilder {
     /// A store for semantic variables, owning their OwnedVariable instances.
     pub semantics: SemanticLoweringMapping,
-    /// The semantic variables that are added/changed in this block.
-    changed_semantics: OrderedHashSet<semantic::VarId>,
+    /// The member paths that are added/changed in this block.
+    changed_semantics: OrderedHashSet<MemberPath>,
     /// Current sequence of lowered statements emitted.
     pub statements: StatementsBuilder,
     /// The block id to use for this block when it's finalized.
@@ -62,8 +62,13 @@ impl BlockBuilder {
 
     /// Binds a semantic variable to a lowered variable.
     pub fn put_semantic(&mut self, semantic_var_id: semantic::VarId, var: VariableId) {
-        self.semantics.introduce(MemberPath::Var(semantic_var_id), var);
-        self.changed_semantics.insert(semantic_var_id);
+        self.put_path(MemberPath::Var(semantic_var_id), var);
+    }
+
+    /// Binds a member path to a lowered variable, replacing its previous binding.
+    fn put_path(&mut self, path: MemberPath, var: VariableId) {
+        self.semantics.introduce(path.clone(), var);
+        self.changed_semantics.insert(path);
     }
 
     pub fn update_ref(
@@ -78,7 +83,7 @@ impl BlockBuilder {
             &member_path.into(),
             var,
         );
-        self.changed_semantics.insert(member_path.base_var());
+        self.changed_semantics.insert(member_path.into());
     }
 
     pub fn get_ref(
@@ -108,6 +113,18 @@ impl BlockBuilder {
             .expect("Use of undefined variable cannot happen after semantic phase.")
     }
 
+    /// Gets the current lowered variable bound to a member path.
+    fn get_path(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        path: &MemberPath,
+        location: StableLocationOption,
+    ) -> VariableId {
+        self.semantics
+            .get(BlockStructRecomposer { statements: &mut self.statements, ctx, location }, path)
+            .expect("Changed member paths are always bound.")
+    }
+
     /// Adds a statement to the block.
     pub fn push_statement(&mut self, statement: Statement) {
         self.statements.push_statement(statement);
@@ -118,6 +135,11 @@ impl BlockBuilder {
         self.finalize(ctx, FlatBlockEnd::Match { info: match_info });
     }
 
+    /// Ends a block with a match, whose arms are already lowered.
+    pub fn end_with_match(self, ctx: &mut LoweringContext<'_, '_>, match_info: MatchInfo) {
+        self.finalize(ctx, FlatBlockEnd::Match { info: match_info });
+    }
+
     /// Ends a block with Panic.
     pub fn panic(self, ctx: &mut LoweringContext<'_, '_>, data: VariableId) -> Maybe<()> {
         self.finalize(ctx, FlatBlockEnd::Panic(data));
@@ -149,8 +171,10 @@ impl BlockBuilder {
             })
             .collect::<Option<Vec<_>>>()
             .ok_or_else(|| {
-                ctx.diagnostics
-                    .report_by_location(location, LoweringDiagnosticKind::UnsupportedMatch)
+                ctx.diagnostics.report_by_location(
+                    location,
+                    LoweringDiagnosticKind::UnavailableRefOrMemberValue,
+                )
             })?;
 
         self.finalize(ctx, FlatBlockEnd::Return(chain!(ref_vars, [expr]).collect()));
@@ -182,6 +206,43 @@ impl BlockBuilder {
         Ok(merged_expr)
     }
 
+    /// Merges descendant builders that should continue with the same code (e.g. builders of
+    /// different alternatives of the same match arm) into a new child builder of `self`.
+    /// Every member path changed in the merged builders is remapped into the new builder.
+    pub fn merge_into_child(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        mut builders: Vec<BlockBuilder>,
+        location: StableLocationOption,
+    ) -> BlockBuilder {
+        let mut semantic_remapping = OrderedHashMap::<MemberPath, VariableId>::default();
+        for builder in builders.iter_mut() {
+            for path in builder.changed_semantics.clone() {
+                let path = builder.semantics.bound_path_of(&path).unwrap();
+                if !semantic_remapping.contains_key(&path) {
+                    let var = builder.get_path(ctx, &path, location);
+                    let var = ctx.variables[var].clone();
+                    semantic_remapping.insert(path, ctx.variables.variables.alloc(var));
+                }
+            }
+        }
+
+        let block_id = ctx.blocks.alloc_empty();
+        for mut builder in builders {
+            let mut remapping = VarRemapping::default();
+            for (path, remapped_var) in semantic_remapping.iter() {
+                remapping.insert(*remapped_var, builder.get_path(ctx, path, location));
+            }
+            builder.finalize(ctx, FlatBlockEnd::Goto(block_id, remapping));
+        }
+
+        let mut child = self.child_block_builder(block_id);
+        for (path, var) in semantic_remapping {
+            child.put_path(path, var);
+        }
+        child
+    }
+
     /// Merges sibling sealed blocks.
     /// If there are reachable blocks, returns the converged expression of the blocks, usable at the
     /// calling builder, and the following block ID.
@@ -211,15 +272,16 @@ impl BlockBuilder {
                     ctx.variables.variables.alloc(var)
                 });
             }
-            for semantic in subscope.changed_semantics.iter() {
-                if !self.semantics.contains_var(semantic) {
+            for path in subscope.changed_semantics.iter() {
+                let Some(path) = self.semantics.bound_path_of(path) else {
                     // This variable is local to the subscope.
                     continue;
-                }
-                // This variable belongs to an outer builder, and it is changed in at least one
-                // branch. It should be remapped.
-                semantic_remapping.semantics.entry(*semantic).or_insert_with(|| {
-                    let var = self.get_semantic(ctx, *semantic, location);
+                };
+                // This path belongs to an outer builder, and it is changed in at least one
+                // branch. It should be remapped. Only the path bound in the outer builder is
+                // remapped, which may be a member path (e.g. in a loop function).
+                semantic_remapping.semantics.entry(path.clone()).or_insert_with(|| {
+                    let var = self.get_path(ctx, &path, location);
                     let var = ctx.variables[var].clone();
                     ctx.variables.variables.alloc(var)
                 });
@@ -238,8 +300,8 @@ impl BlockBuilder {
         }
 
         // Apply remapping on builder.
-        for (semantic, var) in semantic_remapping.semantics {
-            self.put_semantic(semantic, var);
+        for (path, var) in semantic_remapping.semantics {
+            self.put_path(path, var);
         }
 
         let expr = match semantic_remapping.expr {
@@ -255,7 +317,7 @@ impl BlockBuilder {
 #[derive(Debug, Default)]
 pub struct SemanticRemapping {
     expr: Option<VariableId>,
-    semantics: OrderedHashMap<semantic::VarId, VariableId>,
+    semantics: OrderedHashMap<MemberPath, VariableId>,
 }
 
 /// A sealed BlockBuilder, ready to be merged with sibling blocks to end the block.
@@ -279,10 +341,10 @@ impl SealedBlockBuilder {
         if let SealedBlockBuilder::GotoCallsite { mut builder, expr } = self {
             let mut remapping = VarRemapping::default();
             // Since SemanticRemapping should have unique variable ids, these asserts will pass.
-            for (semantic, remapped_var) in semantic_remapping.semantics.iter() {
+            for (path, remapped_var) in semantic_remapping.semantics.iter() {
                 assert!(
                     remapping
-                        .insert(*remapped_var, builder.get_semantic(ctx, *semantic, location))
+                        .insert(*remapped_var, builder.get_path(ctx, path, location))
                         .is_none()
                 );
             }
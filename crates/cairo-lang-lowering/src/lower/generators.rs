@@ -3,6 +3,7 @@
 
 use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::items::imp::ImplConstantId;
 use cairo_lang_semantic::ConcreteVariant;
 use cairo_lang_utils::extract_matches;
 use itertools::chain;
@@ -12,7 +13,7 @@ use super::context::VarRequest;
 use super::VariableId;
 use crate::lower::context::LoweringContext;
 use crate::objects::{
-    Statement, StatementCall, StatementLiteral, StatementStructConstruct,
+    Statement, StatementCall, StatementImplConstant, StatementLiteral, StatementStructConstruct,
     StatementStructDestructure,
 };
 use crate::{StatementDesnap, StatementEnumConstruct, StatementSnapshot};
@@ -46,6 +47,27 @@ impl Literal {
     }
 }
 
+/// Generator for [StatementImplConstant].
+pub struct ImplConstant {
+    pub impl_constant_id: ImplConstantId,
+    pub location: StableLocationOption,
+    pub ty: semantic::TypeId,
+}
+impl ImplConstant {
+    pub fn add(
+        self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut StatementsBuilder,
+    ) -> VariableId {
+        let output = ctx.new_var(VarRequest { ty: self.ty, location: self.location });
+        builder.push_statement(Statement::ImplConstant(StatementImplConstant {
+            impl_constant_id: self.impl_constant_id,
+            output,
+        }));
+        output
+    }
+}
+
 /// Generator for [StatementCall].
 /// Note that builder.finalize_statement() must be called manually after ref bindings.
 pub struct Call {
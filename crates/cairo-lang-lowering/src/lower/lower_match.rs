@@ -0,0 +1,1182 @@
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic as semantic;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+use itertools::{chain, zip_eq, Itertools};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use semantic::corelib::{
+    core_bool_enum, core_bool_ty, core_felt252_is_zero, core_felt252_ty, core_submodule,
+    false_variant, get_core_function_id, get_core_ty_by_name, get_enum_concrete_variant,
+    get_function_id, jump_nz_nonzero_variant, jump_nz_zero_variant, true_variant,
+};
+use semantic::items::enm::SemanticEnumEx;
+use semantic::items::structure::SemanticStructEx;
+use semantic::types::{peel_snapshots, wrap_in_snapshots};
+use semantic::{ConcreteTypeId, GenericArgumentId, TypeLongId};
+
+use super::block_builder::{BlockBuilder, SealedBlockBuilder};
+use super::context::{
+    LoweredExpr, LoweredExprExternEnum, LoweringContext, LoweringFlowError, LoweringResult,
+    VarRequest,
+};
+use super::external::extern_facade_return_tys;
+use super::{
+    alloc_empty_block, bind_pattern_variable, create_subscope, create_subscope_with_bound_refs,
+    generators, lower_expr, lower_literal_value, lower_tail_expr, match_extern_arm_ref_args_bind,
+    match_extern_variant_arm_input_types,
+};
+use crate::diagnostic::LoweringDiagnosticKind::*;
+use crate::ids::SemanticFunctionIdEx;
+use crate::{MatchArm, MatchEnumInfo, MatchExternInfo, MatchInfo, VariableId};
+
+/// Identifier of a value that is matched against patterns: the matched value itself, or a value
+/// nested in it (a member of a struct or a tuple, or the inner value of an enum variant).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct OccurrenceId(usize);
+
+/// The occurrence of the matched value itself.
+const ROOT_OCCURRENCE: OccurrenceId = OccurrenceId(0);
+
+/// How an occurrence is nested in its parent occurrence.
+#[derive(Clone, Debug)]
+enum Constructor {
+    /// The inner value of the given variant.
+    Variant(semantic::ConcreteVariant),
+    /// The member with the given index of a struct or a tuple.
+    Member(usize),
+}
+
+#[derive(Clone, Debug)]
+struct Occurrence {
+    ty: semantic::TypeId,
+    parent: Option<(OccurrenceId, Constructor)>,
+}
+
+/// A numeric type, whose values may be matched by literal and range patterns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NumericType {
+    Felt252,
+    /// An unsigned integer type with the given number of bits.
+    UInt(usize),
+}
+impl NumericType {
+    fn try_from_ty(ctx: &LoweringContext<'_, '_>, ty: semantic::TypeId) -> Option<Self> {
+        let semantic_db = ctx.db.upcast();
+        if ty == core_felt252_ty(semantic_db) {
+            return Some(Self::Felt252);
+        }
+        [8, 16, 32, 64, 128].into_iter().map(Self::UInt).find(|numeric_type| {
+            ty == get_core_ty_by_name(semantic_db, numeric_type.name().into(), vec![])
+        })
+    }
+
+    fn name(self) -> String {
+        match self {
+            Self::Felt252 => "felt252".into(),
+            Self::UInt(bits) => format!("u{bits}"),
+        }
+    }
+
+    /// Returns all the values of the type.
+    fn values(self) -> ValueRanges {
+        let max = match self {
+            Self::Felt252 => (BigInt::one() << 251) + 17 * (BigInt::one() << 192),
+            Self::UInt(bits) => (BigInt::one() << bits) - 1,
+        };
+        ValueRanges(vec![(BigInt::zero(), max)])
+    }
+}
+
+/// A set of numeric values, as a sorted list of disjoint inclusive ranges.
+#[derive(Clone, Debug)]
+struct ValueRanges(Vec<(BigInt, BigInt)>);
+impl ValueRanges {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn min(&self) -> &BigInt {
+        &self.0.first().expect("Empty value ranges.").0
+    }
+
+    fn max(&self) -> &BigInt {
+        &self.0.last().expect("Empty value ranges.").1
+    }
+
+    /// Returns the values of the set between `start` and `end`, inclusive.
+    fn intersection(&self, start: &BigInt, end: &BigInt) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|(range_start, range_end)| {
+                    (range_start.max(start).clone(), range_end.min(end).clone())
+                })
+                .filter(|(range_start, range_end)| range_start <= range_end)
+                .collect(),
+        )
+    }
+
+    /// Returns the values of the set that are not between `start` and `end`, inclusive.
+    fn difference(&self, start: &BigInt, end: &BigInt) -> Self {
+        let mut ranges = vec![];
+        for (range_start, range_end) in &self.0 {
+            if range_start < start {
+                ranges.push((range_start.clone(), range_end.min(&(start - 1)).clone()));
+            }
+            if range_end > end {
+                ranges.push((range_start.max(&(end + 1)).clone(), range_end.clone()));
+            }
+        }
+        Self(ranges)
+    }
+}
+
+/// A test of the value of a numeric occurrence.
+#[derive(Clone, Debug)]
+enum ValueTest {
+    /// The value is equal to the given value.
+    Eq(BigInt),
+    /// The value is greater than or equal to the given value.
+    Ge(BigInt),
+    /// The value is less than or equal to the given value.
+    Le(BigInt),
+}
+
+/// What a [ValueTest] is lowered to a match on.
+enum TestMatch {
+    /// A call to the given extern function, with the given inputs.
+    Extern(semantic::FunctionId, Vec<VariableId>),
+    /// The given `bool` variable.
+    Bool(VariableId),
+}
+
+/// Returns the range of values matched by a literal or a range pattern.
+fn pattern_value_range(pattern: &semantic::Pattern) -> Option<(BigInt, BigInt)> {
+    match pattern {
+        semantic::Pattern::Literal(pattern) => {
+            Some((pattern.literal.value.clone(), pattern.literal.value.clone()))
+        }
+        semantic::Pattern::Range(pattern) => {
+            Some((pattern.start.value.clone(), pattern.end.value.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// A decision tree, describing how to match a value against the arms of a match expression.
+enum DecisionTree {
+    /// The value matches the arm with the given index. `bindings` are the variables bound by the
+    /// pattern of the arm.
+    Leaf { arm_idx: usize, bindings: Vec<(semantic::PatternVariable, OccurrenceId)> },
+    /// Destructures a struct or a tuple into its members, and continues with `next`.
+    Destructure {
+        occurrence: OccurrenceId,
+        members: Vec<(OccurrenceId, StableLocationOption)>,
+        next: Box<DecisionTree>,
+    },
+    /// Matches an enum, continuing with the case of the matched variant.
+    Switch {
+        occurrence: OccurrenceId,
+        concrete_enum_id: semantic::ConcreteEnumId,
+        cases: Vec<SwitchCase>,
+    },
+    /// Tests the value of a number, continuing with `then` if the test passes, and with
+    /// `otherwise` if it fails.
+    Test {
+        occurrence: OccurrenceId,
+        test: ValueTest,
+        then: Box<DecisionTree>,
+        otherwise: Box<DecisionTree>,
+    },
+}
+
+/// A case of [DecisionTree::Switch].
+struct SwitchCase {
+    variant: semantic::ConcreteVariant,
+    inner: OccurrenceId,
+    location: StableLocationOption,
+    tree: DecisionTree,
+}
+
+/// A row of the pattern matrix, representing an alternative of a match arm that may still match
+/// the value.
+#[derive(Clone)]
+struct Row<'a> {
+    /// The pattern to match against each column occurrence. None stands for a pattern that
+    /// matches anything.
+    patterns: Vec<Option<&'a semantic::Pattern>>,
+    /// The variables bound so far by the alternative.
+    bindings: Vec<(semantic::PatternVariable, OccurrenceId)>,
+    arm_idx: usize,
+    alternative_idx: usize,
+}
+
+/// Builds the [DecisionTree] of a match expression, keeping track of the occurrences it creates
+/// and of the reachable arm alternatives.
+struct DecisionTreeBuilder {
+    occurrences: Vec<Occurrence>,
+    reachable: UnorderedHashSet<(usize, usize)>,
+    /// The values numeric occurrences may still have, in the currently built branch.
+    domains: UnorderedHashMap<OccurrenceId, ValueRanges>,
+    location: StableLocationOption,
+}
+impl DecisionTreeBuilder {
+    fn new(ty: semantic::TypeId, location: StableLocationOption) -> Self {
+        Self {
+            occurrences: vec![Occurrence { ty, parent: None }],
+            reachable: UnorderedHashSet::default(),
+            domains: UnorderedHashMap::default(),
+            location,
+        }
+    }
+
+    fn add_occurrence(
+        &mut self,
+        ty: semantic::TypeId,
+        parent: OccurrenceId,
+        constructor: Constructor,
+    ) -> OccurrenceId {
+        self.occurrences.push(Occurrence { ty, parent: Some((parent, constructor)) });
+        OccurrenceId(self.occurrences.len() - 1)
+    }
+
+    /// Builds the decision tree of matching the `columns` occurrences against `rows`.
+    fn build(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        columns: Vec<OccurrenceId>,
+        all_rows: Vec<Row<'_>>,
+    ) -> Maybe<DecisionTree> {
+        let mut rows = vec![];
+        'rows: for mut row in all_rows {
+            for (pattern, occurrence) in zip_eq(row.patterns.iter_mut(), &columns) {
+                match pattern {
+                    // Irrefutable patterns match anything, possibly binding a variable.
+                    Some(semantic::Pattern::Variable(variable)) => {
+                        row.bindings.push((variable.clone(), *occurrence));
+                        *pattern = None;
+                    }
+                    Some(semantic::Pattern::Otherwise(_)) => *pattern = None,
+                    // Literal and range patterns are checked against the values the occurrence may
+                    // still have.
+                    Some(
+                        numeric_pattern @ (semantic::Pattern::Literal(_)
+                        | semantic::Pattern::Range(_)),
+                    ) => {
+                        let (start, end) = pattern_value_range(numeric_pattern).unwrap();
+                        let domain = self.domain(ctx, *occurrence, numeric_pattern)?;
+                        if domain.intersection(&start, &end).is_empty() {
+                            continue 'rows;
+                        }
+                        if domain.difference(&start, &end).is_empty() {
+                            *pattern = None;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            rows.push(row);
+        }
+
+        let Some(first_row) = rows.first() else {
+            return self.build_without_rows(ctx, &columns);
+        };
+        let Some(col) = first_row.patterns.iter().position(Option::is_some) else {
+            // The first row matches anything, so it is taken.
+            self.reachable.insert((first_row.arm_idx, first_row.alternative_idx));
+            return Ok(DecisionTree::Leaf {
+                arm_idx: first_row.arm_idx,
+                bindings: first_row.bindings.clone(),
+            });
+        };
+        match first_row.patterns[col].unwrap() {
+            semantic::Pattern::Struct(_) | semantic::Pattern::Tuple(_) => {
+                self.build_destructure(ctx, columns, rows, col)
+            }
+            semantic::Pattern::EnumVariant(pattern) => {
+                let concrete_enum_id = pattern.variant.concrete_enum_id;
+                self.build_switch(ctx, columns, rows, col, concrete_enum_id)
+            }
+            semantic::Pattern::Literal(_) | semantic::Pattern::Range(_) => {
+                self.build_test(ctx, columns, rows, col)
+            }
+            semantic::Pattern::Variable(_) | semantic::Pattern::Otherwise(_) => unreachable!(),
+        }
+    }
+
+    /// Builds the decision tree when no arm may match the remaining values. This is valid only if
+    /// one of the values is of an empty enum.
+    fn build_without_rows(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        columns: &[OccurrenceId],
+    ) -> Maybe<DecisionTree> {
+        for occurrence in columns {
+            if let Some(concrete_enum_id) = self.try_get_enum(ctx, *occurrence) {
+                if ctx.db.concrete_enum_variants(concrete_enum_id)?.is_empty() {
+                    return Ok(DecisionTree::Switch {
+                        occurrence: *occurrence,
+                        concrete_enum_id,
+                        cases: vec![],
+                    });
+                }
+            }
+        }
+        let witness = self.format_witness(ctx, columns);
+        Err(ctx.diagnostics.report_by_location(self.location, MissingMatchArm(witness)))
+    }
+
+    /// Builds a [DecisionTree::Destructure] node for the column `col`, which holds a struct or a
+    /// tuple.
+    fn build_destructure(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        columns: Vec<OccurrenceId>,
+        rows: Vec<Row<'_>>,
+        col: usize,
+    ) -> Maybe<DecisionTree> {
+        let occurrence = columns[col];
+        let first_pattern = rows[0].patterns[col].unwrap();
+        let pattern_location = ctx.get_location(first_pattern.stable_ptr().untyped());
+        let (n_snapshots, long_ty) =
+            peel_snapshots(ctx.db.upcast(), self.occurrences[occurrence.0].ty);
+        // The type and location of each member, and for structs, its id.
+        let member_infos = match long_ty {
+            TypeLongId::Tuple(tys) => {
+                tys.into_iter().map(|ty| (ty, pattern_location, None)).collect_vec()
+            }
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                let field_patterns = match first_pattern {
+                    semantic::Pattern::Struct(pattern) => pattern.field_patterns.as_slice(),
+                    _ => &[],
+                };
+                ctx.db
+                    .concrete_struct_members(concrete_struct_id)?
+                    .into_iter()
+                    .map(|(_, member)| {
+                        let location = field_patterns
+                            .iter()
+                            .find(|(field, _)| field.id == member.id)
+                            .map(|(_, pattern)| ctx.get_location(pattern.stable_ptr().untyped()))
+                            .unwrap_or(pattern_location);
+                        (member.ty, location, Some(member.id))
+                    })
+                    .collect_vec()
+            }
+            _ => unreachable!("Only structs and tuples may be destructured."),
+        };
+        let members = member_infos
+            .iter()
+            .enumerate()
+            .map(|(idx, (ty, location, _))| {
+                let ty = wrap_in_snapshots(ctx.db.upcast(), *ty, n_snapshots);
+                (self.add_occurrence(ty, occurrence, Constructor::Member(idx)), *location)
+            })
+            .collect_vec();
+
+        let member_columns = members.iter().map(|(member, _)| *member);
+        let columns = chain!(
+            columns[..col].iter().copied(),
+            member_columns,
+            columns[col + 1..].iter().copied()
+        )
+        .collect();
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let member_patterns = match row.patterns[col] {
+                    None => vec![None; member_infos.len()],
+                    Some(semantic::Pattern::Tuple(pattern)) => {
+                        pattern.field_patterns.iter().map(|pattern| Some(&**pattern)).collect()
+                    }
+                    Some(semantic::Pattern::Struct(pattern)) => member_infos
+                        .iter()
+                        .map(|(_, _, member_id)| {
+                            pattern
+                                .field_patterns
+                                .iter()
+                                .find(|(field, _)| Some(field.id) == *member_id)
+                                .map(|(_, pattern)| &**pattern)
+                        })
+                        .collect(),
+                    Some(_) => unreachable!("Pattern does not match the type of the value."),
+                };
+                let patterns = chain!(
+                    row.patterns[..col].iter().copied(),
+                    member_patterns,
+                    row.patterns[col + 1..].iter().copied()
+                )
+                .collect();
+                Row { patterns, ..row }
+            })
+            .collect();
+
+        let next = self.build(ctx, columns, rows)?;
+        Ok(DecisionTree::Destructure { occurrence, members, next: Box::new(next) })
+    }
+
+    /// Builds a [DecisionTree::Switch] node for the column `col`, which holds an enum.
+    fn build_switch(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        columns: Vec<OccurrenceId>,
+        rows: Vec<Row<'_>>,
+        col: usize,
+        concrete_enum_id: semantic::ConcreteEnumId,
+    ) -> Maybe<DecisionTree> {
+        let occurrence = columns[col];
+        let (n_snapshots, _) = peel_snapshots(ctx.db.upcast(), self.occurrences[occurrence.0].ty);
+        let mut cases = vec![];
+        for variant in ctx.db.concrete_enum_variants(concrete_enum_id)? {
+            let ty = wrap_in_snapshots(ctx.db.upcast(), variant.ty, n_snapshots);
+            let inner = self.add_occurrence(ty, occurrence, Constructor::Variant(variant.clone()));
+            let mut location = None;
+            let case_rows = rows
+                .iter()
+                .filter_map(|row| {
+                    let inner_pattern = match row.patterns[col] {
+                        None => None,
+                        Some(semantic::Pattern::EnumVariant(pattern))
+                            if pattern.variant == variant =>
+                        {
+                            location.get_or_insert_with(|| {
+                                ctx.get_location(pattern.inner_pattern.stable_ptr().untyped())
+                            });
+                            Some(&*pattern.inner_pattern)
+                        }
+                        Some(semantic::Pattern::EnumVariant(_)) => return None,
+                        Some(_) => unreachable!("Pattern does not match the type of the value."),
+                    };
+                    let mut row = row.clone();
+                    row.patterns[col] = inner_pattern;
+                    Some(row)
+                })
+                .collect();
+            let mut case_columns = columns.clone();
+            case_columns[col] = inner;
+            let tree = self.build(ctx, case_columns, case_rows)?;
+            cases.push(SwitchCase {
+                variant,
+                inner,
+                location: location.unwrap_or(self.location),
+                tree,
+            });
+        }
+        Ok(DecisionTree::Switch { occurrence, concrete_enum_id, cases })
+    }
+
+    /// Builds a [DecisionTree::Test] node for the column `col`, which holds a number, testing the
+    /// literal or range pattern of the first row.
+    fn build_test(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        columns: Vec<OccurrenceId>,
+        rows: Vec<Row<'_>>,
+        col: usize,
+    ) -> Maybe<DecisionTree> {
+        let occurrence = columns[col];
+        let pattern = rows[0].patterns[col].unwrap();
+        let (start, end) = pattern_value_range(pattern).unwrap();
+        let domain = self.domains[occurrence].clone();
+        // A range is tested by its bounds, skipping a bound that all the remaining values satisfy.
+        let (test, then_start, then_end) = if start == end {
+            (ValueTest::Eq(start.clone()), start, end)
+        } else if domain.min() < &start {
+            (ValueTest::Ge(start.clone()), start, domain.max().clone())
+        } else {
+            (ValueTest::Le(end.clone()), domain.min().clone(), end)
+        };
+
+        self.domains.insert(occurrence, domain.intersection(&then_start, &then_end));
+        let then = self.build(ctx, columns.clone(), rows.clone())?;
+        self.domains.insert(occurrence, domain.difference(&then_start, &then_end));
+        let otherwise = self.build(ctx, columns, rows)?;
+        self.domains.insert(occurrence, domain);
+        Ok(DecisionTree::Test {
+            occurrence,
+            test,
+            then: Box::new(then),
+            otherwise: Box::new(otherwise),
+        })
+    }
+
+    /// Returns the values a numeric occurrence may still have, given a literal or a range pattern
+    /// it is matched against.
+    fn domain(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        occurrence: OccurrenceId,
+        pattern: &semantic::Pattern,
+    ) -> Maybe<ValueRanges> {
+        if let Some(domain) = self.domains.get(&occurrence) {
+            return Ok(domain.clone());
+        }
+        let Some(numeric_type) = NumericType::try_from_ty(ctx, self.occurrences[occurrence.0].ty)
+        else {
+            return Err(ctx.diagnostics.report(pattern.stable_ptr().untyped(), UnsupportedLiteralPattern));
+        };
+        let domain = numeric_type.values();
+        self.domains.insert(occurrence, domain.clone());
+        Ok(domain)
+    }
+
+    /// Returns the concrete enum of the type of an occurrence, if it is a (possibly snapshot)
+    /// enum.
+    fn try_get_enum(
+        &self,
+        ctx: &LoweringContext<'_, '_>,
+        occurrence: OccurrenceId,
+    ) -> Option<semantic::ConcreteEnumId> {
+        match peel_snapshots(ctx.db.upcast(), self.occurrences[occurrence.0].ty).1 {
+            TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => Some(concrete_enum_id),
+            _ => None,
+        }
+    }
+
+    /// Formats a pattern of a value that is not matched by any arm, given the occurrences that
+    /// remained unmatched.
+    fn format_witness(&self, ctx: &LoweringContext<'_, '_>, columns: &[OccurrenceId]) -> String {
+        // The nested occurrences of every occurrence on the way to the columns.
+        let mut children =
+            UnorderedHashMap::<OccurrenceId, Vec<(Constructor, OccurrenceId)>>::default();
+        for column in columns {
+            let mut occurrence = *column;
+            while let Some((parent, constructor)) = self.occurrences[occurrence.0].parent.clone() {
+                let siblings = children.entry(parent).or_insert_with(Vec::new);
+                let is_new = siblings.is_empty();
+                siblings.push((constructor, occurrence));
+                if !is_new {
+                    break;
+                }
+                occurrence = parent;
+            }
+        }
+        self.format_occurrence(ctx, &children, ROOT_OCCURRENCE)
+    }
+
+    fn format_occurrence(
+        &self,
+        ctx: &LoweringContext<'_, '_>,
+        children: &UnorderedHashMap<OccurrenceId, Vec<(Constructor, OccurrenceId)>>,
+        occurrence: OccurrenceId,
+    ) -> String {
+        let semantic_db = ctx.db.upcast();
+        let format_variant = |variant: &semantic::ConcreteVariant, inner: String| {
+            format!(
+                "{}::{}({inner})",
+                variant.concrete_enum_id.enum_id(semantic_db).name(semantic_db.upcast()),
+                variant.id.name(semantic_db.upcast()),
+            )
+        };
+        let Some(nested) = children.get(&occurrence) else {
+            // An unmatched occurrence. Any of its values is missing, so prefer a concrete value
+            // over a wildcard.
+            if let Some(domain) = self.domains.get(&occurrence) {
+                return domain.min().to_string();
+            }
+            return self
+                .try_get_enum(ctx, occurrence)
+                .and_then(|concrete_enum_id| ctx.db.concrete_enum_variants(concrete_enum_id).ok())
+                .and_then(|variants| variants.into_iter().next())
+                .map(|variant| format_variant(&variant, "_".into()))
+                .unwrap_or_else(|| "_".into());
+        };
+        if let (Constructor::Variant(variant), inner) = &nested[0] {
+            return format_variant(variant, self.format_occurrence(ctx, children, *inner));
+        }
+        let members = nested
+            .iter()
+            .sorted_by_key(|(constructor, _)| match constructor {
+                Constructor::Member(idx) => *idx,
+                Constructor::Variant(_) => unreachable!(),
+            })
+            .map(|(_, member)| self.format_occurrence(ctx, children, *member))
+            .collect_vec();
+        match peel_snapshots(semantic_db, self.occurrences[occurrence.0].ty).1 {
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                let member_names =
+                    ctx.db.concrete_struct_members(concrete_struct_id).unwrap_or_default();
+                format!(
+                    "{} {{ {} }}",
+                    concrete_struct_id.struct_id(semantic_db).name(semantic_db.upcast()),
+                    zip_eq(member_names.keys(), members)
+                        .map(|(name, member)| format!("{name}: {member}"))
+                        .join(", ")
+                )
+            }
+            _ => format!("({})", members.join(", ")),
+        }
+    }
+}
+
+/// The state of an occurrence's value, in a specific branch of the lowered decision tree.
+#[derive(Clone)]
+enum ValueState {
+    /// The value is held by a variable.
+    Var(VariableId),
+    /// The value is a tuple, whose elements are held by separate variables.
+    Tuple(Vec<VariableId>),
+    /// The value is the result of an extern function, which is not called yet.
+    ExternEnum(LoweredExprExternEnum),
+    /// The value was destructured to its members. `var` is the destructured variable, if any.
+    Destructured { var: Option<VariableId>, members: Vec<OccurrenceId> },
+    /// The value was matched to be of a specific variant. `var` is the matched variable, if any.
+    Matched { var: Option<VariableId>, variant: semantic::ConcreteVariant, inner: OccurrenceId },
+}
+
+/// Lowers a [DecisionTree] of a match expression.
+struct DecisionTreeLowering<'a> {
+    arms: &'a [semantic::MatchArm],
+    occurrences: Vec<Occurrence>,
+    /// The number of leaves of every arm. An arm with more than one leaf is lowered once, into a
+    /// block which all of its leaves go to.
+    n_leaves: Vec<usize>,
+    /// The sealed blocks of the lowered arms.
+    sealed_blocks: Vec<SealedBlockBuilder>,
+    /// The builders of the leaves of arms with more than one leaf.
+    shared_arm_leaves: OrderedHashMap<usize, Vec<BlockBuilder>>,
+    /// The location of the match expression.
+    location: StableLocationOption,
+}
+impl<'a> DecisionTreeLowering<'a> {
+    /// Lowers the subtree of a branch of the decision tree, whose builder is `builder`.
+    fn lower_subtree(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        mut builder: BlockBuilder,
+        mut values: UnorderedHashMap<OccurrenceId, ValueState>,
+        mut tree: &DecisionTree,
+    ) -> Maybe<()> {
+        loop {
+            match tree {
+                DecisionTree::Leaf { arm_idx, bindings } => {
+                    self.bind_variables(ctx, &mut builder, &values, bindings);
+                    if self.n_leaves[*arm_idx] == 1 {
+                        let sealed_block =
+                            lower_tail_expr(ctx, builder, self.arms[*arm_idx].expression)?;
+                        self.sealed_blocks.push(sealed_block);
+                    } else {
+                        self.shared_arm_leaves.entry(*arm_idx).or_default().push(builder);
+                    }
+                    return Ok(());
+                }
+                DecisionTree::Destructure { occurrence, members, next } => {
+                    self.destructure(ctx, &mut builder, &mut values, *occurrence, members);
+                    tree = next;
+                }
+                DecisionTree::Switch { .. } | DecisionTree::Test { .. } => {
+                    let match_info = self.lower_match(ctx, &mut builder, &values, tree, false)?;
+                    builder.end_with_match(ctx, match_info);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Lowers a [DecisionTree::Switch] or a [DecisionTree::Test] node, given the builder of the
+    /// block it ends. Returns the match to end the block with.
+    fn lower_match(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        values: &UnorderedHashMap<OccurrenceId, ValueState>,
+        tree: &DecisionTree,
+        is_root: bool,
+    ) -> Maybe<MatchInfo> {
+        match tree {
+            DecisionTree::Switch { occurrence, concrete_enum_id, cases } => self.lower_switch(
+                ctx,
+                builder,
+                values,
+                *occurrence,
+                *concrete_enum_id,
+                cases,
+                is_root,
+            ),
+            DecisionTree::Test { occurrence, test, then, otherwise } => {
+                self.lower_test(ctx, builder, values, *occurrence, test, [then, otherwise], is_root)
+            }
+            DecisionTree::Leaf { .. } | DecisionTree::Destructure { .. } => unreachable!(),
+        }
+    }
+
+    /// Lowers a [DecisionTree::Switch] node, given the builder of the block it ends. Returns the
+    /// match to end the block with.
+    #[allow(clippy::too_many_arguments)]
+    fn lower_switch(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &BlockBuilder,
+        values: &UnorderedHashMap<OccurrenceId, ValueState>,
+        occurrence: OccurrenceId,
+        concrete_enum_id: semantic::ConcreteEnumId,
+        cases: &[SwitchCase],
+        is_root: bool,
+    ) -> Maybe<MatchInfo> {
+        let mut arms = vec![];
+        for case in cases {
+            let mut case_values = values.clone();
+            let (subscope, var_ids) = match &values[occurrence] {
+                ValueState::Var(var) => {
+                    // Leaf builders are merged with the builder of the match expression, so nested
+                    // builders keep track of all the changes along the way.
+                    let subscope = if is_root {
+                        create_subscope_with_bound_refs(ctx, builder)
+                    } else {
+                        builder.sibling_block_builder(alloc_empty_block(ctx))
+                    };
+                    let inner_var = ctx.new_var(VarRequest {
+                        ty: self.occurrences[case.inner.0].ty,
+                        location: case.location,
+                    });
+                    case_values.insert(
+                        occurrence,
+                        ValueState::Matched {
+                            var: Some(*var),
+                            variant: case.variant.clone(),
+                            inner: case.inner,
+                        },
+                    );
+                    case_values.insert(case.inner, ValueState::Var(inner_var));
+                    (subscope, vec![inner_var])
+                }
+                ValueState::ExternEnum(extern_enum) => {
+                    let mut subscope = create_subscope(ctx, builder);
+                    let input_tys =
+                        match_extern_variant_arm_input_types(ctx, case.variant.ty, extern_enum);
+                    let mut input_vars = input_tys
+                        .into_iter()
+                        .map(|ty| ctx.new_var(VarRequest { ty, location: extern_enum.location }))
+                        .collect_vec();
+                    let var_ids = input_vars.clone();
+                    // Bind the arm inputs to implicits and semantic variables.
+                    match_extern_arm_ref_args_bind(
+                        ctx,
+                        &mut input_vars,
+                        extern_enum,
+                        &mut subscope,
+                    );
+                    let inner_value = match ctx.db.lookup_intern_type(case.variant.ty) {
+                        TypeLongId::Tuple(_) => ValueState::Tuple(input_vars),
+                        _ => ValueState::Var(input_vars.into_iter().exactly_one().unwrap()),
+                    };
+                    case_values.insert(
+                        occurrence,
+                        ValueState::Matched {
+                            var: None,
+                            variant: case.variant.clone(),
+                            inner: case.inner,
+                        },
+                    );
+                    case_values.insert(case.inner, inner_value);
+                    (subscope, var_ids)
+                }
+                _ => unreachable!("Only an unmatched enum may be matched."),
+            };
+            arms.push(MatchArm {
+                variant_id: case.variant.clone(),
+                block_id: subscope.block_id,
+                var_ids,
+            });
+            self.lower_subtree(ctx, subscope, case_values, &case.tree)?;
+        }
+        Ok(match &values[occurrence] {
+            ValueState::ExternEnum(extern_enum) => MatchInfo::Extern(MatchExternInfo {
+                function: extern_enum.function.lowered(ctx.db),
+                inputs: extern_enum.inputs.clone(),
+                arms,
+                location: extern_enum.location,
+            }),
+            ValueState::Var(input) => {
+                MatchInfo::Enum(MatchEnumInfo { concrete_enum_id, input: *input, arms })
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    /// Lowers a [DecisionTree::Test] node, given the builder of the block it ends. Returns the
+    /// match to end the block with.
+    #[allow(clippy::too_many_arguments)]
+    fn lower_test(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        values: &UnorderedHashMap<OccurrenceId, ValueState>,
+        occurrence: OccurrenceId,
+        test: &ValueTest,
+        [then, otherwise]: [&DecisionTree; 2],
+        is_root: bool,
+    ) -> Maybe<MatchInfo> {
+        let semantic_db = ctx.db.upcast();
+        let location = self.location;
+        let ty = self.occurrences[occurrence.0].ty;
+        let input = self.value_var(ctx, builder, values, occurrence, location);
+        let mut literal = |ctx: &mut LoweringContext<'_, '_>, value: &BigInt| {
+            generators::Literal { value: value.clone(), location, ty }
+                .add(ctx, &mut builder.statements)
+        };
+        let numeric_type = NumericType::try_from_ty(ctx, ty).unwrap();
+        // What to match on, and the variants the test passes and fails on.
+        let (test_match, then_variant, otherwise_variant) = match (numeric_type, test) {
+            (NumericType::Felt252, ValueTest::Eq(value)) => {
+                let input = if value.is_zero() {
+                    input
+                } else {
+                    let value = literal(ctx, value);
+                    generators::Call {
+                        function: get_core_function_id(semantic_db, "felt252_sub".into(), vec![])
+                            .lowered(ctx.db),
+                        inputs: vec![input, value],
+                        extra_ret_tys: vec![],
+                        ret_tys: vec![ty],
+                        location,
+                    }
+                    .add(ctx, &mut builder.statements)
+                    .returns[0]
+                };
+                (
+                    TestMatch::Extern(core_felt252_is_zero(semantic_db), vec![input]),
+                    jump_nz_zero_variant(semantic_db),
+                    jump_nz_nonzero_variant(semantic_db),
+                )
+            }
+            (NumericType::Felt252, ValueTest::Ge(value) | ValueTest::Le(value)) => {
+                // There is no libfunc comparing felt252 values, so they are converted to u256
+                // values, where `a <= b` if `b - a` does not overflow.
+                let u256_ty = get_core_ty_by_name(semantic_db, "u256".into(), vec![]);
+                let input = generators::Call {
+                    function: core_integer_function(ctx, "u256_from_felt252").lowered(ctx.db),
+                    inputs: vec![input],
+                    extra_ret_tys: vec![],
+                    ret_tys: vec![u256_ty],
+                    location,
+                }
+                .add(ctx, &mut builder.statements)
+                .returns[0];
+                let value = lower_literal_value(ctx, value.clone(), u256_ty, location, builder);
+                let inputs = if matches!(test, ValueTest::Ge(_)) {
+                    vec![input, value]
+                } else {
+                    vec![value, input]
+                };
+                let bool_ty = core_bool_ty(semantic_db);
+                let result_ty = ctx.db.intern_type(TypeLongId::Tuple(vec![u256_ty, bool_ty]));
+                let result = generators::Call {
+                    function: core_integer_function(ctx, "u256_overflow_sub").lowered(ctx.db),
+                    inputs,
+                    extra_ret_tys: vec![],
+                    ret_tys: vec![result_ty],
+                    location,
+                }
+                .add(ctx, &mut builder.statements)
+                .returns[0];
+                let overflow = generators::StructDestructure {
+                    input: result,
+                    var_reqs: vec![
+                        VarRequest { ty: u256_ty, location },
+                        VarRequest { ty: bool_ty, location },
+                    ],
+                }
+                .add(ctx, &mut builder.statements)[1];
+                (TestMatch::Bool(overflow), false_variant(semantic_db), true_variant(semantic_db))
+            }
+            (NumericType::UInt(_), ValueTest::Eq(value)) => (
+                TestMatch::Extern(
+                    integer_function(ctx, numeric_type, "eq"),
+                    vec![input, literal(ctx, value)],
+                ),
+                true_variant(semantic_db),
+                false_variant(semantic_db),
+            ),
+            (NumericType::UInt(_), ValueTest::Ge(value) | ValueTest::Le(value)) => {
+                // `a <= b` if `b - a` does not overflow.
+                let value = literal(ctx, value);
+                let inputs = if matches!(test, ValueTest::Ge(_)) {
+                    vec![input, value]
+                } else {
+                    vec![value, input]
+                };
+                let result_variant = |name| {
+                    get_enum_concrete_variant(
+                        semantic_db,
+                        core_submodule(semantic_db, "result"),
+                        "Result",
+                        vec![GenericArgumentId::Type(ty), GenericArgumentId::Type(ty)],
+                        name,
+                    )
+                };
+                (
+                    TestMatch::Extern(
+                        integer_function(ctx, numeric_type, "overflowing_sub"),
+                        inputs,
+                    ),
+                    result_variant("Ok"),
+                    result_variant("Err"),
+                )
+            }
+        };
+
+        let subscope = |ctx: &mut LoweringContext<'_, '_>| {
+            if is_root {
+                create_subscope_with_bound_refs(ctx, builder)
+            } else {
+                builder.sibling_block_builder(alloc_empty_block(ctx))
+            }
+        };
+        let then_subscope = subscope(ctx);
+        let otherwise_subscope = subscope(ctx);
+        let mut arms = vec![];
+        for (variant, subscope) in
+            [(then_variant, &then_subscope), (otherwise_variant, &otherwise_subscope)]
+        {
+            let var_ids = match test_match {
+                TestMatch::Extern(..) => extern_facade_return_tys(ctx, variant.ty)
+                    .into_iter()
+                    .map(|ty| ctx.new_var(VarRequest { ty, location }))
+                    .collect(),
+                TestMatch::Bool(_) => vec![ctx.new_var(VarRequest { ty: variant.ty, location })],
+            };
+            arms.push(MatchArm { variant_id: variant, block_id: subscope.block_id, var_ids });
+        }
+        arms.sort_by_key(|arm| arm.variant_id.idx);
+        self.lower_subtree(ctx, then_subscope, values.clone(), then)?;
+        self.lower_subtree(ctx, otherwise_subscope, values.clone(), otherwise)?;
+        Ok(match test_match {
+            TestMatch::Extern(function, inputs) => MatchInfo::Extern(MatchExternInfo {
+                function: function.lowered(ctx.db),
+                inputs,
+                arms,
+                location,
+            }),
+            TestMatch::Bool(input) => MatchInfo::Enum(MatchEnumInfo {
+                concrete_enum_id: core_bool_enum(semantic_db),
+                input,
+                arms,
+            }),
+        })
+    }
+
+    /// Destructures the value of a struct or tuple occurrence to its members.
+    fn destructure(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        values: &mut UnorderedHashMap<OccurrenceId, ValueState>,
+        occurrence: OccurrenceId,
+        members: &[(OccurrenceId, StableLocationOption)],
+    ) {
+        let (var, member_vars) = match values[occurrence].clone() {
+            ValueState::Var(var) => {
+                let member_vars = generators::StructDestructure {
+                    input: var,
+                    var_reqs: members
+                        .iter()
+                        .map(|(member, location)| VarRequest {
+                            ty: self.occurrences[member.0].ty,
+                            location: *location,
+                        })
+                        .collect(),
+                }
+                .add(ctx, &mut builder.statements);
+                (Some(var), member_vars)
+            }
+            ValueState::Tuple(vars) => (None, vars),
+            _ => unreachable!("Only an unmatched struct or tuple may be destructured."),
+        };
+        for ((member, _), member_var) in zip_eq(members, member_vars) {
+            values.insert(*member, ValueState::Var(member_var));
+        }
+        values.insert(
+            occurrence,
+            ValueState::Destructured {
+                var,
+                members: members.iter().map(|(member, _)| *member).collect(),
+            },
+        );
+    }
+
+    /// Binds the variables of a leaf in its builder.
+    fn bind_variables(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        values: &UnorderedHashMap<OccurrenceId, ValueState>,
+        bindings: &[(semantic::PatternVariable, OccurrenceId)],
+    ) {
+        for (pattern_variable, occurrence) in bindings {
+            let location = ctx.get_location(pattern_variable.stable_ptr.untyped());
+            let var = self.value_var(ctx, builder, values, *occurrence, location);
+            bind_pattern_variable(ctx, builder, pattern_variable, var);
+        }
+    }
+
+    /// Returns a variable holding the value of an occurrence, reconstructing it if it was
+    /// consumed while matching its nested values.
+    fn value_var(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        values: &UnorderedHashMap<OccurrenceId, ValueState>,
+        occurrence: OccurrenceId,
+        location: StableLocationOption,
+    ) -> VariableId {
+        let ty = self.occurrences[occurrence.0].ty;
+        // Snapshots are copyable, so the original variable may be used even if it was consumed.
+        let is_snapshot = matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Snapshot(_));
+        match &values[occurrence] {
+            ValueState::Var(var) => *var,
+            ValueState::Tuple(vars) => {
+                generators::StructConstruct { inputs: vars.clone(), ty, location }
+                    .add(ctx, &mut builder.statements)
+            }
+            ValueState::Destructured { var: Some(var), .. }
+            | ValueState::Matched { var: Some(var), .. }
+                if is_snapshot =>
+            {
+                *var
+            }
+            ValueState::Destructured { members, .. } => {
+                let inputs = members
+                    .iter()
+                    .map(|member| self.value_var(ctx, builder, values, *member, location))
+                    .collect();
+                generators::StructConstruct { inputs, ty, location }
+                    .add(ctx, &mut builder.statements)
+            }
+            ValueState::Matched { variant, inner, .. } => {
+                let input = self.value_var(ctx, builder, values, *inner, location);
+                generators::EnumConstruct { input, variant: variant.clone(), location }
+                    .add(ctx, &mut builder.statements)
+            }
+            ValueState::ExternEnum(_) => {
+                unreachable!("Extern enums are called before their value is used.")
+            }
+        }
+    }
+}
+
+/// Lowers the arms of a match expression, given the lowered matched value.
+/// The arms are compiled into a decision tree, which is lowered into nested matches. Missing and
+/// unreachable arms are reported.
+pub fn lower_match_arms(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    matched_expr: LoweredExpr,
+    matched_ty: semantic::TypeId,
+    arms: &[semantic::MatchArm],
+    location: StableLocationOption,
+) -> LoweringResult<LoweredExpr> {
+    let rows = arms
+        .iter()
+        .enumerate()
+        .flat_map(|(arm_idx, arm)| {
+            arm.patterns.iter().enumerate().map(move |(alternative_idx, pattern)| Row {
+                patterns: vec![Some(pattern)],
+                bindings: vec![],
+                arm_idx,
+                alternative_idx,
+            })
+        })
+        .collect();
+    let mut tree_builder = DecisionTreeBuilder::new(matched_ty, location);
+    let mut tree =
+        tree_builder.build(ctx, vec![ROOT_OCCURRENCE], rows).map_err(LoweringFlowError::Failed)?;
+    for (arm_idx, arm) in arms.iter().enumerate() {
+        for (alternative_idx, pattern) in arm.patterns.iter().enumerate() {
+            if !tree_builder.reachable.contains(&(arm_idx, alternative_idx)) {
+                ctx.diagnostics.report(pattern.stable_ptr().untyped(), UnreachableMatchArm);
+            }
+        }
+    }
+
+    let mut n_leaves = vec![0; arms.len()];
+    count_leaves(&tree, &mut n_leaves);
+    let mut lowering = DecisionTreeLowering {
+        arms,
+        occurrences: tree_builder.occurrences,
+        n_leaves,
+        sealed_blocks: vec![],
+        shared_arm_leaves: OrderedHashMap::default(),
+        location,
+    };
+
+    let mut merge_location = location;
+    let root_value = match matched_expr {
+        LoweredExpr::ExternEnum(extern_enum) if matches!(tree, DecisionTree::Switch { .. }) => {
+            merge_location = extern_enum.location;
+            ValueState::ExternEnum(extern_enum)
+        }
+        LoweredExpr::Tuple { exprs, .. } => ValueState::Tuple(
+            exprs.into_iter().map(|expr| expr.var(ctx, builder)).collect::<Result<_, _>>()?,
+        ),
+        matched_expr => ValueState::Var(matched_expr.var(ctx, builder)?),
+    };
+    let mut values = UnorderedHashMap::from_iter([(ROOT_OCCURRENCE, root_value)]);
+
+    // The code before the first switch is lowered into the builder of the match expression.
+    loop {
+        match tree {
+            DecisionTree::Leaf { arm_idx, bindings } => {
+                lowering.bind_variables(ctx, builder, &values, &bindings);
+                return lower_expr(ctx, builder, arms[arm_idx].expression);
+            }
+            DecisionTree::Destructure { occurrence, members, next } => {
+                lowering.destructure(ctx, builder, &mut values, occurrence, &members);
+                tree = *next;
+            }
+            _ => {
+                let match_info = lowering
+                    .lower_match(ctx, builder, &values, &tree, true)
+                    .map_err(LoweringFlowError::Failed)?;
+                for (arm_idx, leaves) in std::mem::take(&mut lowering.shared_arm_leaves) {
+                    let subscope = builder.merge_into_child(ctx, leaves, location);
+                    let sealed_block = lower_tail_expr(ctx, subscope, arms[arm_idx].expression)
+                        .map_err(LoweringFlowError::Failed)?;
+                    lowering.sealed_blocks.push(sealed_block);
+                }
+                return builder.merge_and_end_with_match(
+                    ctx,
+                    match_info,
+                    lowering.sealed_blocks,
+                    merge_location,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the function with the given name in the `integer` module of the corelib.
+fn core_integer_function(ctx: &LoweringContext<'_, '_>, name: &str) -> semantic::FunctionId {
+    let semantic_db = ctx.db.upcast();
+    get_function_id(semantic_db, core_submodule(semantic_db, "integer"), name.into(), vec![])
+}
+
+/// Returns the extern function of an unsigned integer type with the given name suffix, e.g.
+/// `u8_eq`.
+fn integer_function(
+    ctx: &LoweringContext<'_, '_>,
+    numeric_type: NumericType,
+    suffix: &str,
+) -> semantic::FunctionId {
+    core_integer_function(ctx, &format!("{}_{suffix}", numeric_type.name()))
+}
+
+/// Counts the leaves of every arm in a decision tree.
+fn count_leaves(tree: &DecisionTree, n_leaves: &mut [usize]) {
+    match tree {
+        DecisionTree::Leaf { arm_idx, .. } => n_leaves[*arm_idx] += 1,
+        DecisionTree::Destructure { next, .. } => count_leaves(next, n_leaves),
+        DecisionTree::Switch { cases, .. } => {
+            for case in cases {
+                count_leaves(&case.tree, n_leaves);
+            }
+        }
+        DecisionTree::Test { then, otherwise, .. } => {
+            count_leaves(then, n_leaves);
+            count_leaves(otherwise, n_leaves);
+        }
+    }
+}
@@ -6,17 +6,16 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::{chain, zip_eq, Itertools};
-use num_bigint::BigInt;
-use num_traits::Zero;
+use num_bigint::{BigInt, Sign};
 use semantic::corelib::{
-    core_felt252_is_zero, core_felt252_ty, core_nonzero_ty, get_core_function_id,
-    get_core_ty_by_name, jump_nz_nonzero_variant, jump_nz_zero_variant, never_ty, unit_ty,
+    core_felt252_ty, core_submodule, get_core_function_id, get_core_ty_by_name, get_function_id,
+    never_ty, unit_ty,
 };
-use semantic::items::enm::SemanticEnumEx;
+use semantic::items::constant::ConstValue;
 use semantic::items::structure::SemanticStructEx;
 use semantic::types::{peel_snapshots, wrap_in_snapshots};
 use semantic::{
-    ConcreteTypeId, ExprFunctionCallArg, ExprPropagateError, ExprVarMemberPath, TypeLongId,
+    ExprFunctionCallArg, ExprPropagateError, ExprVarMemberPath, GenericArgumentId, TypeLongId,
 };
 use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
 
@@ -27,6 +26,7 @@ use self::context::{
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
 use self::lower_if::lower_expr_if;
+use self::lower_match::lower_match_arms;
 use crate::blocks::FlatBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::*;
@@ -44,12 +44,16 @@ pub mod context;
 mod external;
 pub mod generators;
 mod lower_if;
+mod lower_match;
 pub mod refs;
 pub mod usage;
 
 #[cfg(test)]
 mod generated_test;
 
+/// The number of bytes in a `bytes31` word of a `ByteArray`.
+const BYTES_IN_BYTES31: usize = 31;
+
 /// Lowering of a function together with extra generated functions.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MultiLowering {
@@ -348,20 +352,10 @@ fn lower_single_pattern(
 ) -> Result<(), LoweringFlowError> {
     log::trace!("Lowering a single pattern.");
     match pattern {
-        semantic::Pattern::Literal(_) => unreachable!(),
-        semantic::Pattern::Variable(semantic::PatternVariable {
-            name: _,
-            var: sem_var,
-            stable_ptr,
-        }) => {
-            let sem_var = semantic::Variable::Local(sem_var.clone());
-            // Deposit the owned variable in the semantic variables store.
+        semantic::Pattern::Literal(_) | semantic::Pattern::Range(_) => unreachable!(),
+        semantic::Pattern::Variable(pattern_variable) => {
             let var = lowered_expr.var(ctx, builder)?;
-            // Override variable location.
-            ctx.variables.variables[var].location = ctx.get_location(stable_ptr.untyped());
-            builder.put_semantic(sem_var.id(), var);
-            // TODO(spapini): Build semantic_defs in semantic model.
-            ctx.semantic_defs.insert(sem_var.id(), sem_var);
+            bind_pattern_variable(ctx, builder, pattern_variable, var);
         }
         semantic::Pattern::Struct(structure) => {
             let members = ctx
@@ -431,6 +425,22 @@ fn lower_single_pattern(
     Ok(())
 }
 
+/// Binds the semantic variable of a variable pattern to a lowered variable.
+fn bind_pattern_variable(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    pattern_variable: &semantic::PatternVariable,
+    var: VariableId,
+) {
+    let sem_var = semantic::Variable::Local(pattern_variable.var.clone());
+    // Override variable location.
+    ctx.variables.variables[var].location = ctx.get_location(pattern_variable.stable_ptr.untyped());
+    // Deposit the owned variable in the semantic variables store.
+    builder.put_semantic(sem_var.id(), var);
+    // TODO(spapini): Build semantic_defs in semantic model.
+    ctx.semantic_defs.insert(sem_var.id(), sem_var);
+}
+
 /// Lowers a semantic expression.
 fn lower_expr(
     ctx: &mut LoweringContext<'_, '_>,
@@ -440,6 +450,7 @@ fn lower_expr(
     let expr = ctx.function_body.exprs[expr_id].clone();
     match &expr {
         semantic::Expr::Constant(expr) => lower_expr_constant(ctx, expr, builder),
+        semantic::Expr::ImplConstant(expr) => lower_expr_impl_constant(ctx, expr, builder),
         semantic::Expr::Tuple(expr) => lower_expr_tuple(ctx, expr, builder),
         semantic::Expr::Snapshot(expr) => lower_expr_snapshot(ctx, expr, builder),
         semantic::Expr::Desnap(expr) => lower_expr_desnap(ctx, expr, builder),
@@ -455,6 +466,7 @@ fn lower_expr(
             Ok(LoweredExpr::Member(member_path, ctx.get_location(expr.stable_ptr.untyped())))
         }
         semantic::Expr::Literal(expr) => lower_expr_literal(ctx, expr, builder),
+        semantic::Expr::StringLiteral(expr) => lower_expr_string_literal(ctx, expr, builder),
         semantic::Expr::MemberAccess(expr) => lower_expr_member_access(ctx, expr, builder),
         semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, builder),
         semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, builder),
@@ -472,35 +484,125 @@ fn lower_expr_literal(
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a literal: {:?}", expr.debug(&ctx.expr_formatter));
     let location = ctx.get_location(expr.stable_ptr.untyped());
+    Ok(LoweredExpr::AtVariable(lower_literal_value(
+        ctx,
+        expr.value.clone(),
+        expr.ty,
+        location,
+        builder,
+    )))
+}
+
+/// Lowers a numeric value of the given type into a variable.
+fn lower_literal_value(
+    ctx: &mut LoweringContext<'_, '_>,
+    value: BigInt,
+    ty: semantic::TypeId,
+    location: StableLocationOption,
+    builder: &mut BlockBuilder,
+) -> VariableId {
     let u256_ty = get_core_ty_by_name(ctx.db.upcast(), "u256".into(), vec![]);
 
-    if expr.ty == u256_ty {
+    if ty == u256_ty {
         let u128_ty = get_core_ty_by_name(ctx.db.upcast(), "u128".into(), vec![]);
 
         let mask128 = BigInt::from(u128::MAX);
-        let low = &expr.value & mask128;
-        let high = &expr.value >> 128;
+        let low = &value & mask128;
+        let high = &value >> 128;
         let u256 = vec![low, high];
 
-        return Ok(LoweredExpr::AtVariable(
-            generators::StructConstruct {
-                inputs: u256
-                    .into_iter()
-                    .map(|value| {
-                        generators::Literal { value, ty: u128_ty, location }
-                            .add(ctx, &mut builder.statements)
-                    })
-                    .collect(),
-                ty: u256_ty,
-                location,
-            }
-            .add(ctx, &mut builder.statements),
-        ));
+        return generators::StructConstruct {
+            inputs: u256
+                .into_iter()
+                .map(|value| {
+                    generators::Literal { value, ty: u128_ty, location }
+                        .add(ctx, &mut builder.statements)
+                })
+                .collect(),
+            ty: u256_ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements);
+    }
+
+    generators::Literal { value, ty, location }.add(ctx, &mut builder.statements)
+}
+
+/// Lowers a string literal into a `ByteArray` - the full 31-byte words are appended to the `data`
+/// array, and the remaining bytes form the pending word.
+fn lower_expr_string_literal(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprStringLiteral,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a string literal: {:?}", expr.debug(&ctx.expr_formatter));
+    let semantic_db = ctx.db.upcast();
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let bytes31_ty = get_core_ty_by_name(semantic_db, "bytes31".into(), vec![]);
+    let data_array_ty =
+        get_core_ty_by_name(semantic_db, "Array".into(), vec![GenericArgumentId::Type(bytes31_ty)]);
+    let array_module = core_submodule(semantic_db, "array");
+    let array_generic_args = vec![GenericArgumentId::Type(bytes31_ty)];
+
+    let mut data_array = generators::Call {
+        function: get_function_id(
+            semantic_db,
+            array_module,
+            "array_new".into(),
+            array_generic_args.clone(),
+        )
+        .lowered(ctx.db),
+        inputs: vec![],
+        extra_ret_tys: vec![],
+        ret_tys: vec![data_array_ty],
+        location,
+    }
+    .add(ctx, &mut builder.statements)
+    .returns[0];
+
+    let array_append =
+        get_function_id(semantic_db, array_module, "array_append".into(), array_generic_args)
+            .lowered(ctx.db);
+    let mut words = expr.value.as_bytes().chunks_exact(BYTES_IN_BYTES31);
+    for word in words.by_ref() {
+        let word_var = generators::Literal {
+            value: BigInt::from_bytes_be(Sign::Plus, word),
+            ty: bytes31_ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements);
+        data_array = generators::Call {
+            function: array_append,
+            inputs: vec![data_array, word_var],
+            extra_ret_tys: vec![data_array_ty],
+            ret_tys: vec![],
+            location,
+        }
+        .add(ctx, &mut builder.statements)
+        .extra_outputs[0];
     }
 
+    let pending_word = words.remainder();
+    let pending_word_var = generators::Literal {
+        value: BigInt::from_bytes_be(Sign::Plus, pending_word),
+        ty: core_felt252_ty(semantic_db),
+        location,
+    }
+    .add(ctx, &mut builder.statements);
+    let pending_word_len_var = generators::Literal {
+        value: pending_word.len().into(),
+        ty: get_core_ty_by_name(semantic_db, "u32".into(), vec![]),
+        location,
+    }
+    .add(ctx, &mut builder.statements);
+
     Ok(LoweredExpr::AtVariable(
-        generators::Literal { value: expr.value.clone(), ty: expr.ty, location }
-            .add(ctx, &mut builder.statements),
+        generators::StructConstruct {
+            inputs: vec![data_array, pending_word_var, pending_word_len_var],
+            ty: expr.ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements),
     ))
 }
 
@@ -510,12 +612,61 @@ fn lower_expr_constant(
     builder: &mut BlockBuilder,
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a constant: {:?}", expr.debug(&ctx.expr_formatter));
-    let const_expr =
-        &ctx.db.constant_semantic_data(expr.constant_id).map_err(LoweringFlowError::Failed)?.value;
-    let semantic::Expr::Literal(const_expr_literal) = const_expr else {
-        panic!("Only literal constants are supported.");
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let value =
+        ctx.db.constant_semantic_data(expr.constant_id).map_err(LoweringFlowError::Failed)?.value;
+    Ok(LoweredExpr::AtVariable(lower_const_value(ctx, &value, location, builder)))
+}
+
+fn lower_expr_impl_constant(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprImplConstant,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering an impl constant: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    // The value of a constant of a generic impl is only known after concretization.
+    let Some(constant) = expr.impl_constant_id.concrete_value(ctx.db.upcast()) else {
+        return Ok(LoweredExpr::AtVariable(
+            generators::ImplConstant {
+                impl_constant_id: expr.impl_constant_id,
+                location,
+                ty: expr.ty,
+            }
+            .add(ctx, &mut builder.statements),
+        ));
     };
-    lower_expr_literal(ctx, const_expr_literal, builder)
+    let value = constant.map_err(LoweringFlowError::Failed)?.value;
+    Ok(LoweredExpr::AtVariable(lower_const_value(ctx, &value, location, builder)))
+}
+
+/// Lowers a value computed at compile time into a variable.
+fn lower_const_value(
+    ctx: &mut LoweringContext<'_, '_>,
+    value: &ConstValue,
+    location: StableLocationOption,
+    builder: &mut BlockBuilder,
+) -> VariableId {
+    match value {
+        ConstValue::Int(value, ty) => {
+            lower_literal_value(ctx, value.clone(), *ty, location, builder)
+        }
+        ConstValue::Struct(values, ty) => generators::StructConstruct {
+            inputs: values
+                .iter()
+                .map(|value| lower_const_value(ctx, value, location, builder))
+                .collect(),
+            ty: *ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements),
+        ConstValue::Enum(variant, value) => generators::EnumConstruct {
+            input: lower_const_value(ctx, value, location, builder),
+            variant: variant.clone(),
+            location,
+        }
+        .add(ctx, &mut builder.statements),
+    }
 }
 
 /// Lowers an expression of type [semantic::ExprTuple].
@@ -732,19 +883,21 @@ fn call_loop_func(
     expr: &semantic::ExprLoop,
 ) -> LoweringResult<LoweredExpr> {
     let stable_ptr = expr.stable_ptr.untyped();
-    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let location = ctx.get_location(stable_ptr);
 
     // Call it.
     let function = ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
         parent: ctx.concrete_function_id.base_semantic_function(ctx.db),
         element: expr.body,
     }));
+    // The loop parameters are the member paths used by the loop. Each of them should be bound in
+    // the calling builder, either by itself or through one of its ancestors.
     let inputs = signature
         .params
         .into_iter()
         .map(|param| {
             builder.get_ref(ctx, &param).ok_or_else(|| {
-                LoweringFlowError::Failed(ctx.diagnostics.report(stable_ptr, MemberPathLoop))
+                LoweringFlowError::Failed(ctx.diagnostics.report(stable_ptr, UnboundLoopMemberPath))
             })
         })
         .collect::<LoweringResult<Vec<_>>>()?;
@@ -771,278 +924,8 @@ fn lower_expr_match(
     let location = ctx.get_location(expr.stable_ptr.untyped());
     let lowered_expr = lower_expr(ctx, builder, expr.matched_expr)?;
 
-    if ctx.function_body.exprs[expr.matched_expr].ty() == ctx.db.core_felt252_ty() {
-        let var = lowered_expr.var(ctx, builder)?;
-        return lower_expr_match_felt252(ctx, expr, var, builder);
-    }
-
-    // TODO(spapini): Use diagnostics.
-    // TODO(spapini): Handle more than just enums.
-    if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
-        return lower_optimized_extern_match(ctx, builder, extern_enum, &expr.arms);
-    }
-
-    let ExtractedEnumDetails { concrete_enum_id, concrete_variants, n_snapshots } =
-        extract_concrete_enum(ctx, expr)?;
-    let expr_var = lowered_expr.var(ctx, builder)?;
-
-    // Merge arm blocks.
-
-    let mut arm_var_ids = vec![];
-    let (sealed_blocks, block_ids): (Vec<_>, Vec<_>) = zip_eq(&concrete_variants, &expr.arms)
-        .map(|(concrete_variant, arm)| {
-            let mut subscope = create_subscope_with_bound_refs(ctx, builder);
-            let block_id = subscope.block_id;
-
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmNotAVariant),
-                )
-            })?;
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmOutOfOrder),
-                ));
-            }
-
-            let pattern_location =
-                ctx.get_location(enum_pattern.inner_pattern.stable_ptr().untyped());
-
-            let var_id = ctx.new_var(VarRequest {
-                ty: wrap_in_snapshots(ctx.db.upcast(), concrete_variant.ty, n_snapshots),
-                location: pattern_location,
-            });
-            arm_var_ids.push(vec![var_id]);
-            let variant_expr = LoweredExpr::AtVariable(var_id);
-
-            match lower_single_pattern(
-                ctx,
-                &mut subscope,
-                &enum_pattern.inner_pattern,
-                variant_expr,
-            ) {
-                Ok(_) => {
-                    // Lower the arm expression.
-                    lower_tail_expr(ctx, subscope, arm.expression)
-                }
-                Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
-            }
-            .map_err(LoweringFlowError::Failed)
-            .map(|sb| (sb, block_id))
-        })
-        .collect::<LoweringResult<Vec<_>>>()?
-        .into_iter()
-        .unzip();
-
-    let match_info = MatchInfo::Enum(MatchEnumInfo {
-        concrete_enum_id,
-        input: expr_var,
-        arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
-            .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
-            .collect(),
-    });
-    builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
-}
-
-/// Lowers a match expression on a LoweredExpr::ExternEnum lowered expression.
-fn lower_optimized_extern_match(
-    ctx: &mut LoweringContext<'_, '_>,
-    builder: &mut BlockBuilder,
-    extern_enum: LoweredExprExternEnum,
-    match_arms: &[semantic::MatchArm],
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Started lowering of an optimized extern match.");
-    let location = extern_enum.location;
-    let concrete_variants = ctx
-        .db
-        .concrete_enum_variants(extern_enum.concrete_enum_id)
-        .map_err(LoweringFlowError::Failed)?;
-    if match_arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report_by_location(location, UnsupportedMatch),
-        ));
-    }
-    // Merge arm blocks.
-    let mut arm_var_ids = vec![];
-
-    let (sealed_blocks, block_ids): (Vec<_>, Vec<_>) = zip_eq(&concrete_variants, match_arms)
-        .map(|(concrete_variant, arm)| {
-            let mut subscope = create_subscope(ctx, builder);
-            let block_id = subscope.block_id;
-
-            let input_tys =
-                match_extern_variant_arm_input_types(ctx, concrete_variant.ty, &extern_enum);
-            let mut input_vars = input_tys
-                .into_iter()
-                .map(|ty| ctx.new_var(VarRequest { ty, location }))
-                .collect_vec();
-            arm_var_ids.push(input_vars.clone());
-
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmNotAVariant),
-                )
-            })?;
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmOutOfOrder),
-                ));
-            }
-
-            // Bind the arm inputs to implicits and semantic variables.
-            match_extern_arm_ref_args_bind(ctx, &mut input_vars, &extern_enum, &mut subscope);
-
-            let variant_expr = extern_facade_expr(ctx, concrete_variant.ty, input_vars, location);
-            match lower_single_pattern(
-                ctx,
-                &mut subscope,
-                &enum_pattern.inner_pattern,
-                variant_expr,
-            ) {
-                Ok(_) => {
-                    // Lower the arm expression.
-                    lower_tail_expr(ctx, subscope, arm.expression)
-                }
-                Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
-            }
-            .map_err(LoweringFlowError::Failed)
-            .map(|sb| (sb, block_id))
-        })
-        .collect::<LoweringResult<Vec<_>>>()?
-        .into_iter()
-        .unzip();
-
-    let match_info = MatchInfo::Extern(MatchExternInfo {
-        function: extern_enum.function.lowered(ctx.db),
-        inputs: extern_enum.inputs,
-        arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
-            .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
-            .collect(),
-        location,
-    });
-    builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
-}
-
-/// Lowers an expression of type [semantic::ExprMatch] where the matched expression is a felt252.
-/// Currently only a simple match-zero is supported.
-fn lower_expr_match_felt252(
-    ctx: &mut LoweringContext<'_, '_>,
-    expr: &semantic::ExprMatch,
-    expr_var: VariableId,
-    builder: &mut BlockBuilder,
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Lowering a match-felt252 expression.");
-    let location = ctx.get_location(expr.stable_ptr.untyped());
-    // Check that the match has the expected form.
-    let (literal, block0, block_otherwise) = if let [
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Literal(semantic::PatternLiteral { literal, .. }),
-            expression: block0,
-        },
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Otherwise(_),
-            expression: block_otherwise,
-        },
-    ] = &expr.arms[..]
-    {
-        (literal, block0, block_otherwise)
-    } else {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), OnlyMatchZeroIsSupported),
-        ));
-    };
-
-    // Make sure literal is 0.
-    if !literal.value.is_zero() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(literal.stable_ptr.untyped(), NonZeroValueInMatch),
-        ));
-    }
-
-    let semantic_db = ctx.db.upcast();
-
-    // Lower both blocks.
-    let zero_block_id = alloc_empty_block(ctx);
-    let nonzero_block_id = alloc_empty_block(ctx);
-
-    let subscope_nz = builder.child_block_builder(nonzero_block_id);
-    let var_nz = ctx.new_var(VarRequest {
-        ty: core_nonzero_ty(semantic_db, core_felt252_ty(semantic_db)),
-        location,
-    });
-
-    let sealed_blocks = vec![
-        lower_tail_expr(ctx, builder.child_block_builder(zero_block_id), *block0)
-            .map_err(LoweringFlowError::Failed)?,
-        lower_tail_expr(ctx, subscope_nz, *block_otherwise).map_err(LoweringFlowError::Failed)?,
-    ];
-
-    let match_info = MatchInfo::Extern(MatchExternInfo {
-        function: core_felt252_is_zero(semantic_db).lowered(ctx.db),
-        inputs: vec![expr_var],
-        arms: vec![
-            MatchArm {
-                variant_id: jump_nz_zero_variant(semantic_db),
-                block_id: zero_block_id,
-                var_ids: vec![],
-            },
-            MatchArm {
-                variant_id: jump_nz_nonzero_variant(semantic_db),
-                block_id: nonzero_block_id,
-                var_ids: vec![var_nz],
-            },
-        ],
-        location,
-    });
-    builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
-}
-
-/// Information about the enum of a match statement. See [extract_concrete_enum].
-struct ExtractedEnumDetails {
-    concrete_enum_id: semantic::ConcreteEnumId,
-    concrete_variants: Vec<semantic::ConcreteVariant>,
-    n_snapshots: usize,
-}
-
-/// Extracts concrete enum and variants from a match expression. Assumes it is indeed a concrete
-/// enum.
-fn extract_concrete_enum(
-    ctx: &mut LoweringContext<'_, '_>,
-    expr: &semantic::ExprMatch,
-) -> Result<ExtractedEnumDetails, LoweringFlowError> {
-    let ty = ctx.function_body.exprs[expr.matched_expr].ty();
-    let (n_snapshots, long_ty) = peel_snapshots(ctx.db.upcast(), ty);
-
-    // Semantic model should have made sure the type is an enum.
-    let concrete_ty = extract_matches!(long_ty, TypeLongId::Concrete);
-    let concrete_enum_id = extract_matches!(concrete_ty, ConcreteTypeId::Enum);
-    let enum_id = concrete_enum_id.enum_id(ctx.db.upcast());
-    let variants = ctx.db.enum_variants(enum_id).map_err(LoweringFlowError::Failed)?;
-    let concrete_variants = variants
-        .values()
-        .map(|variant_id| {
-            let variant =
-                ctx.db.variant_semantic(enum_id, *variant_id).map_err(LoweringFlowError::Failed)?;
-
-            ctx.db
-                .concrete_enum_variant(concrete_enum_id, &variant)
-                .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    if expr.arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatch),
-        ));
-    }
-
-    Ok(ExtractedEnumDetails { concrete_enum_id, concrete_variants, n_snapshots })
+    let matched_ty = ctx.function_body.exprs[expr.matched_expr].ty();
+    lower_match_arms(ctx, builder, lowered_expr, matched_ty, &expr.arms, location)
 }
 
 /// Lowers a sequence of expressions and return them all. If the flow ended in the middle,
@@ -1110,7 +993,7 @@ fn lower_expr_member_access(
     let member_idx =
         members.iter().position(|(_, member)| member.id == expr.member).ok_or_else(|| {
             LoweringFlowError::Failed(
-                ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatch),
+                ctx.diagnostics.report(expr.stable_ptr.untyped(), UnavailableRefOrMemberValue),
             )
         })?;
     if let Some(member_path) = &expr.member_path {
@@ -12,8 +12,19 @@ pub struct SemanticLoweringMapping {
     scattered: OrderedHashMap<MemberPath, Value>,
 }
 impl SemanticLoweringMapping {
-    pub fn contains_var(&mut self, var: &semantic::VarId) -> bool {
-        self.scattered.contains_key(&MemberPath::Var(*var))
+    /// Returns the bound member path containing `path`, i.e. `path` itself or one of its
+    /// ancestors. A loop function, for example, may bind `a.b` without binding `a`.
+    pub fn bound_path_of(&self, path: &MemberPath) -> Option<MemberPath> {
+        let mut current = path;
+        loop {
+            if self.scattered.contains_key(current) {
+                return Some(current.clone());
+            }
+            let MemberPath::Member { parent, .. } = current else {
+                return None;
+            };
+            current = parent;
+        }
     }
 
     pub fn get<TContext: StructRecomposer>(
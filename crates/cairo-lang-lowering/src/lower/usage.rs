@@ -170,7 +170,9 @@ impl BlockUsages {
             Expr::Match(expr) => {
                 self.handle_expr(function_body, expr.matched_expr, current);
                 for arm in &expr.arms {
-                    Self::handle_pattern(&arm.pattern, current);
+                    for pattern in &arm.patterns {
+                        Self::handle_pattern(pattern, current);
+                    }
                     self.handle_expr(function_body, arm.expression, current);
                 }
             }
@@ -186,7 +188,7 @@ impl BlockUsages {
                     .usage
                     .insert(MemberPath::Var(expr.var), ExprVarMemberPath::Var(expr.clone()));
             }
-            Expr::Literal(_) => {}
+            Expr::Literal(_) | Expr::StringLiteral(_) => {}
             Expr::MemberAccess(expr) => {
                 if let Some(member_path) = &expr.member_path {
                     current.usage.insert(member_path.into(), member_path.clone());
@@ -203,14 +205,14 @@ impl BlockUsages {
                 self.handle_expr(function_body, expr.value_expr, current)
             }
             Expr::PropagateError(expr) => self.handle_expr(function_body, expr.inner, current),
-            Expr::Constant(_) => {}
+            Expr::Constant(_) | Expr::ImplConstant(_) => {}
             Expr::Missing(_) => {}
         }
     }
 
     fn handle_pattern(pat: &Pattern, current: &mut Usage) {
         match pat {
-            Pattern::Literal(_) => {}
+            Pattern::Literal(_) | Pattern::Range(_) => {}
             Pattern::Variable(pat) => {
                 current.introductions.insert(VarId::Local(pat.var.id));
             }
@@ -15,7 +15,7 @@ use num_bigint::BigInt;
 pub mod blocks;
 pub use blocks::BlockId;
 use semantic::expr::inference::InferenceResult;
-use semantic::items::imp::ImplId;
+use semantic::items::imp::{ImplConstantId, ImplId};
 
 use self::blocks::FlatBlocks;
 use crate::diagnostic::LoweringDiagnostic;
@@ -118,6 +118,7 @@ pub enum Statement {
     // Values.
     // TODO(spapini): Consts.
     Literal(StatementLiteral),
+    ImplConstant(StatementImplConstant),
 
     // Flow control.
     Call(StatementCall),
@@ -136,6 +137,7 @@ impl Statement {
     pub fn inputs(&self) -> Vec<VariableId> {
         match &self {
             Statement::Literal(_stmt) => vec![],
+            Statement::ImplConstant(_stmt) => vec![],
             Statement::Call(stmt) => stmt.inputs.clone(),
             Statement::StructConstruct(stmt) => stmt.inputs.clone(),
             Statement::StructDestructure(stmt) => vec![stmt.input],
@@ -147,6 +149,7 @@ impl Statement {
     pub fn outputs(&self) -> Vec<VariableId> {
         match &self {
             Statement::Literal(stmt) => vec![stmt.output],
+            Statement::ImplConstant(stmt) => vec![stmt.output],
             Statement::Call(stmt) => stmt.outputs.clone(),
             Statement::StructConstruct(stmt) => vec![stmt.output],
             Statement::StructDestructure(stmt) => stmt.outputs.clone(),
@@ -166,6 +169,17 @@ pub struct StatementLiteral {
     pub output: VariableId,
 }
 
+/// A statement that binds the value of an associated constant of a trait to a variable.
+/// The impl is not known before concretization, where this becomes the statements constructing the
+/// value of the constant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementImplConstant {
+    /// The associated constant.
+    pub impl_constant_id: ImplConstantId,
+    /// The variable to bind the value to.
+    pub output: VariableId,
+}
+
 /// A statement that calls a user function.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatementCall {
@@ -0,0 +1,303 @@
+use std::collections::HashSet;
+
+use cairo_lang_semantic::corelib::{core_submodule, get_core_function_id, get_function_id};
+use cairo_lang_semantic::ConcreteVariant;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+use super::dead_code::{reachable_blocks, used_vars};
+use crate::db::LoweringGroup;
+use crate::ids::{FunctionId, SemanticFunctionIdEx};
+use crate::{
+    BlockId, FlatBlockEnd, FlatLowered, MatchArm, MatchEnumInfo, MatchExternInfo, MatchInfo,
+    Statement, StatementCall, StatementEnumConstruct, StatementLiteral, VarRemapping, VariableId,
+};
+
+/// Evaluates at compile time felt252 and integer arithmetic on known constants, and replaces
+/// matches on values that are known at compile time with a jump to the relevant arm.
+///
+/// Blocks that become unreachable are not removed here - they are removed by `reorganize_blocks`.
+pub fn const_folding(db: &dyn LoweringGroup, lowered: &mut FlatLowered) {
+    if lowered.blocks.has_root().is_err() {
+        return;
+    }
+
+    let libfunc_info = ConstFoldingLibfuncInfo::new(db);
+    // Folding a match makes the other arms unreachable, which may allow propagating more
+    // information into the blocks they used to jump to, so iterate until no match is folded.
+    while fold_reachable_blocks(&libfunc_info, lowered) {}
+}
+
+/// Folds the constants in the blocks reachable from the root.
+/// Returns true if any block end was folded.
+fn fold_reachable_blocks(
+    libfunc_info: &ConstFoldingLibfuncInfo,
+    lowered: &mut FlatLowered,
+) -> bool {
+    let reachable_blocks = reachable_blocks(lowered);
+    let mut ctx = ConstFoldingContext {
+        libfunc_info,
+        var_info: UnorderedHashMap::default(),
+        used_vars: used_vars(lowered, &reachable_blocks),
+        n_predecessors: n_predecessors(lowered, &reachable_blocks),
+    };
+
+    // Traverse the blocks in a DFS order, so that the block defining a variable is always visited
+    // before the blocks using it.
+    let mut folded_end = false;
+    for block_id in reachable_blocks {
+        let block = &mut lowered.blocks[block_id];
+        for stmt in block.statements.iter_mut() {
+            ctx.visit_statement(stmt);
+        }
+
+        if let Some((statements, end)) = ctx.try_fold_end(&block.end) {
+            block.statements.extend(statements);
+            block.end = end;
+            folded_end = true;
+        }
+
+        if let FlatBlockEnd::Goto(target_block_id, remapping) = &block.end {
+            ctx.propagate_remapping(*target_block_id, remapping);
+        }
+    }
+    folded_end
+}
+
+/// The information known at compile time about a variable.
+#[derive(Clone, Debug)]
+enum VarInfo {
+    /// The variable is a known constant.
+    Const(BigInt),
+    /// The variable is a known variant of an enum, with the given input.
+    Variant { variant: ConcreteVariant, input: VariableId },
+}
+
+struct ConstFoldingContext<'a> {
+    /// The extern functions that can be evaluated at compile time.
+    libfunc_info: &'a ConstFoldingLibfuncInfo,
+    /// The information known about each variable.
+    var_info: UnorderedHashMap<VariableId, VarInfo>,
+    /// The variables that are used in the reachable blocks.
+    used_vars: HashSet<VariableId>,
+    /// The number of incoming edges of each block, from the reachable blocks.
+    n_predecessors: Vec<usize>,
+}
+impl ConstFoldingContext<'_> {
+    /// Updates the known information given a statement, replacing it with a literal if its value
+    /// is known at compile time.
+    fn visit_statement(&mut self, stmt: &mut Statement) {
+        match stmt {
+            Statement::Literal(StatementLiteral { value, output }) => {
+                self.var_info.insert(*output, VarInfo::Const(value.clone()));
+            }
+            Statement::EnumConstruct(StatementEnumConstruct { variant, input, output }) => {
+                self.var_info
+                    .insert(*output, VarInfo::Variant { variant: variant.clone(), input: *input });
+            }
+            Statement::Call(call) => {
+                if let Some(value) = self.try_fold_call(call) {
+                    let output = call.outputs[0];
+                    self.var_info.insert(output, VarInfo::Const(value.clone()));
+                    *stmt = Statement::Literal(StatementLiteral { value, output });
+                }
+            }
+            Statement::ImplConstant(_)
+            | Statement::StructConstruct(_)
+            | Statement::StructDestructure(_)
+            | Statement::Snapshot(_)
+            | Statement::Desnap(_) => {}
+        }
+    }
+
+    /// Returns the value of a call to a felt252 arithmetic function, if it is known at compile
+    /// time.
+    fn try_fold_call(&self, call: &StatementCall) -> Option<BigInt> {
+        let op = self.libfunc_info.felt252_ops.get(&call.function)?;
+        let [lhs, rhs] = call.inputs.as_slice() else {
+            return None;
+        };
+        let (lhs, rhs) = (self.as_const(*lhs)?, self.as_const(*rhs)?);
+        let value = match op {
+            Felt252Op::Add => lhs + rhs,
+            Felt252Op::Sub => lhs - rhs,
+            Felt252Op::Mul => lhs * rhs,
+        };
+        let prime = felt252_prime();
+        Some(((value % &prime) + &prime) % &prime)
+    }
+
+    /// Tries to fold a block end that is a match on a value known at compile time.
+    /// Returns the statements to add to the end of the block, and the new block end.
+    fn try_fold_end(&mut self, end: &FlatBlockEnd) -> Option<(Vec<Statement>, FlatBlockEnd)> {
+        let FlatBlockEnd::Match { info } = end else {
+            return None;
+        };
+        match info {
+            MatchInfo::Enum(MatchEnumInfo { input, arms, .. }) => {
+                let VarInfo::Variant { variant, input: variant_input } =
+                    self.var_info.get(input)?.clone() else {
+                    return None;
+                };
+                let arm = arms.iter().find(|arm| arm.variant_id == variant)?;
+                let [var_id] = arm.var_ids.as_slice() else {
+                    panic!("An arm of an EnumMatch should produce a single variable.");
+                };
+                let mut remapping = VarRemapping::default();
+                remapping.insert(*var_id, variant_input);
+                Some((vec![], FlatBlockEnd::Goto(arm.block_id, remapping)))
+            }
+            MatchInfo::Extern(info) => {
+                let (arm, value) = self.try_fold_extern_match(info)?;
+                let statements = match (arm.var_ids.as_slice(), value) {
+                    ([], None) => vec![],
+                    ([var_id], Some(value)) => {
+                        self.var_info.insert(*var_id, VarInfo::Const(value.clone()));
+                        vec![Statement::Literal(StatementLiteral { value, output: *var_id })]
+                    }
+                    // The value of the arm variable can't be represented as a literal, which is
+                    // fine as long as it is not used.
+                    ([var_id], None) if !self.used_vars.contains(var_id) => vec![],
+                    _ => return None,
+                };
+                Some((statements, FlatBlockEnd::Goto(arm.block_id, VarRemapping::default())))
+            }
+        }
+    }
+
+    /// Returns the arm that would be chosen by an extern match, and the value of the variable
+    /// introduced by that arm, if it is known at compile time.
+    fn try_fold_extern_match<'a>(
+        &self,
+        info: &'a MatchExternInfo,
+    ) -> Option<(&'a MatchArm, Option<BigInt>)> {
+        let inputs =
+            info.inputs.iter().map(|var_id| self.as_const(*var_id)).collect::<Option<Vec<_>>>()?;
+        if info.function == self.libfunc_info.felt252_is_zero {
+            let [value] = inputs.as_slice() else {
+                return None;
+            };
+            // The arms are `Zero` and `NonZero`.
+            return Some((&info.arms[usize::from(!value.is_zero())], None));
+        }
+
+        let (op, bits) = self.libfunc_info.uint_ops.get(&info.function)?;
+        let [lhs, rhs] = inputs.as_slice() else {
+            return None;
+        };
+        let modulus = BigInt::one() << *bits;
+        match op {
+            // The arms are `Ok` and `Err`.
+            UintOp::OverflowingAdd => {
+                let value = *lhs + *rhs;
+                let overflow = value >= modulus;
+                Some((&info.arms[usize::from(overflow)], Some(value % modulus)))
+            }
+            UintOp::OverflowingSub => {
+                let overflow = lhs < rhs;
+                let value = (*lhs - *rhs + &modulus) % modulus;
+                Some((&info.arms[usize::from(overflow)], Some(value)))
+            }
+            // The arms are `False` and `True`.
+            UintOp::Eq => Some((&info.arms[usize::from(lhs == rhs)], None)),
+        }
+    }
+
+    /// Propagates the known information over a remapping to a block with a single predecessor.
+    fn propagate_remapping(&mut self, target_block_id: BlockId, remapping: &VarRemapping) {
+        if self.n_predecessors[target_block_id.0] != 1 {
+            return;
+        }
+        for (dst, src) in remapping.iter() {
+            if let Some(info) = self.var_info.get(src).cloned() {
+                self.var_info.insert(*dst, info);
+            }
+        }
+    }
+
+    /// Returns the value of a variable, if it is a known constant.
+    fn as_const(&self, var_id: VariableId) -> Option<&BigInt> {
+        match self.var_info.get(&var_id)? {
+            VarInfo::Const(value) => Some(value),
+            VarInfo::Variant { .. } => None,
+        }
+    }
+}
+
+/// A felt252 arithmetic operation that can be evaluated at compile time.
+enum Felt252Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// An unsigned integer operation that can be evaluated at compile time.
+enum UintOp {
+    OverflowingAdd,
+    OverflowingSub,
+    Eq,
+}
+
+/// The extern functions that can be evaluated at compile time.
+struct ConstFoldingLibfuncInfo {
+    felt252_ops: OrderedHashMap<FunctionId, Felt252Op>,
+    felt252_is_zero: FunctionId,
+    /// The unsigned integer operations, with the number of bits of their type.
+    uint_ops: OrderedHashMap<FunctionId, (UintOp, usize)>,
+}
+impl ConstFoldingLibfuncInfo {
+    fn new(db: &dyn LoweringGroup) -> Self {
+        let semantic_db = db.upcast();
+        let core_function = |name: &str| -> FunctionId {
+            get_core_function_id(semantic_db, name.into(), vec![]).lowered(db)
+        };
+        let integer_module = core_submodule(semantic_db, "integer");
+        let integer_function = |name: String| -> FunctionId {
+            get_function_id(semantic_db, integer_module, name.into(), vec![]).lowered(db)
+        };
+
+        let felt252_ops = OrderedHashMap::from_iter([
+            (core_function("felt252_add"), Felt252Op::Add),
+            (core_function("felt252_sub"), Felt252Op::Sub),
+            (core_function("felt252_mul"), Felt252Op::Mul),
+        ]);
+        let mut uint_ops = OrderedHashMap::default();
+        for (ty, bits) in [("u8", 8), ("u16", 16), ("u32", 32), ("u64", 64), ("u128", 128)] {
+            uint_ops.insert(
+                integer_function(format!("{ty}_overflowing_add")),
+                (UintOp::OverflowingAdd, bits),
+            );
+            uint_ops.insert(
+                integer_function(format!("{ty}_overflowing_sub")),
+                (UintOp::OverflowingSub, bits),
+            );
+            uint_ops.insert(integer_function(format!("{ty}_eq")), (UintOp::Eq, bits));
+        }
+
+        Self { felt252_ops, felt252_is_zero: core_function("felt252_is_zero"), uint_ops }
+    }
+}
+
+/// Returns the prime of the felt252 field.
+fn felt252_prime() -> BigInt {
+    (BigInt::one() << 251) + (BigInt::from(17) << 192) + 1
+}
+
+/// Returns the number of incoming edges of each block, from the given blocks.
+fn n_predecessors(lowered: &FlatLowered, block_ids: &[BlockId]) -> Vec<usize> {
+    let mut n_predecessors = vec![0; lowered.blocks.len()];
+    for block_id in block_ids {
+        match &lowered.blocks[*block_id].end {
+            FlatBlockEnd::Goto(target_block_id, _) => n_predecessors[target_block_id.0] += 1,
+            FlatBlockEnd::Match { info } => {
+                for arm in info.arms() {
+                    n_predecessors[arm.block_id.0] += 1;
+                }
+            }
+            FlatBlockEnd::Return(_) | FlatBlockEnd::Panic(_) | FlatBlockEnd::NotSet => {}
+        }
+    }
+    n_predecessors
+}
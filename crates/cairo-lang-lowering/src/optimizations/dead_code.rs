@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use crate::{BlockId, FlatBlockEnd, FlatLowered, Statement, VariableId};
+
+/// Removes statements without side effects whose outputs are never used.
+///
+/// Only blocks reachable from the root are considered when deciding whether a variable is used.
+/// The unreachable blocks themselves are not removed here - they are removed by
+/// `reorganize_blocks`.
+pub fn eliminate_dead_code(lowered: &mut FlatLowered) {
+    if lowered.blocks.has_root().is_err() {
+        return;
+    }
+
+    let reachable_blocks = reachable_blocks(lowered);
+    // Removing a statement may make the inputs of other statements unused, so iterate until a
+    // fixpoint is reached.
+    loop {
+        let used_vars = used_vars(lowered, &reachable_blocks);
+        let mut changed = false;
+        for block_id in &reachable_blocks {
+            let variables = &lowered.variables;
+            lowered.blocks[*block_id].statements.retain(|stmt| {
+                let removable = is_side_effect_free(stmt)
+                    && stmt.outputs().iter().all(|var_id| !used_vars.contains(var_id))
+                    && stmt.inputs().iter().all(|var_id| variables[*var_id].droppable.is_ok());
+                changed |= removable;
+                !removable
+            });
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Returns true if the statement has no effect other than defining its outputs.
+fn is_side_effect_free(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::Literal(_)
+        | Statement::ImplConstant(_)
+        | Statement::StructConstruct(_)
+        | Statement::StructDestructure(_)
+        | Statement::EnumConstruct(_)
+        | Statement::Snapshot(_)
+        | Statement::Desnap(_) => true,
+        Statement::Call(_) => false,
+    }
+}
+
+/// Returns the blocks reachable from the root block, in DFS order.
+pub(crate) fn reachable_blocks(lowered: &FlatLowered) -> Vec<BlockId> {
+    let mut reachable_blocks = vec![];
+    let mut visited = vec![false; lowered.blocks.len()];
+    let mut stack = vec![BlockId::root()];
+    while let Some(block_id) = stack.pop() {
+        if visited[block_id.0] {
+            continue;
+        }
+        visited[block_id.0] = true;
+        reachable_blocks.push(block_id);
+        match &lowered.blocks[block_id].end {
+            FlatBlockEnd::Goto(target_block_id, _) => stack.push(*target_block_id),
+            FlatBlockEnd::Match { info } => {
+                stack.extend(info.arms().iter().map(|arm| arm.block_id));
+            }
+            FlatBlockEnd::Return(_) | FlatBlockEnd::Panic(_) | FlatBlockEnd::NotSet => {}
+        }
+    }
+    reachable_blocks
+}
+
+/// Returns the variables used in the given blocks.
+pub(crate) fn used_vars(lowered: &FlatLowered, block_ids: &[BlockId]) -> HashSet<VariableId> {
+    let mut used_vars = HashSet::new();
+    for block_id in block_ids {
+        let block = &lowered.blocks[*block_id];
+        for stmt in &block.statements {
+            used_vars.extend(stmt.inputs());
+        }
+        match &block.end {
+            FlatBlockEnd::Goto(_, remapping) => used_vars.extend(remapping.values().copied()),
+            FlatBlockEnd::Match { info } => used_vars.extend(info.inputs()),
+            FlatBlockEnd::Return(returns) => used_vars.extend(returns.iter().copied()),
+            FlatBlockEnd::Panic(data) => {
+                used_vars.insert(*data);
+            }
+            FlatBlockEnd::NotSet => {}
+        }
+    }
+    used_vars
+}
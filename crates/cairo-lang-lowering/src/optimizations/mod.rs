@@ -1,3 +1,8 @@
+#[cfg(test)]
+mod test;
+
+pub mod const_folding;
+pub mod dead_code;
 pub mod delay_var_def;
 pub mod match_optimizer;
 pub mod remappings;
@@ -68,7 +68,8 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         SyntaxKind::TokenLiteralNumber
         | SyntaxKind::TokenFalse
         | SyntaxKind::TokenTrue
-        | SyntaxKind::TokenShortString => text.bright_cyan(),
+        | SyntaxKind::TokenShortString
+        | SyntaxKind::TokenString => text.bright_cyan(),
         SyntaxKind::TokenExtern
         | SyntaxKind::TokenType
         | SyntaxKind::TokenFunction
@@ -93,6 +94,7 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenColon
         | SyntaxKind::TokenColonColon
         | SyntaxKind::TokenDotDot
+        | SyntaxKind::TokenDotDotEq
         | SyntaxKind::TokenSemicolon
         | SyntaxKind::TokenAnd
         | SyntaxKind::TokenAndAnd
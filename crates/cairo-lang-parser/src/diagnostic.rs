@@ -26,6 +26,7 @@ pub enum ParserDiagnosticKind {
     InvalidNumericLiteralValue,
     IllegalStringEscaping,
     ShortStringMustBeAscii,
+    StringMustBeAscii,
     UnterminatedString,
     AttributesWithoutItem,
     AttributesWithoutTraitItem,
@@ -68,6 +69,9 @@ impl DiagnosticEntry for ParserDiagnostic {
             ParserDiagnosticKind::ShortStringMustBeAscii => {
                 "Short strings can only include ASCII characters.".into()
             }
+            ParserDiagnosticKind::StringMustBeAscii => {
+                "String literals can only include ASCII characters.".into()
+            }
             ParserDiagnosticKind::UnterminatedString => "Unterminated string literal.".into(),
             ParserDiagnosticKind::AttributesWithoutItem => {
                 "Missing tokens. Expected an item after attributes.".to_string()
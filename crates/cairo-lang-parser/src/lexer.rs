@@ -165,6 +165,24 @@ impl<'a> Lexer<'a> {
         TokenKind::ShortString
     }
 
+    /// Takes a string.
+    fn take_token_string(&mut self) -> TokenKind {
+        self.take();
+        let mut escaped = false;
+        while let Some(token) = self.peek() {
+            self.take();
+            match token {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => {
+                    break;
+                }
+                _ => {}
+            };
+        }
+        TokenKind::String
+    }
+
     /// Assumes the next character is [a-zA-Z_].
     fn take_token_identifier(&mut self) -> TokenKind {
         // TODO(spapini): Support or explicitly report general unicode characters.
@@ -231,6 +249,7 @@ impl<'a> Lexer<'a> {
             match current {
                 '0'..='9' => self.take_token_literal_number(),
                 '\'' => self.take_token_short_string(),
+                '"' => self.take_token_string(),
                 ',' => self.take_token_of_kind(TokenKind::Comma),
                 ';' => self.take_token_of_kind(TokenKind::Semicolon),
                 '?' => self.take_token_of_kind(TokenKind::QuestionMark),
@@ -240,7 +259,13 @@ impl<'a> Lexer<'a> {
                 ']' => self.take_token_of_kind(TokenKind::RBrack),
                 '(' => self.take_token_of_kind(TokenKind::LParen),
                 ')' => self.take_token_of_kind(TokenKind::RParen),
-                '.' => self.pick_kind('.', TokenKind::DotDot, TokenKind::Dot),
+                '.' => {
+                    self.take();
+                    match self.peek() {
+                        Some('.') => self.pick_kind('=', TokenKind::DotDotEq, TokenKind::DotDot),
+                        _ => TokenKind::Dot,
+                    }
+                }
                 '*' => self.pick_kind('=', TokenKind::MulEq, TokenKind::Mul),
                 '/' => self.pick_kind('=', TokenKind::DivEq, TokenKind::Div),
                 '%' => self.pick_kind('=', TokenKind::ModEq, TokenKind::Mod),
@@ -328,6 +353,7 @@ enum TokenKind {
     // Literals.
     LiteralNumber,
     ShortString,
+    String,
 
     // Keywords.
     As,
@@ -390,6 +416,7 @@ enum TokenKind {
     Comma,
     Dot,
     DotDot,
+    DotDotEq,
     Eq,
     Hash,
     Semicolon,
@@ -416,6 +443,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Identifier => SyntaxKind::TerminalIdentifier,
         TokenKind::LiteralNumber => SyntaxKind::TerminalLiteralNumber,
         TokenKind::ShortString => SyntaxKind::TerminalShortString,
+        TokenKind::String => SyntaxKind::TerminalString,
         TokenKind::False => SyntaxKind::TerminalFalse,
         TokenKind::True => SyntaxKind::TerminalTrue,
         TokenKind::Extern => SyntaxKind::TerminalExtern,
@@ -467,6 +495,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Comma => SyntaxKind::TerminalComma,
         TokenKind::Dot => SyntaxKind::TerminalDot,
         TokenKind::DotDot => SyntaxKind::TerminalDotDot,
+        TokenKind::DotDotEq => SyntaxKind::TerminalDotDotEq,
         TokenKind::Eq => SyntaxKind::TerminalEq,
         TokenKind::Hash => SyntaxKind::TerminalHash,
         TokenKind::Semicolon => SyntaxKind::TerminalSemicolon,
@@ -27,6 +27,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
                 "0xA2_u128",
             ]
         }
+        SyntaxKind::TerminalString => vec!["\"abc\"", "\"\"", "\"a 'b' \\\"c\\\"\""],
         SyntaxKind::TerminalFalse => vec!["false"],
         SyntaxKind::TerminalExtern => vec!["extern"],
         SyntaxKind::TerminalType => vec!["type"],
@@ -61,6 +62,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalModEq => vec!["%="],
         SyntaxKind::TerminalDot => vec!["."],
         SyntaxKind::TerminalDotDot => vec![".."],
+        SyntaxKind::TerminalDotDotEq => vec!["..="],
         SyntaxKind::TerminalEq => vec!["="],
         SyntaxKind::TerminalEqEq => vec!["=="],
         SyntaxKind::TerminalGE => vec![">="],
@@ -102,6 +104,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
     vec![
         SyntaxKind::TerminalIdentifier,
         SyntaxKind::TerminalLiteralNumber,
+        SyntaxKind::TerminalString,
         SyntaxKind::TerminalFalse,
         SyntaxKind::TerminalTrue,
         SyntaxKind::TerminalExtern,
@@ -152,6 +155,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalComma,
         SyntaxKind::TerminalDot,
         SyntaxKind::TerminalDotDot,
+        SyntaxKind::TerminalDotDotEq,
         SyntaxKind::TerminalEq,
         SyntaxKind::TerminalSemicolon,
         SyntaxKind::TerminalQuestionMark,
@@ -194,6 +198,7 @@ fn need_separator(
         || ((text0 == "<" || text0 == ">") && text1.starts_with('='))
         || (text0 == ":" && text1.starts_with(':'))
         || (text0 == "." && text1.starts_with('.'))
+        || (text0 == ".." && text1.starts_with('='))
         || (text0 == "-" && (text1.starts_with('>') || text1.starts_with('=')))
         || ((text0 == "+" || text0 == "*" || text0 == "/" || text0 == "%")
             && text1.starts_with('='))
@@ -145,7 +145,7 @@ impl<'a> Parser<'a> {
         };
 
         match self.peek().kind {
-            SyntaxKind::TerminalConst => Some(self.expect_const(attributes).into()),
+            SyntaxKind::TerminalConst => Some(self.expect_const_item(attributes)),
             SyntaxKind::TerminalModule => Some(self.expect_module(attributes).into()),
             SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes).into()),
             SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes).into()),
@@ -281,10 +281,31 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Assumes the current token is [TerminalConst].
+    /// Expected pattern: `const <Identifier> = <Expr>;` or `const <FunctionDeclaration><Block>`
+    fn expect_const_item(&mut self, attributes: AttributeListGreen) -> ItemGreen {
+        let const_kw = self.take::<TerminalConst>();
+        match self.peek().kind {
+            SyntaxKind::TerminalFunction => {
+                self.expect_function_with_body_inner(attributes, const_kw.into()).into()
+            }
+            _ => self.expect_const_inner(attributes, const_kw).into(),
+        }
+    }
+
     /// Assumes the current token is [TerminalConst].
     /// Expected pattern: `const <Identifier> = <Expr>;`
     fn expect_const(&mut self, attributes: AttributeListGreen) -> ItemConstantGreen {
         let const_kw = self.take::<TerminalConst>();
+        self.expect_const_inner(attributes, const_kw)
+    }
+
+    /// Expected pattern: `<Identifier> = <Expr>;`, after the `const` keyword.
+    fn expect_const_inner(
+        &mut self,
+        attributes: AttributeListGreen,
+        const_kw: TerminalConstGreen,
+    ) -> ItemConstantGreen {
         let name = self.parse_identifier();
         let type_clause = self.parse_type_clause(ErrorRecovery {
             should_stop: is_of_kind!(eq, semicolon, top_level),
@@ -528,10 +549,21 @@ impl<'a> Parser<'a> {
     fn expect_function_with_body(
         &mut self,
         attributes: AttributeListGreen,
+    ) -> FunctionWithBodyGreen {
+        let optional_const = OptionTerminalConstEmpty::new_green(self.db).into();
+        self.expect_function_with_body_inner(attributes, optional_const)
+    }
+
+    /// Assumes the current token is Function.
+    /// Expected pattern: `<FunctionDeclaration><Block>`, after the optional `const` keyword.
+    fn expect_function_with_body_inner(
+        &mut self,
+        attributes: AttributeListGreen,
+        optional_const: OptionTerminalConstGreen,
     ) -> FunctionWithBodyGreen {
         let declaration = self.expect_function_declaration();
         let function_body = self.parse_block();
-        FunctionWithBody::new_green(self.db, attributes, declaration, function_body)
+        FunctionWithBody::new_green(self.db, attributes, optional_const, declaration, function_body)
     }
 
     /// Assumes the current token is Trait.
@@ -571,6 +603,8 @@ impl<'a> Parser<'a> {
 
         match self.peek().kind {
             SyntaxKind::TerminalFunction => Some(self.expect_trait_function(attributes).into()),
+            SyntaxKind::TerminalType => Some(self.expect_trait_type(attributes).into()),
+            SyntaxKind::TerminalConst => Some(self.expect_trait_constant(attributes).into()),
             _ => {
                 if has_attrs {
                     Some(self.create_and_report_missing::<TraitItem>(
@@ -595,6 +629,27 @@ impl<'a> Parser<'a> {
         TraitItemFunction::new_green(self.db, attributes, declaration, body)
     }
 
+    /// Assumes the current token is Type.
+    /// Expected pattern: `type <Identifier>;`
+    fn expect_trait_type(&mut self, attributes: AttributeListGreen) -> TraitItemTypeGreen {
+        let type_kw = self.take::<TerminalType>();
+        let name = self.parse_identifier();
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        TraitItemType::new_green(self.db, attributes, type_kw, name, semicolon)
+    }
+
+    /// Assumes the current token is Const.
+    /// Expected pattern: `const <Identifier><TypeClause>;`
+    fn expect_trait_constant(&mut self, attributes: AttributeListGreen) -> TraitItemConstantGreen {
+        let const_kw = self.take::<TerminalConst>();
+        let name = self.parse_identifier();
+        let type_clause = self.parse_type_clause(ErrorRecovery {
+            should_stop: is_of_kind!(semicolon, rbrace, top_level),
+        });
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        TraitItemConstant::new_green(self.db, attributes, const_kw, name, type_clause, semicolon)
+    }
+
     /// Assumes the current token is Impl.
     fn expect_item_impl(&mut self, attributes: AttributeListGreen) -> ItemGreen {
         match self.expect_impl_inner(attributes) {
@@ -677,12 +732,12 @@ impl<'a> Parser<'a> {
 
         match self.peek().kind {
             SyntaxKind::TerminalFunction => Some(self.expect_function_with_body(attributes).into()),
-            // These are not supported semantically.
             SyntaxKind::TerminalConst => Some(self.expect_const(attributes).into()),
+            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes).into()),
+            // These are not supported semantically.
             SyntaxKind::TerminalModule => Some(self.expect_module(attributes).into()),
             SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes).into()),
             SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes).into()),
-            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes).into()),
             SyntaxKind::TerminalExtern => Some(self.expect_extern_impl_item(attributes)),
             SyntaxKind::TerminalUse => Some(self.expect_use(attributes).into()),
             SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes).into()),
@@ -842,6 +897,7 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalTrue => Some(self.take::<TerminalTrue>().into()),
             SyntaxKind::TerminalLiteralNumber => Some(self.take::<TerminalLiteralNumber>().into()),
             SyntaxKind::TerminalShortString => Some(self.take::<TerminalShortString>().into()),
+            SyntaxKind::TerminalString => Some(self.take::<TerminalString>().into()),
             SyntaxKind::TerminalLParen => {
                 // Note that LBrace is allowed inside parenthesis, even if `lbrace_allowed` is
                 // [LbraceAllowed::Forbid].
@@ -1209,11 +1265,18 @@ impl<'a> Parser<'a> {
     }
 
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
+    /// Expected pattern: `<Pattern> [| <Pattern>]* => <Expr>`.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
-        let pattern = self.try_parse_pattern()?;
+        let mut patterns: Vec<PatternListOrElementOrSeparatorGreen> =
+            vec![self.try_parse_pattern()?.into()];
+        while let Some(or) = self.try_parse_token::<TerminalOr>() {
+            patterns.push(or.into());
+            patterns.push(self.parse_pattern().into());
+        }
+        let patterns = PatternListOr::new_green(self.db, patterns);
         let arrow = self.parse_token::<TerminalMatchArrow>();
         let expr = self.parse_expr();
-        Some(MatchArm::new_green(self.db, pattern, arrow, expr))
+        Some(MatchArm::new_green(self.db, patterns, arrow, expr))
     }
 
     /// Returns a GreenId of a node with some Pattern kind (see
@@ -1226,9 +1289,18 @@ impl<'a> Parser<'a> {
             return Some(PatternIdentifier::new_green(self.db, modifiers, name).into());
         };
 
-        // TODO(yuval): Support "Or" patterns.
         Some(match self.peek().kind {
-            SyntaxKind::TerminalLiteralNumber => self.take::<TerminalLiteralNumber>().into(),
+            SyntaxKind::TerminalLiteralNumber => {
+                let literal = self.take::<TerminalLiteralNumber>();
+                match self.peek().kind {
+                    SyntaxKind::TerminalDotDotEq => {
+                        let dotdoteq = self.take::<TerminalDotDotEq>();
+                        let end = self.parse_token::<TerminalLiteralNumber>();
+                        PatternRange::new_green(self.db, literal, dotdoteq, end).into()
+                    }
+                    _ => literal.into(),
+                }
+            }
             SyntaxKind::TerminalShortString => self.take::<TerminalShortString>().into(),
             SyntaxKind::TerminalUnderscore => self.take::<TerminalUnderscore>().into(),
             SyntaxKind::TerminalIdentifier => {
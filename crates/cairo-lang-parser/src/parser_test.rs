@@ -347,6 +347,7 @@ cairo_lang_test_utils::test_file_test!(
         array: "array",
         attrs: "attrs",
         inline_macro: "inline_macro",
+        match_arm: "match_arm",
     },
     test_partial_parser_tree
 );
@@ -40,6 +40,11 @@ pub fn validate(
                 validate_short_string(node, db, diagnostics, file_id)
             }
 
+            SyntaxKind::TerminalString => {
+                let node = ast::TerminalString::from_syntax_node(db, node);
+                validate_string(node, db, diagnostics, file_id)
+            }
+
             _ => Ok(()),
         })
     })
@@ -146,13 +151,66 @@ fn validate_short_string(
         }
     };
 
+    validate_string_body(
+        body,
+        node.as_syntax_node(),
+        db,
+        diagnostics,
+        file_id,
+        ParserDiagnosticKind::ShortStringMustBeAscii,
+    )
+    .and(result)
+}
+
+/// Validate that the string literal is valid, after it is consumed by the parser.
+///
+/// This function validates that the literal:
+/// 1. Has double quotes on both sides (parser accepts unterminated literals).
+/// 2. Has all escape sequences valid.
+/// 3. Is entirely ASCII.
+fn validate_string(
+    node: ast::TerminalString,
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut DiagnosticsBuilder<ParserDiagnostic>,
+    file_id: FileId,
+) -> Maybe<()> {
+    let text = node.text(db);
+    let Some(body) = text.strip_prefix('"').and_then(|text| text.strip_suffix('"')) else {
+        return Err(diagnostics.add(ParserDiagnostic {
+            file_id,
+            span: node.as_syntax_node().span(db),
+            kind: ParserDiagnosticKind::UnterminatedString,
+        }));
+    };
+
+    validate_string_body(
+        body,
+        node.as_syntax_node(),
+        db,
+        diagnostics,
+        file_id,
+        ParserDiagnosticKind::StringMustBeAscii,
+    )
+}
+
+/// Validates the escape sequences of a string literal body, and that it is entirely ASCII.
+fn validate_string_body(
+    body: &str,
+    node: SyntaxNode,
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut DiagnosticsBuilder<ParserDiagnostic>,
+    file_id: FileId,
+    non_ascii_kind: ParserDiagnosticKind,
+) -> Maybe<()> {
+    let mut result = Ok(());
+
     let body = match unescape(body) {
         Ok(body) => body,
         Err(_) => {
             // TODO(mkaput): Try to always provide full position for entire escape sequence.
             result = Err(diagnostics.add(ParserDiagnostic {
                 file_id,
-                span: node.as_syntax_node().span(db),
+                span: node.span(db),
                 kind: ParserDiagnosticKind::IllegalStringEscaping,
             }));
 
@@ -164,8 +222,8 @@ fn validate_short_string(
         // TODO(mkaput): Try to always provide position of culprit character/escape sequence.
         result = Err(diagnostics.add(ParserDiagnostic {
             file_id,
-            span: node.as_syntax_node().span(db),
-            kind: ParserDiagnosticKind::ShortStringMustBeAscii,
+            span: node.span(db),
+            kind: non_ascii_kind,
         }));
     }
 
@@ -320,6 +320,7 @@ pub fn unwrap_error_propagation_type(
         )
         | TypeLongId::Tuple(_)
         | TypeLongId::Snapshot(_)
+        | TypeLongId::ImplType(_)
         | TypeLongId::Var(_)
         | TypeLongId::Missing(_) => None,
     }
@@ -630,6 +631,8 @@ pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> Str
         "u64_const".into()
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         "u128_const".into()
+    } else if ty == get_core_ty_by_name(db, "bytes31".into(), vec![]) {
+        "bytes31_const".into()
     } else {
         panic!("No const libfunc for type {}.", ty.format(db))
     }
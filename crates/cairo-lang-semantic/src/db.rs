@@ -4,9 +4,10 @@ use cairo_lang_defs::db::{DefsGroup, GeneratedFileInfo};
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, FunctionTitleId,
-    FunctionWithBodyId, GenericParamId, GenericTypeId, ImplAliasId, ImplDefId, ImplFunctionId,
-    LanguageElementId, LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId,
-    TypeAliasId, UseId, VariantId,
+    FunctionWithBodyId, GenericParamId, GenericTypeId, ImplAliasId, ImplConstantDefId, ImplDefId,
+    ImplFunctionId, ImplTypeDefId, LanguageElementId, LookupItemId, ModuleFileId, ModuleId,
+    ModuleItemId, StructId, TraitConstantId, TraitFunctionId, TraitId, TraitTypeId, TypeAliasId,
+    UseId, VariantId,
 };
 use cairo_lang_defs::plugin::MacroPlugin;
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
@@ -97,6 +98,7 @@ pub trait SemanticGroup:
     // ====
     /// Private query to compute data about a constant definition.
     #[salsa::invoke(items::constant::priv_constant_semantic_data)]
+    #[salsa::cycle(items::constant::priv_constant_semantic_data_cycle)]
     fn priv_constant_semantic_data(
         &self,
         const_id: ConstantId,
@@ -109,6 +111,7 @@ pub trait SemanticGroup:
     ) -> Diagnostics<SemanticDiagnostic>;
     /// Returns the semantic data of a constant definition.
     #[salsa::invoke(items::constant::constant_semantic_data)]
+    #[salsa::cycle(items::constant::constant_semantic_data_cycle)]
     fn constant_semantic_data(&self, use_id: ConstantId) -> Maybe<Constant>;
     #[salsa::invoke(items::constant::constant_resolver_data)]
     fn constant_resolver_data(&self, use_id: ConstantId) -> Maybe<Arc<ResolverData>>;
@@ -303,6 +306,23 @@ pub trait SemanticGroup:
         trait_id: TraitId,
         name: SmolStr,
     ) -> Maybe<Option<TraitFunctionId>>;
+    /// Returns the associated types of a trait.
+    #[salsa::invoke(items::trt::trait_types)]
+    fn trait_types(&self, trait_id: TraitId) -> Maybe<OrderedHashMap<SmolStr, TraitTypeId>>;
+    /// Returns the associated type with the given name of the given trait, if exists.
+    #[salsa::invoke(items::trt::trait_type_by_name)]
+    fn trait_type_by_name(&self, trait_id: TraitId, name: SmolStr) -> Maybe<Option<TraitTypeId>>;
+    /// Returns the associated constants of a trait.
+    #[salsa::invoke(items::trt::trait_constants)]
+    fn trait_constants(&self, trait_id: TraitId)
+    -> Maybe<OrderedHashMap<SmolStr, TraitConstantId>>;
+    /// Returns the associated constant with the given name of the given trait, if exists.
+    #[salsa::invoke(items::trt::trait_constant_by_name)]
+    fn trait_constant_by_name(
+        &self,
+        trait_id: TraitId,
+        name: SmolStr,
+    ) -> Maybe<Option<TraitConstantId>>;
 
     // Trait function.
     // ================
@@ -355,6 +375,37 @@ pub trait SemanticGroup:
         concrete_trait_function_id: ConcreteTraitGenericFunctionId,
     ) -> Maybe<semantic::Signature>;
 
+    // Trait constant.
+    // ================
+    /// Private query to compute data about a trait constant.
+    #[salsa::invoke(items::trt::priv_trait_constant_data)]
+    fn priv_trait_constant_data(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<items::trt::TraitConstantData>;
+    /// Returns the semantic diagnostics of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_diagnostics)]
+    fn trait_constant_diagnostics(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the type of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_type)]
+    fn trait_constant_type(&self, trait_constant_id: TraitConstantId) -> Maybe<TypeId>;
+    /// Returns the type of a trait constant in a concrete trait.
+    #[salsa::invoke(items::trt::concrete_trait_constant_type)]
+    fn concrete_trait_constant_type(
+        &self,
+        concrete_trait_id: ConcreteTraitId,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<TypeId>;
+    /// Returns the resolution resolved_items of a trait constant.
+    #[salsa::invoke(items::trt::trait_constant_resolver_data)]
+    fn trait_constant_resolver_data(
+        &self,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<Arc<ResolverData>>;
+
     // Impl.
     // =======
     /// Private query to compute declaration data about an impl.
@@ -410,6 +461,20 @@ pub trait SemanticGroup:
         impl_def_id: ImplDefId,
         trait_function_id: TraitFunctionId,
     ) -> Maybe<Option<ImplFunctionId>>;
+    /// Returns the impl type that matches the given trait type, if exists.
+    #[salsa::invoke(items::imp::impl_type_by_trait_type)]
+    fn impl_type_by_trait_type(
+        &self,
+        impl_def_id: ImplDefId,
+        trait_type_id: TraitTypeId,
+    ) -> Maybe<Option<ImplTypeDefId>>;
+    /// Returns the impl constant that matches the given trait constant, if exists.
+    #[salsa::invoke(items::imp::impl_constant_by_trait_constant)]
+    fn impl_constant_by_trait_constant(
+        &self,
+        impl_def_id: ImplDefId,
+        trait_constant_id: TraitConstantId,
+    ) -> Maybe<Option<ImplConstantDefId>>;
     /// Returns candidate [ImplDefId]s for a specific trait lookup constraint.
     #[salsa::invoke(items::imp::module_impl_ids_for_trait_info)]
     fn module_impl_ids_for_trait_info(
@@ -497,6 +562,58 @@ pub trait SemanticGroup:
         impl_function_id: ImplFunctionId,
     ) -> Maybe<items::function_with_body::FunctionBodyData>;
 
+    // Impl type.
+    // ==========
+    /// Private query to compute data about an impl type.
+    #[salsa::invoke(items::imp::priv_impl_type_def_data)]
+    #[salsa::cycle(items::imp::priv_impl_type_def_data_cycle)]
+    fn priv_impl_type_def_data(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Maybe<items::imp::ImplTypeDefData>;
+    /// Returns the semantic diagnostics of an impl type.
+    #[salsa::invoke(items::imp::impl_type_def_diagnostics)]
+    fn impl_type_def_diagnostics(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the resolved type of an impl type.
+    #[salsa::invoke(items::imp::impl_type_def_resolved_type)]
+    #[salsa::cycle(items::imp::impl_type_def_resolved_type_cycle)]
+    fn impl_type_def_resolved_type(&self, impl_type_def_id: ImplTypeDefId) -> Maybe<TypeId>;
+    /// Returns the resolution resolved_items of an impl type.
+    #[salsa::invoke(items::imp::impl_type_def_resolver_data)]
+    fn impl_type_def_resolver_data(
+        &self,
+        impl_type_def_id: ImplTypeDefId,
+    ) -> Maybe<Arc<ResolverData>>;
+
+    // Impl constant.
+    // ==============
+    /// Private query to compute data about an impl constant.
+    #[salsa::invoke(items::imp::priv_impl_constant_def_data)]
+    #[salsa::cycle(items::imp::priv_impl_constant_def_data_cycle)]
+    fn priv_impl_constant_def_data(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Maybe<items::imp::ImplConstantDefData>;
+    /// Returns the semantic diagnostics of an impl constant.
+    #[salsa::invoke(items::imp::impl_constant_def_diagnostics)]
+    fn impl_constant_def_diagnostics(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the value of an impl constant.
+    #[salsa::invoke(items::imp::impl_constant_def_value)]
+    #[salsa::cycle(items::imp::impl_constant_def_value_cycle)]
+    fn impl_constant_def_value(&self, impl_constant_def_id: ImplConstantDefId) -> Maybe<Constant>;
+    /// Returns the resolution resolved_items of an impl constant.
+    #[salsa::invoke(items::imp::impl_constant_def_resolver_data)]
+    fn impl_constant_def_resolver_data(
+        &self,
+        impl_constant_def_id: ImplConstantDefId,
+    ) -> Maybe<Arc<ResolverData>>;
+
     // Free function.
     // ==============
     /// Returns the semantic diagnostics of a free function's declaration (signature).
@@ -772,6 +889,12 @@ pub trait SemanticGroup:
         id: LookupItemId,
         ptr: ast::TerminalIdentifierPtr,
     ) -> Option<ResolvedConcreteItem>;
+    /// Returns all the identifiers in a lookup item that were resolved to generic items, along
+    /// with the items they were resolved to.
+    fn lookup_resolved_generic_items(
+        &self,
+        id: LookupItemId,
+    ) -> Vec<(ast::TerminalIdentifierPtr, ResolvedGenericItem)>;
 
     // Diagnostics.
     // ============
@@ -814,6 +937,14 @@ pub trait SemanticGroup:
         crate_id: CrateId,
         type_filter: lsp_helpers::TypeFilter,
     ) -> Vec<TraitFunctionId>;
+    /// Returns all the identifiers in the lookup items of a module (including the functions of
+    /// its traits and impls) that were resolved to generic items, along with the module files they
+    /// are in and the items they were resolved to.
+    #[salsa::invoke(lsp_helpers::module_usages)]
+    fn module_usages(
+        &self,
+        module_id: ModuleId,
+    ) -> Maybe<Arc<Vec<(ModuleFileId, ast::TerminalIdentifierPtr, ResolvedGenericItem)>>>;
 }
 
 impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
@@ -1016,6 +1147,23 @@ pub fn lookup_resolved_concrete_item_by_ptr(
         .find_map(|resolver_data| resolver_data.resolved_items.concrete.get(&ptr).cloned())
 }
 
+pub fn lookup_resolved_generic_items(
+    db: &dyn SemanticGroup,
+    id: LookupItemId,
+) -> Vec<(ast::TerminalIdentifierPtr, ResolvedGenericItem)> {
+    get_resolver_datas(id, db)
+        .into_iter()
+        .flat_map(|resolver_data| {
+            resolver_data
+                .resolved_items
+                .generic
+                .iter()
+                .map(|(ptr, item)| (*ptr, item.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 fn get_resolver_datas(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<ResolverData>> {
     match id {
         LookupItemId::ModuleItem(module_item) => match module_item {
@@ -1035,14 +1183,17 @@ fn get_resolver_datas(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<Resol
             }
             ModuleItemId::TypeAlias(id) => vec![db.type_alias_resolver_data(id)],
             ModuleItemId::ImplAlias(id) => vec![db.impl_alias_resolver_data(id)],
-            ModuleItemId::Trait(_) => vec![],
+            ModuleItemId::Trait(id) => vec![db.trait_resolver_data(id)],
             ModuleItemId::Impl(id) => vec![db.impl_def_resolver_data(id)],
             ModuleItemId::ExternType(_) => vec![],
             ModuleItemId::ExternFunction(id) => {
                 vec![db.extern_function_declaration_resolver_data(id)]
             }
         },
-        LookupItemId::ImplFunction(id) => vec![db.impl_function_resolver_data(id)],
+        LookupItemId::ImplFunction(id) => {
+            vec![db.impl_function_resolver_data(id), db.impl_function_body_resolver_data(id)]
+        }
+        LookupItemId::TraitFunction(id) => vec![db.trait_function_resolver_data(id)],
     }
     .into_iter()
     .flatten()
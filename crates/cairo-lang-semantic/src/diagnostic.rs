@@ -142,6 +142,39 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     trait_id.name(defs_db)
                 )
             }
+            SemanticDiagnosticKind::ImplItemNotMemberOfTrait {
+                impl_def_id,
+                item_name,
+                trait_id,
+            } => {
+                let defs_db = db.upcast();
+                format!(
+                    "Impl item `{}::{}` is not a member of trait `{}`.",
+                    impl_def_id.name(defs_db),
+                    item_name,
+                    trait_id.name(defs_db)
+                )
+            }
+            SemanticDiagnosticKind::WrongImplConstantType {
+                impl_def_id,
+                constant_name,
+                expected_ty,
+                actual_ty,
+            } => {
+                format!(
+                    "Impl constant `{}::{}` has type `{}`, but the trait expects `{}`.",
+                    impl_def_id.name(db.upcast()),
+                    constant_name,
+                    actual_ty.format(db),
+                    expected_ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::GenericImplTypeNotSupported => {
+                "Generic parameters are not supported on impl types.".into()
+            }
+            SemanticDiagnosticKind::ImplTypeCycle => {
+                "Cycle detected while resolving impl type items.".into()
+            }
             SemanticDiagnosticKind::UnexpectedGenericArgs => "Unexpected generic arguments".into(),
             SemanticDiagnosticKind::UnknownMember => "Unknown member.".into(),
             SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => {
@@ -449,6 +482,12 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnexpectedTuplePattern { ty } => {
                 format!(r#"Unexpected type for tuple pattern. "{}" is not a tuple."#, ty.format(db),)
             }
+            SemanticDiagnosticKind::MissingVariableInPattern { name } => {
+                format!(r#"Variable "{name}" is not bound in all the alternatives of the pattern."#)
+            }
+            SemanticDiagnosticKind::EmptyRangePattern => {
+                "Range pattern is empty: its start is greater than its end.".into()
+            }
             SemanticDiagnosticKind::WrongEnum { expected_enum, actual_enum } => {
                 format!(
                     r#"Wrong enum in pattern. Expected: "{}". Got: "{}"."#,
@@ -516,14 +555,31 @@ impl DiagnosticEntry for SemanticDiagnostic {
             }
             SemanticDiagnosticKind::UnsupportedOutsideOfFunction { feature_name } => {
                 let feature_name_str = match feature_name {
-                    UnsupportedOutsideOfFunctionFeatureName::FunctionCall => "Function call",
                     UnsupportedOutsideOfFunctionFeatureName::ReturnStatement => "Return statement",
                     UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate => "The '?' operator",
                 };
                 format!("{feature_name_str} is not supported outside of functions.")
             }
-            SemanticDiagnosticKind::OnlyLiteralConstants => {
-                "Only literal constants are currently supported.".into()
+            SemanticDiagnosticKind::UnsupportedConstant => {
+                "This expression is not supported in constants.".into()
+            }
+            SemanticDiagnosticKind::ConstantOverflow { ty } => {
+                format!(
+                    "The result of the operation does not fit within the range of type {}.",
+                    ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::ConstantDivisionByZero => {
+                "Division by zero in constant expression.".into()
+            }
+            SemanticDiagnosticKind::ConstantCycle => {
+                "Cycle detected while evaluating constant.".into()
+            }
+            SemanticDiagnosticKind::UnsupportedConstFunctionExpr => {
+                "This expression is not supported in const functions.".into()
+            }
+            SemanticDiagnosticKind::ConstFunctionCycle => {
+                "Cycle detected while evaluating const function.".into()
             }
             SemanticDiagnosticKind::ExternItemWithImplGenericsNotSupported => {
                 "Extern items with impl generics are not supported".into()
@@ -650,6 +706,19 @@ pub enum SemanticDiagnosticKind {
         impl_function_id: ImplFunctionId,
         trait_id: TraitId,
     },
+    ImplItemNotMemberOfTrait {
+        impl_def_id: ImplDefId,
+        item_name: SmolStr,
+        trait_id: TraitId,
+    },
+    WrongImplConstantType {
+        impl_def_id: ImplDefId,
+        constant_name: SmolStr,
+        expected_ty: semantic::TypeId,
+        actual_ty: semantic::TypeId,
+    },
+    GenericImplTypeNotSupported,
+    ImplTypeCycle,
     UnexpectedGenericArgs,
     UnknownMember,
     MemberSpecifiedMoreThanOnce,
@@ -816,6 +885,10 @@ pub enum SemanticDiagnosticKind {
     UnexpectedTuplePattern {
         ty: semantic::TypeId,
     },
+    MissingVariableInPattern {
+        name: SmolStr,
+    },
+    EmptyRangePattern,
     WrongEnum {
         expected_enum: EnumId,
         actual_enum: EnumId,
@@ -855,7 +928,14 @@ pub enum SemanticDiagnosticKind {
     UnsupportedOutsideOfFunction {
         feature_name: UnsupportedOutsideOfFunctionFeatureName,
     },
-    OnlyLiteralConstants,
+    UnsupportedConstant,
+    ConstantOverflow {
+        ty: semantic::TypeId,
+    },
+    ConstantDivisionByZero,
+    ConstantCycle,
+    UnsupportedConstFunctionExpr,
+    ConstFunctionCycle,
     ExternItemWithImplGenericsNotSupported,
     MissingSemicolon,
     TraitMismatch,
@@ -888,7 +968,6 @@ pub enum NotFoundItemType {
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum UnsupportedOutsideOfFunctionFeatureName {
-    FunctionCall,
     ReturnStatement,
     ErrorPropagate,
 }
@@ -908,7 +987,9 @@ pub enum ElementKind {
 impl From<&ResolvedConcreteItem> for ElementKind {
     fn from(val: &ResolvedConcreteItem) -> Self {
         match val {
-            ResolvedConcreteItem::Constant(_) => ElementKind::Constant,
+            ResolvedConcreteItem::Constant(_) | ResolvedConcreteItem::ImplConstant(_) => {
+                ElementKind::Constant
+            }
             ResolvedConcreteItem::Module(_) => ElementKind::Module,
             ResolvedConcreteItem::Function(_) => ElementKind::Function,
             ResolvedConcreteItem::TraitFunction(_) => ElementKind::TraitFunction,
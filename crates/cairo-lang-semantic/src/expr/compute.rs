@@ -18,14 +18,15 @@ use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper};
 use id_arena::Arena;
-use itertools::{chain, zip_eq};
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::BigInt;
 use smol_str::SmolStr;
 
 use super::inference::{Inference, InferenceError};
 use super::objects::*;
 use super::pattern::{
-    Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
+    Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternRange, PatternTuple,
+    PatternVariable,
 };
 use crate::corelib::{
     core_binary_operator, core_bool_ty, core_unary_operator, false_literal_expr, get_core_trait,
@@ -222,6 +223,9 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::ShortString(literal_syntax) => {
             Ok(Expr::Literal(short_string_to_semantic(ctx, literal_syntax)?))
         }
+        ast::Expr::String(literal_syntax) => {
+            Ok(Expr::StringLiteral(string_literal_to_semantic(ctx, literal_syntax)?))
+        }
         ast::Expr::False(syntax) => Ok(false_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::True(syntax) => Ok(true_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::Parenthesized(paren_syntax) => {
@@ -582,7 +586,9 @@ pub fn compute_root_expr(
     Ok(res)
 }
 
-fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
+/// Applies the inference results to all the expressions and statements computed so far, and
+/// validates the literals.
+pub fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
     let version = ctx.resolver.inference().version;
     for (_id, expr) in ctx.exprs.iter_mut() {
         *expr = ctx
@@ -590,10 +596,18 @@ fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
             .inference()
             .rewrite(expr.clone())
             .map_err(|err| err.report(ctx.diagnostics, expr.stable_ptr().untyped()))?;
-        if let Expr::Literal(expr) = expr {
-            validate_literal(ctx.db, expr.ty, expr.value.clone())
-                .map_err(|err| ctx.diagnostics.report_by_ptr(expr.stable_ptr.untyped(), err))
-                .ok();
+        match expr {
+            Expr::Literal(expr) => {
+                validate_literal(ctx.db, expr.ty, expr.value.clone())
+                    .map_err(|err| ctx.diagnostics.report_by_ptr(expr.stable_ptr.untyped(), err))
+                    .ok();
+            }
+            Expr::Match(expr) => {
+                for pattern in expr.arms.iter().flat_map(|arm| &arm.patterns) {
+                    validate_pattern_literals(ctx.db, ctx.diagnostics, pattern);
+                }
+            }
+            _ => {}
         }
     }
     for (_id, stmt) in ctx.statements.iter_mut() {
@@ -607,6 +621,40 @@ fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
     Ok(())
 }
 
+/// Validates the literals of a pattern and of its nested patterns.
+fn validate_pattern_literals(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    pattern: &Pattern,
+) {
+    let literals = match pattern {
+        Pattern::Literal(pattern) => vec![&pattern.literal],
+        Pattern::Range(pattern) => vec![&pattern.start, &pattern.end],
+        Pattern::Struct(pattern) => {
+            for (_, field_pattern) in &pattern.field_patterns {
+                validate_pattern_literals(db, diagnostics, field_pattern);
+            }
+            vec![]
+        }
+        Pattern::Tuple(pattern) => {
+            for field_pattern in &pattern.field_patterns {
+                validate_pattern_literals(db, diagnostics, field_pattern);
+            }
+            vec![]
+        }
+        Pattern::EnumVariant(pattern) => {
+            validate_pattern_literals(db, diagnostics, &pattern.inner_pattern);
+            vec![]
+        }
+        Pattern::Variable(_) | Pattern::Otherwise(_) => vec![],
+    };
+    for literal in literals {
+        validate_literal(db, literal.ty, literal.value.clone())
+            .map_err(|err| diagnostics.report_by_ptr(literal.stable_ptr.untyped(), err))
+            .ok();
+    }
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprBlock].
 pub fn compute_expr_block_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -709,10 +757,11 @@ fn compute_expr_match_semantic(
                 // Typecheck pattern, and introduce the new variables to the subscope.
                 // Note that if the arm expr is a block, there will be *another* subscope
                 // for it.
-                let pattern =
-                    compute_pattern_semantic(new_ctx, syntax_arm.pattern(syntax_db), expr.ty())?;
-                let variables = pattern.variables();
-                for v in variables {
+                let patterns = compute_match_arm_patterns(new_ctx, syntax_arm, expr.ty())?;
+                // All the alternatives bind the same variables, so it is enough to introduce the
+                // variables of the first one.
+                let variables = patterns.first().map(|pattern| pattern.variables());
+                for v in variables.into_iter().flatten() {
                     let var_def = Variable::Local(v.var.clone());
                     // TODO(spapini): Wrap this in a function to couple with semantic_defs
                     // insertion.
@@ -720,7 +769,7 @@ fn compute_expr_match_semantic(
                     new_ctx.semantic_defs.insert(var_def.id(), var_def);
                 }
                 let arm_expr = compute_expr_semantic(new_ctx, &arm_expr_syntax);
-                Ok((pattern, arm_expr))
+                Ok((patterns, arm_expr))
             })
         })
         .collect();
@@ -740,7 +789,7 @@ fn compute_expr_match_semantic(
     let pattern_and_exprs: Vec<_> = pattern_and_expr_options.into_iter().collect::<Maybe<_>>()?;
     let semantic_arms = pattern_and_exprs
         .into_iter()
-        .map(|(pattern, arm_expr)| MatchArm { pattern, expression: arm_expr.id })
+        .map(|(patterns, arm_expr)| MatchArm { patterns, expression: arm_expr.id })
         .collect();
     Ok(Expr::Match(ExprMatch {
         matched_expr: expr.id,
@@ -750,6 +799,62 @@ fn compute_expr_match_semantic(
     }))
 }
 
+/// Computes the semantic model of the alternatives of a match arm (`A | B => ...`).
+/// The variables of every alternative are bound to the variables of the same name in the first
+/// alternative, so that the arm expression sees a single variable for each name.
+fn compute_match_arm_patterns(
+    ctx: &mut ComputationContext<'_>,
+    syntax_arm: &ast::MatchArm,
+    ty: TypeId,
+) -> Maybe<Vec<Pattern>> {
+    let syntax_db = ctx.db.upcast();
+    // Compute all the alternatives, even if some of them failed, to get as many diagnostics as
+    // possible.
+    let pattern_options = syntax_arm
+        .patterns(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .map(|pattern_syntax| compute_pattern_semantic(ctx, pattern_syntax, ty))
+        .collect_vec();
+    let mut patterns: Vec<_> = pattern_options.into_iter().collect::<Maybe<_>>()?;
+    let Some((first, rest)) = patterns.split_first_mut() else {
+        return Ok(patterns);
+    };
+    let first_variables: OrderedHashMap<SmolStr, LocalVariable> = first
+        .variables()
+        .into_iter()
+        .map(|variable| (variable.name.clone(), variable.var.clone()))
+        .collect();
+    let mut result = Ok(());
+    for pattern in rest {
+        let pattern_ptr = pattern.stable_ptr().untyped();
+        let mut bound_names = UnorderedHashSet::default();
+        for variable in pattern.variables_mut() {
+            let Some(var) = first_variables.get(&variable.name) else {
+                result = Err(ctx.diagnostics.report_by_ptr(
+                    variable.stable_ptr.untyped(),
+                    MissingVariableInPattern { name: variable.name.clone() },
+                ));
+                continue;
+            };
+            ctx.resolver
+                .inference()
+                .conform_ty(variable.var.ty, var.ty)
+                .map_err(|err| err.report(ctx.diagnostics, variable.stable_ptr.untyped()))?;
+            variable.var = var.clone();
+            bound_names.insert(variable.name.clone());
+        }
+        for name in first_variables.keys() {
+            if !bound_names.contains(name) {
+                result = Err(ctx
+                    .diagnostics
+                    .report_by_ptr(pattern_ptr, MissingVariableInPattern { name: name.clone() }));
+            }
+        }
+    }
+    result.map(|()| patterns)
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprIf].
 fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::ExprIf) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
@@ -955,6 +1060,18 @@ fn compute_pattern_semantic(
                 stable_ptr: short_string_pattern.stable_ptr().into(),
             })
         }
+        ast::Pattern::Range(range_pattern) => {
+            let start = literal_to_semantic(ctx, &range_pattern.start(syntax_db))?;
+            let end = literal_to_semantic(ctx, &range_pattern.end(syntax_db))?;
+            ctx.resolver
+                .inference()
+                .conform_ty(end.ty, start.ty)
+                .map_err(|err| err.report(ctx.diagnostics, stable_ptr))?;
+            if start.value > end.value {
+                ctx.diagnostics.report(&range_pattern, EmptyRangePattern);
+            }
+            Pattern::Range(PatternRange { start, end, stable_ptr: range_pattern.stable_ptr() })
+        }
         ast::Pattern::Enum(enum_pattern) => {
             // Peel all snapshot wrappers.
             let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
@@ -1327,6 +1444,22 @@ fn short_string_to_semantic(
     new_literal_expr(ctx, suffix, value, short_string_syntax.stable_ptr().into())
 }
 
+/// Creates the semantic model of a string literal from its AST.
+fn string_literal_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    string_syntax: &ast::TerminalString,
+) -> Maybe<ExprStringLiteral> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr = string_syntax.stable_ptr();
+
+    let value = string_syntax.string_value(syntax_db).unwrap_or_default();
+    let ty = try_get_core_ty_by_name(db, "ByteArray".into(), vec![])
+        .map_err(|err| ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), err))?;
+
+    Ok(ExprStringLiteral { value, ty, stable_ptr: stable_ptr.into() })
+}
+
 /// Given an expression syntax, if it's an identifier, returns it. Otherwise, returns the proper
 /// error.
 fn expr_as_identifier(
@@ -1556,7 +1689,7 @@ fn member_access_expr(
             // TODO(spapini): Handle snapshot members.
             Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
         }
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
             Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name }))
         }
         TypeLongId::Var(_) => Err(ctx
@@ -1587,6 +1720,17 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
     // Check if this is a constant.
     let resolved_item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, path, NotFoundItemType::Identifier)?;
+    if let ResolvedConcreteItem::ImplConstant(impl_constant_id) = resolved_item {
+        let ty = db.concrete_trait_constant_type(
+            impl_constant_id.impl_id().concrete_trait(db)?,
+            impl_constant_id.constant(),
+        )?;
+        return Ok(Expr::ImplConstant(ExprImplConstant {
+            impl_constant_id,
+            ty,
+            stable_ptr: path.stable_ptr().into(),
+        }));
+    }
     let ResolvedConcreteItem::Constant(constant_id) = resolved_item else {
         return Err(
             ctx.diagnostics.report(path, UnexpectedElement{
@@ -1596,7 +1740,7 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
         );
     };
 
-    let ty = db.constant_semantic_data(constant_id)?.value.ty();
+    let ty = db.constant_semantic_data(constant_id)?.ty;
     Ok(Expr::Constant(ExprConstant { constant_id, ty, stable_ptr: path.stable_ptr().into() }))
 }
 
@@ -1647,15 +1791,9 @@ fn expr_function_call(
         ));
     }
 
-    // Check panicable.
-    if signature.panicable
-        && !ctx
-            .get_signature(
-                stable_ptr.untyped(),
-                UnsupportedOutsideOfFunctionFeatureName::FunctionCall,
-            )?
-            .panicable
-    {
+    // Check panicable. Calls outside of functions (e.g. in constants) are evaluated at compile
+    // time, where a panic is reported as a diagnostic.
+    if signature.panicable && ctx.signature.map_or(false, |signature| !signature.panicable) {
         // TODO(spapini): Delay this check until after inference, to allow resolving specific
         //   impls first.
         return Err(ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), PanicableFromNonPanicable));
@@ -7,7 +7,7 @@ use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId,
     ImplAliasId, ImplDefId, ImplFunctionId, LanguageElementId, LocalVarId, MemberId, ParamId,
-    StructId, TraitFunctionId, TraitId, VarId, VariantId,
+    StructId, TraitConstantId, TraitFunctionId, TraitId, TraitTypeId, VarId, VariantId,
 };
 use cairo_lang_diagnostics::{skip_diagnostic, DiagnosticAdded, Maybe};
 use cairo_lang_proc_macros::DebugWithDb;
@@ -21,14 +21,14 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::expr::objects::*;
 use crate::expr::pattern::*;
-use crate::items::constant::Constant;
 use crate::items::functions::{
     ConcreteFunctionWithBody, ConcreteFunctionWithBodyId, GenericFunctionId,
     GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
 use crate::items::imp::{
-    find_possible_impls_at_context, ImplId, ImplLookupContext, UninferredImpl,
+    find_possible_impls_at_context, ImplConstantId, ImplId, ImplLookupContext, ImplTypeId,
+    UninferredImpl,
 };
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
@@ -431,7 +431,9 @@ impl<'db> Inference<'db> {
                 let (ty, n_snapshots) = self.conform_ty_ex(ty0, ty1, ty0_is_self)?;
                 Ok((self.db.intern_type(TypeLongId::Snapshot(ty)), n_snapshots))
             }
-            TypeLongId::GenericParameter(_) => Err(InferenceError::TypeKindMismatch { ty0, ty1 }),
+            TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
+                Err(InferenceError::TypeKindMismatch { ty0, ty1 })
+            }
             TypeLongId::Var(var) => Ok((self.assign_ty(var, ty1)?, n_snapshots)),
             TypeLongId::Missing(_) => Ok((ty0, n_snapshots)),
         }
@@ -536,7 +538,9 @@ impl<'db> Inference<'db> {
                     generic_args,
                 })))
             }
-            ImplId::GenericParameter(_) => Err(InferenceError::ImplKindMismatch { impl0, impl1 }),
+            ImplId::GenericParameter(_) | ImplId::SelfImpl(_) => {
+                Err(InferenceError::ImplKindMismatch { impl0, impl1 })
+            }
         }
     }
 
@@ -594,6 +598,9 @@ impl<'db> Inference<'db> {
                 }
                 false
             }
+            TypeLongId::ImplType(impl_type_id) => {
+                self.impl_contains_var(&impl_type_id.impl_id(), var)?
+            }
             TypeLongId::GenericParameter(_) | TypeLongId::Missing(_) => false,
         })
     }
@@ -629,7 +636,7 @@ impl<'db> Inference<'db> {
                 &self.db.lookup_intern_concrete_impl(*concrete_impl_id).generic_args,
                 var,
             )?,
-            ImplId::GenericParameter(_) => false,
+            ImplId::GenericParameter(_) | ImplId::SelfImpl(_) => false,
             ImplId::ImplVar(new_var) => {
                 if InferenceVar::Impl(new_var.id) == var {
                     return Ok(true);
@@ -1044,7 +1051,14 @@ impl<'a> SemanticRewriter<TypeLongId, InferenceError> for Inference<'a> {
                 return self.rewrite(self.db.lookup_intern_type(*type_id));
             }
         }
-        value.default_rewrite(self)
+        let value = value.default_rewrite(self)?;
+        // An associated type of an impl that became concrete can be replaced by its value.
+        if let TypeLongId::ImplType(impl_type_id) = value {
+            if let Some(ty) = impl_type_id.concrete_type(self.db) {
+                return self.rewrite(self.db.lookup_intern_type(ty));
+            }
+        }
+        Ok(value)
     }
 }
 impl<'a> SemanticRewriter<ImplId, InferenceError> for Inference<'a> {
@@ -8,7 +8,7 @@ use num_bigint::BigInt;
 
 use super::fmt::ExprFormatter;
 use super::pattern::Pattern;
-use crate::items::imp::ImplId;
+use crate::items::imp::{ImplConstantId, ImplId};
 use crate::{semantic, ConcreteStructId, FunctionId, TypeId};
 
 pub type ExprId = Id<Expr>;
@@ -117,11 +117,13 @@ pub enum Expr {
     If(ExprIf),
     Var(ExprVar),
     Literal(ExprLiteral),
+    StringLiteral(ExprStringLiteral),
     MemberAccess(ExprMemberAccess),
     StructCtor(ExprStructCtor),
     EnumVariantCtor(ExprEnumVariantCtor),
     PropagateError(ExprPropagateError),
     Constant(ExprConstant),
+    ImplConstant(ExprImplConstant),
     Missing(ExprMissing),
 }
 impl Expr {
@@ -138,11 +140,13 @@ impl Expr {
             Expr::If(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
+            Expr::StringLiteral(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
             Expr::StructCtor(expr) => expr.ty,
             Expr::EnumVariantCtor(expr) => expr.ty,
             Expr::PropagateError(expr) => expr.ok_variant.ty,
             Expr::Constant(expr) => expr.ty,
+            Expr::ImplConstant(expr) => expr.ty,
             Expr::Missing(expr) => expr.ty,
         }
     }
@@ -159,11 +163,13 @@ impl Expr {
             Expr::If(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
+            Expr::StringLiteral(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
             Expr::StructCtor(expr) => expr.stable_ptr,
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
             Expr::PropagateError(expr) => expr.stable_ptr,
             Expr::Constant(expr) => expr.stable_ptr,
+            Expr::ImplConstant(expr) => expr.stable_ptr,
             Expr::Missing(expr) => expr.stable_ptr,
         }
     }
@@ -321,7 +327,8 @@ pub struct ExprIf {
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct MatchArm {
-    pub pattern: Pattern,
+    /// The alternatives of the arm, i.e. `A | B`. All the alternatives bind the same variables.
+    pub patterns: Vec<Pattern>,
     pub expression: ExprId,
 }
 
@@ -363,6 +370,17 @@ pub struct ExprLiteral {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprStringLiteral {
+    #[dont_rewrite]
+    pub value: String,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMemberAccess {
@@ -424,6 +442,17 @@ pub struct ExprConstant {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A reference to an associated constant of a trait, through an impl.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprImplConstant {
+    pub impl_constant_id: ImplConstantId,
+    pub ty: semantic::TypeId,
+    #[dont_rewrite]
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMissing {
@@ -18,6 +18,7 @@ use crate::{semantic, ConcreteStructId, ExprLiteral, LocalVariable};
 #[debug_db(ExprFormatter<'a>)]
 pub enum Pattern {
     Literal(PatternLiteral),
+    Range(PatternRange),
     Variable(PatternVariable),
     Struct(PatternStruct),
     Tuple(PatternTuple),
@@ -28,6 +29,7 @@ impl Pattern {
     pub fn ty(&self, _db: &dyn SemanticGroup) -> semantic::TypeId {
         match self {
             Pattern::Literal(literal) => literal.literal.ty,
+            Pattern::Range(range) => range.start.ty,
             Pattern::Variable(variable) => variable.var.ty,
             Pattern::Struct(pattern_struct) => pattern_struct.ty,
             Pattern::Tuple(pattern_tuple) => pattern_tuple.ty,
@@ -52,13 +54,34 @@ impl Pattern {
             Pattern::EnumVariant(pattern_enum_variant) => {
                 pattern_enum_variant.inner_pattern.variables()
             }
-            Pattern::Literal(_) | Pattern::Otherwise(_) => vec![],
+            Pattern::Literal(_) | Pattern::Range(_) | Pattern::Otherwise(_) => vec![],
+        }
+    }
+
+    pub fn variables_mut(&mut self) -> Vec<&mut PatternVariable> {
+        match self {
+            Pattern::Variable(variable) => vec![variable],
+            Pattern::Struct(pattern_struct) => pattern_struct
+                .field_patterns
+                .iter_mut()
+                .flat_map(|(_member, pattern)| pattern.variables_mut())
+                .collect(),
+            Pattern::Tuple(pattern_tuple) => pattern_tuple
+                .field_patterns
+                .iter_mut()
+                .flat_map(|pattern| pattern.variables_mut())
+                .collect(),
+            Pattern::EnumVariant(pattern_enum_variant) => {
+                pattern_enum_variant.inner_pattern.variables_mut()
+            }
+            Pattern::Literal(_) | Pattern::Range(_) | Pattern::Otherwise(_) => vec![],
         }
     }
 
     pub fn stable_ptr(&self) -> ast::PatternPtr {
         match self {
             Pattern::Literal(pat) => pat.stable_ptr,
+            Pattern::Range(pat) => pat.stable_ptr.into(),
             Pattern::Variable(pat) => pat.stable_ptr,
             Pattern::Struct(pat) => pat.stable_ptr.into(),
             Pattern::Tuple(pat) => pat.stable_ptr.into(),
@@ -77,6 +100,17 @@ pub struct PatternLiteral {
     pub stable_ptr: ast::PatternPtr,
 }
 
+/// A pattern that matches the values between two literals, inclusive.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct PatternRange {
+    pub start: ExprLiteral,
+    pub end: ExprLiteral,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::PatternRangePtr,
+}
+
 /// A pattern that binds the matched value to a variable.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub struct PatternVariable {
@@ -413,11 +413,11 @@ fn test_expr_match() {
     let expr_formatter = ExprFormatter { db, function_id: test_function.function_id };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "Match(ExprMatch { matched_expr: Var(ParamId(test::a)), arms: [MatchArm { pattern: \
-         Literal(PatternLiteral { literal: ExprLiteral { value: 0, ty: core::felt252 } }), \
-         expression: Literal(ExprLiteral { value: 0, ty: core::felt252 }) }, MatchArm { pattern: \
-         Otherwise(PatternOtherwise { ty: core::felt252 }), expression: Literal(ExprLiteral { \
-         value: 1, ty: core::felt252 }) }], ty: core::felt252 })"
+        "Match(ExprMatch { matched_expr: Var(ParamId(test::a)), arms: [MatchArm { patterns: \
+         [Literal(PatternLiteral { literal: ExprLiteral { value: 0, ty: core::felt252 } })], \
+         expression: Literal(ExprLiteral { value: 0, ty: core::felt252 }) }, MatchArm { \
+         patterns: [Otherwise(PatternOtherwise { ty: core::felt252 })], expression: \
+         Literal(ExprLiteral { value: 1, ty: core::felt252 }) }], ty: core::felt252 })"
     );
 }
 
@@ -1,26 +1,70 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{ConstantId, LanguageElementId};
-use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
-use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_defs::ids::{
+    ConstantId, FreeFunctionId, FunctionWithBodyId, LanguageElementId, VarId,
+};
+use cairo_lang_diagnostics::{DiagnosticAdded, Diagnostics, Maybe, ToMaybe};
+use cairo_lang_proc_macros::DebugWithDb;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use id_arena::Arena;
+use itertools::zip_eq;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
 
+use crate::corelib::{
+    core_felt252_ty, false_variant, get_core_trait, get_core_ty_by_name, true_variant, unit_ty,
+};
 use crate::db::SemanticGroup;
-use crate::diagnostic::SemanticDiagnostics;
-use crate::expr::compute::{compute_expr_semantic, ComputationContext, Environment};
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::expr::compute::{compute_expr_semantic, infer_all, ComputationContext, Environment};
+use crate::items::free_function::is_const_free_function;
+use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use crate::items::structure::SemanticStructEx;
 use crate::resolve::{Resolver, ResolverData};
 use crate::substitution::SemanticRewriter;
 use crate::types::resolve_type;
-use crate::{Expr, SemanticDiagnostic};
+use crate::{
+    ConcreteTypeId, ConcreteVariant, Expr, ExprDesnap, ExprFunctionCall, ExprFunctionCallArg,
+    ExprId, ExprSnapshot, FunctionId, Pattern, SemanticDiagnostic, Statement, TypeId, TypeLongId,
+};
 
 #[cfg(test)]
 #[path = "constant_test.rs"]
 mod test;
 
-#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct Constant {
-    pub value: Expr,
+    /// The type of the constant.
+    pub ty: TypeId,
+    /// The value of the constant, evaluated at compile time.
+    pub value: ConstValue,
+}
+
+/// A value computed at compile time.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub enum ConstValue {
+    /// A felt252 or an unsigned integer value, of the given type.
+    Int(BigInt, TypeId),
+    /// A tuple or a struct of the given type, with its members in order.
+    Struct(Vec<ConstValue>, TypeId),
+    /// A variant of an enum, with its inner value.
+    Enum(ConcreteVariant, Box<ConstValue>),
+}
+impl ConstValue {
+    /// Returns the type of the value.
+    pub fn ty(&self, db: &dyn SemanticGroup) -> TypeId {
+        match self {
+            ConstValue::Int(_, ty) | ConstValue::Struct(_, ty) => *ty,
+            ConstValue::Enum(variant, _) => {
+                db.intern_type(TypeLongId::Concrete(ConcreteTypeId::Enum(variant.concrete_enum_id)))
+            }
+        }
+    }
 }
 
 /// Information about a constant definition.
@@ -30,7 +74,7 @@ pub struct Constant {
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ConstantData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
-    constant: Constant,
+    constant: Maybe<Constant>,
     resolver_data: Arc<ResolverData>,
 }
 
@@ -46,34 +90,37 @@ pub fn priv_constant_semantic_data(
     // the item instead of all the module data.
     let module_constants = db.module_constants(module_file_id.0)?;
     let const_ast = module_constants.get(&const_id).to_maybe()?;
-    let syntax_db = db.upcast();
 
-    let mut resolver = Resolver::new(db, module_file_id);
+    let resolver = Resolver::new(db, module_file_id);
+    let (constant, resolver_data) = compute_constant(db, &mut diagnostics, resolver, const_ast);
+    Ok(ConstantData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolver_data: Arc::new(resolver_data),
+    })
+}
 
+/// Computes the type and the value of a constant item, using the given resolver for its context.
+pub fn compute_constant(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    mut resolver: Resolver<'_>,
+    const_ast: &ast::ItemConstant,
+) -> (Maybe<Constant>, ResolverData) {
+    let syntax_db = db.upcast();
     let const_type = resolve_type(
         db,
-        &mut diagnostics,
+        diagnostics,
         &mut resolver,
         &const_ast.type_clause(syntax_db).ty(syntax_db),
     );
 
-    let mut ctx =
-        ComputationContext::new(db, &mut diagnostics, resolver, None, Environment::default());
+    let mut ctx = ComputationContext::new(db, diagnostics, resolver, None, Environment::default());
     let value = compute_expr_semantic(&mut ctx, &const_ast.value(syntax_db));
     if let Err(err) = ctx.resolver.inference().conform_ty(value.ty(), const_type) {
         err.report(ctx.diagnostics, const_ast.stable_ptr().untyped());
     }
 
-    // Check that the expression is a literal.
-    if !matches!(value.expr, Expr::Literal(_)) {
-        ctx.diagnostics.report(
-            &const_ast.value(syntax_db),
-            crate::diagnostic::SemanticDiagnosticKind::OnlyLiteralConstants,
-        );
-    };
-
-    let constant = Constant { value: value.expr };
-
     // Check fully resolved.
     if let Some((stable_ptr, inference_err)) = ctx.resolver.inference().finalize() {
         inference_err.report(ctx.diagnostics, stable_ptr);
@@ -81,11 +128,476 @@ pub fn priv_constant_semantic_data(
     let constant = ctx
         .resolver
         .inference()
-        .rewrite(constant)
-        .map_err(|err| err.report(ctx.diagnostics, const_ast.stable_ptr().untyped()))?;
+        .rewrite(const_type)
+        .map_err(|err| err.report(ctx.diagnostics, const_ast.stable_ptr().untyped()))
+        .and_then(|ty| {
+            infer_all(&mut ctx)?;
+            let value = ConstantEvaluator {
+                db,
+                diagnostics: ctx.diagnostics,
+                exprs: &ctx.exprs,
+                statements: &ctx.statements,
+                vars: UnorderedHashMap::default(),
+                call_site: None,
+                call_stack: vec![],
+            }
+            .evaluate(value.id)?;
+            Ok(Constant { ty, value })
+        });
+    (constant, ctx.resolver.data)
+}
+
+/// Cycle handling for [SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    const_id: &ConstantId,
+) -> Maybe<ConstantData> {
+    let module_file_id = const_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_constants = db.module_constants(module_file_id.0)?;
+    let const_ast = module_constants.get(const_id).to_maybe()?;
+    let constant = Err(diagnostics.report(&const_ast.name(db.upcast()), ConstantCycle));
+    Ok(ConstantData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolver_data: Arc::new(ResolverData::new(module_file_id)),
+    })
+}
+
+/// Evaluates expressions at compile time, either of a constant or of the body of a const function
+/// called while evaluating a constant.
+///
+/// Supported expressions are literals, references to other constants, tuples, structs, enum
+/// variants, member accesses, arithmetic, bitwise and comparison operators on felt252, unsigned
+/// integers and bool, and calls to const functions. Const function bodies may also use `let`
+/// statements, variables and `if` expressions.
+struct ConstantEvaluator<'a> {
+    db: &'a dyn SemanticGroup,
+    diagnostics: &'a mut SemanticDiagnostics,
+    exprs: &'a Arena<Expr>,
+    statements: &'a Arena<Statement>,
+    /// The values of the parameters and of the local variables of the evaluated const function.
+    vars: UnorderedHashMap<VarId, ConstValue>,
+    /// The call of a const function in the constant, where errors in the body of the function are
+    /// reported. None while evaluating the expression of the constant itself.
+    call_site: Option<SyntaxStablePtrId>,
+    /// The const functions being evaluated, outermost first.
+    call_stack: Vec<FreeFunctionId>,
+}
+impl<'a> ConstantEvaluator<'a> {
+    /// Reports a diagnostic at the given pointer, or at the call site when evaluating the body of
+    /// a const function, as its pointers are not in the file of the constant.
+    fn report(
+        &mut self,
+        stable_ptr: SyntaxStablePtrId,
+        kind: SemanticDiagnosticKind,
+    ) -> DiagnosticAdded {
+        self.diagnostics.report_by_ptr(self.call_site.unwrap_or(stable_ptr), kind)
+    }
+
+    /// Evaluates an expression at compile time.
+    fn evaluate(&mut self, expr_id: ExprId) -> Maybe<ConstValue> {
+        let db = self.db;
+        let expr = &self.exprs[expr_id];
+        match expr {
+            Expr::Literal(expr) => Ok(ConstValue::Int(expr.value.clone(), expr.ty)),
+            Expr::Constant(expr) => Ok(db.constant_semantic_data(expr.constant_id)?.value),
+            Expr::ImplConstant(expr) => match expr.impl_constant_id.concrete_value(db) {
+                Some(constant) => Ok(constant?.value),
+                None => Err(self.report(expr.stable_ptr.untyped(), UnsupportedConstant)),
+            },
+            Expr::Var(expr) => match self.vars.get(&expr.var) {
+                Some(value) => Ok(value.clone()),
+                None => Err(self.report(expr.stable_ptr.untyped(), UnsupportedConstant)),
+            },
+            Expr::Snapshot(ExprSnapshot { inner, .. }) | Expr::Desnap(ExprDesnap { inner, .. }) => {
+                self.evaluate(*inner)
+            }
+            Expr::Tuple(expr) => Ok(ConstValue::Struct(
+                expr.items.iter().map(|item| self.evaluate(*item)).collect::<Maybe<_>>()?,
+                expr.ty,
+            )),
+            Expr::StructCtor(expr) => {
+                let member_exprs: UnorderedHashMap<_, _> = expr.members.iter().cloned().collect();
+                let members = db.concrete_struct_members(expr.concrete_struct_id)?;
+                Ok(ConstValue::Struct(
+                    members
+                        .values()
+                        .map(|member| self.evaluate(member_exprs[member.id]))
+                        .collect::<Maybe<_>>()?,
+                    expr.ty,
+                ))
+            }
+            Expr::EnumVariantCtor(expr) => Ok(ConstValue::Enum(
+                expr.variant.clone(),
+                Box::new(self.evaluate(expr.value_expr)?),
+            )),
+            Expr::MemberAccess(expr) => {
+                let ConstValue::Struct(values, _) = self.evaluate(expr.expr)? else {
+                    return Err(self.report(expr.stable_ptr.untyped(), UnsupportedConstant));
+                };
+                let members = db.concrete_struct_members(expr.concrete_struct_id)?;
+                let member_idx =
+                    members.values().position(|member| member.id == expr.member).to_maybe()?;
+                Ok(values[member_idx].clone())
+            }
+            Expr::Block(expr) => {
+                for statement_id in &expr.statements {
+                    let Statement::Let(statement) = &self.statements[*statement_id] else {
+                        let stable_ptr = self.statements[*statement_id].stable_ptr().untyped();
+                        return Err(self.report(stable_ptr, UnsupportedConstant));
+                    };
+                    let value = self.evaluate(statement.expr)?;
+                    self.bind_pattern(&statement.pattern, value)?;
+                }
+                match expr.tail {
+                    Some(tail) => self.evaluate(tail),
+                    None => Ok(ConstValue::Struct(vec![], expr.ty)),
+                }
+            }
+            Expr::If(expr) => {
+                let condition = self.evaluate(expr.condition)?;
+                let Some(condition) = bool_value(db, &condition) else {
+                    return Err(self.report(expr.stable_ptr.untyped(), UnsupportedConstant));
+                };
+                match (condition, expr.else_block) {
+                    (true, _) => self.evaluate(expr.if_block),
+                    (false, Some(else_block)) => self.evaluate(else_block),
+                    (false, None) => Ok(ConstValue::Struct(vec![], expr.ty)),
+                }
+            }
+            Expr::FunctionCall(expr) => self.evaluate_function_call(expr),
+            Expr::Missing(expr) => Err(expr.diag_added),
+            _ => Err(self.report(expr.stable_ptr().untyped(), UnsupportedConstant)),
+        }
+    }
+
+    /// Binds the variables of a `let` pattern to the parts of the given value.
+    fn bind_pattern(&mut self, pattern: &Pattern, value: ConstValue) -> Maybe<()> {
+        match (pattern, value) {
+            (Pattern::Variable(pattern), value) => {
+                self.vars.insert(VarId::Local(pattern.var.id), value);
+            }
+            (Pattern::Otherwise(_), _) => {}
+            (Pattern::Tuple(pattern), ConstValue::Struct(values, _)) => {
+                for (field_pattern, value) in zip_eq(&pattern.field_patterns, values) {
+                    self.bind_pattern(field_pattern, value)?;
+                }
+            }
+            (Pattern::Struct(pattern), ConstValue::Struct(values, _)) => {
+                let members = self.db.concrete_struct_members(pattern.concrete_struct_id)?;
+                for (member, field_pattern) in &pattern.field_patterns {
+                    let member_idx = members.values().position(|m| m.id == member.id).to_maybe()?;
+                    self.bind_pattern(field_pattern, values[member_idx].clone())?;
+                }
+            }
+            (pattern, _) => {
+                return Err(self.report(pattern.stable_ptr().untyped(), UnsupportedConstant));
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates a call to a core operator or to a const function on constant arguments.
+    fn evaluate_function_call(&mut self, expr: &ExprFunctionCall) -> Maybe<ConstValue> {
+        let db = self.db;
+        let stable_ptr = expr.stable_ptr.untyped();
+        let op = ConstOperator::try_from_function(db, expr.function);
+        let const_function = const_free_function(db, expr.function)?;
+        if op.is_none() && const_function.is_none() {
+            return Err(self.report(stable_ptr, UnsupportedConstant));
+        }
+        let mut args = vec![];
+        for arg in &expr.args {
+            let ExprFunctionCallArg::Value(arg) = arg else {
+                return Err(self.report(stable_ptr, UnsupportedConstant));
+            };
+            args.push(self.evaluate(*arg)?);
+        }
+        match (op, const_function) {
+            (Some(op), _) => self.evaluate_operator(op, &args, stable_ptr),
+            (None, Some(free_function_id)) => {
+                self.evaluate_const_function_call(free_function_id, args, stable_ptr)
+            }
+            (None, None) => unreachable!("Unsupported calls are reported above."),
+        }
+    }
+
+    /// Evaluates the body of a const function, with its parameters bound to the given arguments.
+    fn evaluate_const_function_call(
+        &mut self,
+        free_function_id: FreeFunctionId,
+        args: Vec<ConstValue>,
+        stable_ptr: SyntaxStablePtrId,
+    ) -> Maybe<ConstValue> {
+        let db = self.db;
+        if self.call_stack.contains(&free_function_id) {
+            return Err(self.report(stable_ptr, ConstFunctionCycle));
+        }
+        let signature = db.free_function_signature(free_function_id)?;
+        let body = db.function_body(FunctionWithBodyId::Free(free_function_id))?;
+        let mut call_stack = self.call_stack.clone();
+        call_stack.push(free_function_id);
+        ConstantEvaluator {
+            db,
+            diagnostics: self.diagnostics,
+            exprs: &body.exprs,
+            statements: &body.statements,
+            vars: zip_eq(signature.params.iter().map(|param| VarId::Param(param.id)), args)
+                .collect(),
+            call_site: Some(self.call_site.unwrap_or(stable_ptr)),
+            call_stack,
+        }
+        .evaluate(body.body_expr)
+    }
+
+    /// Evaluates a core operator on constant arguments.
+    fn evaluate_operator(
+        &mut self,
+        op: ConstOperator,
+        args: &[ConstValue],
+        stable_ptr: SyntaxStablePtrId,
+    ) -> Maybe<ConstValue> {
+        let db = self.db;
+        if let Some(args) = args.iter().map(|arg| bool_value(db, arg)).collect::<Option<Vec<_>>>() {
+            let value = match (op, args.as_slice()) {
+                (ConstOperator::BitAnd, [lhs, rhs]) => lhs & rhs,
+                (ConstOperator::BitOr, [lhs, rhs]) => lhs | rhs,
+                (ConstOperator::BitXor | ConstOperator::Ne, [lhs, rhs]) => lhs ^ rhs,
+                (ConstOperator::Eq, [lhs, rhs]) => lhs == rhs,
+                (ConstOperator::Not, [value]) => !value,
+                _ => return Err(self.report(stable_ptr, UnsupportedConstant)),
+            };
+            return Ok(bool_const_value(db, value));
+        }
 
-    let resolver_data = Arc::new(ctx.resolver.data);
-    Ok(ConstantData { diagnostics: diagnostics.build(), constant, resolver_data })
+        let [ConstValue::Int(_, ty), ..] = args else {
+            return Err(self.report(stable_ptr, UnsupportedConstant));
+        };
+        let ty = *ty;
+        let mut int_args = vec![];
+        for arg in args {
+            let ConstValue::Int(value, _) = arg else {
+                return Err(self.report(stable_ptr, UnsupportedConstant));
+            };
+            int_args.push(value);
+        }
+        let comparison = match (op, int_args.as_slice()) {
+            (ConstOperator::Eq, [lhs, rhs]) => Some(lhs == rhs),
+            (ConstOperator::Ne, [lhs, rhs]) => Some(lhs != rhs),
+            (ConstOperator::Lt, [lhs, rhs]) => Some(lhs < rhs),
+            (ConstOperator::Le, [lhs, rhs]) => Some(lhs <= rhs),
+            (ConstOperator::Gt, [lhs, rhs]) => Some(lhs > rhs),
+            (ConstOperator::Ge, [lhs, rhs]) => Some(lhs >= rhs),
+            _ => None,
+        };
+        if let Some(value) = comparison {
+            return Ok(bool_const_value(db, value));
+        }
+
+        let value = if ty == core_felt252_ty(db) {
+            let prime = felt252_prime();
+            let value = match (op, int_args.as_slice()) {
+                (ConstOperator::Add, [lhs, rhs]) => *lhs + *rhs,
+                (ConstOperator::Sub, [lhs, rhs]) => *lhs - *rhs,
+                (ConstOperator::Mul, [lhs, rhs]) => *lhs * *rhs,
+                (ConstOperator::Neg, [value]) => -*value,
+                _ => return Err(self.report(stable_ptr, UnsupportedConstant)),
+            };
+            ((value % &prime) + &prime) % prime
+        } else if let Some(bits) = unsigned_int_bits(db, ty) {
+            let value = match (op, int_args.as_slice()) {
+                (ConstOperator::Add, [lhs, rhs]) => *lhs + *rhs,
+                (ConstOperator::Sub, [lhs, rhs]) => *lhs - *rhs,
+                (ConstOperator::Mul, [lhs, rhs]) => *lhs * *rhs,
+                (ConstOperator::Div | ConstOperator::Rem, [_, rhs]) if rhs.is_zero() => {
+                    return Err(self.report(stable_ptr, ConstantDivisionByZero));
+                }
+                (ConstOperator::Div, [lhs, rhs]) => *lhs / *rhs,
+                (ConstOperator::Rem, [lhs, rhs]) => *lhs % *rhs,
+                (ConstOperator::BitAnd, [lhs, rhs]) => *lhs & *rhs,
+                (ConstOperator::BitOr, [lhs, rhs]) => *lhs | *rhs,
+                (ConstOperator::BitXor, [lhs, rhs]) => *lhs ^ *rhs,
+                _ => return Err(self.report(stable_ptr, UnsupportedConstant)),
+            };
+            if value.is_negative() || value.bits() > bits {
+                return Err(self.report(stable_ptr, ConstantOverflow { ty }));
+            }
+            value
+        } else {
+            return Err(self.report(stable_ptr, UnsupportedConstant));
+        };
+        Ok(ConstValue::Int(value, ty))
+    }
+}
+
+/// Returns the const free function called by the given function, if it is one. Generic const
+/// functions are not evaluated at compile time.
+fn const_free_function(
+    db: &dyn SemanticGroup,
+    function_id: FunctionId,
+) -> Maybe<Option<FreeFunctionId>> {
+    let concrete_function = db.lookup_intern_function(function_id).function;
+    let GenericFunctionId::Free(free_function_id) = concrete_function.generic_function else {
+        return Ok(None);
+    };
+    if !concrete_function.generic_args.is_empty() {
+        return Ok(None);
+    }
+    Ok(is_const_free_function(db, free_function_id)?.then_some(free_function_id))
+}
+
+/// Reports the expressions and statements of the body of a const function that can't be evaluated
+/// at compile time.
+pub fn validate_const_function_body(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    exprs: &Arena<Expr>,
+    statements: &Arena<Statement>,
+) {
+    for (_, expr) in exprs.iter() {
+        if !is_const_function_expr(db, expr) {
+            diagnostics.report_by_ptr(expr.stable_ptr().untyped(), UnsupportedConstFunctionExpr);
+        }
+    }
+    for (_, statement) in statements.iter() {
+        let supported = match statement {
+            Statement::Let(statement) => is_const_binding_pattern(&statement.pattern),
+            // Unsupported expressions are already reported above.
+            Statement::Expr(statement) => !is_const_function_expr(db, &exprs[statement.expr]),
+            _ => false,
+        };
+        if !supported {
+            diagnostics
+                .report_by_ptr(statement.stable_ptr().untyped(), UnsupportedConstFunctionExpr);
+        }
+    }
+}
+
+/// Returns whether an expression in the body of a const function can be evaluated at compile
+/// time, given that its sub-expressions can.
+fn is_const_function_expr(db: &dyn SemanticGroup, expr: &Expr) -> bool {
+    match expr {
+        Expr::Tuple(_)
+        | Expr::Snapshot(_)
+        | Expr::Desnap(_)
+        | Expr::Block(_)
+        | Expr::If(_)
+        | Expr::Var(_)
+        | Expr::Literal(_)
+        | Expr::MemberAccess(_)
+        | Expr::StructCtor(_)
+        | Expr::EnumVariantCtor(_)
+        | Expr::Constant(_)
+        | Expr::ImplConstant(_)
+        | Expr::Missing(_) => true,
+        Expr::FunctionCall(expr) => {
+            (ConstOperator::try_from_function(db, expr.function).is_some()
+                || matches!(const_free_function(db, expr.function), Ok(Some(_))))
+                && expr.args.iter().all(|arg| matches!(arg, ExprFunctionCallArg::Value(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether a `let` pattern of a const function can be bound at compile time.
+fn is_const_binding_pattern(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Variable(_) | Pattern::Otherwise(_) => true,
+        Pattern::Tuple(pattern) => {
+            pattern.field_patterns.iter().all(|p| is_const_binding_pattern(p))
+        }
+        Pattern::Struct(pattern) => {
+            pattern.field_patterns.iter().all(|(_, p)| is_const_binding_pattern(p))
+        }
+        _ => false,
+    }
+}
+
+/// An operator of the core library that can be evaluated at compile time.
+#[derive(Clone, Copy)]
+enum ConstOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+impl ConstOperator {
+    /// Returns the operator implemented by the given function, if it is an implementation of one
+    /// of the core operator traits.
+    fn try_from_function(db: &dyn SemanticGroup, function_id: FunctionId) -> Option<Self> {
+        let GenericFunctionId::Impl(ImplGenericFunctionId { function: trait_function, .. }) =
+            db.lookup_intern_function(function_id).function.generic_function else {
+            return None;
+        };
+        let (trait_name, op) = match trait_function.name(db.upcast()).as_str() {
+            "add" => ("Add", ConstOperator::Add),
+            "sub" => ("Sub", ConstOperator::Sub),
+            "mul" => ("Mul", ConstOperator::Mul),
+            "div" => ("Div", ConstOperator::Div),
+            "rem" => ("Rem", ConstOperator::Rem),
+            "neg" => ("Neg", ConstOperator::Neg),
+            "bitand" => ("BitAnd", ConstOperator::BitAnd),
+            "bitor" => ("BitOr", ConstOperator::BitOr),
+            "bitxor" => ("BitXor", ConstOperator::BitXor),
+            "not" => ("Not", ConstOperator::Not),
+            "eq" => ("PartialEq", ConstOperator::Eq),
+            "ne" => ("PartialEq", ConstOperator::Ne),
+            "lt" => ("PartialOrd", ConstOperator::Lt),
+            "le" => ("PartialOrd", ConstOperator::Le),
+            "gt" => ("PartialOrd", ConstOperator::Gt),
+            "ge" => ("PartialOrd", ConstOperator::Ge),
+            _ => return None,
+        };
+        (trait_function.trait_id(db.upcast()) == get_core_trait(db, trait_name.into()))
+            .then_some(op)
+    }
+}
+
+/// Returns the value of a constant of type bool.
+fn bool_value(db: &dyn SemanticGroup, value: &ConstValue) -> Option<bool> {
+    let ConstValue::Enum(variant, _) = value else {
+        return None;
+    };
+    if *variant == true_variant(db) {
+        Some(true)
+    } else if *variant == false_variant(db) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Returns the constant of type bool with the given value.
+fn bool_const_value(db: &dyn SemanticGroup, value: bool) -> ConstValue {
+    let variant = if value { true_variant(db) } else { false_variant(db) };
+    ConstValue::Enum(variant, Box::new(ConstValue::Struct(vec![], unit_ty(db))))
+}
+
+/// Returns the number of bits of the given type, if it is an unsigned integer type.
+fn unsigned_int_bits(db: &dyn SemanticGroup, ty: TypeId) -> Option<u64> {
+    [("u8", 8), ("u16", 16), ("u32", 32), ("u64", 64), ("u128", 128), ("u256", 256)]
+        .into_iter()
+        .find(|(name, _)| ty == get_core_ty_by_name(db, (*name).into(), vec![]))
+        .map(|(_, bits)| bits)
+}
+
+/// Returns the prime of the felt252 field.
+fn felt252_prime() -> BigInt {
+    (BigInt::one() << 251) + (BigInt::from(17) << 192) + 1
 }
 
 /// Query implementation of [SemanticGroup::constant_semantic_diagnostics].
@@ -98,7 +610,17 @@ pub fn constant_semantic_diagnostics(
 
 /// Query implementation of [SemanticGroup::constant_semantic_data].
 pub fn constant_semantic_data(db: &dyn SemanticGroup, const_id: ConstantId) -> Maybe<Constant> {
-    Ok(db.priv_constant_semantic_data(const_id)?.constant)
+    db.priv_constant_semantic_data(const_id)?.constant
+}
+
+/// Cycle handling for [SemanticGroup::constant_semantic_data].
+pub fn constant_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    cycle: &[String],
+    const_id: &ConstantId,
+) -> Maybe<Constant> {
+    // Forwarding to the cycle handling of the private query, which reports the diagnostic.
+    priv_constant_semantic_data_cycle(db, cycle, const_id)?.constant
 }
 
 /// Query implementation of [crate::db::SemanticGroup::constant_resolver_data].
@@ -3,9 +3,10 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::{FreeFunctionId, FunctionTitleId, LanguageElementId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 
+use super::constant::validate_const_function_body;
 use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
 use super::functions::{
     forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
@@ -210,6 +211,9 @@ pub fn priv_free_function_body_data(
     let function_body = function_syntax.body(db.upcast());
     let return_type = declaration.signature.return_type;
     let body_expr = compute_root_expr(&mut ctx, &function_body, return_type)?;
+    if is_const_free_function(db, free_function_id)? {
+        validate_const_function_body(db, ctx.diagnostics, &ctx.exprs, &ctx.statements);
+    }
     let ComputationContext { exprs, statements, resolver, .. } = ctx;
 
     let expr_lookup: UnorderedHashMap<_, _> =
@@ -222,3 +226,18 @@ pub fn priv_free_function_body_data(
         body: Arc::new(FunctionBody { exprs, statements, body_expr }),
     })
 }
+
+/// Returns whether the given free function is declared as a `const fn`, which allows calling it in
+/// constant expressions.
+pub fn is_const_free_function(
+    db: &dyn SemanticGroup,
+    free_function_id: FreeFunctionId,
+) -> Maybe<bool> {
+    let module_file_id = free_function_id.module_file_id(db.upcast());
+    let module_free_functions = db.module_free_functions(module_file_id.0)?;
+    let function_syntax = module_free_functions.get(&free_function_id).to_maybe()?;
+    Ok(matches!(
+        function_syntax.optional_const(db.upcast()),
+        ast::OptionTerminalConst::TerminalConst(_)
+    ))
+}
@@ -22,7 +22,9 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::expr::compute::Environment;
 use crate::resolve::{Resolver, ResolverData};
-use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::substitution::{
+    GenericSubstitution, SelfImplRewriter, SemanticRewriter, SubstitutionRewriter,
+};
 use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, ConcreteImplId, ConcreteImplLongId, GenericArgumentId,
@@ -42,7 +44,7 @@ impl ImplGenericFunctionId {
             ImplId::Concrete(concrete_impl_id) => {
                 concrete_impl_id.get_impl_function(db, self.function)
             }
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => Ok(None),
+            ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::SelfImpl(_) => Ok(None),
         }
     }
     /// Converts to ImplGenericFunctionWithBodyId if this is a function of a concrete impl.
@@ -115,9 +117,10 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_signature(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
+                let impl_id = id.impl_id;
                 let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
 
-                db.concrete_trait_function_signature(id)
+                SelfImplRewriter { db, impl_id }.rewrite(db.concrete_trait_function_signature(id)?)
             }
         }
     }
@@ -127,8 +130,10 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_declaration_generic_params(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
+                let impl_id = id.impl_id;
                 let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
-                db.concrete_trait_function_generic_params(id)
+                SelfImplRewriter { db, impl_id }
+                    .rewrite(db.concrete_trait_function_generic_params(id)?)
             }
         }
     }
@@ -338,7 +343,7 @@ impl ConcreteFunctionWithBody {
 }
 
 /// Converts each generic param to a generic argument that passes the same generic param.
-fn generic_params_to_args(
+pub(crate) fn generic_params_to_args(
     params: Vec<GenericParam>,
     db: &dyn SemanticGroup,
 ) -> Maybe<Vec<GenericArgumentId>> {
@@ -3,8 +3,10 @@ use std::vec;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, GenericParamId, ImplAliasId, ImplDefId, ImplFunctionId, ImplFunctionLongId,
-    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+    FunctionTitleId, GenericParamId, ImplAliasId, ImplConstantDefId, ImplConstantDefLongId,
+    ImplDefId, ImplFunctionId, ImplFunctionLongId, ImplTypeDefId, ImplTypeDefLongId,
+    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitConstantId, TraitFunctionId,
+    TraitId, TraitTypeId,
 };
 use cairo_lang_diagnostics::{
     skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
@@ -23,10 +25,12 @@ use itertools::{chain, izip, Itertools};
 use smol_str::SmolStr;
 use syntax::node::db::SyntaxGroup;
 
+use super::constant::{compute_constant, Constant};
 use super::enm::SemanticEnumEx;
 use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
 use super::functions::{
-    forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
+    forbid_inline_always_with_impl_generic_param, generic_params_to_args, FunctionDeclarationData,
+    InlineConfiguration,
 };
 use super::generics::{semantic_generic_params, GenericArgumentHead};
 use super::structure::SemanticStructEx;
@@ -41,7 +45,10 @@ use crate::items::function_with_body::get_implicit_precedence;
 use crate::items::functions::ImplicitPrecedence;
 use crate::items::us::SemanticUseEx;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver, ResolverData};
-use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::substitution::{
+    GenericSubstitution, SelfImplRewriter, SemanticRewriter, SubstitutionRewriter,
+};
+use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, ConcreteFunction, ConcreteTraitId, ConcreteTraitLongId,
     FunctionId, FunctionLongId, GenericArgumentId, GenericParam, Mutability, SemanticDiagnostic,
@@ -98,6 +105,34 @@ impl ConcreteImplId {
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
         self.impl_def_id(db).name(db.upcast())
     }
+    /// Returns the type this concrete impl assigns to an associated type of its trait.
+    pub fn get_impl_type(&self, db: &dyn SemanticGroup, trait_type: TraitTypeId) -> Maybe<TypeId> {
+        let long_impl = db.lookup_intern_concrete_impl(*self);
+        let Some(impl_type_def_id) =
+            db.impl_type_by_trait_type(long_impl.impl_def_id, trait_type)? else {
+            // The missing item is reported on the impl.
+            return Err(skip_diagnostic());
+        };
+        let ty = db.impl_type_def_resolved_type(impl_type_def_id)?;
+        let substitution = GenericSubstitution::new(
+            &db.impl_def_generic_params(long_impl.impl_def_id)?,
+            &long_impl.generic_args,
+        );
+        SubstitutionRewriter { db, substitution: &substitution }.rewrite(ty)
+    }
+    /// Returns the constant this concrete impl assigns to an associated constant of its trait.
+    pub fn get_impl_constant(
+        &self,
+        db: &dyn SemanticGroup,
+        trait_constant: TraitConstantId,
+    ) -> Maybe<Constant> {
+        let Some(impl_constant_def_id) =
+            db.impl_constant_by_trait_constant(self.impl_def_id(db), trait_constant)? else {
+            // The missing item is reported on the impl.
+            return Err(skip_diagnostic());
+        };
+        db.impl_constant_def_value(impl_constant_def_id)
+    }
 }
 
 /// Represents a "callee" impl that can be referred to in the code.
@@ -107,13 +142,15 @@ pub enum ImplId {
     Concrete(ConcreteImplId),
     GenericParameter(GenericParamId),
     ImplVar(ImplVar),
+    /// The impl implementing the given trait, as referred to by `Self` inside the trait's items.
+    SelfImpl(ConcreteTraitId),
 }
 impl ImplId {
     /// Returns the [ImplHead] of an impl if available.
     pub fn head(&self, db: &dyn SemanticGroup) -> Option<ImplHead> {
         Some(match self {
             ImplId::Concrete(concrete) => ImplHead::Concrete(concrete.impl_def_id(db)),
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+            ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::SelfImpl(_) => return None,
         })
     }
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
@@ -121,6 +158,7 @@ impl ImplId {
             ImplId::Concrete(concrete_impl) => concrete_impl.name(db),
             ImplId::GenericParameter(generic_param_impl) => generic_param_impl.name(db.upcast()),
             ImplId::ImplVar(var) => format!("{var:?}").into(),
+            ImplId::SelfImpl(_) => "Self".into(),
         }
     }
     pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> Maybe<ConcreteTraitId> {
@@ -137,10 +175,90 @@ impl DebugWithDb<dyn SemanticGroup> for ImplId {
             ImplId::Concrete(concrete_impl_id) => write!(f, "{:?}", concrete_impl_id.debug(db)),
             ImplId::GenericParameter(param) => write!(f, "{:?}", param.debug(db)),
             ImplId::ImplVar(var) => write!(f, "?{}", var.id),
+            ImplId::SelfImpl(_) => write!(f, "Self"),
         }
     }
 }
 
+/// An associated type of a trait, as implemented by an impl.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplTypeId {
+    impl_id: ImplId,
+    ty: TraitTypeId,
+}
+impl ImplTypeId {
+    pub fn new(impl_id: ImplId, ty: TraitTypeId) -> Self {
+        Self { impl_id, ty }
+    }
+    pub fn impl_id(&self) -> ImplId {
+        self.impl_id
+    }
+    pub fn ty(&self) -> TraitTypeId {
+        self.ty
+    }
+    /// Returns the type this associated type stands for, if the impl is concrete.
+    /// Otherwise, the type can't be determined yet, and None is returned.
+    pub fn concrete_type(&self, db: &dyn SemanticGroup) -> Option<TypeId> {
+        let ImplId::Concrete(concrete_impl_id) = self.impl_id else {
+            return None;
+        };
+        Some(
+            concrete_impl_id
+                .get_impl_type(db, self.ty)
+                .unwrap_or_else(|diag_added| TypeId::missing(db, diag_added)),
+        )
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> SmolStr {
+        format!("{}::{}", self.impl_id.name(db), self.ty.name(db.upcast())).into()
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplTypeId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
+/// An associated constant of a trait, as implemented by an impl.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplConstantId {
+    impl_id: ImplId,
+    constant: TraitConstantId,
+}
+impl ImplConstantId {
+    pub fn new(impl_id: ImplId, constant: TraitConstantId) -> Self {
+        Self { impl_id, constant }
+    }
+    pub fn impl_id(&self) -> ImplId {
+        self.impl_id
+    }
+    pub fn constant(&self) -> TraitConstantId {
+        self.constant
+    }
+    /// Returns the value of this associated constant, if the impl is concrete.
+    pub fn concrete_value(&self, db: &dyn SemanticGroup) -> Option<Maybe<Constant>> {
+        let ImplId::Concrete(concrete_impl_id) = self.impl_id else {
+            return None;
+        };
+        Some(concrete_impl_id.get_impl_constant(db, self.constant))
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> SmolStr {
+        format!("{}::{}", self.impl_id.name(db), self.constant.name(db.upcast())).into()
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplConstantId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
 /// Head of an impl. A non-param non-variable impl has a head, which represents the kind of the root
 /// node in its tree representation. This is used for caching queries for fast lookups when the impl
 /// is not completely inferred yet.
@@ -221,6 +339,7 @@ pub fn impl_concrete_trait(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<Con
             param_impl.concrete_trait
         }
         ImplId::ImplVar(var) => Ok(var.concrete_trait_id),
+        ImplId::SelfImpl(concrete_trait_id) => Ok(concrete_trait_id),
     }
 }
 
@@ -296,6 +415,8 @@ pub fn priv_impl_declaration_data_inner(
         .rewrite(concrete_trait)
         .map_err(|err| err.report(&mut diagnostics, impl_ast.stable_ptr().untyped()))?;
 
+    resolver.set_self_impl(impl_def_self_impl(db, impl_def_id, &generic_params)?);
+
     let attributes = impl_ast.attributes(syntax_db).structurize(syntax_db);
     let resolver_data = Arc::new(resolver.data);
     Ok(ImplDeclarationData {
@@ -307,11 +428,23 @@ pub fn priv_impl_declaration_data_inner(
     })
 }
 
+/// Returns the impl that stands for the impl itself (`Self`) inside its items.
+fn impl_def_self_impl(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    generic_params: &[GenericParam],
+) -> Maybe<ImplId> {
+    let generic_args = generic_params_to_args(generic_params.to_vec(), db)?;
+    Ok(ImplId::Concrete(db.intern_concrete_impl(ConcreteImplLongId { impl_def_id, generic_args })))
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ImplDefinitionData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     function_asts: OrderedHashMap<ImplFunctionId, ast::FunctionWithBody>,
+    type_asts: OrderedHashMap<ImplTypeDefId, ast::ItemTypeAlias>,
+    constant_asts: OrderedHashMap<ImplConstantDefId, ast::ItemConstant>,
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_semantic_definition_diagnostics].
@@ -330,6 +463,12 @@ pub fn impl_semantic_definition_diagnostics(
         diagnostics.extend(db.impl_function_declaration_diagnostics(*impl_function_id));
         diagnostics.extend(db.impl_function_body_diagnostics(*impl_function_id));
     }
+    for impl_type_def_id in data.type_asts.keys() {
+        diagnostics.extend(db.impl_type_def_diagnostics(*impl_type_def_id));
+    }
+    for impl_constant_def_id in data.constant_asts.keys() {
+        diagnostics.extend(db.impl_constant_def_diagnostics(*impl_constant_def_id));
+    }
 
     diagnostics.build()
 }
@@ -382,16 +521,41 @@ pub fn priv_impl_definition_data(
     // TODO(yuval): verify that all functions of `concrete_trait` appear in this impl.
 
     let mut function_asts = OrderedHashMap::default();
+    let mut type_asts = OrderedHashMap::default();
+    let mut constant_asts = OrderedHashMap::default();
     let mut impl_item_names = OrderedHashSet::default();
 
     if let MaybeImplBody::Some(body) = impl_ast.body(syntax_db) {
         for item in body.items(syntax_db).elements(syntax_db) {
             match item {
-                ImplItem::Constant(constant) => report_invalid_impl_item(
-                    syntax_db,
-                    &mut diagnostics,
-                    constant.const_kw(syntax_db),
-                ),
+                ImplItem::Constant(constant) => {
+                    let impl_constant_def_id = db.intern_impl_constant_def(ImplConstantDefLongId(
+                        module_file_id,
+                        constant.stable_ptr(),
+                    ));
+                    if !impl_item_names.insert(impl_constant_def_id.name(defs_db)) {
+                        diagnostics.report_by_ptr(
+                            constant.name(syntax_db).stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes {
+                                name: impl_constant_def_id.name(defs_db),
+                            },
+                        );
+                    }
+                    constant_asts.insert(impl_constant_def_id, constant);
+                }
+                ImplItem::TypeAlias(ty) => {
+                    let impl_type_def_id =
+                        db.intern_impl_type_def(ImplTypeDefLongId(module_file_id, ty.stable_ptr()));
+                    if !impl_item_names.insert(impl_type_def_id.name(defs_db)) {
+                        diagnostics.report_by_ptr(
+                            ty.name(syntax_db).stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes {
+                                name: impl_type_def_id.name(defs_db),
+                            },
+                        );
+                    }
+                    type_asts.insert(impl_type_def_id, ty);
+                }
                 ImplItem::Module(module) => report_invalid_impl_item(
                     syntax_db,
                     &mut diagnostics,
@@ -427,9 +591,6 @@ pub fn priv_impl_definition_data(
                 ImplItem::Enum(enm) => {
                     report_invalid_impl_item(syntax_db, &mut diagnostics, enm.enum_kw(syntax_db))
                 }
-                ImplItem::TypeAlias(ty) => {
-                    report_invalid_impl_item(syntax_db, &mut diagnostics, ty.type_kw(syntax_db))
-                }
                 ImplItem::ImplAlias(imp) => {
                     report_invalid_impl_item(syntax_db, &mut diagnostics, imp.impl_kw(syntax_db))
                 }
@@ -459,10 +620,13 @@ pub fn priv_impl_definition_data(
     // to verify here that all items in `concrete_trait` appear in this impl.
     // TODO(yuval): Once default implementation of trait functions is supported, filter such
     // functions out.
-    let trait_item_names = db
-        .trait_functions(db.lookup_intern_concrete_trait(concrete_trait).trait_id)?
-        .into_keys()
-        .collect::<OrderedHashSet<_>>();
+    let trait_id = db.lookup_intern_concrete_trait(concrete_trait).trait_id;
+    let trait_item_names = chain!(
+        db.trait_functions(trait_id)?.into_keys(),
+        db.trait_types(trait_id)?.into_keys(),
+        db.trait_constants(trait_id)?.into_keys()
+    )
+    .collect::<OrderedHashSet<_>>();
     let missing_items_in_impl =
         trait_item_names.difference(&impl_item_names).cloned().collect::<Vec<_>>();
     if !missing_items_in_impl.is_empty() {
@@ -474,7 +638,12 @@ pub fn priv_impl_definition_data(
         );
     }
 
-    Ok(ImplDefinitionData { diagnostics: diagnostics.build(), function_asts })
+    Ok(ImplDefinitionData {
+        diagnostics: diagnostics.build(),
+        function_asts,
+        type_asts,
+        constant_asts,
+    })
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_functions].
@@ -509,6 +678,38 @@ pub fn impl_function_by_trait_function(
     Ok(None)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::impl_type_by_trait_type].
+pub fn impl_type_by_trait_type(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    trait_type_id: TraitTypeId,
+) -> Maybe<Option<ImplTypeDefId>> {
+    let defs_db = db.upcast();
+    let name = trait_type_id.name(defs_db);
+    Ok(db
+        .priv_impl_definition_data(impl_def_id)?
+        .type_asts
+        .keys()
+        .find(|impl_type_def_id| impl_type_def_id.name(defs_db) == name)
+        .copied())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_by_trait_constant].
+pub fn impl_constant_by_trait_constant(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Option<ImplConstantDefId>> {
+    let defs_db = db.upcast();
+    let name = trait_constant_id.name(defs_db);
+    Ok(db
+        .priv_impl_definition_data(impl_def_id)?
+        .constant_asts
+        .keys()
+        .find(|impl_constant_def_id| impl_constant_def_id.name(defs_db) == name)
+        .copied())
+}
+
 /// Handle special cases such as Copy and Drop checking.
 fn check_special_impls(
     db: &dyn SemanticGroup,
@@ -575,7 +776,7 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<Vec<TypeId>> {
         }
         TypeLongId::Tuple(tys) => tys,
         TypeLongId::Snapshot(_) => vec![],
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
             return Err(skip_diagnostic());
         }
         TypeLongId::Var(_) => panic!("Types should be fully resolved at this point."),
@@ -994,6 +1195,7 @@ pub fn priv_impl_function_declaration_data(
     let declaration = function_syntax.declaration(syntax_db);
     let mut resolver = Resolver::new(db, module_file_id);
     let impl_def_generic_params = db.impl_def_generic_params(impl_def_id)?;
+    resolver.set_self_impl(impl_def_self_impl(db, impl_def_id, &impl_def_generic_params)?);
     for generic_param in impl_def_generic_params {
         resolver.add_generic_param(generic_param);
     }
@@ -1119,6 +1321,9 @@ fn validate_impl_function_signature(
     );
     let concrete_trait_signature = SubstitutionRewriter { db, substitution: &substitution }
         .rewrite(concrete_trait_signature)?;
+    let self_impl = impl_def_self_impl(db, impl_def_id, &db.impl_def_generic_params(impl_def_id)?)?;
+    let concrete_trait_signature =
+        SelfImplRewriter { db, impl_id: self_impl }.rewrite(concrete_trait_signature)?;
 
     if signature.params.len() != concrete_trait_signature.params.len() {
         diagnostics.report(
@@ -1289,3 +1494,253 @@ pub fn priv_impl_function_body_data(
         body: Arc::new(FunctionBody { exprs, statements, body_expr }),
     })
 }
+
+// === Impl type ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ImplTypeDefData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    resolved_type: Maybe<TypeId>,
+    resolver_data: Arc<ResolverData>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_diagnostics].
+pub fn impl_type_def_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_type_def_data(impl_type_def_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_resolved_type].
+pub fn impl_type_def_resolved_type(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<TypeId> {
+    db.priv_impl_type_def_data(impl_type_def_id)?.resolved_type
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::impl_type_def_resolved_type].
+pub fn impl_type_def_resolved_type_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_type_def_id: &ImplTypeDefId,
+) -> Maybe<TypeId> {
+    db.priv_impl_type_def_data(*impl_type_def_id)?.resolved_type
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_type_def_resolver_data].
+pub fn impl_type_def_resolver_data(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<Arc<ResolverData>> {
+    Ok(db.priv_impl_type_def_data(impl_type_def_id)?.resolver_data)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_type_def_data].
+pub fn priv_impl_type_def_data(
+    db: &dyn SemanticGroup,
+    impl_type_def_id: ImplTypeDefId,
+) -> Maybe<ImplTypeDefData> {
+    let module_file_id = impl_type_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_type_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let type_syntax = &data.type_asts[impl_type_def_id];
+    let syntax_db = db.upcast();
+
+    validate_impl_item_in_trait(
+        db,
+        &mut diagnostics,
+        impl_def_id,
+        impl_type_def_id.name(db.upcast()),
+        type_syntax.name(syntax_db).stable_ptr().untyped(),
+        |trait_id, name| Ok(db.trait_type_by_name(trait_id, name)?.is_some()),
+    );
+    if let ast::OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
+        type_syntax.generic_params(syntax_db)
+    {
+        diagnostics.report(&generic_params, GenericImplTypeNotSupported);
+    }
+
+    let mut resolver = Resolver::with_data(db, (*db.impl_def_resolver_data(impl_def_id)?).clone());
+    let ty = resolve_type(db, &mut diagnostics, &mut resolver, &type_syntax.ty(syntax_db));
+
+    // Check fully resolved.
+    if let Some((stable_ptr, inference_err)) = resolver.inference().finalize() {
+        inference_err.report(&mut diagnostics, stable_ptr);
+    }
+    let resolved_type = resolver
+        .inference()
+        .rewrite(ty)
+        .map_err(|err| err.report(&mut diagnostics, type_syntax.stable_ptr().untyped()));
+
+    let resolver_data = Arc::new(resolver.data);
+    Ok(ImplTypeDefData { diagnostics: diagnostics.build(), resolved_type, resolver_data })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_impl_type_def_data].
+pub fn priv_impl_type_def_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_type_def_id: &ImplTypeDefId,
+) -> Maybe<ImplTypeDefData> {
+    let module_file_id = impl_type_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_type_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let type_syntax = &data.type_asts[*impl_type_def_id];
+    let resolved_type = Err(diagnostics.report(&type_syntax.name(db.upcast()), ImplTypeCycle));
+    Ok(ImplTypeDefData {
+        diagnostics: diagnostics.build(),
+        resolved_type,
+        resolver_data: Arc::new(ResolverData::new(module_file_id)),
+    })
+}
+
+// === Impl constant ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ImplConstantDefData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    constant: Maybe<Constant>,
+    resolver_data: Arc<ResolverData>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_diagnostics].
+pub fn impl_constant_def_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_constant_def_data(impl_constant_def_id)
+        .map(|data| data.diagnostics)
+        .unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_value].
+pub fn impl_constant_def_value(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<Constant> {
+    db.priv_impl_constant_def_data(impl_constant_def_id)?.constant
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::impl_constant_def_value].
+pub fn impl_constant_def_value_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_constant_def_id: &ImplConstantDefId,
+) -> Maybe<Constant> {
+    db.priv_impl_constant_def_data(*impl_constant_def_id)?.constant
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_resolver_data].
+pub fn impl_constant_def_resolver_data(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<Arc<ResolverData>> {
+    Ok(db.priv_impl_constant_def_data(impl_constant_def_id)?.resolver_data)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_constant_def_data].
+pub fn priv_impl_constant_def_data(
+    db: &dyn SemanticGroup,
+    impl_constant_def_id: ImplConstantDefId,
+) -> Maybe<ImplConstantDefData> {
+    let module_file_id = impl_constant_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_constant_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let constant_syntax = &data.constant_asts[impl_constant_def_id];
+    let syntax_db = db.upcast();
+    let constant_name = impl_constant_def_id.name(db.upcast());
+
+    validate_impl_item_in_trait(
+        db,
+        &mut diagnostics,
+        impl_def_id,
+        constant_name.clone(),
+        constant_syntax.name(syntax_db).stable_ptr().untyped(),
+        |trait_id, name| Ok(db.trait_constant_by_name(trait_id, name)?.is_some()),
+    );
+
+    let resolver = Resolver::with_data(db, (*db.impl_def_resolver_data(impl_def_id)?).clone());
+    let (constant, resolver_data) =
+        compute_constant(db, &mut diagnostics, resolver, constant_syntax);
+
+    // Validate the type against the trait constant, in the concrete trait of the impl.
+    if let Ok(constant) = &constant {
+        let concrete_trait_constant =
+            db.impl_def_concrete_trait(impl_def_id).and_then(|concrete_trait| {
+                Ok(db
+                    .trait_constant_by_name(concrete_trait.trait_id(db), constant_name.clone())?
+                    .map(|trait_constant_id| (concrete_trait, trait_constant_id)))
+            });
+        if let Ok(Some((concrete_trait, trait_constant_id))) = concrete_trait_constant {
+            if let Ok(expected_ty) =
+                db.concrete_trait_constant_type(concrete_trait, trait_constant_id)
+            {
+                if expected_ty != constant.ty && !expected_ty.is_missing(db) {
+                    diagnostics.report(
+                        &constant_syntax.type_clause(syntax_db).ty(syntax_db),
+                        WrongImplConstantType {
+                            impl_def_id,
+                            constant_name,
+                            expected_ty,
+                            actual_ty: constant.ty,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(ImplConstantDefData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolver_data: Arc::new(resolver_data),
+    })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_impl_constant_def_data].
+pub fn priv_impl_constant_def_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    impl_constant_def_id: &ImplConstantDefId,
+) -> Maybe<ImplConstantDefData> {
+    let module_file_id = impl_constant_def_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_def_id = impl_constant_def_id.impl_def_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_def_id)?;
+    let constant_syntax = &data.constant_asts[*impl_constant_def_id];
+    let constant = Err(diagnostics.report(&constant_syntax.name(db.upcast()), ConstantCycle));
+    Ok(ImplConstantDefData {
+        diagnostics: diagnostics.build(),
+        constant,
+        resolver_data: Arc::new(ResolverData::new(module_file_id)),
+    })
+}
+
+/// Reports a diagnostic if an impl item doesn't have a matching item in the trait of the impl.
+/// `item_in_trait` checks whether the trait has an item of the right kind with the given name.
+fn validate_impl_item_in_trait(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    impl_def_id: ImplDefId,
+    item_name: SmolStr,
+    stable_ptr: SyntaxStablePtrId,
+    item_in_trait: impl FnOnce(TraitId, SmolStr) -> Maybe<bool>,
+) {
+    let Ok(concrete_trait_id) = db.impl_def_concrete_trait(impl_def_id) else {
+        return;
+    };
+    let trait_id = concrete_trait_id.trait_id(db);
+    if let Ok(false) = item_in_trait(trait_id, item_name.clone()) {
+        diagnostics.report_by_ptr(
+            stable_ptr,
+            ImplItemNotMemberOfTrait { impl_def_id, item_name, trait_id },
+        );
+    }
+}
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, LanguageElementId, TopLevelLanguageElementId, TraitFunctionId,
-    TraitFunctionLongId, TraitId,
+    FunctionTitleId, LanguageElementId, TopLevelLanguageElementId, TraitConstantId,
+    TraitConstantLongId, TraitFunctionId, TraitFunctionLongId, TraitId, TraitTypeId,
+    TraitTypeLongId,
 };
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
@@ -14,16 +15,19 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use smol_str::SmolStr;
 
+use super::functions::generic_params_to_args;
 use super::generics::semantic_generic_params;
+use super::imp::ImplId;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::{self, *};
 use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::Environment;
 use crate::resolve::{Resolver, ResolverData};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, GenericArgumentId, GenericParam, Mutability,
-    SemanticDiagnostic,
+    SemanticDiagnostic, TypeId,
 };
 
 #[cfg(test)]
@@ -135,6 +139,8 @@ pub struct TraitData {
     generic_params: Vec<GenericParam>,
     attributes: Vec<Attribute>,
     function_asts: OrderedHashMap<TraitFunctionId, ast::TraitItemFunction>,
+    type_asts: OrderedHashMap<TraitTypeId, ast::TraitItemType>,
+    constant_asts: OrderedHashMap<TraitConstantId, ast::TraitItemConstant>,
     resolver_data: Arc<ResolverData>,
 }
 
@@ -153,6 +159,9 @@ pub fn trait_semantic_diagnostics(
     for trait_function_id in data.function_asts.keys() {
         diagnostics.extend(db.trait_function_diagnostics(*trait_function_id));
     }
+    for trait_constant_id in data.constant_asts.keys() {
+        diagnostics.extend(db.trait_constant_diagnostics(*trait_constant_id));
+    }
 
     diagnostics.build()
 }
@@ -183,6 +192,56 @@ pub fn trait_functions(
         .collect())
 }
 
+/// Query implementation of [crate::db::SemanticGroup::trait_types].
+pub fn trait_types(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Maybe<OrderedHashMap<SmolStr, TraitTypeId>> {
+    Ok(db
+        .priv_trait_semantic_data(trait_id)?
+        .type_asts
+        .keys()
+        .map(|type_id| (type_id.name(db.upcast()), *type_id))
+        .collect())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_type_by_name].
+pub fn trait_type_by_name(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+    name: SmolStr,
+) -> Maybe<Option<TraitTypeId>> {
+    Ok(db.trait_types(trait_id)?.get(&name).copied())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constants].
+pub fn trait_constants(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Maybe<OrderedHashMap<SmolStr, TraitConstantId>> {
+    Ok(db
+        .priv_trait_semantic_data(trait_id)?
+        .constant_asts
+        .keys()
+        .map(|constant_id| (constant_id.name(db.upcast()), *constant_id))
+        .collect())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_by_name].
+pub fn trait_constant_by_name(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+    name: SmolStr,
+) -> Maybe<Option<TraitConstantId>> {
+    Ok(db.trait_constants(trait_id)?.get(&name).copied())
+}
+
+/// Returns the impl that stands for the implementing impl (`Self`) inside the trait's items.
+pub fn trait_self_impl(db: &dyn SemanticGroup, trait_id: TraitId) -> Maybe<ImplId> {
+    let generic_args = generic_params_to_args(db.trait_generic_params(trait_id)?, db)?;
+    Ok(ImplId::SelfImpl(db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args })))
+}
+
 /// Query implementation of [crate::db::SemanticGroup::trait_resolver_data].
 pub fn trait_resolver_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Maybe<Arc<ResolverData>> {
     Ok(db.priv_trait_semantic_data(trait_id)?.resolver_data)
@@ -221,6 +280,8 @@ pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Ma
 
     let attributes = trait_ast.attributes(syntax_db).structurize(syntax_db);
     let mut function_asts = OrderedHashMap::default();
+    let mut type_asts = OrderedHashMap::default();
+    let mut constant_asts = OrderedHashMap::default();
     let mut trait_item_names = OrderedHashSet::default();
     if let ast::MaybeTraitBody::Some(body) = trait_ast.body(syntax_db) {
         for item in body.items(syntax_db).elements(syntax_db) {
@@ -240,6 +301,34 @@ pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Ma
                     }
                     function_asts.insert(trait_func_id, func);
                 }
+                ast::TraitItem::Type(ty) => {
+                    let trait_type_id =
+                        db.intern_trait_type(TraitTypeLongId(module_file_id, ty.stable_ptr()));
+                    if !trait_item_names.insert(trait_type_id.name(db.upcast())) {
+                        diagnostics.report_by_ptr(
+                            ty.name(syntax_db).stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes {
+                                name: trait_type_id.name(db.upcast()),
+                            },
+                        );
+                    }
+                    type_asts.insert(trait_type_id, ty);
+                }
+                ast::TraitItem::Constant(constant) => {
+                    let trait_constant_id = db.intern_trait_constant(TraitConstantLongId(
+                        module_file_id,
+                        constant.stable_ptr(),
+                    ));
+                    if !trait_item_names.insert(trait_constant_id.name(db.upcast())) {
+                        diagnostics.report_by_ptr(
+                            constant.name(syntax_db).stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes {
+                                name: trait_constant_id.name(db.upcast()),
+                            },
+                        );
+                    }
+                    constant_asts.insert(trait_constant_id, constant);
+                }
                 ast::TraitItem::Missing(_) => {}
             }
         }
@@ -264,6 +353,8 @@ pub fn priv_trait_semantic_data(db: &dyn SemanticGroup, trait_id: TraitId) -> Ma
         generic_params,
         attributes,
         function_asts,
+        type_asts,
+        constant_asts,
         resolver_data,
     })
 }
@@ -333,6 +424,7 @@ pub fn priv_trait_function_data(
     for generic_param in trait_generic_params {
         resolver.add_generic_param(generic_param);
     }
+    resolver.set_self_impl(trait_self_impl(db, trait_id)?);
     let function_generic_params = semantic_generic_params(
         db,
         &mut diagnostics,
@@ -412,6 +504,72 @@ pub fn concrete_trait_function_signature(
     SubstitutionRewriter { db, substitution: &substitution }.rewrite(generic_signature)
 }
 
+// Trait constant.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct TraitConstantData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    ty: TypeId,
+    resolver_data: Arc<ResolverData>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_diagnostics].
+pub fn trait_constant_diagnostics(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_trait_constant_data(trait_constant_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_type].
+pub fn trait_constant_type(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TypeId> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.ty)
+}
+/// Query implementation of [crate::db::SemanticGroup::concrete_trait_constant_type].
+pub fn concrete_trait_constant_type(
+    db: &dyn SemanticGroup,
+    concrete_trait_id: ConcreteTraitId,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TypeId> {
+    let substitution = GenericSubstitution::new(
+        &db.trait_generic_params(concrete_trait_id.trait_id(db))?,
+        &concrete_trait_id.generic_args(db),
+    );
+    let generic_ty = db.trait_constant_type(trait_constant_id)?;
+    SubstitutionRewriter { db, substitution: &substitution }.rewrite(generic_ty)
+}
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_resolver_data].
+pub fn trait_constant_resolver_data(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Arc<ResolverData>> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.resolver_data)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_trait_constant_data].
+pub fn priv_trait_constant_data(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TraitConstantData> {
+    let syntax_db = db.upcast();
+    let module_file_id = trait_constant_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let trait_id = trait_constant_id.trait_id(db.upcast());
+    let data = db.priv_trait_semantic_data(trait_id)?;
+    let constant_syntax = &data.constant_asts[trait_constant_id];
+    let mut resolver = Resolver::new(db, module_file_id);
+    for generic_param in db.trait_generic_params(trait_id)? {
+        resolver.add_generic_param(generic_param);
+    }
+    let ty_syntax = constant_syntax.type_clause(syntax_db).ty(syntax_db);
+    let ty = resolve_type(db, &mut diagnostics, &mut resolver, &ty_syntax);
+
+    let resolver_data = Arc::new(resolver.data);
+    Ok(TraitConstantData { diagnostics: diagnostics.build(), ty, resolver_data })
+}
+
 fn validate_trait_function_signature(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
@@ -1,7 +1,14 @@
-use cairo_lang_defs::ids::{ModuleId, TraitFunctionId};
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{
+    LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, TraitFunctionId,
+};
+use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_syntax::node::ast;
 
 use crate::db::SemanticGroup;
+use crate::resolve::ResolvedGenericItem;
 use crate::types::TypeHead;
 
 /// A filter for types.
@@ -64,3 +71,36 @@ pub fn methods_in_crate(
     }
     result
 }
+
+/// Query implementation of [crate::db::SemanticGroup::module_usages].
+pub fn module_usages(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Maybe<Arc<Vec<(ModuleFileId, ast::TerminalIdentifierPtr, ResolvedGenericItem)>>> {
+    let mut usages = vec![];
+    let mut add_lookup_item = |lookup_item_id: LookupItemId| {
+        let module_file_id = lookup_item_id.module_file_id(db.upcast());
+        for (identifier, item) in db.lookup_resolved_generic_items(lookup_item_id) {
+            usages.push((module_file_id, identifier, item));
+        }
+    };
+    for module_item in db.module_items(module_id)?.iter() {
+        add_lookup_item(LookupItemId::ModuleItem(*module_item));
+        match module_item {
+            ModuleItemId::Trait(trait_id) => {
+                for trait_function_id in db.trait_functions(*trait_id).unwrap_or_default().values()
+                {
+                    add_lookup_item(LookupItemId::TraitFunction(*trait_function_id));
+                }
+            }
+            ModuleItemId::Impl(impl_def_id) => {
+                for impl_function_id in db.impl_functions(*impl_def_id).unwrap_or_default().values()
+                {
+                    add_lookup_item(LookupItemId::ImplFunction(*impl_function_id));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(Arc::new(usages))
+}
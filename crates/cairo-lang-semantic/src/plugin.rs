@@ -27,6 +27,11 @@ pub trait PluginAuxData:
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn Any,
     ) -> Option<PluginMappedDiagnostic>;
+
+    /// Maps a span in the generated file to the span of the code it was copied from, if any.
+    fn map_span(&self, _db: &(dyn SemanticGroup + 'static), _span: TextSpan) -> Option<TextSpan> {
+        None
+    }
 }
 pub trait AsDynGeneratedFileAuxData {
     fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static);
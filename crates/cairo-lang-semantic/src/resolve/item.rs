@@ -7,7 +7,7 @@ use cairo_lang_proc_macros::DebugWithDb;
 
 use crate::db::SemanticGroup;
 use crate::items::functions::GenericFunctionId;
-use crate::items::imp::ImplId;
+use crate::items::imp::{ImplConstantId, ImplId};
 use crate::items::trt::ConcreteTraitGenericFunctionId;
 use crate::items::us::SemanticUseEx;
 use crate::{ConcreteTraitId, ConcreteVariant, FunctionId, TypeId, TypeLongId, Variant};
@@ -16,7 +16,7 @@ use crate::{ConcreteTraitId, ConcreteVariant, FunctionId, TypeId, TypeLongId, Va
 // ResolvedConcreteItem - returned by resolve_concrete_path(). Paths with generic arguments.
 // ResolvedGenericItem - returned by resolve_generic_path(). Paths without generic arguments.
 
-#[derive(Clone, PartialEq, Eq, Debug, DebugWithDb)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub enum ResolvedGenericItem {
     Constant(ConstantId),
@@ -90,6 +90,7 @@ pub enum ResolvedConcreteItem {
     Variant(ConcreteVariant),
     Trait(ConcreteTraitId),
     Impl(ImplId),
+    ImplConstant(ImplConstantId),
 }
 impl ResolvedConcreteItem {
     pub fn generic(&self, db: &dyn SemanticGroup) -> Option<ResolvedGenericItem> {
@@ -124,8 +125,11 @@ impl ResolvedConcreteItem {
                 ImplId::Concrete(concrete_impl_id) => ResolvedGenericItem::Impl(
                     db.lookup_intern_concrete_impl(*concrete_impl_id).impl_def_id,
                 ),
-                ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+                ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::SelfImpl(_) => {
+                    return None;
+                }
             },
+            ResolvedConcreteItem::ImplConstant(_) => return None,
         })
     }
 }
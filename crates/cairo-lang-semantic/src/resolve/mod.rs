@@ -27,7 +27,9 @@ use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::{Inference, InferenceData};
 use crate::items::enm::SemanticEnumEx;
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
-use crate::items::imp::{ConcreteImplId, ConcreteImplLongId, ImplId, ImplLookupContext};
+use crate::items::imp::{
+    ConcreteImplId, ConcreteImplLongId, ImplConstantId, ImplId, ImplLookupContext, ImplTypeId,
+};
 use crate::items::trt::{ConcreteTraitGenericFunctionLongId, ConcreteTraitId, ConcreteTraitLongId};
 use crate::literals::LiteralLongId;
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
@@ -49,7 +51,9 @@ pub mod scope;
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ResolvedItems {
     pub concrete: UnorderedHashMap<ast::TerminalIdentifierPtr, ResolvedConcreteItem>,
-    pub generic: UnorderedHashMap<ast::TerminalIdentifierPtr, ResolvedGenericItem>,
+    // Ordered, so the usages of items can be iterated deterministically, e.g. when finding
+    // references.
+    pub generic: OrderedHashMap<ast::TerminalIdentifierPtr, ResolvedGenericItem>,
 }
 impl ResolvedItems {
     // Relates a path segment to a ResolvedConcreteItem, and adds to a resolved_items map. This will
@@ -93,6 +97,8 @@ pub struct ResolverData {
     pub resolved_items: ResolvedItems,
     /// Inference data for the resolver.
     pub inference_data: InferenceData,
+    /// The impl `Self` refers to, inside trait and impl items.
+    self_impl: Option<ImplId>,
 }
 impl ResolverData {
     pub fn new(module_file_id: ModuleFileId) -> Self {
@@ -101,6 +107,7 @@ impl ResolverData {
             generic_params: Default::default(),
             resolved_items: Default::default(),
             inference_data: Default::default(),
+            self_impl: None,
         }
     }
 }
@@ -140,15 +147,7 @@ impl AsSegments for Vec<ast::PathSegment> {
 
 impl<'db> Resolver<'db> {
     pub fn new(db: &'db dyn SemanticGroup, module_file_id: ModuleFileId) -> Self {
-        Self {
-            db,
-            data: ResolverData {
-                module_file_id,
-                generic_params: Default::default(),
-                resolved_items: Default::default(),
-                inference_data: Default::default(),
-            },
-        }
+        Self { db, data: ResolverData::new(module_file_id) }
     }
 
     pub fn with_data(db: &'db dyn SemanticGroup, data: ResolverData) -> Self {
@@ -167,6 +166,11 @@ impl<'db> Resolver<'db> {
         self.generic_params.insert(generic_param.id().name(db), generic_param);
     }
 
+    /// Sets the impl that `Self` refers to, for resolving items of traits and impls.
+    pub fn set_self_impl(&mut self, impl_id: ImplId) {
+        self.self_impl = Some(impl_id);
+    }
+
     /// Resolves a concrete item, given a path.
     /// Guaranteed to result in at most one diagnostic.
     pub fn resolve_concrete_path(
@@ -396,9 +400,36 @@ impl<'db> Resolver<'db> {
                 }
             }
             ResolvedConcreteItem::Trait(concrete_trait_id) => {
-                // Find the relevant function in the trait.
                 let long_trait_id = self.db.lookup_intern_concrete_trait(*concrete_trait_id);
                 let trait_id = long_trait_id.trait_id;
+                // Associated types and constants are resolved through an inferred impl of the
+                // trait.
+                if self.db.trait_type_by_name(trait_id, ident.clone())?.is_some()
+                    || self.db.trait_constant_by_name(trait_id, ident.clone())?.is_some()
+                {
+                    let impl_lookup_context = self.impl_lookup_context();
+                    let impl_id = self
+                        .inference()
+                        .new_impl_var(
+                            *concrete_trait_id,
+                            identifier.stable_ptr().untyped(),
+                            impl_lookup_context,
+                        )
+                        .map_err(|err| {
+                            err.report(diagnostics, identifier.stable_ptr().untyped())
+                        })?;
+                    return self
+                        .resolve_impl_type_or_constant(
+                            diagnostics,
+                            impl_id,
+                            trait_id,
+                            identifier,
+                            &generic_args_syntax,
+                        )?
+                        .ok_or_else(|| diagnostics.report(identifier, InvalidPath));
+                }
+
+                // Find the relevant function in the trait.
                 let Some(trait_function_id) = self.db.trait_function_by_name(trait_id, ident)? else {
                     return Err(diagnostics.report(identifier, InvalidPath));
                 };
@@ -430,8 +461,17 @@ impl<'db> Resolver<'db> {
                 )?))
             }
             ResolvedConcreteItem::Impl(impl_id) => {
-                let concrete_trait_id = self.db.impl_concrete_trait(*impl_id)?;
+                let concrete_trait_id = self.impl_concrete_trait(*impl_id)?;
                 let trait_id = concrete_trait_id.trait_id(self.db);
+                if let Some(item) = self.resolve_impl_type_or_constant(
+                    diagnostics,
+                    *impl_id,
+                    trait_id,
+                    identifier,
+                    &generic_args_syntax,
+                )? {
+                    return Ok(item);
+                }
                 let Some(trait_function_id) = self.db.trait_function_by_name(
                     trait_id, ident,
                 )? else {
@@ -453,6 +493,49 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Returns the concrete trait of an impl. Impl generic params of the resolver are handled
+    /// directly, as querying them may cycle while the item declaring them is computed.
+    fn impl_concrete_trait(&self, impl_id: ImplId) -> Maybe<ConcreteTraitId> {
+        if let ImplId::GenericParameter(param_id) = impl_id {
+            if let Some(GenericParam::Impl(param)) =
+                self.generic_params.values().find(|param| param.id() == param_id)
+            {
+                return param.concrete_trait;
+            }
+        }
+        self.db.impl_concrete_trait(impl_id)
+    }
+
+    /// Resolves an associated type or constant of the trait of the given impl.
+    /// Returns None if the trait has no such item.
+    fn resolve_impl_type_or_constant(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        impl_id: ImplId,
+        trait_id: TraitId,
+        identifier: &ast::TerminalIdentifier,
+        generic_args_syntax: &Option<Vec<ast::GenericArg>>,
+    ) -> Maybe<Option<ResolvedConcreteItem>> {
+        let ident = identifier.text(self.db.upcast());
+        let item = if let Some(trait_type_id) =
+            self.db.trait_type_by_name(trait_id, ident.clone())?
+        {
+            let impl_type_id = ImplTypeId::new(impl_id, trait_type_id);
+            let ty = impl_type_id
+                .concrete_type(self.db)
+                .unwrap_or_else(|| self.db.intern_type(TypeLongId::ImplType(impl_type_id)));
+            ResolvedConcreteItem::Type(ty)
+        } else if let Some(trait_constant_id) = self.db.trait_constant_by_name(trait_id, ident)? {
+            ResolvedConcreteItem::ImplConstant(ImplConstantId::new(impl_id, trait_constant_id))
+        } else {
+            return Ok(None);
+        };
+        if generic_args_syntax.is_some() {
+            return Err(diagnostics.report(identifier, UnexpectedGenericArgs));
+        }
+        Ok(Some(item))
+    }
+
     /// Specializes a ResolvedGenericItem that came from a ModuleItem.
     fn specialize_generic_module_item(
         &mut self,
@@ -597,6 +680,12 @@ impl<'db> Resolver<'db> {
             return Some(item);
         }
 
+        if ident == "Self" {
+            if let Some(self_impl) = self.self_impl {
+                return Some(ResolvedConcreteItem::Impl(self_impl));
+            }
+        }
+
         // TODO(spapini): Resolve local variables.
 
         None
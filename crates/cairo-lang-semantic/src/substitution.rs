@@ -2,7 +2,8 @@ use std::ops::Deref;
 
 use cairo_lang_defs::ids::{
     EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId, ImplAliasId, ImplDefId,
-    ImplFunctionId, LocalVarId, MemberId, ParamId, StructId, TraitFunctionId, TraitId, VariantId,
+    ImplFunctionId, LocalVarId, MemberId, ParamId, StructId, TraitConstantId, TraitFunctionId,
+    TraitId, TraitTypeId, VariantId,
 };
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_utils::extract_matches;
@@ -16,7 +17,7 @@ use crate::items::functions::{
     GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
-use crate::items::imp::{ImplId, UninferredImpl};
+use crate::items::imp::{ImplConstantId, ImplId, ImplTypeId, UninferredImpl};
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
 use crate::types::{ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId};
@@ -175,6 +176,8 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__identitity_helper, ImplAliasId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, TraitId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, TraitFunctionId, $($exclude)*);
+        $crate::prune_single!(__identitity_helper, TraitTypeId, $($exclude)*);
+        $crate::prune_single!(__identitity_helper, TraitConstantId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, VariantId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, ImplFunctionId, $($exclude)*);
         $crate::prune_single!(__identitity_helper, EnumId, $($exclude)*);
@@ -219,6 +222,8 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ImplId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplTypeId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplConstantId, $($exclude)*);
         $crate::prune_single!(__regular_helper, UninferredImpl, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprVarMemberPath, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprVar, $($exclude)*);
@@ -250,11 +255,13 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, ExprIf, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprLoop, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprLiteral, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprStringLiteral, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMemberAccess, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprStructCtor, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprEnumVariantCtor, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprPropagateError, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprConstant, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprImplConstant, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMissing, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprFunctionCallArg, $($exclude)*);
         $crate::prune_single!(__regular_helper, MatchArm, $($exclude)*);
@@ -266,6 +273,7 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, StatementBreak, $($exclude)*);
         $crate::prune_single!(__regular_helper, Pattern, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternLiteral, $($exclude)*);
+        $crate::prune_single!(__regular_helper, PatternRange, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternVariable, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternStruct, $($exclude)*);
         $crate::prune_single!(__regular_helper, PatternTuple, $($exclude)*);
@@ -273,7 +281,6 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, PatternOtherwise, $($exclude)*);
         $crate::prune_single!(__regular_helper, LocalVariable, $($exclude)*);
         $crate::prune_single!(__regular_helper, Member, $($exclude)*);
-        $crate::prune_single!(__regular_helper, Constant, $($exclude)*);
     };
 }
 
@@ -296,7 +303,7 @@ impl<'a> SemanticRewriter<TypeLongId, DiagnosticAdded> for SubstitutionRewriter<
                 return Ok(self.db.lookup_intern_type(type_id));
             }
         }
-        value.default_rewrite(self)
+        reduce_impl_type(self.db, value.default_rewrite(self)?)
     }
 }
 impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SubstitutionRewriter<'a> {
@@ -310,3 +317,38 @@ impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SubstitutionRewriter<'a>
         value.default_rewrite(self)
     }
 }
+
+/// Replaces an associated type of a concrete impl with the type the impl assigns to it.
+pub fn reduce_impl_type(db: &dyn SemanticGroup, ty: TypeLongId) -> Maybe<TypeLongId> {
+    if let TypeLongId::ImplType(impl_type_id) = ty {
+        if let Some(reduced) = impl_type_id.concrete_type(db) {
+            return Ok(db.lookup_intern_type(reduced));
+        }
+    }
+    Ok(ty)
+}
+
+/// A rewriter replacing the `Self` impl of a trait with a given impl.
+pub struct SelfImplRewriter<'a> {
+    pub db: &'a dyn SemanticGroup,
+    pub impl_id: ImplId,
+}
+impl<'a> HasDb<&'a dyn SemanticGroup> for SelfImplRewriter<'a> {
+    fn get_db(&self) -> &'a dyn SemanticGroup {
+        self.db
+    }
+}
+add_basic_rewrites!(<'a>, SelfImplRewriter<'a>, DiagnosticAdded, @exclude TypeLongId ImplId);
+impl<'a> SemanticRewriter<TypeLongId, DiagnosticAdded> for SelfImplRewriter<'a> {
+    fn rewrite(&mut self, value: TypeLongId) -> Maybe<TypeLongId> {
+        reduce_impl_type(self.db, value.default_rewrite(self)?)
+    }
+}
+impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SelfImplRewriter<'a> {
+    fn rewrite(&mut self, value: ImplId) -> Maybe<ImplId> {
+        if let ImplId::SelfImpl(_) = value {
+            return Ok(self.impl_id);
+        }
+        value.default_rewrite(self)
+    }
+}
@@ -12,7 +12,7 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::{InferenceResult, TypeVar};
-use crate::items::imp::{get_impl_at_context, ImplId, ImplLookupContext};
+use crate::items::imp::{get_impl_at_context, ImplId, ImplLookupContext, ImplTypeId};
 use crate::resolve::{ResolvedConcreteItem, Resolver};
 use crate::{semantic, semantic_object_for_id};
 
@@ -24,6 +24,8 @@ pub enum TypeLongId {
     Tuple(Vec<TypeId>),
     Snapshot(TypeId),
     GenericParameter(GenericParamId),
+    /// An associated type of a trait, as implemented by an impl that is not concrete yet.
+    ImplType(ImplTypeId),
     Var(TypeVar),
     Missing(#[dont_rewrite] DiagnosticAdded),
 }
@@ -83,6 +85,7 @@ impl TypeLongId {
             TypeLongId::GenericParameter(generic_param) => {
                 format!("{}", generic_param.name(db.upcast()))
             }
+            TypeLongId::ImplType(impl_type_id) => impl_type_id.format(db).into(),
             TypeLongId::Var(var) => format!("?{}", var.id),
             TypeLongId::Missing(_) => "<missing>".to_string(),
         }
@@ -94,7 +97,10 @@ impl TypeLongId {
             TypeLongId::Concrete(concrete) => TypeHead::Concrete(concrete.generic_type(db)),
             TypeLongId::Tuple(_) => TypeHead::Tuple,
             TypeLongId::Snapshot(inner) => TypeHead::Snapshot(Box::new(inner.head(db)?)),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Missing(_) => {
                 return None;
             }
         })
@@ -8,7 +8,7 @@ use itertools::zip_eq;
 use crate::core_libfunc_cost_base::{core_libfunc_postcost, core_libfunc_precost, CostOperations};
 pub use crate::core_libfunc_cost_base::{
     InvocationCostInfoProvider, DICT_SQUASH_FIXED_COST, DICT_SQUASH_REPEATED_ACCESS_COST,
-    DICT_SQUASH_UNIQUE_KEY_COST, SEGMENT_ARENA_ALLOCATION_COST,
+    DICT_SQUASH_UNIQUE_KEY_COST, FUNCTION_CALL_COST, SEGMENT_ARENA_ALLOCATION_COST,
 };
 use crate::gas_info::GasInfo;
 pub use crate::starknet_libfunc_cost_base::SYSTEM_CALL_COST;
@@ -3,6 +3,7 @@ use std::iter;
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
 use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
 use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{self, *};
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
@@ -40,6 +41,9 @@ use itertools::{chain, Itertools};
 use crate::objects::{BranchCost, ConstCost, CostInfoProvider, PreCost};
 use crate::starknet_libfunc_cost_base::starknet_libfunc_cost_base;
 
+/// The cost of a function call, not including the cost of the called function.
+pub const FUNCTION_CALL_COST: ConstCost = ConstCost { steps: 2, holes: 0, range_checks: 0 };
+
 /// The cost per each unique key in the dictionary. This cost is pre-charged for each access
 /// (read/write/entry), and the overhead cost is refunded for each repeated access.
 /// Repeated access is access to a key that has already been accessed before.
@@ -126,7 +130,10 @@ pub fn core_libfunc_cost(
         |token_type| PreCost(OrderedHashMap::from_iter((vec![(token_type, 1)]).into_iter()));
     match libfunc {
         FunctionCall(FunctionCallConcreteLibfunc { function, .. }) => {
-            vec![BranchCost::FunctionCall { const_cost: steps(2), function: function.clone() }]
+            vec![BranchCost::FunctionCall {
+                const_cost: FUNCTION_CALL_COST,
+                function: function.clone(),
+            }]
         }
         Bitwise(_) => {
             vec![BranchCost::Regular {
@@ -142,6 +149,14 @@ pub fn core_libfunc_cost(
             BoolConcreteLibfunc::Equal(_) => vec![steps(2).into(), steps(3).into()],
             BoolConcreteLibfunc::ToFelt252(_) => vec![steps(0).into()],
         },
+        Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![steps(0).into()]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+                vec![(steps(7) + range_checks(3)).into(), (steps(9) + range_checks(3)).into()]
+            }
+        },
         Cast(libfunc) => match libfunc {
             CastConcreteLibfunc::Downcast(_) => {
                 vec![(steps(3) + range_checks(1)).into(), (steps(4) + range_checks(1)).into()]
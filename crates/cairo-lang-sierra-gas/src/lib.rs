@@ -28,6 +28,7 @@ mod core_libfunc_cost_expr;
 mod cost_expr;
 pub mod gas_info;
 mod generate_equations;
+pub mod libfunc_cost_estimate;
 pub mod objects;
 mod starknet_libfunc_cost_base;
 
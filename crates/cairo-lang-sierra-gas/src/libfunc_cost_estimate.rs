@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+
+use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::lib_func::{
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use cairo_lang_sierra::extensions::type_specialization_context::TypeSpecializationContext;
+use cairo_lang_sierra::extensions::types::TypeInfo;
+use cairo_lang_sierra::extensions::{ConcreteType, GenericLibfuncEx, GenericTypeEx};
+use cairo_lang_sierra::ids::{ConcreteTypeId, FunctionId, GenericLibfuncId, GenericTypeId};
+use cairo_lang_sierra::program::{ConcreteTypeLongId, Function, FunctionSignature, GenericArg};
+use cairo_lang_utils::casts::IntoOrPanic;
+
+use crate::core_libfunc_cost_base::core_libfunc_cost;
+use crate::objects::{BranchCost, ConstCost, CostInfoProvider};
+
+#[cfg(test)]
+#[path = "libfunc_cost_estimate_test.rs"]
+mod test;
+
+/// Returns an estimate of the cost of invoking the libfunc with the given generic id and no generic
+/// arguments, without a Sierra program: the constant cost of its most expensive branch.
+/// Useful before Sierra generation, e.g. to decide whether to inline a function.
+///
+/// Returns None if the libfunc is unknown, or can not be specialized without generic arguments.
+pub fn estimate_libfunc_cost(libfunc_id: &GenericLibfuncId) -> Option<ConstCost> {
+    let context = StandaloneSpecializationContext::default();
+    let libfunc = CoreLibfunc::specialize_by_id(&context, libfunc_id, &[]).ok()?;
+    core_libfunc_cost(&libfunc, &context)
+        .into_iter()
+        .filter_map(|branch_cost| match branch_cost {
+            BranchCost::Regular { const_cost, .. } | BranchCost::WithdrawGas { const_cost, .. } => {
+                Some(const_cost)
+            }
+            BranchCost::FunctionCall { .. }
+            | BranchCost::BranchAlign
+            | BranchCost::RedepositGas => None,
+        })
+        .max_by_key(|const_cost| const_cost.cost())
+}
+
+/// A specialization context that declares every concrete type it is asked for, for specializing
+/// libfuncs outside of a Sierra program. User functions are not supported.
+#[derive(Default)]
+struct StandaloneSpecializationContext {
+    /// The declared concrete types, where the id of a type is its index.
+    types: RefCell<Vec<ConcreteTypeLongId>>,
+}
+impl TypeSpecializationContext for StandaloneSpecializationContext {
+    fn try_get_type_info(&self, id: ConcreteTypeId) -> Option<TypeInfo> {
+        let long_id = self.types.borrow().get(id.id as usize)?.clone();
+        let concrete_type =
+            CoreType::specialize_by_id(self, &long_id.generic_id, &long_id.generic_args).ok()?;
+        Some(concrete_type.info().clone())
+    }
+}
+impl SignatureSpecializationContext for StandaloneSpecializationContext {
+    fn try_get_concrete_type(
+        &self,
+        id: GenericTypeId,
+        generic_args: &[GenericArg],
+    ) -> Option<ConcreteTypeId> {
+        let long_id = ConcreteTypeLongId { generic_id: id, generic_args: generic_args.to_vec() };
+        let mut types = self.types.borrow_mut();
+        let index = match types.iter().position(|declared| *declared == long_id) {
+            Some(index) => index,
+            None => {
+                types.push(long_id);
+                types.len() - 1
+            }
+        };
+        Some(ConcreteTypeId::new(index as u64))
+    }
+
+    fn try_get_function_signature(&self, _function_id: &FunctionId) -> Option<FunctionSignature> {
+        None
+    }
+
+    fn try_get_function_ap_change(&self, _function_id: &FunctionId) -> Option<SierraApChange> {
+        None
+    }
+
+    fn as_type_specialization_context(&self) -> &dyn TypeSpecializationContext {
+        self
+    }
+}
+impl SpecializationContext for StandaloneSpecializationContext {
+    fn upcast(&self) -> &dyn SignatureSpecializationContext {
+        self
+    }
+
+    fn try_get_function(&self, _function_id: &FunctionId) -> Option<Function> {
+        None
+    }
+}
+impl CostInfoProvider for StandaloneSpecializationContext {
+    fn type_size(&self, ty: &ConcreteTypeId) -> usize {
+        self.try_get_type_info(ty.clone()).map_or(1, |info| info.size.into_or_panic())
+    }
+}
@@ -0,0 +1,13 @@
+use test_case::test_case;
+
+use super::estimate_libfunc_cost;
+use crate::objects::ConstCost;
+
+#[test_case("felt252_add" => Some(ConstCost { steps: 0, holes: 0, range_checks: 0 }); "felt252_add")]
+#[test_case("felt252_div" => Some(ConstCost { steps: 5, holes: 0, range_checks: 0 }); "felt252_div")]
+#[test_case("u128_overflowing_add" => Some(ConstCost { steps: 5, holes: 0, range_checks: 1 }); "u128_overflowing_add")]
+#[test_case("store_temp" => None; "generic libfunc")]
+#[test_case("not_a_libfunc" => None; "unknown libfunc")]
+fn test_estimate_libfunc_cost(libfunc_id: &str) -> Option<ConstCost> {
+    estimate_libfunc_cost(&libfunc_id.into())
+}
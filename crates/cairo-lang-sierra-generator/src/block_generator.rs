@@ -257,6 +257,9 @@ pub fn generate_statement_code(
         lowering::Statement::Desnap(statement) => {
             generate_statement_desnap(context, statement, statement_location)
         }
+        lowering::Statement::ImplConstant(_) => {
+            panic!("Impl constants should have been replaced during concretization.")
+        }
     }
 }
 
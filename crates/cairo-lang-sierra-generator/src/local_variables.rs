@@ -295,6 +295,9 @@ impl<'a> FindLocalsContext<'a> {
                 self.non_ap_based.insert(statement_literal.output);
                 BranchInfo { known_ap_change: true }
             }
+            lowering::Statement::ImplConstant(_) => {
+                panic!("Impl constants should have been replaced during concretization.")
+            }
             lowering::Statement::Call(statement_call) => {
                 let (_, concrete_function_id) =
                     get_concrete_libfunc_id(self.db, statement_call.function);
@@ -21,6 +21,7 @@ fn test_program_generator() {
                     bar(5)
                 }
 
+                #[inline(never)]
                 fn bar(a: felt252) -> felt252 {
                     felt252_add(felt252_add(a, a), a)
                 }
@@ -114,10 +115,15 @@ fn test_type_dependency() {
 #[test_case("f6", &["test::f6"]; "self loop")]
 fn test_only_include_dependencies(func_name: &str, sierra_used_funcs: &[&str]) {
     let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        #[inline(never)]
         fn f1() { f2(); f3(); }
+        #[inline(never)]
         fn f2() { f3(); f4(); f5(); }
+        #[inline(never)]
         fn f3() { f5(); }
+        #[inline(never)]
         fn f4() { f5(); f6(); }
+        #[inline(never)]
         fn f5() { f6(); }
         fn f6() { f6(); }
     "});
@@ -96,6 +96,7 @@ pub fn get_concrete_type_id(
             Ok(snapshot_ty(&SierraSignatureSpecializationContext(db), inner_ty).unwrap())
         }
         semantic::TypeLongId::GenericParameter(_)
+        | semantic::TypeLongId::ImplType(_)
         | semantic::TypeLongId::Var(_)
         | semantic::TypeLongId::Missing(_) => {
             panic!(
@@ -0,0 +1,21 @@
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use num_bigint::BigInt;
+
+use super::misc::{build_bounded_try_from_felt252, build_identity, build_single_cell_const};
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+
+/// Builds instructions for Sierra bytes31 operations.
+pub fn build(
+    libfunc: &Bytes31ConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(libfunc) => {
+            build_single_cell_const(builder, libfunc.c.clone())
+        }
+        Bytes31ConcreteLibfunc::ToFelt252(_) => build_identity(builder),
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+            build_bounded_try_from_felt252(builder, BigInt::from(1) << 248)
+        }
+    }
+}
@@ -1,14 +1,15 @@
+use cairo_felt::Felt252;
 use cairo_lang_casm::builder::{CasmBuilder, Var};
 use cairo_lang_casm::cell_expression::CellExpression;
 use cairo_lang_casm::{casm, casm_build_extend};
 use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
 use cairo_lang_sierra_gas::objects::ConstCost;
 use itertools::Itertools;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, ToBigInt};
 
 use super::{
     get_non_fallthrough_statement_id, CompiledInvocation, CompiledInvocationBuilder,
-    InvocationError,
+    CostValidationInfo, InvocationError,
 };
 use crate::invocations::add_input_variables;
 use crate::references::ReferenceExpression;
@@ -36,6 +37,52 @@ pub fn build_drop(
     Ok(builder.build_only_reference_changes([].into_iter()))
 }
 
+/// Builds a libfunc that tries to convert a felt252 to type with values in the range[0, bound).
+pub fn build_bounded_try_from_felt252(
+    builder: CompiledInvocationBuilder<'_>,
+    bound: BigInt,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    casm_build_extend! {casm_builder,
+        const limit = bound.clone();
+        let orig_range_check = range_check;
+        tempvar is_valid;
+        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid};
+        jump IsValid if is_valid != 0;
+        tempvar shifted_value = value - limit;
+    }
+    validate_under_limit::<1>(
+        &mut casm_builder,
+        &(Felt252::prime().to_bigint().unwrap() - bound.clone()),
+        shifted_value,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        jump Failure;
+        IsValid:
+    };
+    validate_under_limit::<1>(&mut casm_builder, &bound, value, range_check, &auxiliary_vars);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
 /// Handles a const single cell immediate value libfunc.
 pub fn build_single_cell_const(
     builder: CompiledInvocationBuilder<'_>,
@@ -34,6 +34,7 @@ mod array;
 mod bitwise;
 mod boolean;
 mod boxing;
+mod bytes31;
 mod casts;
 mod debug;
 mod ec;
@@ -575,6 +576,7 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Felt252(libfunc) => felt252::build(libfunc, builder),
         CoreConcreteLibfunc::Bitwise(_) => bitwise::build(builder),
         CoreConcreteLibfunc::Bool(libfunc) => boolean::build(libfunc, builder),
+        CoreConcreteLibfunc::Bytes31(libfunc) => bytes31::build(libfunc, builder),
         CoreConcreteLibfunc::Cast(libfunc) => casts::build(libfunc, builder),
         CoreConcreteLibfunc::Ec(libfunc) => ec::build(libfunc, builder),
         CoreConcreteLibfunc::Uint8(libfunc) => {
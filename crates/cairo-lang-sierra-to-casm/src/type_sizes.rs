@@ -21,6 +21,7 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::GasBuiltin(_)
             | CoreTypeConcrete::Bitwise(_)
             | CoreTypeConcrete::BuiltinCosts(_)
+            | CoreTypeConcrete::Bytes31(_)
             | CoreTypeConcrete::EcOp(_)
             | CoreTypeConcrete::Nullable(_)
             | CoreTypeConcrete::Uint8(_)
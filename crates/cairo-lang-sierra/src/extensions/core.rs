@@ -3,6 +3,7 @@ use super::array::{ArrayLibfunc, ArrayType};
 use super::bitwise::{BitwiseLibfunc, BitwiseType};
 use super::boolean::BoolLibfunc;
 use super::branch_align::BranchAlignLibfunc;
+use super::bytes31::{Bytes31Libfunc, Bytes31Type};
 use super::casts::CastLibfunc;
 use super::debug::DebugLibfunc;
 use super::drop::DropLibfunc;
@@ -50,6 +51,7 @@ define_type_hierarchy! {
         Felt252(Felt252Type),
         GasBuiltin(GasBuiltinType),
         BuiltinCosts(BuiltinCostsType),
+        Bytes31(Bytes31Type),
         Uint8(Uint8Type),
         Uint16(Uint16Type),
         Uint32(Uint32Type),
@@ -81,6 +83,7 @@ define_libfunc_hierarchy! {
         BranchAlign(BranchAlignLibfunc),
         Bool(BoolLibfunc),
         Box(BoxLibfunc),
+        Bytes31(Bytes31Libfunc),
         Cast(CastLibfunc),
         Drop(DropLibfunc),
         Dup(DupLibfunc),
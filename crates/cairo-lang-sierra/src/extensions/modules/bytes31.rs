@@ -0,0 +1,83 @@
+use num_bigint::BigInt;
+
+use super::consts::{ConstGenLibfunc, WrapConstGenLibfunc};
+use super::felt252::Felt252Type;
+use super::try_from_felt252::{TryFromFelt252, TryFromFelt252Libfunc};
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, ParamSignature, SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::GenericTypeId;
+
+/// Type for bytes31, a word of 31 bytes - a value in the range [0, 2 ** 248).
+#[derive(Default)]
+pub struct Bytes31Type {}
+impl NoGenericArgsGenericType for Bytes31Type {
+    const ID: GenericTypeId = GenericTypeId::new_inline("bytes31");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+
+define_libfunc_hierarchy! {
+    pub enum Bytes31Libfunc {
+        Const(Bytes31ConstLibfunc),
+        ToFelt252(Bytes31ToFelt252Libfunc),
+        TryFromFelt252(Bytes31FromFelt252Libfunc),
+    }, Bytes31ConcreteLibfunc
+}
+
+/// Libfunc for creating a constant bytes31.
+#[derive(Default)]
+pub struct Bytes31ConstLibfuncWrapped {}
+impl ConstGenLibfunc for Bytes31ConstLibfuncWrapped {
+    const STR_ID: &'static str = "bytes31_const";
+    const GENERIC_TYPE_ID: GenericTypeId = <Bytes31Type as NoGenericArgsGenericType>::ID;
+
+    fn bound() -> BigInt {
+        BigInt::from(2).pow(248)
+    }
+}
+
+pub type Bytes31ConstLibfunc = WrapConstGenLibfunc<Bytes31ConstLibfuncWrapped>;
+
+/// Libfunc for converting a bytes31 into a felt252.
+#[derive(Default)]
+pub struct Bytes31ToFelt252Libfunc {}
+impl NoGenericArgsGenericLibfunc for Bytes31ToFelt252Libfunc {
+    const STR_ID: &'static str = "bytes31_to_felt252";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(Bytes31Type::id(), &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for attempting to convert a felt252 into a bytes31.
+#[derive(Default)]
+pub struct Bytes31FromFelt252Trait;
+impl TryFromFelt252 for Bytes31FromFelt252Trait {
+    const STR_ID: &'static str = "bytes31_try_from_felt252";
+    const GENERIC_TYPE_ID: GenericTypeId = <Bytes31Type as NoGenericArgsGenericType>::ID;
+}
+
+pub type Bytes31FromFelt252Libfunc = TryFromFelt252Libfunc<Bytes31FromFelt252Trait>;
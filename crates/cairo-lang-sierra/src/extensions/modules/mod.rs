@@ -12,6 +12,7 @@ pub mod bitwise;
 pub mod boolean;
 pub mod boxing;
 pub mod branch_align;
+pub mod bytes31;
 pub mod casts;
 pub mod consts;
 pub mod debug;
@@ -48,6 +48,7 @@ impl TypeSpecializationContext for MockSpecializationContext {
             || id == "U128AndFelt252".into()
             || id == "StorageAddress".into()
             || id == "ContractAddress".into()
+            || id == "bytes31".into()
         {
             Some(TypeInfo {
                 long_id: self.mapping.get_by_left(&id)?.clone(),
@@ -252,6 +253,8 @@ fn find_type_specialization(
 #[test_case("contract_address_const", vec![value_arg(8)] => Ok(()); "contract_address_const<8>")]
 #[test_case("contract_address_const", vec![] => Err(UnsupportedGenericArg);
 "contract_address_const")]
+#[test_case("bytes31_const", vec![value_arg(8)] => Ok(()); "bytes31_const<8>")]
+#[test_case("bytes31_const", vec![] => Err(UnsupportedGenericArg); "bytes31_const")]
 #[test_case("drop", vec![type_arg("u128")] => Ok(()); "drop<u128>")]
 #[test_case("drop", vec![] => Err(WrongNumberOfGenericArgs); "drop<>")]
 #[test_case("drop", vec![type_arg("GasBuiltin")] => Err(UnsupportedGenericArg);
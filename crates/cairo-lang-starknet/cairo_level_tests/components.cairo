@@ -0,0 +1,104 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+use result::ResultTrait;
+use starknet::ContractAddress;
+use starknet::class_hash::Felt252TryIntoClassHash;
+use starknet::contract_address::Felt252TryIntoContractAddress;
+use starknet::syscalls::deploy_syscall;
+use traits::TryInto;
+
+#[component]
+mod Ownable {
+    use starknet::ContractAddress;
+
+    struct Storage {
+        owner: ContractAddress, 
+    }
+
+    #[event]
+    fn OwnershipTransferred(previous_owner: ContractAddress, new_owner: ContractAddress) {}
+
+    fn initializer(owner: ContractAddress) {
+        owner::write(owner);
+    }
+
+    #[view]
+    fn get_owner() -> ContractAddress {
+        owner::read()
+    }
+
+    #[external]
+    fn transfer_ownership(new_owner: ContractAddress) {
+        let previous_owner = owner::read();
+        owner::write(new_owner);
+        OwnershipTransferred(previous_owner, new_owner);
+    }
+}
+
+#[contract]
+mod OwnableCounter {
+    use starknet::ContractAddress;
+    #[embed]
+    use super::Ownable;
+
+    struct Storage {
+        // Doesn't collide with the `owner` variable of the component.
+        owner: felt252,
+        counter: felt252,
+    }
+
+    #[constructor]
+    fn constructor(owner_: ContractAddress) {
+        Ownable::initializer(owner_);
+        owner::write('not the owner');
+    }
+
+    #[external]
+    fn increment() {
+        counter::write(counter::read() + 1);
+    }
+
+    #[view]
+    fn get_counter() -> felt252 {
+        counter::read()
+    }
+
+    #[view]
+    fn get_own_owner() -> felt252 {
+        owner::read()
+    }
+}
+
+#[abi]
+trait IOwnableCounter {
+    #[view]
+    fn get_owner() -> ContractAddress;
+    #[external]
+    fn transfer_ownership(new_owner: ContractAddress);
+    #[external]
+    fn increment();
+    #[view]
+    fn get_counter() -> felt252;
+    #[view]
+    fn get_own_owner() -> felt252;
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_embedded_component() {
+    let mut calldata = ArrayTrait::new();
+    calldata.append(10);
+    let (address, _) = deploy_syscall(
+        OwnableCounter::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let contract = IOwnableCounterDispatcher { contract_address: address };
+
+    assert(contract.get_owner() == 10.try_into().unwrap(), 'Wrong initial owner');
+    contract.transfer_ownership(20.try_into().unwrap());
+    assert(contract.get_owner() == 20.try_into().unwrap(), 'Wrong owner');
+    assert(contract.get_own_owner() == 'not the owner', 'Storage collision');
+
+    contract.increment();
+    assert(contract.get_counter() == 1, 'Wrong counter');
+}
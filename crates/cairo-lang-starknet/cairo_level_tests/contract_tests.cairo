@@ -17,10 +17,41 @@ mod TestContract {
     use traits::Into;
     use starknet::StorageAddress;
 
+    #[derive(Copy, Drop, starknet::StorageAccess)]
+    struct Fees {
+        rate: u8,
+        collector: felt252,
+    }
+
+    #[derive(Copy, Drop, starknet::StorageAccess)]
+    struct Config {
+        owner: felt252,
+        limit: u256,
+        fees: Fees,
+        enabled: bool,
+    }
+
     struct Storage {
         value: felt252,
         mapping: LegacyMap::<u128, bool>,
         large_mapping: LegacyMap::<u256, u256>,
+        allowances: Map::<u128, Map<u128, u256>>,
+        pair_mapping: Map::<(felt252, u8), felt252>,
+        byte_array_value: ByteArray,
+        config: Config,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct ValueChanged {
+        #[key]
+        old_value: felt252,
+        new_value: felt252,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        #[event]
+        ValueChanged: ValueChanged,
     }
 
     #[view]
@@ -45,6 +76,12 @@ mod TestContract {
         value::write(a);
     }
 
+    #[external]
+    fn change_value(a: felt252) {
+        emit(Event::ValueChanged(ValueChanged { old_value: value::read(), new_value: a }));
+        value::write(a);
+    }
+
     #[view]
     fn get_value() -> felt252 {
         value::read()
@@ -75,6 +112,56 @@ mod TestContract {
         large_mapping::read(key)
     }
 
+    #[external]
+    fn set_allowance(owner: u128, spender: u128, amount: u256) {
+        allowances::write(owner, spender, amount)
+    }
+
+    #[view]
+    fn get_allowance(owner: u128, spender: u128) -> u256 {
+        allowances::read(owner, spender)
+    }
+
+    #[external]
+    fn set_pair(key: (felt252, u8), value: felt252) {
+        pair_mapping::write(key, value)
+    }
+
+    #[view]
+    fn get_pair(key: (felt252, u8)) -> felt252 {
+        pair_mapping::read(key)
+    }
+
+    #[external]
+    fn set_byte_array(value: ByteArray) {
+        byte_array_value::write(value)
+    }
+
+    #[view]
+    fn get_byte_array() -> ByteArray {
+        byte_array_value::read()
+    }
+
+    fn set_config(value: Config) {
+        config::write(value)
+    }
+
+    fn get_config() -> Config {
+        config::read()
+    }
+
+    fn set_config_limit(limit: u256) {
+        config::limit::write(limit)
+    }
+
+    fn set_fee_rate(rate: u8) {
+        config::fees::rate::write(rate)
+    }
+
+    fn get_fee_rate() -> u8 {
+        config::fees::rate::read()
+    }
+
     #[view]
     fn test_storage_address(storage_address: StorageAddress) -> StorageAddress {
         storage_address
@@ -124,6 +211,14 @@ fn test_wrapper_array_arg_and_output() {
     assert(retdata.is_empty(), 'Array not empty');
 }
 
+#[test]
+#[available_gas(200000)]
+fn change_value_emitting_event() {
+    TestContract::set_value(4);
+    TestContract::change_value(5);
+    assert(TestContract::get_value() == 5, 'Wrong value');
+}
+
 #[test]
 #[available_gas(200000)]
 fn read_first_value() {
@@ -196,6 +291,119 @@ fn write_read_large_value() {
     assert(retdata.is_empty(), 'Array not empty');
 }
 
+#[test]
+#[available_gas(3000000)]
+fn write_read_nested_mapping() {
+    TestContract::set_allowance(1, 2, 100);
+    TestContract::set_allowance(2, 1, 200);
+    assert(TestContract::get_allowance(1, 2) == 100, 'Wrong allowance');
+    assert(TestContract::get_allowance(2, 1) == 200, 'Wrong swapped allowance');
+    assert(TestContract::get_allowance(1, 1) == 0, 'Wrong empty allowance');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn write_read_tuple_key_mapping() {
+    TestContract::set_pair((1, 2), 3);
+    TestContract::set_pair((2, 1), 4);
+    assert(TestContract::get_pair((1, 2)) == 3, 'Wrong value');
+    assert(TestContract::get_pair((2, 1)) == 4, 'Wrong swapped value');
+    assert(TestContract::get_pair((1, 1)) == 0, 'Wrong empty value');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn nested_mapping_address() {
+    // `starknet_keccak('allowances')`.
+    let base = 0xbf4c436d6f8521e5c6189511c75075de702ad597ce22c1786275e8e5167ec7;
+    let mut owner_input = ArrayTrait::new();
+    owner_input.append(base);
+    owner_input.append(1);
+    let mut spender_input = ArrayTrait::new();
+    spender_input.append(poseidon::poseidon_hash_span(owner_input.span()));
+    spender_input.append(2);
+    let expected = starknet::storage_base_address_from_felt252(
+        poseidon::poseidon_hash_span(spender_input.span())
+    );
+    let address = TestContract::allowances::address(1, 2);
+    let address: felt252 = starknet::storage_address_from_base(address).into();
+    let expected: felt252 = starknet::storage_address_from_base(expected).into();
+    assert(address == expected, 'Wrong address');
+}
+
+#[test]
+#[available_gas(300000)]
+fn read_byte_array_first_value() {
+    let mut retdata = TestContract::__external::get_byte_array(ArrayTrait::new().span());
+    let value: ByteArray = single_deserialize(ref retdata);
+    assert(value == Default::default(), 'Wrong result');
+    assert(retdata.is_empty(), 'Array not empty');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn write_read_byte_array_value() {
+    let value: ByteArray =
+        "A string that is long enough to span several storage words, and then some more.";
+    let mut args = ArrayTrait::new();
+    serde::Serde::serialize(@value, ref args);
+    let mut retdata = TestContract::__external::set_byte_array(args.span());
+    assert(retdata.is_empty(), 'Array not empty');
+    let mut retdata = TestContract::__external::get_byte_array(ArrayTrait::new().span());
+    let read_value: ByteArray = single_deserialize(ref retdata);
+    assert(read_value == value, 'Wrong result');
+    assert(retdata.is_empty(), 'Array not empty');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn write_read_storage_struct() {
+    let fees = TestContract::Fees { rate: 3_u8, collector: 4 };
+    TestContract::set_config(
+        TestContract::Config { owner: 1, limit: 2, fees: fees, enabled: true }
+    );
+    let config = TestContract::get_config();
+    assert(config.owner == 1, 'Wrong owner');
+    assert(config.limit == 2, 'Wrong limit');
+    assert(config.fees.rate == 3_u8, 'Wrong fee rate');
+    assert(config.fees.collector == 4, 'Wrong fee collector');
+    assert(config.enabled, 'Wrong enabled');
+    assert(TestContract::config::owner::read() == 1, 'Wrong member owner');
+    assert(TestContract::config::limit::read() == 2, 'Wrong member limit');
+    assert(TestContract::get_fee_rate() == 3_u8, 'Wrong member fee rate');
+    assert(TestContract::config::fees::collector::read() == 4, 'Wrong member fee collector');
+    assert(TestContract::config::enabled::read(), 'Wrong member enabled');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn write_storage_struct_member() {
+    let fees = TestContract::Fees { rate: 3_u8, collector: 4 };
+    TestContract::set_config(
+        TestContract::Config { owner: 1, limit: 2, fees: fees, enabled: true }
+    );
+    TestContract::set_config_limit(5);
+    TestContract::set_fee_rate(6_u8);
+    let config = TestContract::get_config();
+    assert(config.owner == 1, 'Wrong owner');
+    assert(config.limit == 5, 'Wrong limit');
+    assert(config.fees.rate == 6_u8, 'Wrong fee rate');
+    assert(config.fees.collector == 4, 'Wrong fee collector');
+    assert(config.enabled, 'Wrong enabled');
+}
+
+#[test]
+#[available_gas(3000000)]
+fn storage_struct_member_offsets() {
+    assert(TestContract::config::owner::offset() == 0_u8, 'Wrong owner offset');
+    assert(TestContract::config::limit::offset() == 1_u8, 'Wrong limit offset');
+    assert(TestContract::config::fees::offset() == 3_u8, 'Wrong fees offset');
+    assert(TestContract::config::fees::rate::offset() == 3_u8, 'Wrong fee rate offset');
+    assert(TestContract::config::fees::collector::offset() == 4_u8, 'Wrong fee collector offset');
+    assert(TestContract::config::enabled::offset() == 5_u8, 'Wrong enabled offset');
+    assert(starknet::StorageAccess::<TestContract::Config>::size_internal() == 6_u8, 'Wrong size');
+}
+
 #[test]
 #[available_gas(300000)]
 fn test_get_block_info() {
@@ -309,6 +517,7 @@ fn test_storage_address() {
 
 #[derive(starknet::Event, PartialEq, Drop, Clone, Serde)]
 struct MyEventStruct {
+    #[key]
     x: felt252,
     data: usize,
 }
@@ -318,6 +527,8 @@ enum MyEventEnum {
     #[event]
     A: MyEventStruct,
     B: felt252,
+    #[key]
+    C: felt252,
 }
 
 fn event_serde_tester<
@@ -345,4 +556,24 @@ fn test_event_serde() {
     event_serde_tester(event.clone());
     let event = MyEventEnum::A(event);
     event_serde_tester(event.clone());
+    event_serde_tester(MyEventEnum::B(0x17));
+    event_serde_tester(MyEventEnum::C(0x17));
+}
+
+#[test]
+fn test_event_keys() {
+    let mut keys = ArrayTrait::new();
+    let mut values = ArrayTrait::new();
+    MyEventStruct { x: 0x17, data: 2 }.append_keys_and_values(ref keys, ref values);
+    assert(keys.len() == 1, 'Wrong keys length');
+    assert(*keys[0] == 0x17, 'Wrong key');
+    assert(values.len() == 1, 'Wrong values length');
+    assert(*values[0] == 2, 'Wrong value');
+
+    let mut keys = ArrayTrait::new();
+    let mut values = ArrayTrait::new();
+    MyEventEnum::C(0x17).append_keys_and_values(ref keys, ref values);
+    assert(keys.len() == 2, 'Wrong keys length');
+    assert(*keys[1] == 0x17, 'Wrong key');
+    assert(values.is_empty(), 'Wrong values length');
 }
@@ -161,3 +161,37 @@ fn test_entrypoint_failed() {
     let contract = IContractDispatcher { contract_address: address0 };
     contract.foo(300);
 }
+
+#[test]
+#[available_gas(30000000)]
+fn test_safe_dispatchers() {
+    let mut calldata = ArrayTrait::new();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let contract0 = IContractSafeDispatcher { contract_address: address0 };
+    assert(contract0.foo(300).unwrap() == 100, 'contract0.foo(300) == 100');
+    assert(contract0.foo(300).unwrap() == 300, 'contract0.foo(300) == 300');
+
+    let library = IContractSafeLibraryDispatcher {
+        class_hash: ContractA::TEST_CLASS_HASH.try_into().unwrap()
+    };
+    assert(library.foo(300).unwrap() == 0, 'library.foo(300) == 0');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_safe_dispatcher_entrypoint_failed() {
+    let mut calldata = ArrayTrait::new();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractFailedEntrypoint::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let contract = IContractSafeDispatcher { contract_address: address0 };
+    let mut err = contract.foo(300).unwrap_err();
+    assert(err.pop_front().unwrap() == 'Failure', 'err == "Failure"');
+    assert(err.pop_front().unwrap() == 'ENTRYPOINT_FAILED', 'err == "ENTRYPOINT_FAILED"');
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod contract_tests;
 mod abi_dispatchers_tests;
+mod components;
 mod interoperability;
 mod utils;
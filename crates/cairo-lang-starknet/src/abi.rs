@@ -6,10 +6,12 @@ use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::items::structure::SemanticStructEx;
 use cairo_lang_semantic::{ConcreteTypeId, GenericArgumentId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::{ast, Terminal};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::plugin::consts::{EVENT_ATTR, VIEW_ATTR};
+use crate::plugin::utils::get_event_field_kind;
 
 #[cfg(test)]
 #[path = "abi_test.rs"]
@@ -35,24 +37,49 @@ pub struct AbiBuilder {
     /// List of type that were included abi.
     /// Used to avoid redendency.
     types: HashSet<TypeId>,
+
+    /// List of event types that were included in the abi.
+    /// Used to avoid redundancy.
+    event_types: HashSet<TypeId>,
 }
 
 impl AbiBuilder {
     /// Creates a Starknet contract ABI from a TraitId.
     pub fn from_trait(db: &dyn SemanticGroup, trait_id: TraitId) -> Result<Contract, ABIError> {
-        if !db.trait_generic_params(trait_id).map_err(|_| ABIError::CompilationError)?.is_empty() {
-            return Err(ABIError::GenericTraitsUnsupported);
-        }
+        Self::from_traits(db, &[trait_id], &[])
+    }
 
-        let mut builder = Self { abi: Contract::default(), types: HashSet::new() };
+    /// Creates a Starknet contract ABI from several TraitIds, e.g. the ABI traits of a contract
+    /// and of the components embedded in it, and from the types of the events they emit (types
+    /// deriving `starknet::Event`).
+    pub fn from_traits(
+        db: &dyn SemanticGroup,
+        trait_ids: &[TraitId],
+        event_types: &[TypeId],
+    ) -> Result<Contract, ABIError> {
+        let mut builder =
+            Self { abi: Contract::default(), types: HashSet::new(), event_types: HashSet::new() };
+
+        for trait_id in trait_ids {
+            if !db
+                .trait_generic_params(*trait_id)
+                .map_err(|_| ABIError::CompilationError)?
+                .is_empty()
+            {
+                return Err(ABIError::GenericTraitsUnsupported);
+            }
 
-        for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
-            if trait_function_has_attr(db, *trait_function_id, EVENT_ATTR)? {
-                builder.add_event(db, *trait_function_id)?;
-            } else {
-                builder.add_function(db, *trait_function_id)?;
+            for trait_function_id in db.trait_functions(*trait_id).unwrap_or_default().values() {
+                if trait_function_has_attr(db, *trait_function_id, EVENT_ATTR)? {
+                    builder.add_event(db, *trait_function_id)?;
+                } else {
+                    builder.add_function(db, *trait_function_id)?;
+                }
             }
         }
+        for event_type in event_types {
+            builder.add_event_type(db, *event_type)?;
+        }
 
         Ok(builder.abi)
     }
@@ -106,19 +133,102 @@ impl AbiBuilder {
             .map_err(|_| ABIError::CompilationError)?;
         self.abi.items.push(Item::Event(Event {
             name,
-            inputs: signature
-                .params
-                .into_iter()
-                .map(|param| Input {
-                    name: param.id.name(db.upcast()).into(),
-                    ty: param.ty.format(db),
-                })
-                .collect(),
+            kind: EventKind::Function {
+                inputs: signature
+                    .params
+                    .into_iter()
+                    .map(|param| Input {
+                        name: param.id.name(db.upcast()).into(),
+                        ty: param.ty.format(db),
+                    })
+                    .collect(),
+            },
         }));
 
         Ok(())
     }
 
+    /// Adds an event to the ABI from the TypeId of a type deriving `starknet::Event`, and the
+    /// events nested in it.
+    fn add_event_type(&mut self, db: &dyn SemanticGroup, type_id: TypeId) -> Result<(), ABIError> {
+        if !self.event_types.insert(type_id) {
+            // The event was handled previously.
+            return Ok(());
+        }
+
+        let TypeLongId::Concrete(concrete) = db.lookup_intern_type(type_id) else {
+            return Err(ABIError::UnexpectedType);
+        };
+        let kind = match concrete {
+            ConcreteTypeId::Struct(id) => {
+                let struct_id = id.struct_id(db);
+                let struct_ast = db
+                    .module_structs(struct_id.parent_module(db.upcast()))
+                    .ok()
+                    .and_then(|structs| structs.get(&struct_id).cloned())
+                    .ok_or(ABIError::UnexpectedType)?;
+                let semantic_members =
+                    db.concrete_struct_members(id).map_err(|_| ABIError::UnexpectedType)?;
+                let mut members = vec![];
+                for member_ast in struct_ast.members(db.upcast()).elements(db.upcast()) {
+                    let name = member_ast.name(db.upcast()).text(db.upcast());
+                    let member = semantic_members.get(&name).ok_or(ABIError::UnexpectedType)?;
+                    members.push(self.add_event_field(db, name.into(), &member_ast, member.ty)?);
+                }
+                EventTypeKind::Struct { members }
+            }
+            ConcreteTypeId::Enum(id) => {
+                let enum_id = id.enum_id(db);
+                let enum_ast = db
+                    .module_enums(enum_id.parent_module(db.upcast()))
+                    .ok()
+                    .and_then(|enums| enums.get(&enum_id).cloned())
+                    .ok_or(ABIError::UnexpectedType)?;
+                let variant_ids =
+                    db.enum_variants(enum_id).map_err(|_| ABIError::UnexpectedType)?;
+                let mut variants = vec![];
+                for variant_ast in enum_ast.variants(db.upcast()).elements(db.upcast()) {
+                    let name = variant_ast.name(db.upcast()).text(db.upcast());
+                    let variant_id = variant_ids.get(&name).ok_or(ABIError::UnexpectedType)?;
+                    let variant = db
+                        .variant_semantic(enum_id, *variant_id)
+                        .and_then(|variant| db.concrete_enum_variant(id, &variant))
+                        .map_err(|_| ABIError::UnexpectedType)?;
+                    variants.push(self.add_event_field(
+                        db,
+                        name.into(),
+                        &variant_ast,
+                        variant.ty,
+                    )?);
+                }
+                EventTypeKind::Enum { variants }
+            }
+            ConcreteTypeId::Extern(_) => return Err(ABIError::UnexpectedType),
+        };
+        self.abi
+            .items
+            .push(Item::Event(Event { name: concrete.format(db), kind: EventKind::Type(kind) }));
+
+        Ok(())
+    }
+
+    /// Adds the type of a member of an event struct (or a variant of an event enum) to the ABI,
+    /// and returns the ABI of the member.
+    fn add_event_field(
+        &mut self,
+        db: &dyn SemanticGroup,
+        name: String,
+        member_ast: &ast::Member,
+        ty: TypeId,
+    ) -> Result<EventField, ABIError> {
+        let kind = get_event_field_kind(db.upcast(), member_ast);
+        match kind {
+            EventFieldKind::Nested => self.add_event_type(db, ty)?,
+            EventFieldKind::Key | EventFieldKind::Data => self.add_type(db, ty)?,
+        }
+        Ok(EventField { name, ty: ty.format(db), kind })
+    }
+
     /// Adds a type to the ABI from a TypeId.
     fn add_type(&mut self, db: &dyn SemanticGroup, type_id: TypeId) -> Result<(), ABIError> {
         if !self.types.insert(type_id) {
@@ -135,9 +245,10 @@ impl AbiBuilder {
                 Ok(())
             }
             TypeLongId::Snapshot(ty) => self.add_type(db, ty),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
-                Err(ABIError::UnexpectedType)
-            }
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Missing(_) => Err(ABIError::UnexpectedType),
         }
     }
 
@@ -273,7 +384,54 @@ pub struct Function {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
     pub name: String,
-    pub inputs: Vec<Input>,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// The kind of a contract event, by the way it is declared.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EventKind {
+    /// An event declared by an `#[event]` function. All its inputs are serialized to the data of
+    /// the event.
+    Function { inputs: Vec<Input> },
+    /// An event declared by a type deriving `starknet::Event`.
+    Type(EventTypeKind),
+}
+
+/// The kind of a type deriving `starknet::Event`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EventTypeKind {
+    Struct {
+        members: Vec<EventField>,
+    },
+    /// The selector of the emitted variant (the `starknet_keccak` of its name) is appended to the
+    /// keys of the event, before the variant itself.
+    Enum {
+        variants: Vec<EventField>,
+    },
+}
+
+/// A member of an event struct, or a variant of an event enum.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub kind: EventFieldKind,
+}
+
+/// The way a member of an event is serialized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventFieldKind {
+    /// Serialized to the keys of the event (`#[key]`), so it can be used for filtering events.
+    Key,
+    /// Serialized to the data of the event.
+    Data,
+    /// An event by itself (`#[event]`), whose keys and data are appended to those of the event.
+    Nested,
 }
 
 /// Function input ABI.
@@ -3,8 +3,9 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_filesystem::flag::OptimizationLevel;
 use cairo_lang_starknet::allowed_libfuncs::ListSelector;
-use cairo_lang_starknet::contract_class::starknet_compile;
+use cairo_lang_starknet::contract_class::starknet_compile_with_storage_layout;
 use clap::Parser;
 
 /// Command line args parser.
@@ -19,9 +20,15 @@ struct Args {
     contract_path: Option<String>,
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// The storage layout output file name (default: not emitted).
+    #[arg(long)]
+    storage_layout_output: Option<String>,
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// The level of optimizations to apply: 0, 1 or 2.
+    #[arg(short = 'O', long, default_value = "1")]
+    optimization_level: OptimizationLevel,
     /// The allowed libfuncs list to use (default: most recent audited list).
     #[arg(long)]
     allowed_libfuncs_list_name: Option<String>,
@@ -35,16 +42,23 @@ fn main() -> anyhow::Result<()> {
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let res = starknet_compile(
+    let (res, storage_layout) = starknet_compile_with_storage_layout(
         args.path,
         args.contract_path,
-        Some(CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() }),
+        Some(CompilerConfig {
+            replace_ids: args.replace_ids,
+            optimization_level: args.optimization_level,
+            ..CompilerConfig::default()
+        }),
         Some(list_selector),
     )?;
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
         None => println!("{res}"),
     }
+    if let Some(path) = args.storage_layout_output {
+        fs::write(path, storage_layout).with_context(|| "Failed to write storage layout.")?;
+    }
 
     Ok(())
 }
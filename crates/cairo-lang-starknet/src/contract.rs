@@ -9,18 +9,24 @@ use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyLongId};
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::items::us::SemanticUseEx;
 use cairo_lang_semantic::plugin::DynPluginAuxData;
-use cairo_lang_semantic::Expr;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::types::ConcreteEnumLongId;
+use cairo_lang_semantic::{ConcreteTypeId, TypeId, TypeLongId};
 use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::SierraIdReplacer;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
+use itertools::chain;
 use num_bigint::BigUint;
 use sha3::{Digest, Keccak256};
 
 use crate::contract_class::{extract_semantic_entrypoints, SemanticEntryPoints};
 use crate::plugin::aux_data::StarkNetContractAuxData;
-use crate::plugin::consts::ABI_TRAIT;
+use crate::plugin::consts::{ABI_TRAIT, EVENT_ENUM_NAME, EVENT_TRAIT};
+use crate::plugin::utils::has_derive;
 
 #[cfg(test)]
 #[path = "contract_test.rs"]
@@ -95,13 +101,27 @@ pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<Cont
     contracts
 }
 
-/// Returns the list of functions in a given module.
+/// Returns the list of functions in a given module, including the functions in the matching
+/// modules of the components embedded in the contract.
 pub fn get_module_functions(
     db: &dyn SemanticGroup,
     contract: &ContractDeclaration,
     module_name: &str,
 ) -> anyhow::Result<Vec<FreeFunctionId>> {
     let generated_module_id = get_generated_contract_module(db, contract)?;
+    let mut functions = get_generated_module_functions(db, generated_module_id, module_name)?;
+    for component_module_id in get_embedded_components(db, contract)? {
+        functions.extend(get_generated_module_functions(db, component_module_id, module_name)?);
+    }
+    Ok(functions)
+}
+
+/// Returns the list of functions in a given module of a generated contract or component module.
+fn get_generated_module_functions(
+    db: &dyn SemanticGroup,
+    generated_module_id: ModuleId,
+    module_name: &str,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
     match db
         .module_item_by_name(generated_module_id, module_name.into())
         .to_option()
@@ -115,12 +135,25 @@ pub fn get_module_functions(
     }
 }
 
-/// Returns the ABI trait of the given contract.
+/// Returns the ABI traits of the given contract: the ABI trait of the contract, followed by the ABI
+/// traits of the components embedded in it.
 pub fn get_abi(
     db: &(dyn SemanticGroup + 'static),
     contract: &ContractDeclaration,
-) -> anyhow::Result<TraitId> {
+) -> anyhow::Result<Vec<TraitId>> {
     let generated_module_id = get_generated_contract_module(db, contract)?;
+    let mut abi_traits = vec![get_generated_module_abi(db, generated_module_id)?];
+    for component_module_id in get_embedded_components(db, contract)? {
+        abi_traits.push(get_generated_module_abi(db, component_module_id)?);
+    }
+    Ok(abi_traits)
+}
+
+/// Returns the ABI trait of a generated contract or component module.
+fn get_generated_module_abi(
+    db: &dyn SemanticGroup,
+    generated_module_id: ModuleId,
+) -> anyhow::Result<TraitId> {
     match db
         .module_item_by_name(generated_module_id, ABI_TRAIT.into())
         .to_option()
@@ -131,8 +164,102 @@ pub fn get_abi(
     }
 }
 
+/// Returns the types of the events of the given contract: the `Event` enums of the contract and of
+/// the components embedded in it, if they define one.
+pub fn get_event_types(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<TypeId>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    let mut event_types = vec![];
+    for module_id in
+        chain!([generated_module_id], get_embedded_components(db, contract)?.into_iter())
+    {
+        let Some(ModuleItemId::Enum(enum_id)) = db
+            .module_item_by_name(module_id, EVENT_ENUM_NAME.into())
+            .to_option()
+            .with_context(|| "Failed to initiate a lookup in the generated module.")? else {
+            continue;
+        };
+        let enum_ast = db
+            .module_enums(module_id)
+            .to_option()
+            .and_then(|enums| enums.get(&enum_id).cloned())
+            .with_context(|| "Failed to get the event enum.")?;
+        if !has_derive(&enum_ast, db.upcast(), EVENT_TRAIT) {
+            continue;
+        }
+        let concrete_enum_id =
+            db.intern_concrete_enum(ConcreteEnumLongId { enum_id, generic_args: vec![] });
+        event_types
+            .push(db.intern_type(TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id))));
+    }
+    Ok(event_types)
+}
+
+/// Returns the generated modules of the components embedded in the given contract.
+pub fn get_embedded_components(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<ModuleId>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    let embedded_components = get_plugin_aux_data(db, contract.submodule_id, |aux_data| {
+        aux_data.embedded_components.clone()
+    })?;
+    let mut component_module_ids = vec![];
+    for name in embedded_components {
+        let Some(ModuleItemId::Use(use_id)) = db
+            .module_item_by_name(generated_module_id, name.clone())
+            .to_option()
+            .with_context(|| "Failed to initiate a lookup in the generated module.")? else {
+            anyhow::bail!("Failed to get the embedded component `{name}`.");
+        };
+        let Some(ResolvedGenericItem::Module(ModuleId::Submodule(component_submodule_id))) = db
+            .use_resolved_item(use_id)
+            .to_option() else {
+            anyhow::bail!("`{name}` is not a component.");
+        };
+        if !get_plugin_aux_data(db, component_submodule_id, |aux_data| {
+            aux_data.components.contains(&component_submodule_id.name(db.upcast()))
+        })? {
+            anyhow::bail!("`{name}` is not a component.");
+        }
+        let component_module_id = ModuleId::Submodule(component_submodule_id);
+        if component_module_ids.contains(&component_module_id) {
+            anyhow::bail!("Component `{name}` is embedded more than once.");
+        }
+        component_module_ids.push(component_module_id);
+    }
+    Ok(component_module_ids)
+}
+
+/// Applies `f` to the auxiliary data the plugin generated for the given contract or component.
+pub fn get_plugin_aux_data<T>(
+    db: &dyn SemanticGroup,
+    submodule_id: SubmoduleId,
+    f: impl FnOnce(&StarkNetContractAuxData) -> T,
+) -> anyhow::Result<T> {
+    let parent_module_id = submodule_id.parent_module(db.upcast());
+    let name = submodule_id.name(db.upcast());
+    let generated_file_infos = db
+        .module_generated_file_infos(parent_module_id)
+        .to_option()
+        .with_context(|| format!("Failed to get the generated files of the module of `{name}`."))?;
+    // See `find_contracts` for why the first generated file is skipped.
+    for generated_file_info in generated_file_infos.iter().skip(1).flatten() {
+        let Some(mapper) = generated_file_info.aux_data.0.as_any(
+        ).downcast_ref::<DynPluginAuxData>() else { continue; };
+        let Some(aux_data) = mapper.0.as_any(
+        ).downcast_ref::<StarkNetContractAuxData>() else { continue; };
+        if aux_data.contracts.contains(&name) || aux_data.components.contains(&name) {
+            return Ok(f(aux_data));
+        }
+    }
+    anyhow::bail!("Failed to get the plugin data of `{name}`.")
+}
+
 /// Returns the generated contract module.
-fn get_generated_contract_module(
+pub fn get_generated_contract_module(
     db: &dyn SemanticGroup,
     contract: &ContractDeclaration,
 ) -> anyhow::Result<ModuleId> {
@@ -187,9 +314,9 @@ fn analyze_contract<T: SierraIdReplacer>(
     let item =
         db.module_item_by_name(contract.module_id(), "TEST_CLASS_HASH".into()).unwrap().unwrap();
     let constant_id = extract_matches!(item, ModuleItemId::Constant);
-    let value =
-        extract_matches!(db.constant_semantic_data(constant_id).unwrap().value, Expr::Literal)
-            .value;
+    let ConstValue::Int(value, _) = db.constant_semantic_data(constant_id).unwrap().value else {
+        panic!("TEST_CLASS_HASH should be an integer constant.");
+    };
     let class_hash = Felt252::try_from(value).unwrap();
 
     // Extract functions.
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -7,7 +8,9 @@ use cairo_lang_compiler::project::setup_project;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
@@ -24,12 +27,13 @@ use crate::allowed_libfuncs::{
 };
 use crate::compiler_version::{self};
 use crate::contract::{
-    find_contracts, get_abi, get_module_functions, get_selector_and_sierra_function,
-    ContractDeclaration,
+    find_contracts, get_abi, get_event_types, get_module_functions,
+    get_selector_and_sierra_function, ContractDeclaration,
 };
 use crate::felt252_serde::sierra_to_felt252s;
 use crate::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
 use crate::plugin::StarkNetPlugin;
+use crate::storage_layout::{get_storage_layout, StorageLayout};
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
@@ -91,6 +95,27 @@ pub fn compile_path(
     compile_contract_in_prepared_db(&mut db, contract_path, main_crate_ids, compiler_config)
 }
 
+/// Compile the contract given by path, and return it along with its storage layout.
+/// Errors if there is ambiguity.
+pub fn compile_path_with_storage_layout(
+    path: &Path,
+    contract_path: Option<&str>,
+    compiler_config: CompilerConfig<'_>,
+) -> Result<(ContractClass, StorageLayout)> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()?;
+
+    let main_crate_ids = setup_project(&mut db, Path::new(&path))?;
+    let contracts = find_contracts(&db, &main_crate_ids);
+    let contract = find_contract(&db, &contracts, contract_path)?;
+
+    let mut classes = compile_prepared_db(&mut db, &[contract], compiler_config)?;
+    assert_eq!(classes.len(), 1);
+    Ok((classes.remove(0), get_storage_layout(&db, contract)?))
+}
+
 /// Runs StarkNet contract compiler on the specified contract.
 /// If no contract was specified, verify that there is only one.
 /// Otherwise, return an error.
@@ -101,8 +126,24 @@ fn compile_contract_in_prepared_db(
     compiler_config: CompilerConfig<'_>,
 ) -> Result<ContractClass> {
     let contracts = find_contracts(db, &main_crate_ids);
+    let contract = find_contract(db, &contracts, contract_path)?;
+
+    let contracts = vec![contract];
+    let mut classes = compile_prepared_db(db, &contracts, compiler_config)?;
+    assert_eq!(classes.len(), 1);
+    Ok(classes.remove(0))
+}
+
+/// Returns the contract with the given path among `contracts`.
+/// If no contract path was specified, verify that there is only one contract.
+/// Otherwise, return an error.
+fn find_contract<'a>(
+    db: &RootDatabase,
+    contracts: &'a [ContractDeclaration],
+    contract_path: Option<&str>,
+) -> Result<&'a ContractDeclaration> {
     // TODO(ilya): Add contract names.
-    let contract = if let Some(contract_path) = contract_path {
+    Ok(if let Some(contract_path) = contract_path {
         contracts
             .iter()
             .find(|contract| contract.submodule_id.full_path(db) == contract_path)
@@ -123,12 +164,7 @@ fn compile_contract_in_prepared_db(
                 );
             }
         }
-    };
-
-    let contracts = vec![contract];
-    let mut classes = compile_prepared_db(db, &contracts, compiler_config)?;
-    assert_eq!(classes.len(), 1);
-    Ok(classes.remove(0))
+    })
 }
 
 /// Runs Starknet contracts compiler.
@@ -146,6 +182,10 @@ pub fn compile_prepared_db(
     contracts: &[&ContractDeclaration],
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<Vec<ContractClass>> {
+    db.set_flag(
+        FlagId::new(db, "optimization_level"),
+        Some(Arc::new(Flag::OptimizationLevel(compiler_config.optimization_level))),
+    );
     compiler_config.diagnostics_reporter.ensure(db)?;
 
     contracts
@@ -198,7 +238,10 @@ fn compile_contract_with_prepared_and_checked_db(
         )),
         contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
         entry_points_by_type,
-        abi: Some(AbiBuilder::from_trait(db, get_abi(db, contract)?).with_context(|| "ABI error")?),
+        abi: Some(
+            AbiBuilder::from_traits(db, &get_abi(db, contract)?, &get_event_types(db, contract)?)
+                .with_context(|| "ABI error")?,
+        ),
     };
     Ok(contract_class)
 }
@@ -214,11 +257,21 @@ pub fn extract_semantic_entrypoints(
     db: &dyn SierraGenGroup,
     contract: &ContractDeclaration,
 ) -> core::result::Result<SemanticEntryPoints, anyhow::Error> {
-    let external: Vec<_> = get_module_functions(db.upcast(), contract, EXTERNAL_MODULE)?
+    let external_functions = get_module_functions(db.upcast(), contract, EXTERNAL_MODULE)?;
+    let l1_handler_functions = get_module_functions(db.upcast(), contract, L1_HANDLER_MODULE)?;
+    // Entry points of embedded components may clash with the entry points of the contract.
+    let mut names = HashSet::new();
+    for function in chain!(&external_functions, &l1_handler_functions) {
+        let name = function.name(db.upcast());
+        if !names.insert(name.clone()) {
+            anyhow::bail!("Duplicate entry point: `{name}`.");
+        }
+    }
+    let external: Vec<_> = external_functions
         .into_iter()
         .flat_map(|f| ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), f))
         .collect();
-    let l1_handler: Vec<_> = get_module_functions(db.upcast(), contract, L1_HANDLER_MODULE)?
+    let l1_handler: Vec<_> = l1_handler_functions
         .into_iter()
         .flat_map(|f| ConcreteFunctionWithBodyId::from_no_generics_free(db.upcast(), f))
         .collect();
@@ -259,7 +312,24 @@ pub fn starknet_compile(
     config: Option<CompilerConfig<'_>>,
     allowed_libfuncs_list: Option<ListSelector>,
 ) -> anyhow::Result<String> {
-    let contract = compile_path(
+    let (contract, _) = starknet_compile_with_storage_layout(
+        crate_path,
+        contract_path,
+        config,
+        allowed_libfuncs_list,
+    )?;
+    Ok(contract)
+}
+
+/// Compile Starknet crate (or specific contract in the crate), and return the serialized contract
+/// class along with its serialized storage layout.
+pub fn starknet_compile_with_storage_layout(
+    crate_path: PathBuf,
+    contract_path: Option<String>,
+    config: Option<CompilerConfig<'_>>,
+    allowed_libfuncs_list: Option<ListSelector>,
+) -> anyhow::Result<(String, String)> {
+    let (contract, storage_layout) = compile_path_with_storage_layout(
         &crate_path,
         contract_path.as_deref(),
         if let Some(config) = config { config } else { CompilerConfig::default() },
@@ -272,5 +342,8 @@ pub fn starknet_compile(
             ListSelector::default()
         },
     )?;
-    serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")
+    Ok((
+        serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?,
+        storage_layout.json(),
+    ))
 }
@@ -7,7 +7,11 @@ use indoc::indoc;
 use itertools::Itertools;
 use pretty_assertions::assert_eq;
 
-use crate::contract::{find_contracts, get_module_functions, starknet_keccak};
+use crate::abi::AbiBuilder;
+use crate::contract::{
+    find_contracts, get_abi, get_event_types, get_module_functions, starknet_keccak,
+};
+use crate::contract_class::extract_semantic_entrypoints;
 use crate::plugin::consts::EXTERNAL_MODULE;
 use crate::plugin::StarkNetPlugin;
 
@@ -51,6 +55,232 @@ fn test_contract_resolving() {
     );
 }
 
+#[test]
+fn test_embedded_component_resolving() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[component]
+            mod Pausable {
+                struct Storage {
+                    paused: bool,
+                }
+
+                #[event]
+                fn Paused() {}
+
+                #[view]
+                fn is_paused() -> bool {
+                    paused::read()
+                }
+            }
+
+            #[contract]
+            mod ERC20 {
+                #[embed]
+                use super::Pausable;
+
+                struct Storage {}
+
+                #[external]
+                fn ep1() {}
+            }
+        "},
+    );
+
+    // Components are not contracts by themselves.
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(contracts.len(), 1);
+
+    assert_eq!(
+        get_module_functions(db, &contracts[0], EXTERNAL_MODULE)
+            .unwrap()
+            .into_iter()
+            .map(|func_id| func_id.name(db))
+            .collect_vec(),
+        vec!["ep1", "is_paused"]
+    );
+    assert_eq!(
+        AbiBuilder::from_traits(db, &get_abi(db, &contracts[0]).unwrap(), &[]).unwrap().json(),
+        indoc! {r#"
+            [
+              {
+                "type": "function",
+                "name": "ep1",
+                "inputs": [],
+                "outputs": [],
+                "state_mutability": "external"
+              },
+              {
+                "type": "function",
+                "name": "is_paused",
+                "inputs": [],
+                "outputs": [
+                  {
+                    "type": "core::bool"
+                  }
+                ],
+                "state_mutability": "view"
+              },
+              {
+                "type": "event",
+                "name": "Paused",
+                "inputs": []
+              }
+            ]"#}
+    );
+}
+
+#[test]
+fn test_embedded_component_duplicate_entry_point() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[component]
+            mod Pausable {
+                struct Storage {}
+
+                #[external]
+                fn ep() {}
+            }
+
+            #[contract]
+            mod ERC20 {
+                #[embed]
+                use super::Pausable;
+
+                struct Storage {}
+
+                #[external]
+                fn ep() {}
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(
+        extract_semantic_entrypoints(db, &contracts[0]).err().unwrap().to_string(),
+        "Duplicate entry point: `ep`."
+    );
+}
+
+#[test]
+fn test_event_enum_abi() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[contract]
+            mod ERC20 {
+                #[derive(Drop, starknet::Event)]
+                struct Transfer {
+                    #[key]
+                    from: felt252,
+                    #[key]
+                    to: felt252,
+                    amount: u128,
+                }
+
+                #[derive(Drop, starknet::Event)]
+                enum Event {
+                    #[event]
+                    Transfer: Transfer,
+                    #[key]
+                    Paused: bool,
+                }
+
+                #[external]
+                fn transfer(to: felt252, amount: u128) {
+                    emit(Event::Transfer(Transfer { from: 0, to, amount }));
+                }
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(
+        AbiBuilder::from_traits(
+            db,
+            &get_abi(db, &contracts[0]).unwrap(),
+            &get_event_types(db, &contracts[0]).unwrap()
+        )
+        .unwrap()
+        .json(),
+        indoc! {r#"
+            [
+              {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                  {
+                    "name": "to",
+                    "type": "core::felt252"
+                  },
+                  {
+                    "name": "amount",
+                    "type": "core::integer::u128"
+                  }
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              },
+              {
+                "type": "event",
+                "name": "test::ERC20::Transfer",
+                "kind": "struct",
+                "members": [
+                  {
+                    "name": "from",
+                    "type": "core::felt252",
+                    "kind": "key"
+                  },
+                  {
+                    "name": "to",
+                    "type": "core::felt252",
+                    "kind": "key"
+                  },
+                  {
+                    "name": "amount",
+                    "type": "core::integer::u128",
+                    "kind": "data"
+                  }
+                ]
+              },
+              {
+                "type": "event",
+                "name": "test::ERC20::Event",
+                "kind": "enum",
+                "variants": [
+                  {
+                    "name": "Transfer",
+                    "type": "test::ERC20::Transfer",
+                    "kind": "nested"
+                  },
+                  {
+                    "name": "Paused",
+                    "type": "core::bool",
+                    "kind": "key"
+                  }
+                ]
+              }
+            ]"#}
+    );
+}
+
 #[test]
 fn test_starknet_keccak() {
     assert_eq!(
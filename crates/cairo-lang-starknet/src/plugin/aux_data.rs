@@ -1,5 +1,6 @@
 use cairo_lang_defs::plugin::GeneratedFileAuxData;
 use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::patcher::Patches;
 use cairo_lang_semantic::plugin::{
@@ -7,6 +8,8 @@ use cairo_lang_semantic::plugin::{
 };
 use cairo_lang_semantic::SemanticDiagnostic;
 
+use crate::storage_layout::StorageVariableKind;
+
 /// Contract related auxiliary data of the Starknet plugin.
 #[derive(Debug, PartialEq, Eq)]
 pub struct StarkNetContractAuxData {
@@ -15,6 +18,16 @@ pub struct StarkNetContractAuxData {
 
     /// A list of contracts that were processed by the plugin.
     pub contracts: Vec<smol_str::SmolStr>,
+
+    /// A list of components that were processed by the plugin.
+    pub components: Vec<smol_str::SmolStr>,
+
+    /// The storage variables of the contract (or component), by order of declaration, with their
+    /// kinds.
+    pub storage_variables: Vec<(smol_str::SmolStr, StorageVariableKind)>,
+
+    /// The names, in the contract module, of the components embedded in the contract.
+    pub embedded_components: Vec<smol_str::SmolStr>,
 }
 impl GeneratedFileAuxData for StarkNetContractAuxData {
     fn as_any(&self) -> &dyn std::any::Any {
@@ -41,6 +54,10 @@ impl PluginAuxData for StarkNetContractAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
 }
 
 /// Contract related auxiliary data of the Starknet plugin.
@@ -74,4 +91,8 @@ impl PluginAuxData for StarkNetABIAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
 }
@@ -6,12 +6,19 @@ pub const STORAGE_STRUCT_NAME: &str = "Storage";
 
 pub const VIEW_ATTR: &str = "view";
 pub const EVENT_ATTR: &str = "event";
+pub const EVENT_TRAIT: &str = "starknet::Event";
+pub const EVENT_ENUM_NAME: &str = "Event";
+pub const EMIT_FUNCTION_NAME: &str = "emit";
+pub const STORAGE_ACCESS_TRAIT: &str = "starknet::StorageAccess";
+pub const ACCOUNT_CONTRACT_ATTR: &str = "account_contract";
+pub const CONTRACT_ATTR: &str = "contract";
+pub const COMPONENT_ATTR: &str = "component";
+pub const EXTERNAL_ATTR: &str = "external";
+pub const L1_HANDLER_ATTR: &str = "l1_handler";
+pub const CONSTRUCTOR_ATTR: &str = "constructor";
 pub(super) const ABI_ATTR: &str = "abi";
-pub(super) const ACCOUNT_CONTRACT_ATTR: &str = "account_contract";
-pub(super) const CONTRACT_ATTR: &str = "contract";
-pub(super) const EXTERNAL_ATTR: &str = "external";
-pub(super) const L1_HANDLER_ATTR: &str = "l1_handler";
-pub(super) const CONSTRUCTOR_ATTR: &str = "constructor";
+pub(super) const EMBED_ATTR: &str = "embed";
+pub(super) const KEY_ATTR: &str = "key";
 pub(super) const RAW_OUTPUT_ATTR: &str = "raw_output";
 
 pub(super) const EXECUTE_ENTRY_POINT_NAME: &str = "__execute__";
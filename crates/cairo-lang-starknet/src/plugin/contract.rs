@@ -13,34 +13,42 @@ use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use indoc::formatdoc;
+use itertools::Itertools;
 
 use super::consts::{
-    ABI_TRAIT, ACCOUNT_CONTRACT_ATTR, ACCOUNT_CONTRACT_ENTRY_POINTS, CONSTRUCTOR_MODULE,
-    CONTRACT_ATTR, EVENT_ATTR, EXTERNAL_MODULE, L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE,
-    STORAGE_STRUCT_NAME,
+    ABI_TRAIT, ACCOUNT_CONTRACT_ATTR, ACCOUNT_CONTRACT_ENTRY_POINTS, COMPONENT_ATTR,
+    CONSTRUCTOR_MODULE, CONTRACT_ATTR, EMBED_ATTR, EMIT_FUNCTION_NAME, EVENT_ATTR, EVENT_ENUM_NAME,
+    EVENT_TRAIT, EXTERNAL_MODULE, L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE,
+    STORAGE_ACCESS_TRAIT, STORAGE_STRUCT_NAME,
 };
 use super::entry_point::{generate_entry_point_wrapper, EntryPointKind};
 use super::events::handle_event;
 use super::storage::handle_storage_struct;
-use super::utils::{is_felt252, is_mut_param, maybe_strip_underscore};
+use super::utils::{has_derive, is_felt252, is_mut_param, maybe_strip_underscore};
 use crate::contract::starknet_keccak;
 use crate::plugin::aux_data::StarkNetContractAuxData;
 
 /// If the module is annotated with CONTRACT_ATTR, generate the relevant contract logic.
+/// If the module is annotated with COMPONENT_ATTR, generate the same logic for a component - a
+/// module with storage, events and entry points that contracts can embed (using EMBED_ATTR on a
+/// `use` of the component). The storage variables of a component are namespaced by its name, and
+/// its entry points and ABI are merged into those of the embedding contracts.
 pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult {
     let is_account_contract = module_ast.has_attr(db, ACCOUNT_CONTRACT_ATTR);
+    let is_component = module_ast.has_attr(db, COMPONENT_ATTR);
 
-    if !is_account_contract && !module_ast.has_attr(db, CONTRACT_ATTR) {
+    if !is_account_contract && !is_component && !module_ast.has_attr(db, CONTRACT_ATTR) {
         return PluginResult::default();
     }
 
     let body = match module_ast.body(db) {
         MaybeModuleBody::Some(body) => body,
         MaybeModuleBody::None(empty_body) => {
+            let kind = if is_component { "Components" } else { "Contracts" };
             return PluginResult {
                 code: None,
                 diagnostics: vec![PluginDiagnostic {
-                    message: "Contracts without body are not supported.".to_string(),
+                    message: format!("{kind} without body are not supported."),
                     stable_ptr: empty_body.stable_ptr().untyped(),
                 }],
                 remove_original_item: false,
@@ -52,7 +60,16 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
 
     // A mapping from a 'use' item to its path.
     let mut extra_uses = OrderedHashMap::default();
+    // The structs deriving `starknet::StorageAccess`, by name.
+    let mut storage_structs = OrderedHashMap::default();
+    // The names of the embedded components.
+    let mut embedded_components = vec![];
     for item in body.items(db).elements(db) {
+        if let ast::Item::Struct(item) = &item {
+            if has_derive(item, db, STORAGE_ACCESS_TRAIT) {
+                storage_structs.insert(item.name(db).text(db), item.clone());
+            }
+        }
         // Skipping elements that only generate other code, but their code itself is ignored.
         if matches!(&item, ast::Item::FreeFunction(item) if item.has_attr(db, EVENT_ATTR))
             || matches!(&item, ast::Item::Struct(item) if item.name(db).text(db) == STORAGE_STRUCT_NAME)
@@ -65,6 +82,17 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
             ast::Item::Module(item) => Some(item.name(db)),
             ast::Item::Use(item) => {
                 let leaves = get_all_path_leafs(db, item.use_path(db));
+                if item.has_attr(db, EMBED_ATTR) {
+                    if is_component {
+                        diagnostics.push(PluginDiagnostic {
+                            message: "Components can't embed other components.".to_string(),
+                            stable_ptr: item.stable_ptr().untyped(),
+                        });
+                    } else {
+                        embedded_components
+                            .extend(leaves.iter().map(|leaf| leaf.stable_ptr().identifier(db)));
+                    }
+                }
                 for leaf in leaves {
                     extra_uses
                         .entry(leaf.stable_ptr().identifier(db))
@@ -115,6 +143,7 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
     let mut generated_l1_handler_functions = Vec::new();
 
     let mut storage_code = RewriteNode::Text("".to_string());
+    let mut storage_variables = vec![];
     let mut abi_functions = Vec::new();
     let mut event_functions = Vec::new();
     let mut abi_events = Vec::new();
@@ -148,6 +177,14 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                     let name = declaration.name(db);
                     let name_str = name.text(db);
 
+                    if is_component && matches!(entry_point_kind, EntryPointKind::Constructor) {
+                        diagnostics.push(PluginDiagnostic {
+                            message: "Components can't have a constructor.".to_string(),
+                            stable_ptr: name.stable_ptr().untyped(),
+                        });
+                        continue;
+                    }
+
                     if !is_account_contract {
                         for account_contract_entry_point in ACCOUNT_CONTRACT_ENTRY_POINTS {
                             if name_str == account_contract_entry_point {
@@ -209,12 +246,33 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                     }
                 }
             }
+            ast::Item::Enum(item_enum) if item_enum.name(db).text(db) == EVENT_ENUM_NAME => {
+                if has_derive(item_enum, db, EVENT_TRAIT) {
+                    event_functions.push(RewriteNode::Text(generate_emit_function()));
+                } else {
+                    diagnostics.push(PluginDiagnostic {
+                        message: format!(
+                            "The `{EVENT_ENUM_NAME}` enum of a contract must derive \
+                             `{EVENT_TRAIT}`."
+                        ),
+                        stable_ptr: item_enum.name(db).stable_ptr().untyped(),
+                    });
+                }
+            }
             ast::Item::Struct(item_struct)
                 if item_struct.name(db).text(db) == STORAGE_STRUCT_NAME =>
             {
-                let (storage_rewrite_node, storage_diagnostics) =
-                    handle_storage_struct(db, item_struct.clone(), &extra_uses_node);
+                let (storage_rewrite_node, storage_vars, storage_diagnostics) =
+                    handle_storage_struct(
+                        db,
+                        item_struct.clone(),
+                        &extra_uses_node,
+                        &storage_structs,
+                        &extra_uses.keys().cloned().collect_vec(),
+                        is_component.then(|| module_ast.name(db).text(db)).as_deref(),
+                    );
                 storage_code = storage_rewrite_node;
+                storage_variables = storage_vars;
                 diagnostics.extend(storage_diagnostics);
             }
             _ => {}
@@ -222,9 +280,15 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
     }
 
     let module_name_ast = module_ast.name(db);
-    let test_class_hash = starknet_keccak(
-        module_ast.as_syntax_node().get_text_without_trivia(db).as_str().as_bytes(),
-    );
+    // Components are not deployable by themselves, so they have no class hash.
+    let test_class_hash_item = if is_component {
+        "".to_string()
+    } else {
+        let test_class_hash = starknet_keccak(
+            module_ast.as_syntax_node().get_text_without_trivia(db).as_str().as_bytes(),
+        );
+        format!("\n    const TEST_CLASS_HASH: felt252 = {test_class_hash};")
+    };
     let generated_contract_mod = RewriteNode::interpolate_patched(
         formatdoc!(
             "
@@ -232,8 +296,7 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                 use starknet::SyscallResultTrait;
                 use starknet::SyscallResultTraitImpl;
 
-            $original_items$
-                const TEST_CLASS_HASH: felt252 = {test_class_hash};
+            $original_items${test_class_hash_item}
                 $storage_code$
 
                 $event_functions$
@@ -289,14 +352,22 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
 
     let mut builder = PatchBuilder::new(db);
     builder.add_modified(generated_contract_mod);
+    let (contracts, components) = if is_component {
+        (vec![], vec![module_name_ast.text(db)])
+    } else {
+        (vec![module_name_ast.text(db)], vec![])
+    };
     PluginResult {
         code: Some(PluginGeneratedFile {
-            name: "contract".into(),
+            name: if is_component { "component" } else { "contract" }.into(),
             content: builder.code,
             aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(
                 StarkNetContractAuxData {
                     patches: builder.patches,
-                    contracts: vec![module_name_ast.text(db)],
+                    contracts,
+                    components,
+                    storage_variables,
+                    embedded_components,
                 },
             )),
         }),
@@ -305,6 +376,23 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
     }
 }
 
+/// Generates the function emitting the events of a contract, given as its `Event` enum.
+fn generate_emit_function() -> String {
+    format!(
+        "
+    fn {EMIT_FUNCTION_NAME}(event: {EVENT_ENUM_NAME}) {{
+        let mut keys = array::ArrayTrait::new();
+        let mut values = array::ArrayTrait::new();
+        starknet::Event::append_keys_and_values(event, ref keys, ref values);
+        starknet::syscalls::emit_event_syscall(
+            array::ArrayTrait::span(@keys),
+            array::ArrayTrait::span(@values),
+        ).unwrap_syscall()
+    }}
+"
+    )
+}
+
 /// Validates the first parameter of an L1 handler is `from_address: felt252` or `_from_address:
 /// felt252`.
 fn validate_l1_handler_first_parameter(
@@ -40,10 +40,16 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     let mut dispatcher_signatures = vec![];
     let mut contract_caller_method_impls = vec![];
     let mut library_caller_method_impls = vec![];
+    let mut safe_dispatcher_signatures = vec![];
+    let mut safe_contract_caller_method_impls = vec![];
+    let mut safe_library_caller_method_impls = vec![];
     let base_name = trait_ast.name(db).text(db);
     let dispatcher_name = format!("{base_name}DispatcherTrait");
     let contract_caller_name = format!("{base_name}Dispatcher");
     let library_caller_name = format!("{base_name}LibraryDispatcher");
+    let safe_dispatcher_name = format!("{base_name}SafeDispatcherTrait");
+    let safe_contract_caller_name = format!("{base_name}SafeDispatcher");
+    let safe_library_caller_name = format!("{base_name}SafeLibraryDispatcher");
     for item_ast in body.items(db).elements(db) {
         match item_ast {
             ast::TraitItem::Function(func) => {
@@ -88,18 +94,30 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     continue;
                 }
 
-                let ret_decode = match signature.ret_ty(db) {
-                    OptionReturnTypeClause::Empty(_) => "".to_string(),
+                let (ret_type_name, ret_decode, safe_ret_decode) = match signature.ret_ty(db) {
+                    OptionReturnTypeClause::Empty(_) => {
+                        ("()".to_string(), "".to_string(), "\n        Result::Ok(())".to_string())
+                    }
                     OptionReturnTypeClause::ReturnTypeClause(ty) => {
                         let ret_type_ast = ty.ty(db);
                         let type_name = ret_type_ast.as_syntax_node().get_text(db);
-                        format!(
+                        let ret_decode = format!(
                             "
         option::OptionTrait::expect(
             serde::Serde::<{type_name}>::deserialize(ref ret_data),
             'Returned data too short',
         )"
-                        )
+                        );
+                        let safe_ret_decode = format!(
+                            "
+        Result::Ok(
+            option::OptionTrait::expect(
+                serde::Serde::<{type_name}>::deserialize(ref ret_data),
+                'Returned data too short',
+            )
+        )"
+                        );
+                        (type_name, ret_decode, safe_ret_decode)
                     }
                 };
                 dispatcher_signatures.push(RewriteNode::interpolate_patched(
@@ -109,6 +127,13 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                         dispatcher_signature(db, &declaration, "T"),
                     )]),
                 ));
+                safe_dispatcher_signatures.push(RewriteNode::interpolate_patched(
+                    "$func_decl$;",
+                    HashMap::from([(
+                        "func_decl".to_string(),
+                        safe_dispatcher_signature(db, &declaration, "T", &ret_type_name),
+                    )]),
+                ));
                 let entry_point_selector = RewriteNode::Text(format!(
                     "0x{:x}",
                     starknet_keccak(declaration.name(db).text(db).as_bytes())
@@ -120,16 +145,55 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     "call_contract_syscall",
                     serialization_code.clone(),
                     ret_decode.clone(),
+                    false,
                 ));
                 library_caller_method_impls.push(declaration_method_impl(
                     dispatcher_signature(db, &declaration, &library_caller_name),
+                    entry_point_selector.clone(),
+                    "class_hash",
+                    "syscalls::library_call_syscall",
+                    serialization_code.clone(),
+                    ret_decode,
+                    false,
+                ));
+                safe_contract_caller_method_impls.push(declaration_method_impl(
+                    safe_dispatcher_signature(
+                        db,
+                        &declaration,
+                        &safe_contract_caller_name,
+                        &ret_type_name,
+                    ),
+                    entry_point_selector.clone(),
+                    "contract_address",
+                    "call_contract_syscall",
+                    serialization_code.clone(),
+                    safe_ret_decode.clone(),
+                    true,
+                ));
+                safe_library_caller_method_impls.push(declaration_method_impl(
+                    safe_dispatcher_signature(
+                        db,
+                        &declaration,
+                        &safe_library_caller_name,
+                        &ret_type_name,
+                    ),
                     entry_point_selector,
                     "class_hash",
                     "syscalls::library_call_syscall",
                     serialization_code,
-                    ret_decode,
+                    safe_ret_decode,
+                    true,
                 ));
             }
+            ast::TraitItem::Type(ty) => diagnostics.push(PluginDiagnostic {
+                message: "Associated types are not supported in the ABI of a contract.".to_string(),
+                stable_ptr: ty.stable_ptr().untyped(),
+            }),
+            ast::TraitItem::Constant(constant) => diagnostics.push(PluginDiagnostic {
+                message: "Associated constants are not supported in the ABI of a contract."
+                    .to_string(),
+                stable_ptr: constant.stable_ptr().untyped(),
+            }),
             // ignore the missing item.
             ast::TraitItem::Missing(_) => {}
         }
@@ -142,7 +206,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
             $dispatcher_signatures$
             }}
 
-            #[derive(Copy, Drop)]
+            #[derive(Copy, Drop, starknet::StorageAccess)]
             struct {contract_caller_name} {{
                 contract_address: starknet::ContractAddress,
             }}
@@ -151,7 +215,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
             $contract_caller_method_impls$
             }}
 
-            #[derive(Copy, Drop)]
+            #[derive(Copy, Drop, starknet::StorageAccess)]
             struct {library_caller_name} {{
                 class_hash: starknet::ClassHash,
             }}
@@ -160,40 +224,28 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
             $library_caller_method_impls$
             }}
 
-            impl {contract_caller_name}StorageAccess of \
-             starknet::StorageAccess::<{contract_caller_name}> {{
-                fn read(address_domain: u32, base: starknet::StorageBaseAddress) -> \
-             starknet::SyscallResult<{contract_caller_name}> {{
-                    Result::Ok(
-                        {contract_caller_name} {{
-                            contract_address: \
-             starknet::StorageAccess::<starknet::ContractAddress>::read(address_domain, base)?
-                        }}
-                    )
-                }}
-                fn write(address_domain: u32, base: starknet::StorageBaseAddress, value: \
-             {contract_caller_name}) -> starknet::SyscallResult<()> {{
-                    starknet::StorageAccess::<starknet::ContractAddress>::write(address_domain, \
-             base, value.contract_address)
-                }}
+            trait {safe_dispatcher_name}<T> {{
+            $safe_dispatcher_signatures$
             }}
 
-            impl {library_caller_name}StorageAccess of \
-             starknet::StorageAccess::<{library_caller_name}> {{
-                fn read(address_domain: u32, base: starknet::StorageBaseAddress) -> \
-             starknet::SyscallResult<{library_caller_name}> {{
-                    Result::Ok(
-                        {library_caller_name} {{
-                            class_hash: \
-             starknet::StorageAccess::<starknet::ClassHash>::read(address_domain, base)?
-                        }}
-                    )
-                }}
-                fn write(address_domain: u32, base: starknet::StorageBaseAddress, value: \
-             {library_caller_name}) -> starknet::SyscallResult<()> {{
-                    starknet::StorageAccess::<starknet::ClassHash>::write(address_domain, base, \
-             value.class_hash)
-                }}
+            #[derive(Copy, Drop, starknet::StorageAccess)]
+            struct {safe_contract_caller_name} {{
+                contract_address: starknet::ContractAddress,
+            }}
+
+            impl {safe_contract_caller_name}Impl of \
+             {safe_dispatcher_name}::<{safe_contract_caller_name}> {{
+            $safe_contract_caller_method_impls$
+            }}
+
+            #[derive(Copy, Drop, starknet::StorageAccess)]
+            struct {safe_library_caller_name} {{
+                class_hash: starknet::ClassHash,
+            }}
+
+            impl {safe_library_caller_name}Impl of \
+             {safe_dispatcher_name}::<{safe_library_caller_name}> {{
+            $safe_library_caller_method_impls$
             }}
             ",
         ),
@@ -207,6 +259,18 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                 "library_caller_method_impls".to_string(),
                 RewriteNode::new_modified(library_caller_method_impls),
             ),
+            (
+                "safe_dispatcher_signatures".to_string(),
+                RewriteNode::new_modified(safe_dispatcher_signatures),
+            ),
+            (
+                "safe_contract_caller_method_impls".to_string(),
+                RewriteNode::new_modified(safe_contract_caller_method_impls),
+            ),
+            (
+                "safe_library_caller_method_impls".to_string(),
+                RewriteNode::new_modified(safe_library_caller_method_impls),
+            ),
         ]),
     ));
     PluginResult {
@@ -223,6 +287,7 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
 }
 
 /// Returns the method implementation rewrite node for a declaration.
+/// If `safe` is true, a failure of the syscall is propagated to the caller instead of panicking.
 fn declaration_method_impl(
     func_declaration: RewriteNode,
     entry_point_selector: RewriteNode,
@@ -230,21 +295,34 @@ fn declaration_method_impl(
     syscall: &str,
     serialization_code: Vec<RewriteNode>,
     ret_decode: String,
+    safe: bool,
 ) -> RewriteNode {
-    RewriteNode::interpolate_patched(
-        "$func_decl$ {
-        let mut calldata = array::ArrayTrait::new();
-$serialization_code$
+    let call_code = if safe {
+        "
+        let mut ret_data = starknet::$syscall$(
+            self.$member$,
+            $entry_point_selector$,
+            array::ArrayTrait::span(@calldata),
+        )?;"
+    } else {
+        "
         let mut ret_data = starknet::SyscallResultTrait::unwrap_syscall(
             starknet::$syscall$(
                 self.$member$,
                 $entry_point_selector$,
                 array::ArrayTrait::span(@calldata),
             )
-        );
+        );"
+    };
+    RewriteNode::interpolate_patched(
+        &format!(
+            "$func_decl$ {{
+        let mut calldata = array::ArrayTrait::new();
+$serialization_code${call_code}
 $deserialization_code$
-    }
-",
+    }}
+"
+        ),
         HashMap::from([
             ("func_decl".to_string(), func_declaration),
             ("entry_point_selector".to_string(), entry_point_selector),
@@ -279,3 +357,24 @@ fn dispatcher_signature(
         );
     func_declaration
 }
+
+/// Returns the matching signature for a safe dispatcher implementation for the given declaration,
+/// whose return type is wrapped by a `SyscallResult`.
+fn safe_dispatcher_signature(
+    db: &dyn SyntaxGroup,
+    declaration: &ast::FunctionDeclaration,
+    self_type_name: &str,
+    ret_type_name: &str,
+) -> RewriteNode {
+    let mut func_declaration = dispatcher_signature(db, declaration, self_type_name);
+    // The whitespace before an existing return type is the trailing trivia of the parameters.
+    let separator = match declaration.signature(db).ret_ty(db) {
+        OptionReturnTypeClause::Empty(_) => " ",
+        OptionReturnTypeClause::ReturnTypeClause(_) => "",
+    };
+    func_declaration
+        .modify_child(db, ast::FunctionDeclaration::INDEX_SIGNATURE)
+        .modify_child(db, ast::FunctionSignature::INDEX_RET_TY)
+        .set_str(format!("{separator}-> starknet::SyscallResult<{ret_type_name}>"));
+    func_declaration
+}
@@ -5,9 +5,6 @@ use cairo_lang_defs::plugin::{
 };
 use cairo_lang_semantic::patcher::{ModifiedNode, PatchBuilder, RewriteNode};
 use cairo_lang_semantic::plugin::DynPluginAuxData;
-use cairo_lang_syntax::attribute::structured::{
-    AttributeArg, AttributeArgVariant, AttributeStructurize,
-};
 use cairo_lang_syntax::node::ast::{self, OptionReturnTypeClause, OptionWrappedGenericParamList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -15,19 +12,20 @@ use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use indoc::indoc;
 
 use super::aux_data::StarkNetABIAuxData;
-use super::utils::is_ref_param;
+use super::consts::{EVENT_ATTR, EVENT_TRAIT, KEY_ATTR};
+use super::utils::{get_event_field_kind, has_derive, is_ref_param};
+use crate::abi::EventFieldKind;
 use crate::contract::starknet_keccak;
 
-// TODO(spapini): Handle member and variant attributes for serde / event.
 /// Derive the `Event` trait for structs annotated with `derive(starknet::Event)`.
-pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> PluginResult {
-    if !derive_event_needed(&struct_ast, db) {
-        return PluginResult::default();
-    }
-
-    let mut builder = PatchBuilder::new(db);
-    let mut diagnostics = vec![];
-
+/// Members annotated with `#[key]` are serialized to the keys of the event, and members annotated
+/// with `#[event]` are nested events.
+/// Returns the code of the implementation, or None if it could not be derived.
+pub fn handle_struct(
+    db: &dyn SyntaxGroup,
+    struct_ast: &ast::ItemStruct,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<RewriteNode> {
     // TODO(spapini): Support generics.
     let generic_params = struct_ast.generic_params(db);
     let OptionWrappedGenericParamList::Empty(_) = generic_params else {
@@ -35,7 +33,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
             message: "Event structs with generic arguments are unsupported".to_string(),
             stable_ptr: generic_params.stable_ptr().untyped(),
         });
-        return PluginResult{ code: None, diagnostics, remove_original_item: false };
+        return None;
     };
 
     // Generate append_keys_and_values() code.
@@ -44,13 +42,13 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
     let mut ctor = vec![];
     for member in struct_ast.members(db).elements(db) {
         let member_name = RewriteNode::new_trimmed(member.name(db).as_syntax_node());
-        let as_event = member.has_attr(db, "event");
+        let kind = get_member_kind(db, &member, diagnostics);
         let value_for_append = RewriteNode::interpolate_patched(
             "self.$member_name$",
             HashMap::from([(String::from("member_name"), member_name.clone())]),
         );
-        let append_member = append_field(as_event, value_for_append);
-        let deserialize_member = deserialize_field(as_event, member_name.clone());
+        let append_member = append_field(kind, value_for_append);
+        let deserialize_member = deserialize_field(kind, member_name.clone());
         append_members.push(append_member);
         deserialize_members.push(deserialize_member);
         ctor.push(RewriteNode::interpolate_patched(
@@ -65,7 +63,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
 
     // Add an implementation for `Event<StructName>`.
     let struct_name = RewriteNode::new_trimmed(struct_ast.name(db).as_syntax_node());
-    let event_impl = RewriteNode::interpolate_patched(
+    Some(RewriteNode::interpolate_patched(
         indoc! {"
             impl $struct_name$IsEvent of starknet::Event<$struct_name$> {
                 fn append_keys_and_values(
@@ -84,26 +82,12 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
             (String::from("deserialize_members"), deserialize_members),
             (String::from("ctor"), ctor),
         ]),
-    );
-
-    builder.add_modified(event_impl);
-
-    PluginResult {
-        code: Some(PluginGeneratedFile {
-            name: "event_impl".into(),
-            content: builder.code,
-            aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(StarkNetABIAuxData {
-                patches: builder.patches,
-            })),
-        }),
-        diagnostics,
-        remove_original_item: false,
-    }
+    ))
 }
 
 /// Derive the `Event` trait for enums annotated with `derive(starknet::Event)`.
 pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResult {
-    if !derive_event_needed(&enum_ast, db) {
+    if !has_derive(&enum_ast, db, EVENT_TRAIT) {
         return PluginResult::default();
     }
 
@@ -127,8 +111,8 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
         let variant_name = RewriteNode::new_trimmed(member.name(db).as_syntax_node());
         let name = member.name(db).text(db);
         let variant_selector = format!("0x{:x}", starknet_keccak(name.as_bytes()));
-        let as_event = member.has_attr(db, "event");
-        let append_member = append_field(as_event, RewriteNode::Text("val".into()));
+        let kind = get_member_kind(db, &member, &mut diagnostics);
+        let append_member = append_field(kind, RewriteNode::Text("val".into()));
         let append_variant = RewriteNode::interpolate_patched(
             "
             $enum_name$::$variant_name$(val) => {
@@ -141,7 +125,7 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
                 (String::from("append_member"), append_member),
             ]),
         );
-        let deserialize_member = deserialize_field(as_event, RewriteNode::Text("val".into()));
+        let deserialize_member = deserialize_field(kind, RewriteNode::Text("val".into()));
         let deserialize_variant = RewriteNode::interpolate_patched(
             "
             if selector == $variant_selector$ {$deserialize_member$
@@ -202,64 +186,68 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
     }
 }
 
-/// Returns true if the type should be derived as an event.
-fn derive_event_needed<T: QueryAttrs>(with_attrs: &T, db: &dyn SyntaxGroup) -> bool {
-    with_attrs.query_attr(db, "derive").into_iter().any(|attr| {
-        let attr = attr.structurize(db);
-        for arg in &attr.args {
-            let AttributeArg{
-                variant: AttributeArgVariant::Unnamed {
-                    value: ast::Expr::Path(path),
-                    ..
-                },
-                ..
-            } = arg else {
-                continue;
-            };
-            if path.as_syntax_node().get_text_without_trivia(db) == "starknet::Event" {
-                return true;
-            }
-        }
-        false
-    })
+/// Returns the kind of a member of an event struct (or a variant of an event enum).
+/// Adds a diagnostic if the member has conflicting attributes.
+fn get_member_kind(
+    db: &dyn SyntaxGroup,
+    member: &ast::Member,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> EventFieldKind {
+    if member.has_attr(db, EVENT_ATTR) && member.has_attr(db, KEY_ATTR) {
+        diagnostics.push(PluginDiagnostic {
+            message: format!("A nested `#[{EVENT_ATTR}]` member can't be a `#[{KEY_ATTR}]`."),
+            stable_ptr: member.stable_ptr().untyped(),
+        });
+    }
+    get_event_field_kind(db, member)
 }
 
 /// Generates code to emit an event for a value
-fn append_field(as_event: bool, value: RewriteNode) -> RewriteNode {
-    if as_event {
-        RewriteNode::interpolate_patched(
+fn append_field(kind: EventFieldKind, value: RewriteNode) -> RewriteNode {
+    match kind {
+        EventFieldKind::Nested => RewriteNode::interpolate_patched(
             "
                 starknet::Event::append_keys_and_values(
                     $value$, ref keys, ref values
                 );",
             HashMap::from([(String::from("value"), value)]),
-        )
-    } else {
-        RewriteNode::interpolate_patched(
+        ),
+        EventFieldKind::Key => RewriteNode::interpolate_patched(
+            "
+                serde::Serde::serialize(@$value$, ref keys);",
+            HashMap::from([(String::from("value"), value)]),
+        ),
+        EventFieldKind::Data => RewriteNode::interpolate_patched(
             "
                 serde::Serde::serialize(@$value$, ref values);",
             HashMap::from([(String::from("value"), value)]),
-        )
+        ),
     }
 }
 
-fn deserialize_field(as_event: bool, member_name: RewriteNode) -> RewriteNode {
-    if as_event {
-        RewriteNode::interpolate_patched(
+fn deserialize_field(kind: EventFieldKind, member_name: RewriteNode) -> RewriteNode {
+    match kind {
+        EventFieldKind::Nested => RewriteNode::interpolate_patched(
             "
                 let $member_name$ = starknet::Event::deserialize(
                     ref keys, ref values
                 )?;",
             HashMap::from([(String::from("member_name"), member_name)]),
-        )
-    } else {
-        RewriteNode::interpolate_patched(
+        ),
+        EventFieldKind::Key => RewriteNode::interpolate_patched(
+            "
+                let $member_name$ = serde::Serde::deserialize(
+                    ref keys
+                )?;",
+            HashMap::from([(String::from("member_name"), member_name)]),
+        ),
+        EventFieldKind::Data => RewriteNode::interpolate_patched(
             "
                 let $member_name$ = serde::Serde::deserialize(
                     ref values
                 )?;",
             HashMap::from([(String::from("member_name"), member_name)]),
-        )
+        ),
     }
 }
 
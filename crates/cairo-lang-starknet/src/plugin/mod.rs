@@ -4,8 +4,11 @@ mod test;
 pub mod consts;
 use std::sync::Arc;
 
-use cairo_lang_defs::plugin::{MacroPlugin, PluginResult};
-use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin};
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_semantic::patcher::PatchBuilder;
+use cairo_lang_semantic::plugin::{AsDynMacroPlugin, DynPluginAuxData, SemanticPlugin};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use consts::*;
@@ -16,11 +19,13 @@ mod dispatcher;
 mod entry_point;
 mod events;
 mod storage;
-mod utils;
+mod storage_access;
+pub(crate) mod utils;
 
+use aux_data::StarkNetABIAuxData;
 use contract::handle_mod;
 use dispatcher::handle_trait;
-use events::handle_struct;
+use utils::has_derive;
 
 use self::events::handle_enum;
 
@@ -49,3 +54,36 @@ impl AsDynMacroPlugin for StarkNetPlugin {
     }
 }
 impl SemanticPlugin for StarkNetPlugin {}
+
+/// Derive the Starknet traits (`starknet::Event` and `starknet::StorageAccess`) for a struct.
+fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> PluginResult {
+    let mut builder = PatchBuilder::new(db);
+    let mut diagnostics = vec![];
+    if has_derive(&struct_ast, db, EVENT_TRAIT) {
+        if let Some(event_impl) = events::handle_struct(db, &struct_ast, &mut diagnostics) {
+            builder.add_modified(event_impl);
+        }
+    }
+    if has_derive(&struct_ast, db, STORAGE_ACCESS_TRAIT) {
+        if let Some(storage_access_impl) =
+            storage_access::handle_struct(db, &struct_ast, &mut diagnostics)
+        {
+            builder.add_modified(storage_access_impl);
+        }
+    }
+    if builder.code.is_empty() {
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    }
+
+    PluginResult {
+        code: Some(PluginGeneratedFile {
+            name: "impls".into(),
+            content: builder.code,
+            aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(StarkNetABIAuxData {
+                patches: builder.patches,
+            })),
+        }),
+        diagnostics,
+        remove_original_item: false,
+    }
+}
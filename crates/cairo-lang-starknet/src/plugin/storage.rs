@@ -4,25 +4,59 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_semantic::patcher::RewriteNode;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::try_extract_matches;
+use itertools::Itertools;
+use smol_str::SmolStr;
 
 use crate::contract::starknet_keccak;
+use crate::storage_layout::StorageVariableKind;
+
+/// The names of the items generated in the module of a storage variable, which members of storage
+/// structs can't use as names.
+const STORAGE_VAR_ITEM_NAMES: [&str; 4] = ["address", "offset", "read", "write"];
 
 /// Generate getters and setters for the variables in the storage struct.
+/// Also returns the names and kinds of the variables, for the storage layout of the contract.
+///
+/// `storage_structs` are the structs of the contract deriving `starknet::StorageAccess`. Storage
+/// variables of these types also get getters and setters for each of their members, in modules
+/// nested in the module of the variable. `extra_use_idents` are the identifiers used by the
+/// generated modules from the contract module.
+///
+/// `storage_namespace` is the name of the component, for the storage of a component. The address
+/// of a variable of a component is derived from its name prefixed by the namespace (e.g.
+/// `Ownable::owner`), so it doesn't collide with the variables of the embedding contract.
 pub fn handle_storage_struct(
     db: &dyn SyntaxGroup,
     struct_ast: ast::ItemStruct,
     extra_uses_node: &RewriteNode,
-) -> (RewriteNode, Vec<PluginDiagnostic>) {
+    storage_structs: &OrderedHashMap<SmolStr, ast::ItemStruct>,
+    extra_use_idents: &[SmolStr],
+    storage_namespace: Option<&str>,
+) -> (RewriteNode, Vec<(SmolStr, StorageVariableKind)>, Vec<PluginDiagnostic>) {
     let mut members_code = Vec::new();
+    let mut storage_variables = vec![];
     let mut diagnostics = vec![];
 
     for member in struct_ast.members(db).elements(db) {
         let name = member.name(db).text(db);
-        let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        let address_name = match storage_namespace {
+            Some(namespace) => format!("{namespace}::{name}"),
+            None => name.to_string(),
+        };
+        let address = format!("0x{:x}", starknet_keccak(address_name.as_bytes()));
         let type_ast = member.type_clause(db).ty(db);
         match try_extract_mapping_types(db, &type_ast) {
             Some((key_type_ast, value_type_ast, MappingType::Legacy)) => {
+                if try_extract_generic_arg_mapping_types(db, &value_type_ast).is_some() {
+                    diagnostics.push(PluginDiagnostic {
+                        message: "Nested mappings are only supported with `Map`.".to_string(),
+                        stable_ptr: value_type_ast.stable_ptr().untyped(),
+                    });
+                    continue;
+                }
+                storage_variables.push((name.clone(), StorageVariableKind::LegacyMap));
                 members_code.push(RewriteNode::interpolate_patched(
                     handle_legacy_mapping_storage_var(&address).as_str(),
                     HashMap::from([
@@ -42,13 +76,52 @@ pub fn handle_storage_struct(
                     ]),
                 ));
             }
-            Some((_, _, MappingType::NonLegacy)) => {
-                diagnostics.push(PluginDiagnostic {
-                    message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
-                    stable_ptr: type_ast.stable_ptr().untyped(),
-                });
+            Some((key_type_ast, value_type_ast, MappingType::NonLegacy)) => {
+                let (key_types, value_type_ast) =
+                    match flatten_nested_mapping(db, key_type_ast, value_type_ast) {
+                        Ok(flattened) => flattened,
+                        Err(diagnostic) => {
+                            diagnostics.push(diagnostic);
+                            continue;
+                        }
+                    };
+                let mut patches = HashMap::from([
+                    (
+                        "storage_var_name".to_string(),
+                        RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                    ),
+                    ("extra_uses".to_string(), extra_uses_node.clone()),
+                    (
+                        "value_type".to_string(),
+                        RewriteNode::new_trimmed(value_type_ast.as_syntax_node()),
+                    ),
+                ]);
+                for (i, key_type_ast) in key_types.iter().enumerate() {
+                    patches.insert(
+                        format!("key_type_{i}"),
+                        RewriteNode::new_trimmed(key_type_ast.as_syntax_node()),
+                    );
+                }
+                storage_variables.push((name.clone(), StorageVariableKind::Map));
+                members_code.push(RewriteNode::interpolate_patched(
+                    handle_mapping_storage_var(&address, key_types.len()).as_str(),
+                    patches,
+                ));
             }
             None => {
+                let member_accessors =
+                    match try_extract_storage_struct(db, &type_ast, storage_structs) {
+                        Some(storage_struct) => handle_storage_struct_members(
+                            db,
+                            storage_struct,
+                            storage_structs,
+                            extra_use_idents,
+                            &mut vec![],
+                            &mut diagnostics,
+                        ),
+                        None => RewriteNode::Text("".to_string()),
+                    };
+                storage_variables.push((name.clone(), StorageVariableKind::Simple));
                 members_code.push(RewriteNode::interpolate_patched(
                     handle_simple_storage_var(&address).as_str(),
                     HashMap::from([
@@ -61,19 +134,139 @@ pub fn handle_storage_struct(
                             "type_name".to_string(),
                             RewriteNode::new_trimmed(type_ast.as_syntax_node()),
                         ),
+                        ("member_accessors".to_string(), member_accessors),
                     ]),
                 ));
             }
         }
     }
-    (RewriteNode::new_modified(members_code), diagnostics)
+    (RewriteNode::new_modified(members_code), storage_variables, diagnostics)
+}
+
+/// Given a type, if it is a path to one of the `storage_structs`, returns this struct.
+fn try_extract_storage_struct<'a>(
+    db: &dyn SyntaxGroup,
+    type_ast: &ast::Expr,
+    storage_structs: &'a OrderedHashMap<SmolStr, ast::ItemStruct>,
+) -> Option<&'a ast::ItemStruct> {
+    let as_path = try_extract_matches!(type_ast, ast::Expr::Path)?;
+    let [ast::PathSegment::Simple(segment)] = &as_path.elements(db)[..] else {
+        return None;
+    };
+    storage_structs.get(&segment.ident(db).text(db))
+}
+
+/// Generate getters and setters for each member of a storage struct, to be nested in the module
+/// of a storage variable (or of a member) of this type.
+///
+/// `parents` are the storage structs whose members' modules the generated modules are nested in.
+/// A member is stored at the offset following the previous member, relative to the offset of the
+/// enclosing module (0 for a storage variable).
+fn handle_storage_struct_members(
+    db: &dyn SyntaxGroup,
+    struct_ast: &ast::ItemStruct,
+    storage_structs: &OrderedHashMap<SmolStr, ast::ItemStruct>,
+    extra_use_idents: &[SmolStr],
+    parents: &mut Vec<SmolStr>,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> RewriteNode {
+    let struct_name = struct_ast.name(db).text(db);
+    if parents.contains(&struct_name) {
+        return RewriteNode::Text("".to_string());
+    }
+    let indent = "    ".repeat(parents.len() + 2);
+    let base_offset = if parents.is_empty() { "0_u8" } else { "super::offset()" };
+    let uses =
+        extra_use_idents.iter().map(|ident| format!("\n{indent}    use super::{ident};")).join("");
+    parents.push(struct_name);
+
+    let mut members_code = vec![];
+    let mut previous_sizes = vec![];
+    for member in struct_ast.members(db).elements(db) {
+        let name = member.name(db).text(db);
+        let type_ast = member.type_clause(db).ty(db);
+        let member_type = RewriteNode::new_trimmed(type_ast.as_syntax_node());
+        let size = RewriteNode::interpolate_patched(
+            " + starknet::StorageAccess::<$member_type$>::size_internal()",
+            HashMap::from([("member_type".to_string(), member_type.clone())]),
+        );
+        if STORAGE_VAR_ITEM_NAMES.contains(&name.as_str()) {
+            diagnostics.push(PluginDiagnostic {
+                message: format!(
+                    "Storage struct members can't be named `{name}`, as it is used by the \
+                     generated storage accessors."
+                ),
+                stable_ptr: member.name(db).stable_ptr().untyped(),
+            });
+            previous_sizes.push(size);
+            continue;
+        }
+        let nested_members = match try_extract_storage_struct(db, &type_ast, storage_structs) {
+            Some(storage_struct) => handle_storage_struct_members(
+                db,
+                storage_struct,
+                storage_structs,
+                extra_use_idents,
+                parents,
+                diagnostics,
+            ),
+            None => RewriteNode::Text("".to_string()),
+        };
+        members_code.push(RewriteNode::interpolate_patched(
+            &format!(
+                "
+{indent}mod $member_name$ {{{uses}
+{indent}    use starknet::SyscallResultTrait;
+{indent}    use starknet::SyscallResultTraitImpl;
+
+{indent}    fn address() -> starknet::StorageBaseAddress {{
+{indent}        super::address()
+{indent}    }}
+{indent}    fn offset() -> u8 {{
+{indent}        {base_offset}$previous_sizes$
+{indent}    }}
+{indent}    fn read() -> $member_type$ {{
+{indent}        // Only address_domain 0 is currently supported.
+{indent}        let address_domain = 0_u32;
+{indent}        starknet::StorageAccess::<$member_type$>::read_at_offset_internal(
+{indent}            address_domain,
+{indent}            address(),
+{indent}            offset(),
+{indent}        ).unwrap_syscall()
+{indent}    }}
+{indent}    fn write(value: $member_type$) {{
+{indent}        // Only address_domain 0 is currently supported.
+{indent}        let address_domain = 0_u32;
+{indent}        starknet::StorageAccess::<$member_type$>::write_at_offset_internal(
+{indent}            address_domain,
+{indent}            address(),
+{indent}            offset(),
+{indent}            value,
+{indent}        ).unwrap_syscall()
+{indent}    }}$nested_members$
+{indent}}}"
+            ),
+            HashMap::from([
+                (
+                    "member_name".to_string(),
+                    RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+                ),
+                ("member_type".to_string(), member_type),
+                ("previous_sizes".to_string(), RewriteNode::new_modified(previous_sizes.clone())),
+                ("nested_members".to_string(), nested_members),
+            ]),
+        ));
+        previous_sizes.push(size);
+    }
+    parents.pop();
+    RewriteNode::new_modified(members_code)
 }
 
 /// The type of the mapping storage variable.
 enum MappingType {
     /// Pedersen based.
     Legacy,
-    /// Poseidon based.
+    /// Poseidon based. See `starknet::storage_access::storage_map_entry_address`.
     NonLegacy,
 }
 
@@ -103,6 +296,38 @@ fn try_extract_mapping_types(
     }
 }
 
+/// Flattens a nested `Map` (e.g. `Map::<K0, Map::<K1, V>>`) into its key types and its innermost
+/// value type.
+fn flatten_nested_mapping(
+    db: &dyn SyntaxGroup,
+    key_type_ast: ast::GenericArg,
+    mut value_type_ast: ast::GenericArg,
+) -> Result<(Vec<ast::GenericArg>, ast::GenericArg), PluginDiagnostic> {
+    let mut key_types = vec![key_type_ast];
+    while let Some((key_type_ast, inner_value_type_ast, mapping_type)) =
+        try_extract_generic_arg_mapping_types(db, &value_type_ast)
+    {
+        if let MappingType::Legacy = mapping_type {
+            return Err(PluginDiagnostic {
+                message: "Nested mappings are only supported with `Map`.".to_string(),
+                stable_ptr: value_type_ast.stable_ptr().untyped(),
+            });
+        }
+        key_types.push(key_type_ast);
+        value_type_ast = inner_value_type_ast;
+    }
+    Ok((key_types, value_type_ast))
+}
+
+/// Same as [try_extract_mapping_types], for a generic argument.
+fn try_extract_generic_arg_mapping_types(
+    db: &dyn SyntaxGroup,
+    generic_arg: &ast::GenericArg,
+) -> Option<(ast::GenericArg, ast::GenericArg, MappingType)> {
+    let expr = try_extract_matches!(generic_arg, ast::GenericArg::Expr)?;
+    try_extract_mapping_types(db, &expr.value(db))
+}
+
 /// Generate getters and setters skeleton for a non-mapping member in the storage struct.
 fn handle_simple_storage_var(address: &str) -> String {
     format!(
@@ -130,12 +355,12 @@ fn handle_simple_storage_var(address: &str) -> String {
                 address(),
                 value,
             ).unwrap_syscall()
-        }}
+        }}$member_accessors$
     }}"
     )
 }
 
-/// Generate getters and setters skeleton for a non-mapping member in the storage struct.
+/// Generate getters and setters skeleton for a legacy mapping member in the storage struct.
 fn handle_legacy_mapping_storage_var(address: &str) -> String {
     format!(
         "
@@ -167,3 +392,63 @@ fn handle_legacy_mapping_storage_var(address: &str) -> String {
     }}"
     )
 }
+
+/// Generate getters and setters skeleton for a (possibly nested) mapping member in the storage
+/// struct, with `n_keys` keys.
+///
+/// The address of an entry is derived by
+/// `starknet::storage_access::storage_map_entry_address`, once per key, starting from the address
+/// of the storage variable.
+fn handle_mapping_storage_var(address: &str, n_keys: usize) -> String {
+    let key_names = if n_keys == 1 {
+        vec!["key".to_string()]
+    } else {
+        (0..n_keys).map(|i| format!("key{i}")).collect()
+    };
+    let params = key_names
+        .iter()
+        .enumerate()
+        .map(|(i, key_name)| format!("{key_name}: $key_type_{i}$"))
+        .join(", ");
+    let args = key_names.join(", ");
+    let address_derivation = key_names
+        .iter()
+        .map(|key_name| {
+            format!(
+                "
+            let address = starknet::storage_access::storage_map_entry_address(
+                address, @{key_name}
+            );"
+            )
+        })
+        .join("");
+    format!(
+        "
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+
+        fn address({params}) -> starknet::StorageBaseAddress {{
+            let address = {address};{address_derivation}
+            starknet::storage_base_address_from_felt252(address)
+        }}
+        fn read({params}) -> $value_type$ {{
+            // Only address_domain 0 is currently supported.
+            let address_domain = 0_u32;
+            starknet::StorageAccess::<$value_type$>::read(
+                address_domain,
+                address({args}),
+            ).unwrap_syscall()
+        }}
+        fn write({params}, value: $value_type$) {{
+            // Only address_domain 0 is currently supported.
+            let address_domain = 0_u32;
+            starknet::StorageAccess::<$value_type$>::write(
+                address_domain,
+                address({args}),
+                value,
+            ).unwrap_syscall()
+        }}
+    }}"
+    )
+}
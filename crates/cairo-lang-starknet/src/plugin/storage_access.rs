@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_semantic::patcher::RewriteNode;
+use cairo_lang_syntax::node::ast::{self, OptionWrappedGenericParamList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use indoc::indoc;
+
+/// Derive the `StorageAccess` trait for structs annotated with `derive(starknet::StorageAccess)`.
+/// The members are stored one after the other, each at the offset following the previous member.
+/// Returns the code of the implementation, or None if it could not be derived.
+pub fn handle_struct(
+    db: &dyn SyntaxGroup,
+    struct_ast: &ast::ItemStruct,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<RewriteNode> {
+    let generic_params = struct_ast.generic_params(db);
+    let OptionWrappedGenericParamList::Empty(_) = generic_params else {
+        diagnostics.push(PluginDiagnostic {
+            message: "Storage structs with generic arguments are unsupported".to_string(),
+            stable_ptr: generic_params.stable_ptr().untyped(),
+        });
+        return None;
+    };
+
+    let mut read_members = vec![];
+    let mut write_members = vec![];
+    let mut member_sizes = vec![];
+    let mut member_names = vec![];
+    for member in struct_ast.members(db).elements(db) {
+        let patches = HashMap::from([
+            ("member_name".to_string(), RewriteNode::new_trimmed(member.name(db).as_syntax_node())),
+            (
+                "member_type".to_string(),
+                RewriteNode::new_trimmed(member.type_clause(db).ty(db).as_syntax_node()),
+            ),
+        ]);
+        read_members.push(RewriteNode::interpolate_patched(
+            "
+        let $member_name$ = starknet::StorageAccess::<$member_type$>::read_at_offset_internal(
+            address_domain, base, offset
+        )?;
+        let offset = offset + starknet::StorageAccess::<$member_type$>::size_internal();",
+            patches.clone(),
+        ));
+        write_members.push(RewriteNode::interpolate_patched(
+            "
+        starknet::StorageAccess::<$member_type$>::write_at_offset_internal(
+            address_domain, base, offset, $member_name$
+        )?;
+        let offset = offset + starknet::StorageAccess::<$member_type$>::size_internal();",
+            patches.clone(),
+        ));
+        member_sizes.push(RewriteNode::interpolate_patched(
+            " + starknet::StorageAccess::<$member_type$>::size_internal()",
+            patches.clone(),
+        ));
+        member_names.push(RewriteNode::interpolate_patched("$member_name$, ", patches));
+    }
+
+    Some(RewriteNode::interpolate_patched(
+        indoc! {"
+            impl $struct_name$StorageAccess of starknet::StorageAccess::<$struct_name$> {
+                fn read(
+                    address_domain: u32, base: starknet::StorageBaseAddress
+                ) -> starknet::SyscallResult<$struct_name$> {
+                    starknet::StorageAccess::<$struct_name$>::read_at_offset_internal(
+                        address_domain, base, 0_u8
+                    )
+                }
+                fn write(
+                    address_domain: u32, base: starknet::StorageBaseAddress, value: $struct_name$
+                ) -> starknet::SyscallResult<()> {
+                    starknet::StorageAccess::<$struct_name$>::write_at_offset_internal(
+                        address_domain, base, 0_u8, value
+                    )
+                }
+                fn read_at_offset_internal(
+                    address_domain: u32, base: starknet::StorageBaseAddress, offset: u8
+                ) -> starknet::SyscallResult<$struct_name$> {$read_members$
+                    Result::Ok($struct_name$ {$member_names$})
+                }
+                fn write_at_offset_internal(
+                    address_domain: u32, base: starknet::StorageBaseAddress, offset: u8,
+                    value: $struct_name$
+                ) -> starknet::SyscallResult<()> {
+                    let $struct_name$ {$member_names$} = value;$write_members$
+                    Result::Ok(())
+                }
+                fn size_internal() -> u8 {
+                    0_u8$member_sizes$
+                }
+            }
+        "},
+        HashMap::from([
+            (
+                "struct_name".to_string(),
+                RewriteNode::new_trimmed(struct_ast.name(db).as_syntax_node()),
+            ),
+            ("read_members".to_string(), RewriteNode::new_modified(read_members)),
+            ("write_members".to_string(), RewriteNode::new_modified(write_members)),
+            ("member_sizes".to_string(), RewriteNode::new_modified(member_sizes)),
+            ("member_names".to_string(), RewriteNode::new_modified(member_names)),
+        ]),
+    ))
+}
@@ -73,6 +73,7 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         dispatcher: "dispatcher",
         user_defined_types: "user_defined_types",
         l1_handler: "l1_handler",
+        component: "component",
     },
     ExpandContractTestRunner
 );
@@ -1,6 +1,13 @@
+use cairo_lang_syntax::attribute::structured::{
+    Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
+};
 use cairo_lang_syntax::node::ast::{self, Modifier};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::Terminal;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+
+use super::consts::{EVENT_ATTR, KEY_ATTR};
+use crate::abi::EventFieldKind;
 
 /// Checks if the parameter is defined as a ref parameter.
 pub fn is_ref_param(db: &dyn SyntaxGroup, param: &ast::Param) -> bool {
@@ -64,3 +71,48 @@ pub fn maybe_strip_underscore(s: &str) -> &str {
         None => s,
     }
 }
+
+/// Returns true if the item has a `derive` attribute with the given derived trait path (e.g.
+/// `starknet::Event`).
+pub fn has_derive<T: QueryAttrs>(
+    with_attrs: &T,
+    db: &dyn SyntaxGroup,
+    derived_trait: &str,
+) -> bool {
+    with_attrs
+        .query_attr(db, "derive")
+        .into_iter()
+        .any(|attr| is_derive_of(db, &attr.structurize(db), derived_trait))
+}
+
+/// Returns true if the attribute is a `derive` attribute with the given derived trait path (e.g.
+/// `starknet::Event`).
+pub fn is_derive_of(db: &dyn SyntaxGroup, attr: &Attribute, derived_trait: &str) -> bool {
+    if attr.id != "derive" {
+        return false;
+    }
+    attr.args.iter().any(|arg| {
+        let AttributeArg{
+            variant: AttributeArgVariant::Unnamed {
+                value: ast::Expr::Path(path),
+                ..
+            },
+            ..
+        } = arg else {
+            return false;
+        };
+        path.as_syntax_node().get_text_without_trivia(db) == derived_trait
+    })
+}
+
+/// Returns the way a member of an event struct (or a variant of an event enum) is serialized, by
+/// its attributes.
+pub fn get_event_field_kind(db: &dyn SyntaxGroup, member: &ast::Member) -> EventFieldKind {
+    if member.has_attr(db, EVENT_ATTR) {
+        EventFieldKind::Nested
+    } else if member.has_attr(db, KEY_ATTR) {
+        EventFieldKind::Key
+    } else {
+        EventFieldKind::Data
+    }
+}
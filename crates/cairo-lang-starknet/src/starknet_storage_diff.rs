@@ -0,0 +1,55 @@
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_starknet::storage_layout::{diff_storage_layouts, StorageLayout};
+use clap::Parser;
+use serde::de::DeserializeOwned;
+
+/// Command line args parser.
+/// Compares the storage layouts of two compiled versions of a contract, and reports the changes
+/// that would corrupt the state of the contract when upgrading from the old version to the new one.
+/// Each version is given by its contract class and its storage layout, as emitted by
+/// `starknet-compile --storage-layout-output`.
+/// Exits with 0/1 if the layouts are compatible/incompatible.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The contract class of the old version of the contract.
+    old: String,
+    /// The contract class of the new version of the contract.
+    new: String,
+    /// The storage layout of the old version of the contract.
+    #[arg(long)]
+    old_storage_layout: String,
+    /// The storage layout of the new version of the contract.
+    #[arg(long)]
+    new_storage_layout: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    // The contract classes are only read to validate that the given files are compiled contracts.
+    read_json::<ContractClass>(&args.old, "contract class")?;
+    read_json::<ContractClass>(&args.new, "contract class")?;
+    let old = read_json::<StorageLayout>(&args.old_storage_layout, "storage layout")?;
+    let new = read_json::<StorageLayout>(&args.new_storage_layout, "storage layout")?;
+    let changes = diff_storage_layouts(&old, &new);
+    for change in &changes {
+        println!("{change}");
+    }
+    let incompatible_changes = changes.iter().filter(|change| !change.is_compatible()).count();
+    if incompatible_changes == 0 {
+        println!("The storage layouts are compatible.");
+        return Ok(());
+    }
+    anyhow::bail!("Found {incompatible_changes} storage layout incompatibilities.");
+}
+
+/// Reads a JSON file, describing the given kind of artifact.
+fn read_json<T: DeserializeOwned>(path: &str, kind: &str) -> anyhow::Result<T> {
+    serde_json::from_str(
+        &fs::read_to_string(path).with_context(|| format!("Failed to read {path}."))?,
+    )
+    .with_context(|| format!("Failed to deserialize the {kind} in {path}."))
+}
@@ -0,0 +1,304 @@
+use anyhow::Context;
+use cairo_lang_defs::ids::{FreeFunctionId, ModuleId, ModuleItemId, SubmoduleId};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_semantic::corelib::{
+    core_bool_ty, core_felt252_ty, core_submodule, get_core_ty_by_name, get_ty_by_name,
+};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::{ConcreteTypeId, TypeId, TypeLongId};
+use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint};
+use itertools::chain;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::contract::{
+    get_embedded_components, get_generated_contract_module, get_plugin_aux_data, starknet_keccak,
+    ContractDeclaration,
+};
+use crate::plugin::consts::STORAGE_ACCESS_TRAIT;
+use crate::plugin::utils::is_derive_of;
+
+#[cfg(test)]
+#[path = "storage_layout_test.rs"]
+mod test;
+
+/// The storage layout of a contract.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageLayout {
+    /// The storage variables of the contract, by order of declaration.
+    pub variables: Vec<StorageVariable>,
+}
+impl StorageLayout {
+    pub fn json(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+
+    /// Returns the storage variable with the given name, if any.
+    fn variable(&self, name: &str) -> Option<&StorageVariable> {
+        self.variables.iter().find(|var| var.name == name)
+    }
+}
+
+/// A storage variable of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageVariable {
+    pub name: String,
+    /// The base address of the variable: the `starknet_keccak` of its name.
+    #[serde(serialize_with = "serialize_big_uint", deserialize_with = "deserialize_big_uint")]
+    pub address: BigUint,
+    pub kind: StorageVariableKind,
+    /// The types of the keys of a mapping, empty for a simple variable.
+    pub key_types: Vec<String>,
+    pub value_type: String,
+    /// The number of consecutive storage slots a value takes (for a mapping, per entry), if known.
+    pub slots: Option<usize>,
+}
+
+/// The kind of a storage variable, which determines how the addresses of its values are derived.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageVariableKind {
+    /// A single value, stored at the base address of the variable.
+    Simple,
+    /// A `LegacyMap`, whose entries are stored at Pedersen based addresses.
+    LegacyMap,
+    /// A (possibly nested) `Map`, whose entries are stored at Poseidon based addresses.
+    Map,
+}
+
+/// A change between the storage layouts of two versions of a contract. All changes but added
+/// variables would corrupt the state of the contract when upgrading from the old version to the new
+/// one.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum StorageLayoutChange {
+    #[error("Storage variable `{name}` was added.")]
+    Added { name: String },
+    #[error("Storage variable `{name}` was removed.")]
+    Removed { name: String },
+    #[error("The kind of storage variable `{name}` changed from `{old:?}` to `{new:?}`.")]
+    KindChanged { name: String, old: StorageVariableKind, new: StorageVariableKind },
+    #[error("The key types of storage variable `{name}` changed from `({old})` to `({new})`.")]
+    KeyTypesChanged { name: String, old: String, new: String },
+    #[error("The value type of storage variable `{name}` changed from `{old}` to `{new}`.")]
+    ValueTypeChanged { name: String, old: String, new: String },
+    #[error("The number of storage slots of `{name}` changed from {old} to {new}.")]
+    SlotsChanged { name: String, old: String, new: String },
+    #[error("Storage variable `{name}` collides with the storage of `{other}`.")]
+    Collision { name: String, other: String },
+}
+impl StorageLayoutChange {
+    /// Returns whether the change keeps the state of the contract intact after the upgrade.
+    pub fn is_compatible(&self) -> bool {
+        matches!(self, StorageLayoutChange::Added { .. })
+    }
+}
+
+/// Returns the changes between the storage layout of a contract (`old`) and the storage layout of
+/// the contract it is upgraded to (`new`), e.g. using `replace_class_syscall`.
+///
+/// Variables are matched by name, as the base address of a variable is the `starknet_keccak` of its
+/// name, regardless of its position in the layout. The kind, the key types, the value type and the
+/// number of slots of matched variables are compared independently of each other.
+/// Added variables are compatible, unless their storage collides with the storage of another
+/// variable (of either layout).
+pub fn diff_storage_layouts(old: &StorageLayout, new: &StorageLayout) -> Vec<StorageLayoutChange> {
+    let mut changes = vec![];
+    for old_var in &old.variables {
+        let name = &old_var.name;
+        let Some(new_var) = new.variable(name) else {
+            changes.push(StorageLayoutChange::Removed { name: name.clone() });
+            continue;
+        };
+        if old_var.kind != new_var.kind {
+            changes.push(StorageLayoutChange::KindChanged {
+                name: name.clone(),
+                old: old_var.kind,
+                new: new_var.kind,
+            });
+        }
+        if old_var.key_types != new_var.key_types {
+            changes.push(StorageLayoutChange::KeyTypesChanged {
+                name: name.clone(),
+                old: old_var.key_types.join(", "),
+                new: new_var.key_types.join(", "),
+            });
+        }
+        if old_var.value_type != new_var.value_type {
+            changes.push(StorageLayoutChange::ValueTypeChanged {
+                name: name.clone(),
+                old: old_var.value_type.clone(),
+                new: new_var.value_type.clone(),
+            });
+        }
+        if old_var.slots != new_var.slots {
+            let format_slots = |slots: Option<usize>| match slots {
+                Some(slots) => slots.to_string(),
+                None => "unknown".to_string(),
+            };
+            changes.push(StorageLayoutChange::SlotsChanged {
+                name: name.clone(),
+                old: format_slots(old_var.slots),
+                new: format_slots(new_var.slots),
+            });
+        }
+    }
+    for new_var in &new.variables {
+        if old.variable(&new_var.name).is_none() {
+            changes.push(StorageLayoutChange::Added { name: new_var.name.clone() });
+        }
+    }
+
+    // Only the storage of simple variables is contiguous; the entries of mappings are stored at
+    // hash based addresses.
+    let simple_vars = |layout: &'_ StorageLayout| {
+        layout
+            .variables
+            .iter()
+            .filter(|var| var.kind == StorageVariableKind::Simple)
+            .map(|var| {
+                let start = var.address.clone();
+                // A variable with an unknown size is assumed to take a single slot.
+                let end = &start + var.slots.unwrap_or(1);
+                (var.name.clone(), start, end)
+            })
+            .collect::<Vec<_>>()
+    };
+    let new_vars = simple_vars(new);
+    // Old variables still hold their values after the upgrade, even if they were removed.
+    let mut other_vars = simple_vars(old);
+    other_vars.retain(|(name, _, _)| new_vars.iter().all(|(new_name, _, _)| new_name != name));
+    for (i, (name, start, end)) in new_vars.iter().enumerate() {
+        for (other, other_start, other_end) in chain!(&new_vars[..i], &other_vars) {
+            if start < other_end && other_start < end {
+                changes.push(StorageLayoutChange::Collision {
+                    name: name.clone(),
+                    other: other.clone(),
+                });
+            }
+        }
+    }
+    changes
+}
+
+/// Returns the storage layout of the given contract.
+///
+/// The variables of the components embedded in the contract follow the variables of the contract,
+/// named by their namespaced names (e.g. `Ownable::owner`).
+pub fn get_storage_layout(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<StorageLayout> {
+    let mut variables = get_storage_variables(
+        db,
+        contract.submodule_id,
+        get_generated_contract_module(db, contract)?,
+        None,
+    )?;
+    for component_module_id in get_embedded_components(db, contract)? {
+        let ModuleId::Submodule(component_submodule_id) = component_module_id else {
+            anyhow::bail!("Failed to get the module of an embedded component.");
+        };
+        let namespace = component_submodule_id.name(db.upcast());
+        variables.extend(get_storage_variables(
+            db,
+            component_submodule_id,
+            component_module_id,
+            Some(&namespace),
+        )?);
+    }
+    Ok(StorageLayout { variables })
+}
+
+/// Returns the storage variables of the given contract or component, as recorded by the plugin.
+/// `storage_namespace` is the name of the component, for a component.
+fn get_storage_variables(
+    db: &dyn SemanticGroup,
+    submodule_id: SubmoduleId,
+    generated_module_id: ModuleId,
+    storage_namespace: Option<&str>,
+) -> anyhow::Result<Vec<StorageVariable>> {
+    get_plugin_aux_data(db, submodule_id, |aux_data| aux_data.storage_variables.clone())?
+        .into_iter()
+        .map(|(name, kind)| {
+            let read_function_id =
+                get_storage_variable_read_function(db, generated_module_id, &name)?;
+            let signature = db
+                .free_function_signature(read_function_id)
+                .to_option()
+                .with_context(|| format!("Failed to get the signature of `{name}::read`."))?;
+            let name = match storage_namespace {
+                Some(namespace) => format!("{namespace}::{name}"),
+                None => name.to_string(),
+            };
+            Ok(StorageVariable {
+                address: starknet_keccak(name.as_bytes()),
+                name,
+                kind,
+                key_types: signature.params.iter().map(|param| param.ty.format(db)).collect(),
+                value_type: signature.return_type.format(db),
+                slots: storage_slots(db, signature.return_type),
+            })
+        })
+        .collect()
+}
+
+/// Returns the `read` function generated for the given storage variable.
+fn get_storage_variable_read_function(
+    db: &dyn SemanticGroup,
+    generated_module_id: ModuleId,
+    name: &SmolStr,
+) -> anyhow::Result<FreeFunctionId> {
+    let Some(ModuleItemId::Submodule(storage_variable_module_id)) = db
+        .module_item_by_name(generated_module_id, name.clone())
+        .to_option()
+        .with_context(|| "Failed to initiate a lookup in the generated module.")? else {
+        anyhow::bail!("Failed to get the module of storage variable `{name}`.");
+    };
+    match db
+        .module_item_by_name(ModuleId::Submodule(storage_variable_module_id), "read".into())
+        .to_option()
+        .with_context(|| format!("Failed to initiate a lookup in the `{name}` module."))?
+    {
+        Some(ModuleItemId::FreeFunction(function_id)) => Ok(function_id),
+        _ => anyhow::bail!("Failed to get the `read` function of storage variable `{name}`."),
+    }
+}
+
+/// Returns the number of consecutive storage slots a value of the given type takes, if known.
+///
+/// This is known for the types whose `StorageAccess` implementation is in the corelib, and for
+/// structs deriving `starknet::StorageAccess`, which are stored member after member.
+fn storage_slots(db: &dyn SemanticGroup, ty: TypeId) -> Option<usize> {
+    if let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
+        db.lookup_intern_type(ty)
+    {
+        let struct_id = concrete_struct_id.struct_id(db);
+        let attributes = db.struct_attributes(struct_id).ok()?;
+        if attributes.iter().any(|attr| is_derive_of(db.upcast(), attr, STORAGE_ACCESS_TRAIT)) {
+            return db
+                .concrete_struct_members(concrete_struct_id)
+                .ok()?
+                .values()
+                .map(|member| storage_slots(db, member.ty))
+                .sum();
+        }
+    }
+    let core_ty = |name: &str| get_core_ty_by_name(db, name.into(), vec![]);
+    let starknet_module = core_submodule(db, "starknet");
+    let starknet_ty = |name: &str| get_ty_by_name(db, starknet_module, name.into(), vec![]);
+    let mut single_slot_types = chain!(
+        [core_felt252_ty(db), core_bool_ty(db), core_ty("ByteArray")],
+        ["u8", "u16", "u32", "u64", "u128"].map(core_ty),
+        ["StorageAddress", "ContractAddress", "ClassHash"].map(starknet_ty),
+    );
+    if single_slot_types.any(|single_slot_ty| single_slot_ty == ty) {
+        Some(1)
+    } else if ty == core_ty("u256") {
+        Some(2)
+    } else {
+        None
+    }
+}
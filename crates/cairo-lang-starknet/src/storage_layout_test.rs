@@ -0,0 +1,346 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use crate::contract::find_contracts;
+use crate::plugin::StarkNetPlugin;
+use crate::storage_layout::{
+    diff_storage_layouts, get_storage_layout, StorageLayout, StorageLayoutChange, StorageVariable,
+    StorageVariableKind,
+};
+
+#[test]
+fn test_storage_layout() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[contract]
+            mod TestContract {
+                use starknet::ContractAddress;
+
+                #[derive(Copy, Drop, starknet::StorageAccess)]
+                struct Config {
+                    owner: ContractAddress,
+                    limit: u256,
+                }
+
+                #[derive(Copy, Drop)]
+                struct Wrapper {
+                    value: felt252,
+                }
+                impl WrapperStorageAccess of starknet::StorageAccess::<Wrapper> {
+                    fn read(
+                        address_domain: u32, base: starknet::StorageBaseAddress
+                    ) -> starknet::SyscallResult<Wrapper> {
+                        Result::Ok(Wrapper { value: starknet::StorageAccess::read(address_domain, base)? })
+                    }
+                    fn write(
+                        address_domain: u32, base: starknet::StorageBaseAddress, value: Wrapper
+                    ) -> starknet::SyscallResult<()> {
+                        starknet::StorageAccess::write(address_domain, base, value.value)
+                    }
+                    fn read_at_offset_internal(
+                        address_domain: u32, base: starknet::StorageBaseAddress, offset: u8
+                    ) -> starknet::SyscallResult<Wrapper> {
+                        Result::Ok(
+                            Wrapper {
+                                value: starknet::StorageAccess::read_at_offset_internal(
+                                    address_domain, base, offset
+                                )?
+                            }
+                        )
+                    }
+                    fn write_at_offset_internal(
+                        address_domain: u32, base: starknet::StorageBaseAddress, offset: u8,
+                        value: Wrapper
+                    ) -> starknet::SyscallResult<()> {
+                        starknet::StorageAccess::write_at_offset_internal(
+                            address_domain, base, offset, value.value
+                        )
+                    }
+                    fn size_internal() -> u8 {
+                        1_u8
+                    }
+                }
+
+                struct Storage {
+                    total_supply: u256,
+                    balances: LegacyMap::<ContractAddress, u256>,
+                    allowances: Map::<ContractAddress, Map::<ContractAddress, u256>>,
+                    config: Config,
+                    name: ByteArray,
+                    wrapper: Wrapper,
+                }
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(
+        get_storage_layout(db, &contracts[0]).unwrap().json(),
+        indoc! {r#"
+            {
+              "variables": [
+                {
+                  "name": "total_supply",
+                  "address": "0x1557182e4359a1f0c6301278e8f5b35a776ab58d39892581e357578fb287836",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "core::integer::u256",
+                  "slots": 2
+                },
+                {
+                  "name": "balances",
+                  "address": "0x25b1ef8ee6544359221f3cf316f768360e83448109193bdcef77f52a79d95c4",
+                  "kind": "legacy_map",
+                  "key_types": [
+                    "core::starknet::contract_address::ContractAddress"
+                  ],
+                  "value_type": "core::integer::u256",
+                  "slots": 2
+                },
+                {
+                  "name": "allowances",
+                  "address": "0xbf4c436d6f8521e5c6189511c75075de702ad597ce22c1786275e8e5167ec7",
+                  "kind": "map",
+                  "key_types": [
+                    "core::starknet::contract_address::ContractAddress",
+                    "core::starknet::contract_address::ContractAddress"
+                  ],
+                  "value_type": "core::integer::u256",
+                  "slots": 2
+                },
+                {
+                  "name": "config",
+                  "address": "0x349c88cd3d1ba3c99fdd9a41ced95ec8629bda85e80b6c506c15db62ab8f761",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "test::TestContract::Config",
+                  "slots": 3
+                },
+                {
+                  "name": "name",
+                  "address": "0x361458367e696363fbcc70777d07ebbd2394e89fd0adcaf147faccd1d294d60",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "core::byte_array::ByteArray",
+                  "slots": 1
+                },
+                {
+                  "name": "wrapper",
+                  "address": "0x114e948546168f94e72ec7ba5bd4241120062c526ce1edc87e5161116db274c",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "test::TestContract::Wrapper",
+                  "slots": null
+                }
+              ]
+            }"#}
+    );
+}
+
+#[test]
+fn test_storage_layout_with_component() {
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let _crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[component]
+            mod Ownable {
+                use starknet::ContractAddress;
+
+                struct Storage {
+                    owner: ContractAddress,
+                }
+            }
+
+            #[contract]
+            mod TestContract {
+                #[embed]
+                use super::Ownable;
+
+                struct Storage {
+                    owner: felt252,
+                }
+            }
+        "},
+    );
+
+    let contracts = find_contracts(db, &db.crates());
+    assert_eq!(
+        get_storage_layout(db, &contracts[0]).unwrap().json(),
+        indoc! {r#"
+            {
+              "variables": [
+                {
+                  "name": "owner",
+                  "address": "0x2016836a56b71f0d02689e69e326f4f4c1b9057164ef592671cf0d37c8040c0",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "core::felt252",
+                  "slots": 1
+                },
+                {
+                  "name": "Ownable::owner",
+                  "address": "0x3db50198d2471ec1c5b126cf42805578fd6ddbfbfe01821f502e48da5e2e2f",
+                  "kind": "simple",
+                  "key_types": [],
+                  "value_type": "core::starknet::contract_address::ContractAddress",
+                  "slots": 1
+                }
+              ]
+            }"#}
+    );
+}
+
+/// Returns a storage variable for the layouts of the diff tests.
+fn variable(
+    name: &str,
+    address: u32,
+    kind: StorageVariableKind,
+    key_types: &[&str],
+    value_type: &str,
+    slots: Option<usize>,
+) -> StorageVariable {
+    StorageVariable {
+        name: name.to_string(),
+        address: address.into(),
+        kind,
+        key_types: key_types.iter().map(|ty| ty.to_string()).collect(),
+        value_type: value_type.to_string(),
+        slots,
+    }
+}
+
+#[test]
+fn test_diff_compatible_storage_layouts() {
+    let old = StorageLayout {
+        variables: vec![
+            variable("a", 10, StorageVariableKind::Simple, &[], "core::integer::u256", Some(2)),
+            variable(
+                "b",
+                20,
+                StorageVariableKind::LegacyMap,
+                &["core::felt252"],
+                "core::bool",
+                Some(1),
+            ),
+        ],
+    };
+    let mut new = old.clone();
+    new.variables.reverse();
+    new.variables.push(variable(
+        "c",
+        12,
+        StorageVariableKind::Simple,
+        &[],
+        "core::felt252",
+        Some(1),
+    ));
+    // Mapping entries are not stored contiguously, so they do not collide with simple variables.
+    new.variables.push(variable(
+        "d",
+        21,
+        StorageVariableKind::Map,
+        &["core::felt252"],
+        "core::felt252",
+        None,
+    ));
+    assert_eq!(
+        diff_storage_layouts(&old, &new),
+        vec![
+            StorageLayoutChange::Added { name: "c".to_string() },
+            StorageLayoutChange::Added { name: "d".to_string() },
+        ]
+    );
+    assert!(diff_storage_layouts(&old, &new).iter().all(StorageLayoutChange::is_compatible));
+}
+
+#[test]
+fn test_diff_incompatible_storage_layouts() {
+    let old = StorageLayout {
+        variables: vec![
+            variable("removed", 10, StorageVariableKind::Simple, &[], "core::felt252", Some(1)),
+            variable(
+                "kind",
+                20,
+                StorageVariableKind::LegacyMap,
+                &["core::felt252"],
+                "core::bool",
+                Some(1),
+            ),
+            variable(
+                "keys",
+                30,
+                StorageVariableKind::Map,
+                &["core::felt252"],
+                "core::bool",
+                Some(1),
+            ),
+            variable("value", 40, StorageVariableKind::Simple, &[], "core::integer::u128", Some(1)),
+            variable("slots", 50, StorageVariableKind::Simple, &[], "test::Config", Some(2)),
+        ],
+    };
+    let new = StorageLayout {
+        variables: vec![
+            variable(
+                "kind",
+                20,
+                StorageVariableKind::Map,
+                &["core::felt252"],
+                "core::bool",
+                Some(1),
+            ),
+            variable(
+                "keys",
+                30,
+                StorageVariableKind::Map,
+                &["core::felt252", "core::felt252"],
+                "core::bool",
+                Some(1),
+            ),
+            variable("value", 40, StorageVariableKind::Simple, &[], "core::integer::u256", Some(2)),
+            variable("slots", 50, StorageVariableKind::Simple, &[], "test::Config", None),
+            variable("added", 10, StorageVariableKind::Simple, &[], "core::felt252", Some(1)),
+            variable("overlapping", 41, StorageVariableKind::Simple, &[], "core::felt252", Some(1)),
+        ],
+    };
+    assert_eq!(
+        diff_storage_layouts(&old, &new)
+            .iter()
+            .map(|change| change.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "Storage variable `removed` was removed.",
+            "The kind of storage variable `kind` changed from `LegacyMap` to `Map`.",
+            "The key types of storage variable `keys` changed from `(core::felt252)` to \
+             `(core::felt252, core::felt252)`.",
+            "The value type of storage variable `value` changed from `core::integer::u128` to \
+             `core::integer::u256`.",
+            "The number of storage slots of `value` changed from 1 to 2.",
+            "The number of storage slots of `slots` changed from 2 to unknown.",
+            "Storage variable `added` was added.",
+            "Storage variable `overlapping` was added.",
+            "Storage variable `added` collides with the storage of `removed`.",
+            "Storage variable `overlapping` collides with the storage of `value`.",
+        ]
+    );
+    assert_eq!(
+        diff_storage_layouts(&old, &new)[0],
+        StorageLayoutChange::Removed { name: "removed".to_string() }
+    );
+}
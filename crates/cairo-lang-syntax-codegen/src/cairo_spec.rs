@@ -17,6 +17,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("Path")
         .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
         .node_with_explicit_kind("ShortString", "TerminalShortString")
+        .node_with_explicit_kind("String", "TerminalString")
         .node_with_explicit_kind("False", "TerminalFalse")
         .node_with_explicit_kind("True", "TerminalTrue")
         .node("Parenthesized")
@@ -147,7 +148,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_separated_list("MatchArms", "MatchArm", "TerminalComma")
     .add_struct(StructBuilder::new("MatchArm")
-        .node("pattern", "Pattern")
+        .node("patterns", "PatternListOr")
         .node("arrow", "TerminalMatchArrow")
         .node("expression", "Expr")
     )
@@ -207,18 +208,22 @@ pub fn get_spec() -> Vec<Node> {
         .node("rbrace", "TerminalRBrace")
     )
     // ---Patterns ---
-    // TODO(spapini): Support "Or" patterns (e.g. 1 | 2).
-    // TODO(spapini): Support tuple patterns (e.g. (x, _)).
     .add_enum(EnumBuilder::new("Pattern")
         .node_with_explicit_kind("Underscore", "TerminalUnderscore")
         .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
         .node_with_explicit_kind("ShortString", "TerminalShortString")
+        .node("Range")
         .node("Identifier")
         .node("Struct")
         .node("Tuple")
         .node("Enum")
         .node_with_explicit_kind("Path", "ExprPath")
     )
+    .add_struct(StructBuilder::new("PatternRange")
+        .node("start", "TerminalLiteralNumber")
+        .node("dotdoteq", "TerminalDotDotEq")
+        .node("end", "TerminalLiteralNumber")
+    )
     .add_struct(StructBuilder::new("PatternIdentifier")
         .node("modifiers", "ModifierList")
         .key_node("name", "TerminalIdentifier")
@@ -237,6 +242,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("rparen", "TerminalRParen")
     )
     .add_separated_list("PatternList", "Pattern", "TerminalComma")
+    .add_separated_list("PatternListOr", "Pattern", "TerminalOr")
     .add_enum(EnumBuilder::new("PatternStructParam")
         .node_with_explicit_kind("Single", "PatternIdentifier")
         .node("WithExpr")
@@ -396,8 +402,10 @@ pub fn get_spec() -> Vec<Node> {
         .node("value", "Expr")
         .node("semicolon", "TerminalSemicolon")
     )
+    .add_option("TerminalConst")
     .add_struct(StructBuilder::new("FunctionWithBody")
         .node("attributes" ,"AttributeList")
+        .node("optional_const", "OptionTerminalConst")
          // TODO(ilya): Use only the name as key node.
         .key_node("declaration", "FunctionDeclaration")
         .node("body", "ExprBlock")
@@ -437,10 +445,24 @@ pub fn get_spec() -> Vec<Node> {
     .add_list("TraitItemList", "TraitItem")
     .add_enum(EnumBuilder::new("TraitItem")
         .missing("Missing")
-        // TODO(spapini): types and constants.
         .node("Function")
+        .node("Type")
+        .node("Constant")
     )
     .add_struct(StructBuilder::new("TraitItemMissing"))
+    .add_struct(StructBuilder::new("TraitItemType")
+        .node("attributes" ,"AttributeList")
+        .node("type_kw", "TerminalType")
+        .key_node("name", "TerminalIdentifier")
+        .node("semicolon", "TerminalSemicolon")
+    )
+    .add_struct(StructBuilder::new("TraitItemConstant")
+        .node("attributes" ,"AttributeList")
+        .node("const_kw", "TerminalConst")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "TypeClause")
+        .node("semicolon", "TerminalSemicolon")
+    )
     .add_struct(StructBuilder::new("TraitItemFunction")
         .node("attributes" ,"AttributeList")
          // TODO(ilya): Use only the name as key node.
@@ -472,10 +494,10 @@ pub fn get_spec() -> Vec<Node> {
     .add_list("ImplItemList", "ImplItem")
     .add_enum(EnumBuilder::new("ImplItem")
         .missing("Missing")
-        // TODO(spapini): types and constants.
         .node_with_explicit_kind("Function", "FunctionWithBody")
-        // These are not supported semantically.
         .node_with_explicit_kind("Constant", "ItemConstant")
+        .node_with_explicit_kind("TypeAlias", "ItemTypeAlias")
+        // These are not supported semantically.
         .node_with_explicit_kind("Module", "ItemModule")
         .node_with_explicit_kind("Use", "ItemUse")
         .node_with_explicit_kind("ExternFunction", "ItemExternFunction")
@@ -485,7 +507,6 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("ImplAlias", "ItemImplAlias")
         .node_with_explicit_kind("Struct", "ItemStruct")
         .node_with_explicit_kind("Enum", "ItemEnum")
-        .node_with_explicit_kind("TypeAlias", "ItemTypeAlias")
     )
     .add_struct(StructBuilder::new("ImplItemMissing"))
     .add_struct(StructBuilder::new("ItemImplAlias")
@@ -603,6 +624,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
     .add_token_and_terminal("ShortString")
+    .add_token_and_terminal("String")
     .add_keyword_token_and_terminal("As")
     .add_keyword_token_and_terminal("Const")
     .add_keyword_token_and_terminal("Else")
@@ -641,6 +663,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("DivEq")
     .add_token_and_terminal("Dot")
     .add_token_and_terminal("DotDot")
+    .add_token_and_terminal("DotDotEq")
     .add_token_and_terminal("EndOfFile")
     .add_token_and_terminal("Eq")
     .add_token_and_terminal("EqEq")
@@ -180,6 +180,7 @@ pub enum Expr {
     Path(ExprPath),
     Literal(TerminalLiteralNumber),
     ShortString(TerminalShortString),
+    String(TerminalString),
     False(TerminalFalse),
     True(TerminalTrue),
     Parenthesized(ExprParenthesized),
@@ -220,6 +221,11 @@ impl From<TerminalShortStringPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<TerminalStringPtr> for ExprPtr {
+    fn from(value: TerminalStringPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalsePtr> for ExprPtr {
     fn from(value: TerminalFalsePtr) -> Self {
         Self(value.0)
@@ -320,6 +326,11 @@ impl From<TerminalShortStringGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<TerminalStringGreen> for ExprGreen {
+    fn from(value: TerminalStringGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalseGreen> for ExprGreen {
     fn from(value: TerminalFalseGreen) -> Self {
         Self(value.0)
@@ -424,6 +435,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::TerminalShortString => {
                 Expr::ShortString(TerminalShortString::from_syntax_node(db, node))
             }
+            SyntaxKind::TerminalString => Expr::String(TerminalString::from_syntax_node(db, node)),
             SyntaxKind::TerminalFalse => Expr::False(TerminalFalse::from_syntax_node(db, node)),
             SyntaxKind::TerminalTrue => Expr::True(TerminalTrue::from_syntax_node(db, node)),
             SyntaxKind::ExprParenthesized => {
@@ -461,6 +473,7 @@ impl TypedSyntaxNode for Expr {
             Expr::Path(x) => x.as_syntax_node(),
             Expr::Literal(x) => x.as_syntax_node(),
             Expr::ShortString(x) => x.as_syntax_node(),
+            Expr::String(x) => x.as_syntax_node(),
             Expr::False(x) => x.as_syntax_node(),
             Expr::True(x) => x.as_syntax_node(),
             Expr::Parenthesized(x) => x.as_syntax_node(),
@@ -494,6 +507,7 @@ impl Expr {
             SyntaxKind::ExprPath => true,
             SyntaxKind::TerminalLiteralNumber => true,
             SyntaxKind::TerminalShortString => true,
+            SyntaxKind::TerminalString => true,
             SyntaxKind::TerminalFalse => true,
             SyntaxKind::TerminalTrue => true,
             SyntaxKind::ExprParenthesized => true,
@@ -3054,16 +3068,16 @@ pub struct MatchArm {
     children: Vec<SyntaxNode>,
 }
 impl MatchArm {
-    pub const INDEX_PATTERN: usize = 0;
+    pub const INDEX_PATTERNS: usize = 0;
     pub const INDEX_ARROW: usize = 1;
     pub const INDEX_EXPRESSION: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        pattern: PatternGreen,
+        patterns: PatternListOrGreen,
         arrow: TerminalMatchArrowGreen,
         expression: ExprGreen,
     ) -> MatchArmGreen {
-        let children: Vec<GreenId> = vec![pattern.0, arrow.0, expression.0];
+        let children: Vec<GreenId> = vec![patterns.0, arrow.0, expression.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         MatchArmGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::MatchArm,
@@ -3072,8 +3086,8 @@ impl MatchArm {
     }
 }
 impl MatchArm {
-    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[0].clone())
+    pub fn patterns(&self, db: &dyn SyntaxGroup) -> PatternListOr {
+        PatternListOr::from_syntax_node(db, self.children[0].clone())
     }
     pub fn arrow(&self, db: &dyn SyntaxGroup) -> TerminalMatchArrow {
         TerminalMatchArrow::from_syntax_node(db, self.children[1].clone())
@@ -3100,7 +3114,7 @@ impl TypedSyntaxNode for MatchArm {
             kind: SyntaxKind::MatchArm,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    Pattern::missing(db).0,
+                    PatternListOr::missing(db).0,
                     TerminalMatchArrow::missing(db).0,
                     Expr::missing(db).0,
                 ],
@@ -4422,6 +4436,7 @@ pub enum Pattern {
     Underscore(TerminalUnderscore),
     Literal(TerminalLiteralNumber),
     ShortString(TerminalShortString),
+    Range(PatternRange),
     Identifier(PatternIdentifier),
     Struct(PatternStruct),
     Tuple(PatternTuple),
@@ -4450,6 +4465,11 @@ impl From<TerminalShortStringPtr> for PatternPtr {
         Self(value.0)
     }
 }
+impl From<PatternRangePtr> for PatternPtr {
+    fn from(value: PatternRangePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<PatternIdentifierPtr> for PatternPtr {
     fn from(value: PatternIdentifierPtr) -> Self {
         Self(value.0)
@@ -4490,6 +4510,11 @@ impl From<TerminalShortStringGreen> for PatternGreen {
         Self(value.0)
     }
 }
+impl From<PatternRangeGreen> for PatternGreen {
+    fn from(value: PatternRangeGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<PatternIdentifierGreen> for PatternGreen {
     fn from(value: PatternIdentifierGreen) -> Self {
         Self(value.0)
@@ -4536,6 +4561,7 @@ impl TypedSyntaxNode for Pattern {
             SyntaxKind::TerminalShortString => {
                 Pattern::ShortString(TerminalShortString::from_syntax_node(db, node))
             }
+            SyntaxKind::PatternRange => Pattern::Range(PatternRange::from_syntax_node(db, node)),
             SyntaxKind::PatternIdentifier => {
                 Pattern::Identifier(PatternIdentifier::from_syntax_node(db, node))
             }
@@ -4551,6 +4577,7 @@ impl TypedSyntaxNode for Pattern {
             Pattern::Underscore(x) => x.as_syntax_node(),
             Pattern::Literal(x) => x.as_syntax_node(),
             Pattern::ShortString(x) => x.as_syntax_node(),
+            Pattern::Range(x) => x.as_syntax_node(),
             Pattern::Identifier(x) => x.as_syntax_node(),
             Pattern::Struct(x) => x.as_syntax_node(),
             Pattern::Tuple(x) => x.as_syntax_node(),
@@ -4572,6 +4599,7 @@ impl Pattern {
             SyntaxKind::TerminalUnderscore => true,
             SyntaxKind::TerminalLiteralNumber => true,
             SyntaxKind::TerminalShortString => true,
+            SyntaxKind::PatternRange => true,
             SyntaxKind::PatternIdentifier => true,
             SyntaxKind::PatternStruct => true,
             SyntaxKind::PatternTuple => true,
@@ -4582,6 +4610,88 @@ impl Pattern {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PatternRange {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl PatternRange {
+    pub const INDEX_START: usize = 0;
+    pub const INDEX_DOTDOTEQ: usize = 1;
+    pub const INDEX_END: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        start: TerminalLiteralNumberGreen,
+        dotdoteq: TerminalDotDotEqGreen,
+        end: TerminalLiteralNumberGreen,
+    ) -> PatternRangeGreen {
+        let children: Vec<GreenId> = vec![start.0, dotdoteq.0, end.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        PatternRangeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternRange,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl PatternRange {
+    pub fn start(&self, db: &dyn SyntaxGroup) -> TerminalLiteralNumber {
+        TerminalLiteralNumber::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn dotdoteq(&self, db: &dyn SyntaxGroup) -> TerminalDotDotEq {
+        TerminalDotDotEq::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn end(&self, db: &dyn SyntaxGroup) -> TerminalLiteralNumber {
+        TerminalLiteralNumber::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternRangePtr(pub SyntaxStablePtrId);
+impl PatternRangePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternRangeGreen(pub GreenId);
+impl TypedSyntaxNode for PatternRange {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternRange);
+    type StablePtr = PatternRangePtr;
+    type Green = PatternRangeGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        PatternRangeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternRange,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLiteralNumber::missing(db).0,
+                    TerminalDotDotEq::missing(db).0,
+                    TerminalLiteralNumber::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::PatternRange,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::PatternRange
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        PatternRangePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct PatternIdentifier {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
@@ -4989,6 +5099,84 @@ impl TypedSyntaxNode for PatternList {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PatternListOr(ElementList<Pattern, 2>);
+impl Deref for PatternListOr {
+    type Target = ElementList<Pattern, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl PatternListOr {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<PatternListOrElementOrSeparatorGreen>,
+    ) -> PatternListOrGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        PatternListOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternListOr,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternListOrPtr(pub SyntaxStablePtrId);
+impl PatternListOrPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PatternListOrElementOrSeparatorGreen {
+    Separator(TerminalOrGreen),
+    Element(PatternGreen),
+}
+impl From<TerminalOrGreen> for PatternListOrElementOrSeparatorGreen {
+    fn from(value: TerminalOrGreen) -> Self {
+        PatternListOrElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<PatternGreen> for PatternListOrElementOrSeparatorGreen {
+    fn from(value: PatternGreen) -> Self {
+        PatternListOrElementOrSeparatorGreen::Element(value)
+    }
+}
+impl PatternListOrElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            PatternListOrElementOrSeparatorGreen::Separator(green) => green.0,
+            PatternListOrElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternListOrGreen(pub GreenId);
+impl TypedSyntaxNode for PatternListOr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternListOr);
+    type StablePtr = PatternListOrPtr;
+    type Green = PatternListOrGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        PatternListOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternListOr,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        PatternListOrPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum PatternStructParam {
     Single(PatternIdentifier),
     WithExpr(PatternStructParamWithExpr),
@@ -8435,21 +8623,159 @@ impl TypedSyntaxNode for ItemConstant {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionTerminalConst {
+    Empty(OptionTerminalConstEmpty),
+    TerminalConst(TerminalConst),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalConstPtr(pub SyntaxStablePtrId);
+impl OptionTerminalConstPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionTerminalConstEmptyPtr> for OptionTerminalConstPtr {
+    fn from(value: OptionTerminalConstEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalConstPtr> for OptionTerminalConstPtr {
+    fn from(value: TerminalConstPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionTerminalConstEmptyGreen> for OptionTerminalConstGreen {
+    fn from(value: OptionTerminalConstEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalConstGreen> for OptionTerminalConstGreen {
+    fn from(value: TerminalConstGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalConstGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionTerminalConstPtr;
+    type Green = OptionTerminalConstGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionTerminalConstEmpty => {
+                OptionTerminalConst::Empty(OptionTerminalConstEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalConst => {
+                OptionTerminalConst::TerminalConst(TerminalConst::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionTerminalConst"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionTerminalConst::Empty(x) => x.as_syntax_node(),
+            OptionTerminalConst::TerminalConst(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionTerminalConstPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl OptionTerminalConst {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::OptionTerminalConstEmpty => true,
+            SyntaxKind::TerminalConst => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionTerminalConstEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionTerminalConstEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTerminalConstEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionTerminalConstEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalConstEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionTerminalConstEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalConstEmptyPtr(pub SyntaxStablePtrId);
+impl OptionTerminalConstEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalConstEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalConstEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTerminalConstEmpty);
+    type StablePtr = OptionTerminalConstEmptyPtr;
+    type Green = OptionTerminalConstEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionTerminalConstEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalConstEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionTerminalConstEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionTerminalConstEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionTerminalConstEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct FunctionWithBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
 impl FunctionWithBody {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
+    pub const INDEX_OPTIONAL_CONST: usize = 1;
+    pub const INDEX_DECLARATION: usize = 2;
+    pub const INDEX_BODY: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
+        optional_const: OptionTerminalConstGreen,
         declaration: FunctionDeclarationGreen,
         body: ExprBlockGreen,
     ) -> FunctionWithBodyGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        let children: Vec<GreenId> = vec![attributes.0, optional_const.0, declaration.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         FunctionWithBodyGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::FunctionWithBody,
@@ -8461,11 +8787,14 @@ impl FunctionWithBody {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
+    pub fn optional_const(&self, db: &dyn SyntaxGroup) -> OptionTerminalConst {
+        OptionTerminalConst::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+        FunctionDeclaration::from_syntax_node(db, self.children[2].clone())
     }
     pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
-        ExprBlock::from_syntax_node(db, self.children[2].clone())
+        ExprBlock::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -8495,6 +8824,7 @@ impl TypedSyntaxNode for FunctionWithBody {
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
+                    OptionTerminalConst::missing(db).0,
                     FunctionDeclaration::missing(db).0,
                     ExprBlock::missing(db).0,
                 ],
@@ -9045,6 +9375,8 @@ impl TypedSyntaxNode for TraitItemList {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum TraitItem {
     Function(TraitItemFunction),
+    Type(TraitItemType),
+    Constant(TraitItemConstant),
     Missing(TraitItemMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -9059,6 +9391,16 @@ impl From<TraitItemFunctionPtr> for TraitItemPtr {
         Self(value.0)
     }
 }
+impl From<TraitItemTypePtr> for TraitItemPtr {
+    fn from(value: TraitItemTypePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TraitItemConstantPtr> for TraitItemPtr {
+    fn from(value: TraitItemConstantPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TraitItemMissingPtr> for TraitItemPtr {
     fn from(value: TraitItemMissingPtr) -> Self {
         Self(value.0)
@@ -9069,6 +9411,16 @@ impl From<TraitItemFunctionGreen> for TraitItemGreen {
         Self(value.0)
     }
 }
+impl From<TraitItemTypeGreen> for TraitItemGreen {
+    fn from(value: TraitItemTypeGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TraitItemConstantGreen> for TraitItemGreen {
+    fn from(value: TraitItemConstantGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TraitItemMissingGreen> for TraitItemGreen {
     fn from(value: TraitItemMissingGreen) -> Self {
         Self(value.0)
@@ -9089,6 +9441,10 @@ impl TypedSyntaxNode for TraitItem {
             SyntaxKind::TraitItemFunction => {
                 TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
             }
+            SyntaxKind::TraitItemType => TraitItem::Type(TraitItemType::from_syntax_node(db, node)),
+            SyntaxKind::TraitItemConstant => {
+                TraitItem::Constant(TraitItemConstant::from_syntax_node(db, node))
+            }
             SyntaxKind::TraitItemMissing => {
                 TraitItem::Missing(TraitItemMissing::from_syntax_node(db, node))
             }
@@ -9098,6 +9454,8 @@ impl TypedSyntaxNode for TraitItem {
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
             TraitItem::Function(x) => x.as_syntax_node(),
+            TraitItem::Type(x) => x.as_syntax_node(),
+            TraitItem::Constant(x) => x.as_syntax_node(),
             TraitItem::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -9113,6 +9471,8 @@ impl TraitItem {
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
             SyntaxKind::TraitItemFunction => true,
+            SyntaxKind::TraitItemType => true,
+            SyntaxKind::TraitItemConstant => true,
             SyntaxKind::TraitItemMissing => true,
             _ => false,
         }
@@ -9176,41 +9536,240 @@ impl TypedSyntaxNode for TraitItemMissing {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemFunction {
+pub struct TraitItemType {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitItemFunction {
+impl TraitItemType {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
+    pub const INDEX_TYPE_KW: usize = 1;
+    pub const INDEX_NAME: usize = 2;
+    pub const INDEX_SEMICOLON: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        declaration: FunctionDeclarationGreen,
-        body: MaybeTraitFunctionBodyGreen,
-    ) -> TraitItemFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        type_kw: TerminalTypeGreen,
+        name: TerminalIdentifierGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> TraitItemTypeGreen {
+        let children: Vec<GreenId> = vec![attributes.0, type_kw.0, name.0, semicolon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        TraitItemTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemType,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl TraitItemFunction {
+impl TraitItemType {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
+        TerminalType::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
-        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
+pub struct TraitItemTypePtr(pub SyntaxStablePtrId);
+impl TraitItemTypePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemTypeGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemType);
+    type StablePtr = TraitItemTypePtr;
+    type Green = TraitItemTypeGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitItemTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemType,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalType::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TraitItemType,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TraitItemType
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemTypePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitItemConstant {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl TraitItemConstant {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_CONST_KW: usize = 1;
+    pub const INDEX_NAME: usize = 2;
+    pub const INDEX_TYPE_CLAUSE: usize = 3;
+    pub const INDEX_SEMICOLON: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        const_kw: TerminalConstGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: TypeClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> TraitItemConstantGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, const_kw.0, name.0, type_clause.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TraitItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemConstant,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl TraitItemConstant {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
+        TerminalConst::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemConstantPtr(pub SyntaxStablePtrId);
+impl TraitItemConstantPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemConstantGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemConstant {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemConstant);
+    type StablePtr = TraitItemConstantPtr;
+    type Green = TraitItemConstantGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemConstant,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalConst::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TraitItemConstant,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TraitItemConstant
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemConstantPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitItemFunction {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl TraitItemFunction {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_DECLARATION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        declaration: FunctionDeclarationGreen,
+        body: MaybeTraitFunctionBodyGreen,
+    ) -> TraitItemFunctionGreen {
+        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl TraitItemFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
+        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
 impl TraitItemFunctionPtr {
     pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
@@ -9671,6 +10230,7 @@ impl TypedSyntaxNode for ImplItemList {
 pub enum ImplItem {
     Function(FunctionWithBody),
     Constant(ItemConstant),
+    TypeAlias(ItemTypeAlias),
     Module(ItemModule),
     Use(ItemUse),
     ExternFunction(ItemExternFunction),
@@ -9680,7 +10240,6 @@ pub enum ImplItem {
     ImplAlias(ItemImplAlias),
     Struct(ItemStruct),
     Enum(ItemEnum),
-    TypeAlias(ItemTypeAlias),
     Missing(ImplItemMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -9700,6 +10259,11 @@ impl From<ItemConstantPtr> for ImplItemPtr {
         Self(value.0)
     }
 }
+impl From<ItemTypeAliasPtr> for ImplItemPtr {
+    fn from(value: ItemTypeAliasPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ItemModulePtr> for ImplItemPtr {
     fn from(value: ItemModulePtr) -> Self {
         Self(value.0)
@@ -9745,11 +10309,6 @@ impl From<ItemEnumPtr> for ImplItemPtr {
         Self(value.0)
     }
 }
-impl From<ItemTypeAliasPtr> for ImplItemPtr {
-    fn from(value: ItemTypeAliasPtr) -> Self {
-        Self(value.0)
-    }
-}
 impl From<ImplItemMissingPtr> for ImplItemPtr {
     fn from(value: ImplItemMissingPtr) -> Self {
         Self(value.0)
@@ -9765,6 +10324,11 @@ impl From<ItemConstantGreen> for ImplItemGreen {
         Self(value.0)
     }
 }
+impl From<ItemTypeAliasGreen> for ImplItemGreen {
+    fn from(value: ItemTypeAliasGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ItemModuleGreen> for ImplItemGreen {
     fn from(value: ItemModuleGreen) -> Self {
         Self(value.0)
@@ -9810,11 +10374,6 @@ impl From<ItemEnumGreen> for ImplItemGreen {
         Self(value.0)
     }
 }
-impl From<ItemTypeAliasGreen> for ImplItemGreen {
-    fn from(value: ItemTypeAliasGreen) -> Self {
-        Self(value.0)
-    }
-}
 impl From<ImplItemMissingGreen> for ImplItemGreen {
     fn from(value: ImplItemMissingGreen) -> Self {
         Self(value.0)
@@ -9838,6 +10397,9 @@ impl TypedSyntaxNode for ImplItem {
             SyntaxKind::ItemConstant => {
                 ImplItem::Constant(ItemConstant::from_syntax_node(db, node))
             }
+            SyntaxKind::ItemTypeAlias => {
+                ImplItem::TypeAlias(ItemTypeAlias::from_syntax_node(db, node))
+            }
             SyntaxKind::ItemModule => ImplItem::Module(ItemModule::from_syntax_node(db, node)),
             SyntaxKind::ItemUse => ImplItem::Use(ItemUse::from_syntax_node(db, node)),
             SyntaxKind::ItemExternFunction => {
@@ -9853,9 +10415,6 @@ impl TypedSyntaxNode for ImplItem {
             }
             SyntaxKind::ItemStruct => ImplItem::Struct(ItemStruct::from_syntax_node(db, node)),
             SyntaxKind::ItemEnum => ImplItem::Enum(ItemEnum::from_syntax_node(db, node)),
-            SyntaxKind::ItemTypeAlias => {
-                ImplItem::TypeAlias(ItemTypeAlias::from_syntax_node(db, node))
-            }
             SyntaxKind::ImplItemMissing => {
                 ImplItem::Missing(ImplItemMissing::from_syntax_node(db, node))
             }
@@ -9866,6 +10425,7 @@ impl TypedSyntaxNode for ImplItem {
         match self {
             ImplItem::Function(x) => x.as_syntax_node(),
             ImplItem::Constant(x) => x.as_syntax_node(),
+            ImplItem::TypeAlias(x) => x.as_syntax_node(),
             ImplItem::Module(x) => x.as_syntax_node(),
             ImplItem::Use(x) => x.as_syntax_node(),
             ImplItem::ExternFunction(x) => x.as_syntax_node(),
@@ -9875,7 +10435,6 @@ impl TypedSyntaxNode for ImplItem {
             ImplItem::ImplAlias(x) => x.as_syntax_node(),
             ImplItem::Struct(x) => x.as_syntax_node(),
             ImplItem::Enum(x) => x.as_syntax_node(),
-            ImplItem::TypeAlias(x) => x.as_syntax_node(),
             ImplItem::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -9892,6 +10451,7 @@ impl ImplItem {
         match kind {
             SyntaxKind::FunctionWithBody => true,
             SyntaxKind::ItemConstant => true,
+            SyntaxKind::ItemTypeAlias => true,
             SyntaxKind::ItemModule => true,
             SyntaxKind::ItemUse => true,
             SyntaxKind::ItemExternFunction => true,
@@ -9901,7 +10461,6 @@ impl ImplItem {
             SyntaxKind::ItemImplAlias => true,
             SyntaxKind::ItemStruct => true,
             SyntaxKind::ItemEnum => true,
-            SyntaxKind::ItemTypeAlias => true,
             SyntaxKind::ImplItemMissing => true,
             _ => false,
         }
@@ -12542,6 +13101,147 @@ impl TypedSyntaxNode for TerminalShortString {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenString {
+    node: SyntaxNode,
+}
+impl Token for TokenString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenString,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenStringPtr(pub SyntaxStablePtrId);
+impl TokenStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenStringGreen(pub GreenId);
+impl TokenStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenString);
+    type StablePtr = TokenStringPtr;
+    type Green = TokenStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenString)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalString {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalString;
+    type TokenType = TokenString;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenString {
+        TokenString::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalStringPtr(pub SyntaxStablePtrId);
+impl TerminalStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalString);
+    type StablePtr = TerminalStringPtr;
+    type Green = TerminalStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenString::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalString,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalString
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenAs {
     node: SyntaxNode,
 }
@@ -17901,6 +18601,147 @@ impl TypedSyntaxNode for TerminalDotDot {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenDotDotEq {
+    node: SyntaxNode,
+}
+impl Token for TokenDotDotEq {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenDotDotEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenDotDotEq,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenDotDotEqPtr(pub SyntaxStablePtrId);
+impl TokenDotDotEqPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenDotDotEqGreen(pub GreenId);
+impl TokenDotDotEqGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenDotDotEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDotDotEq);
+    type StablePtr = TokenDotDotEqPtr;
+    type Green = TokenDotDotEqGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenDotDotEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDotDotEq)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenDotDotEqPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalDotDotEq {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalDotDotEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDotDotEq;
+    type TokenType = TokenDotDotEq;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalDotDotEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalDotDotEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDotDotEq,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalDotDotEq {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDotDotEq {
+        TokenDotDotEq::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalDotDotEqPtr(pub SyntaxStablePtrId);
+impl TerminalDotDotEqPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalDotDotEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDotDotEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDotDotEq);
+    type StablePtr = TerminalDotDotEqPtr;
+    type Green = TerminalDotDotEqGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalDotDotEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDotDotEq,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenDotDotEq::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalDotDotEq,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalDotDotEq
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalDotDotEqPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenEndOfFile {
     node: SyntaxNode,
 }
@@ -7,7 +7,9 @@ use num_traits::Num;
 use smol_str::SmolStr;
 use unescaper::unescape;
 
-use super::{TerminalFalse, TerminalLiteralNumber, TerminalShortString, TerminalTrue};
+use super::{
+    TerminalFalse, TerminalLiteralNumber, TerminalShortString, TerminalString, TerminalTrue,
+};
 use crate::node::db::SyntaxGroup;
 use crate::node::Terminal;
 
@@ -99,3 +101,26 @@ impl TerminalShortString {
         Some(suffix.into())
     }
 }
+
+impl TerminalString {
+    /// Interpret this token/terminal as a string.
+    pub fn string_value(&self, db: &dyn SyntaxGroup) -> Option<String> {
+        let text = self.text(db);
+        let (prefix, text) = text.split_once('"')?;
+        if !prefix.is_empty() {
+            return None;
+        }
+        let (text, suffix) = text.rsplit_once('"')?;
+        if !suffix.is_empty() {
+            return None;
+        }
+
+        let text = unescape(text).ok()?;
+
+        if !text.is_ascii() {
+            return None;
+        }
+
+        Some(text)
+    }
+}
@@ -8,7 +8,8 @@ use super::ast::{
     TraitItemFunctionPtr,
 };
 use super::db::SyntaxGroup;
-use super::Terminal;
+use super::kind::SyntaxKind;
+use super::{SyntaxNode, Terminal};
 use crate::node::ast::{Attribute, AttributeList};
 use crate::node::green::GreenNodeDetails;
 
@@ -171,6 +172,50 @@ pub trait QueryAttrs {
         self.find_attr(db, attr).is_some()
     }
 }
+
+/// Trait for getting the doc comments (`///`) of AST items.
+pub trait GetDocComments {
+    /// Returns the text of the doc comments of the item, without their `///` prefixes, or None if
+    /// it has none. Doc comments are written before the item, or among its attributes.
+    fn doc_comments(&self, db: &dyn SyntaxGroup) -> Option<String>;
+}
+impl GetDocComments for SyntaxNode {
+    fn doc_comments(&self, db: &dyn SyntaxGroup) -> Option<String> {
+        let mut lines = vec![];
+        for child in self.children(db) {
+            let is_attribute_list = child.kind(db) == SyntaxKind::AttributeList;
+            let parts: Vec<_> =
+                if is_attribute_list { child.children(db).collect() } else { vec![child] };
+            let mut found_terminal = false;
+            for part in parts {
+                // The leading trivia of a terminal is its first child.
+                let Some(terminal) = part.descendants(db).find(|node| node.kind(db).is_terminal())
+                else {
+                    continue;
+                };
+                found_terminal = true;
+                let leading_trivia = terminal.children(db).next().into_iter();
+                for trivium in leading_trivia.flat_map(|trivia| trivia.children(db)) {
+                    if trivium.kind(db) != SyntaxKind::TokenSingleLineComment {
+                        continue;
+                    }
+                    let text = trivium.text(db).unwrap_or_default();
+                    match text.strip_prefix("///") {
+                        // Comments starting with four slashes are not doc comments.
+                        Some(line) if !line.starts_with('/') => lines
+                            .push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            if found_terminal && !is_attribute_list {
+                break;
+            }
+        }
+        if lines.is_empty() { None } else { Some(lines.join("\n")) }
+    }
+}
+
 impl QueryAttrs for ItemConstant {
     fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
         self.attributes(db).elements(db)
@@ -108,6 +108,9 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ArgListBraced => {
             vec![]
         }
+        SyntaxKind::PatternRange => {
+            vec![]
+        }
         SyntaxKind::PatternIdentifier => {
             vec![/* name */ children[1]]
         }
@@ -119,6 +122,7 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![]
         }
         SyntaxKind::PatternList => vec![],
+        SyntaxKind::PatternListOr => vec![],
         SyntaxKind::PatternStructParamWithExpr => {
             vec![]
         }
@@ -201,8 +205,11 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ItemConstant => {
             vec![/* name */ children[2]]
         }
+        SyntaxKind::OptionTerminalConstEmpty => {
+            vec![]
+        }
         SyntaxKind::FunctionWithBody => {
-            vec![/* declaration */ children[1]]
+            vec![/* declaration */ children[2]]
         }
         SyntaxKind::ItemExternFunction => {
             vec![/* declaration */ children[2]]
@@ -220,6 +227,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TraitItemMissing => {
             vec![]
         }
+        SyntaxKind::TraitItemType => {
+            vec![/* name */ children[2]]
+        }
+        SyntaxKind::TraitItemConstant => {
+            vec![/* name */ children[2]]
+        }
         SyntaxKind::TraitItemFunction => {
             vec![/* declaration */ children[1]]
         }
@@ -299,6 +312,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalShortString => {
             vec![]
         }
+        SyntaxKind::TokenString => vec![],
+        SyntaxKind::TerminalString => {
+            vec![]
+        }
         SyntaxKind::TokenAs => vec![],
         SyntaxKind::TerminalAs => {
             vec![]
@@ -451,6 +468,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalDotDot => {
             vec![]
         }
+        SyntaxKind::TokenDotDotEq => vec![],
+        SyntaxKind::TerminalDotDotEq => {
+            vec![]
+        }
         SyntaxKind::TokenEndOfFile => vec![],
         SyntaxKind::TerminalEndOfFile => {
             vec![]
@@ -40,11 +40,13 @@ pub enum SyntaxKind {
     StructArgTail,
     StructArgList,
     ArgListBraced,
+    PatternRange,
     PatternIdentifier,
     PatternStruct,
     PatternStructParamList,
     PatternTuple,
     PatternList,
+    PatternListOr,
     PatternStructParamWithExpr,
     PatternEnum,
     TypeClause,
@@ -77,6 +79,7 @@ pub enum SyntaxKind {
     ModuleBody,
     FunctionDeclaration,
     ItemConstant,
+    OptionTerminalConstEmpty,
     FunctionWithBody,
     ItemExternFunction,
     ItemExternType,
@@ -84,6 +87,8 @@ pub enum SyntaxKind {
     TraitBody,
     TraitItemList,
     TraitItemMissing,
+    TraitItemType,
+    TraitItemConstant,
     TraitItemFunction,
     ItemImpl,
     ImplBody,
@@ -115,6 +120,8 @@ pub enum SyntaxKind {
     TerminalLiteralNumber,
     TokenShortString,
     TerminalShortString,
+    TokenString,
+    TerminalString,
     TokenAs,
     TerminalAs,
     TokenConst,
@@ -191,6 +198,8 @@ pub enum SyntaxKind {
     TerminalDot,
     TokenDotDot,
     TerminalDotDot,
+    TokenDotDotEq,
+    TerminalDotDotEq,
     TokenEndOfFile,
     TerminalEndOfFile,
     TokenEq,
@@ -269,6 +278,7 @@ impl SyntaxKind {
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
                 | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenString
                 | SyntaxKind::TokenAs
                 | SyntaxKind::TokenConst
                 | SyntaxKind::TokenElse
@@ -307,6 +317,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenDivEq
                 | SyntaxKind::TokenDot
                 | SyntaxKind::TokenDotDot
+                | SyntaxKind::TokenDotDotEq
                 | SyntaxKind::TokenEndOfFile
                 | SyntaxKind::TokenEq
                 | SyntaxKind::TokenEqEq
@@ -352,6 +363,7 @@ impl SyntaxKind {
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
                 | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalString
                 | SyntaxKind::TerminalAs
                 | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalElse
@@ -390,6 +402,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalDivEq
                 | SyntaxKind::TerminalDot
                 | SyntaxKind::TerminalDotDot
+                | SyntaxKind::TerminalDotDotEq
                 | SyntaxKind::TerminalEndOfFile
                 | SyntaxKind::TerminalEq
                 | SyntaxKind::TerminalEqEq
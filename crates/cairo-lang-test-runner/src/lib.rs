@@ -32,6 +32,7 @@ use cairo_lang_starknet::plugin::StarkNetPlugin;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use colored::Colorize;
 use itertools::{chain, Itertools};
+use num_traits::ToPrimitive;
 use plugin::TestPlugin;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
@@ -114,8 +115,38 @@ impl TestRunner {
         })
     }
 
-    /// Runs the tests and process the results for a summary.
-    pub fn run(&self) -> Result<Option<TestsSummary>> {
+    /// Configure a test runner for crates of an existing database, configured with the `test`
+    /// cfg.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The database of the crates
+    /// * `main_crate_ids` - The crates to run the tests of
+    /// * `filter` - Run only tests containing the filter string
+    /// * `include_ignored` - Include ignored tests as well
+    /// * `starknet` - Whether the starknet plugin is added to the database
+    pub fn with_db(
+        db: RootDatabase,
+        main_crate_ids: Vec<CrateId>,
+        filter: &str,
+        include_ignored: bool,
+        starknet: bool,
+    ) -> Self {
+        Self {
+            db,
+            main_crate_ids,
+            filter: filter.into(),
+            include_ignored,
+            ignored: false,
+            starknet,
+            mocked_addresses: HashMap::new(),
+            show_mock: false,
+        }
+    }
+
+    /// Compiles the tests matching the filter, along with the contracts they may call. The
+    /// compiled tests do not refer to the database, so they can be run after it is released.
+    pub fn compile(&self) -> Result<CompiledTests> {
         let db = &self.db;
 
         let all_entry_points = if self.starknet {
@@ -187,9 +218,29 @@ impl TestRunner {
           .collect_vec();
         let filtered_out = total_tests_count - named_tests.len();
         let contracts_info = get_contracts_info(db, self.main_crate_ids.clone(), &replacer)?;
+        Ok(CompiledTests {
+            named_tests,
+            sierra_program,
+            function_set_costs,
+            contracts_info,
+            mocked_addresses: self.mocked_addresses.clone(),
+            filtered_out,
+        })
+    }
+
+    /// Runs the tests and process the results for a summary.
+    pub fn run(&self) -> Result<Option<TestsSummary>> {
+        let CompiledTests {
+            named_tests,
+            sierra_program,
+            function_set_costs,
+            contracts_info,
+            mocked_addresses,
+            filtered_out,
+        } = self.compile()?;
 
         let TestsSummary { passed, failed, ignored, failed_run_results } =
-            run_tests(named_tests, sierra_program, function_set_costs, contracts_info, &self.mocked_addresses, self.show_mock)?;
+            run_tests(named_tests, sierra_program, function_set_costs, contracts_info, &mocked_addresses, self.show_mock)?;
         if failed.is_empty() {
             println!(
                 "\ntest result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -231,13 +282,62 @@ impl TestRunner {
     }
 }
 
+/// The tests to run, compiled into a Sierra program.
+pub struct CompiledTests {
+    named_tests: Vec<(String, TestConfig)>,
+    sierra_program: cairo_lang_sierra::program::Program,
+    function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
+    contracts_info: HashMap<Felt252, ContractInfo>,
+    mocked_addresses: HashMap<String, MockConfig>,
+    filtered_out: usize,
+}
+
+impl CompiledTests {
+    /// Runs the tests without printing anything, and returns the name and the result of each
+    /// test.
+    pub fn run_silently(self) -> Result<Vec<(String, TestResult)>> {
+        let runner = SierraCasmRunner::new(
+            self.sierra_program,
+            Some(MetadataComputationConfig { function_set_costs: self.function_set_costs }),
+            self.contracts_info.clone(),
+        )
+        .with_context(|| "Failed setting up runner.")?;
+        self.named_tests
+            .into_iter()
+            .map(|(name, test)| {
+                let result = if test.ignored {
+                    TestResult { status: TestStatus::Ignore, gas_used: None }
+                } else {
+                    run_test(
+                        &runner,
+                        &name,
+                        test,
+                        &self.contracts_info,
+                        &self.mocked_addresses,
+                        false,
+                        false,
+                    )?
+                };
+                Ok((name, result))
+            })
+            .collect()
+    }
+}
+
 /// The status of a ran test.
-enum TestStatus {
+pub enum TestStatus {
     Success,
     Fail(RunResultValue),
     Ignore,
 }
 
+/// The result of a ran test.
+pub struct TestResult {
+    pub status: TestStatus,
+    /// The gas used by the test, if it had available gas.
+    pub gas_used: Option<usize>,
+}
+
 /// Summary data of the ran tests.
 pub struct TestsSummary {
     passed: Vec<String>,
@@ -285,55 +385,19 @@ fn run_tests(
             }
 
             let mut is_first_m = is_first.lock().expect("can't acquire mutex is_first");
-
-            // New state for each test.
-            // The mocked addresses found in the JSON are always
-            // inserted.
-            let mut starknet_state_mocked = Default::default();
-            mock::starknet_add_mocked_addresses(
-                &mut starknet_state_mocked,
-                mocked_addresses,
-                &contracts_info,
-                ".caironet.json",
-                show_mock && *is_first_m,
-            )?;
-
+            let show_project_mock = show_mock && *is_first_m;
             *is_first_m = false;
 
-            mock::starknet_add_mocked_addresses(
-                &mut starknet_state_mocked,
-                &test.caironet,
-                &contracts_info,
+            let result = run_test(
+                &runner,
                 &name,
+                test,
+                &contracts_info,
+                mocked_addresses,
+                show_project_mock,
                 show_mock,
             )?;
-
-            let result = runner
-                .run_function(
-                    runner.find_function(name.as_str())?,
-                    &[],
-                    test.available_gas,
-                    starknet_state_mocked,
-                )
-                .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-            Ok((
-                name,
-                match &result.value {
-                    RunResultValue::Success(_) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
-                    },
-                    RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
-                        TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                            PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
-                            }
-                            _ => TestStatus::Success,
-                        },
-                    },
-                },
-            ))
+            Ok((name, result.status))
         })
         .for_each(|r| {
             let mut wrapped_summary = wrapped_summary.lock().unwrap();
@@ -362,6 +426,66 @@ fn run_tests(
     wrapped_summary.into_inner().unwrap()
 }
 
+/// Runs a test with the mocked addresses of the project and of the test, and returns its result.
+fn run_test(
+    runner: &SierraCasmRunner,
+    name: &str,
+    test: TestConfig,
+    contracts_info: &HashMap<Felt252, ContractInfo>,
+    mocked_addresses: &HashMap<String, MockConfig>,
+    show_project_mock: bool,
+    show_mock: bool,
+) -> anyhow::Result<TestResult> {
+    // New state for each test.
+    // The mocked addresses found in the JSON are always
+    // inserted.
+    let mut starknet_state_mocked = Default::default();
+    mock::starknet_add_mocked_addresses(
+        &mut starknet_state_mocked,
+        mocked_addresses,
+        contracts_info,
+        ".caironet.json",
+        show_project_mock,
+    )?;
+
+    mock::starknet_add_mocked_addresses(
+        &mut starknet_state_mocked,
+        &test.caironet,
+        contracts_info,
+        name,
+        show_mock,
+    )?;
+
+    let result = runner
+        .run_function(
+            runner.find_function(name)?,
+            &[],
+            test.available_gas,
+            starknet_state_mocked,
+        )
+        .with_context(|| format!("Failed to run the function `{name}`."))?;
+    let gas_used = test
+        .available_gas
+        .zip(result.gas_counter.as_ref())
+        .and_then(|(available_gas, gas_counter)| Some(available_gas - gas_counter.to_usize()?));
+    let status = match &result.value {
+        RunResultValue::Success(_) => match test.expectation {
+            TestExpectation::Success => TestStatus::Success,
+            TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+        },
+        RunResultValue::Panic(value) => match test.expectation {
+            TestExpectation::Success => TestStatus::Fail(result.value),
+            TestExpectation::Panics(panic_expectation) => match panic_expectation {
+                PanicExpectation::Exact(expected) if value != &expected => {
+                    TestStatus::Fail(result.value)
+                }
+                _ => TestStatus::Success,
+            },
+        },
+    };
+    Ok(TestResult { status, gas_used })
+}
+
 /// Finds the tests in the requested crates.
 fn find_all_tests(
     db: &dyn SemanticGroup,
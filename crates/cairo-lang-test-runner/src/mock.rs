@@ -22,7 +22,7 @@ use cairo_lang_runner::StarknetState;
 use cairo_lang_starknet::contract::ContractInfo;
 
 /// Mock configuration for a contract address.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum MockConfig {
     /// Only one address is represented by a single string.
     /// Example: "Contract1": "0x1".
@@ -39,6 +39,7 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         bitwise: "bitwise",
         bool: "bool",
         box_: "box",
+        bytes31: "bytes31",
         builtin_costs: "builtin_costs",
         casts: "casts",
         ec: "ec",